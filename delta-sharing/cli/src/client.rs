@@ -13,6 +13,7 @@ pub struct ClientCommand {
 #[derive(Debug, Subcommand)]
 enum ClientCommands {
     Catalogs(CatalogArgs),
+    Credentials(CredentialArgs),
     Schemas(SchemaArgs),
 }
 
@@ -53,6 +54,30 @@ enum CatalogCommands {
     },
 }
 
+#[derive(Debug, Args)]
+struct CredentialArgs {
+    #[command(subcommand)]
+    command: Option<CredentialCommands>,
+}
+
+#[derive(Debug, Subcommand)]
+enum CredentialCommands {
+    /// Validate that a credential can access a storage location
+    Validate {
+        /// The name of the credential to validate
+        #[clap(short, long)]
+        name: String,
+
+        /// The storage location to validate the credential against
+        #[clap(short, long)]
+        url: String,
+
+        /// Whether to only validate read operations
+        #[clap(short, long)]
+        read_only: Option<bool>,
+    },
+}
+
 #[derive(Debug, Args)]
 struct SchemaArgs {
     #[command(subcommand)]
@@ -112,6 +137,26 @@ pub async fn handle_client(
                 println!("No command provided: {:?}", args.command);
             }
         },
+        Some(ClientCommands::Credentials(args)) => match &args.command {
+            Some(CredentialCommands::Validate {
+                name,
+                url,
+                read_only,
+            }) => {
+                let results = client.credentials().validate(name, url, *read_only).await?;
+                for result in results {
+                    println!(
+                        "{}: {} {}",
+                        result.operation().as_str_name(),
+                        result.result().as_str_name(),
+                        result.message.unwrap_or_default()
+                    );
+                }
+            }
+            None => {
+                println!("No command provided: {:?}", args.command);
+            }
+        },
         Some(ClientCommands::Schemas(args)) => match &args.command {
             Some(SchemaCommands::List { catalog_name }) => {
                 println!("List schemas: {:?}", catalog_name);
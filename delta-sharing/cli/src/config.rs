@@ -13,6 +13,7 @@ pub struct Config {
 #[serde(rename_all = "snake_case")]
 pub enum Backend {
     Postgres(PostgresBackendConfig),
+    Sqlite(SqliteBackendConfig),
     InMemory(InMemoryBackendConfig),
 }
 
@@ -21,6 +22,12 @@ pub struct PostgresBackendConfig {
     pub url: String,
 }
 
+#[derive(Debug, Deserialize, Serialize)]
+pub struct SqliteBackendConfig {
+    /// Path or `sqlite:` url of the database file.
+    pub url: String,
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 pub struct InMemoryBackendConfig {
     pub config: String,
@@ -1,14 +1,18 @@
 use chrono::Days;
 use clap::{Args, Parser, Subcommand};
-use delta_sharing_profiles::{DefaultClaims, DeltaProfileManager, ProfileManager, TokenManager};
+use delta_sharing_profiles::{
+    DefaultClaims, DeltaProfileManager, KeyRing, ProfileManager, SigningKey, TokenManager,
+};
 
 use self::client::{handle_client, ClientCommand};
+use self::policy::{handle_policy, PolicyCommand};
 use crate::error::Result;
 use crate::server::{handle_rest, ServerArgs};
 
 mod client;
 mod config;
 mod error;
+mod policy;
 mod server;
 
 #[derive(Parser)]
@@ -53,6 +57,12 @@ enum Commands {
     )]
     Profile(ProfileArgs),
 
+    #[clap(
+        arg_required_else_help = true,
+        about = "work with authorization policy rules"
+    )]
+    Policy(PolicyCommand),
+
     #[clap(about = "run database migrations")]
     Migrate,
 }
@@ -65,8 +75,26 @@ struct ClientArgs {
 
 #[derive(Parser)]
 struct ProfileArgs {
-    #[clap(long, help = "secret used to encode the profile token")]
-    secret: String,
+    #[clap(
+        long,
+        help = "secret used to encode the profile token",
+        required_unless_present = "key_file"
+    )]
+    secret: Option<String>,
+
+    #[clap(
+        long,
+        help = "PEM encoded RSA or Ed25519 private key used to sign the profile token",
+        conflicts_with = "secret"
+    )]
+    key_file: Option<std::path::PathBuf>,
+
+    #[clap(
+        long,
+        help = "key id added to tokens signed with the key file",
+        default_value = "default"
+    )]
+    key_id: String,
 
     #[clap(long, short, help = "server endpoint")]
     endpoint: String,
@@ -104,6 +132,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             handle_client(client_args, args.global_opts).await?;
         }
         Commands::Profile(args) => handle_profile(args).await?,
+        Commands::Policy(args) => handle_policy(args)?,
         Commands::Migrate => todo!(),
     };
 
@@ -112,7 +141,14 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
 /// Handle the profile command.
 async fn handle_profile(args: &ProfileArgs) -> Result<()> {
-    let token_manager = TokenManager::new_from_secret(args.secret.as_bytes(), None);
+    let token_manager = match (&args.key_file, &args.secret) {
+        (Some(path), _) => TokenManager::new_from_key_ring(
+            KeyRing::new(SigningKey::from_pem_file(args.key_id.clone(), path)?),
+            None,
+        ),
+        (None, Some(secret)) => TokenManager::new_from_secret(secret.as_bytes(), None),
+        (None, None) => unreachable!("clap requires either a secret or a key file"),
+    };
     let profile_manager = DeltaProfileManager::new(args.endpoint.clone(), 1, token_manager);
 
     let exp = args
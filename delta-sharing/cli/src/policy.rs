@@ -0,0 +1,89 @@
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use clap::{Args, Subcommand};
+use delta_sharing_common::{
+    ObjectLabel, Permission, Recipient, ResourceIdent, ResourceName, ResourceRef, RulesPolicy,
+};
+
+use crate::error::{Error, Result};
+
+#[derive(Debug, Args)]
+pub struct PolicyCommand {
+    #[command(subcommand)]
+    command: PolicyCommands,
+}
+
+#[derive(Debug, Subcommand)]
+enum PolicyCommands {
+    /// Evaluate a request against a rules file
+    Test(PolicyTestArgs),
+}
+
+#[derive(Debug, Args)]
+struct PolicyTestArgs {
+    /// The YAML or JSON file containing the rules
+    #[clap(long, short)]
+    rules: PathBuf,
+
+    /// Name of the user making the request
+    #[clap(long, conflicts_with = "claims")]
+    user: Option<String>,
+
+    /// JSON encoded claims of the recipient making the request
+    #[clap(long)]
+    claims: Option<String>,
+
+    /// Label of the requested resource, e.g. share_info or table_info
+    #[clap(long, short)]
+    label: String,
+
+    /// Dot separated name of the requested resource, omit to check undefined resources
+    #[clap(long, short)]
+    name: Option<String>,
+
+    /// The requested permission
+    #[clap(long, short)]
+    permission: String,
+}
+
+/// Handle the policy command.
+pub fn handle_policy(cmd: &PolicyCommand) -> Result<()> {
+    match &cmd.command {
+        PolicyCommands::Test(args) => handle_test(args),
+    }
+}
+
+fn handle_test(args: &PolicyTestArgs) -> Result<()> {
+    let policy = RulesPolicy::try_from_file(&args.rules)?;
+    let label = ObjectLabel::from_str(&args.label)
+        .map_err(|_| Error::Generic(format!("unknown resource label: {}", args.label)))?;
+    let reference = args
+        .name
+        .as_ref()
+        .map(|name| ResourceRef::Name(ResourceName::from_naive_str_split(name)))
+        .unwrap_or(ResourceRef::Undefined);
+    let resource: ResourceIdent = label.to_ident(reference);
+    let permission = Permission::from_str(&args.permission)
+        .map_err(|_| Error::Generic(format!("unknown permission: {}", args.permission)))?;
+    let recipient = match (&args.user, &args.claims) {
+        (Some(user), _) => Recipient::user(user),
+        (None, Some(claims)) => {
+            let claims: serde_json::Value = serde_json::from_str(claims)?;
+            Recipient::custom(serde_json::to_vec(&claims)?.into())
+        }
+        (None, None) => Recipient::anonymous(),
+    };
+
+    let rules = policy.rules();
+    let matching = rules.matching(&resource, &permission, &recipient);
+    for rule in &matching {
+        println!(
+            "matched {:?} rule: {}",
+            rule.effect,
+            rule.description.as_deref().unwrap_or("<no description>")
+        );
+    }
+    println!("{:?}", rules.decide(&resource, &permission, &recipient));
+    Ok(())
+}
@@ -1,14 +1,25 @@
 use std::sync::{Arc, LazyLock};
+use std::time::Duration;
 
 use clap::Parser;
+use delta_sharing_common::api::recipients::parse_ip_network;
+use delta_sharing_common::api::{CachedLocationResolver, StoredLocationResolver};
+use delta_sharing_common::audit::{
+    AuditLog, AuditedPolicy, DeltaSink, RollingFileSink, StdoutSink,
+};
 use delta_sharing_common::{
-    memory::InMemoryResourceStore, rest::AnonymousAuthenticator, ConstantPolicy,
-    KernelQueryHandler, ServerHandler,
+    memory::InMemoryResourceStore,
+    rest::{AnonymousAuthenticator, IpAccessLayer, JwksAuthenticator, JwksConfig, JwksSource},
+    ConstantPolicy, EncryptedSecretManager, KernelQueryHandler, LocalKeyProvider, Policy,
+    ResourceStore, RowFilterMode, RulesPolicy, SecretManager, ServerHandler,
 };
-use delta_sharing_postgres::GraphStore;
-use delta_sharing_server::run_rest_server_full;
+use delta_sharing_postgres::{GraphStore, PostgresSecretManager};
+use delta_sharing_profiles::{KeyRing, SigningKey, TokenManager};
+use delta_sharing_server::{run_rest_server_full, SHARING_PREFIX};
+use delta_sharing_sqlite::SqliteStore;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
+use crate::config::{Backend, InMemoryBackendConfig, PostgresBackendConfig, SqliteBackendConfig};
 use crate::error::{Error, Result};
 
 #[derive(Parser)]
@@ -24,40 +35,372 @@ pub struct ServerArgs {
 
     #[clap(long, help = "use database", default_value_t = false)]
     use_db: bool,
+
+    #[clap(
+        long,
+        help = "path of a SQLite database to use, created if it does not exist",
+        conflicts_with = "use_db"
+    )]
+    sqlite: Option<String>,
+
+    #[clap(
+        long,
+        help = "YAML or JSON file with authorization rules, reloaded on change"
+    )]
+    policy_file: Option<std::path::PathBuf>,
+
+    #[clap(
+        long,
+        help = "write audit events to `stdout`, a local file, or a Delta table given by URL"
+    )]
+    audit_log: Option<String>,
+
+    #[clap(
+        long = "trusted-proxy",
+        help = "network (CIDR) of a proxy whose `X-Forwarded-For` headers are trusted, may be repeated"
+    )]
+    trusted_proxies: Vec<String>,
+
+    #[clap(
+        long,
+        help = "days superseded and deleted secret versions are kept in the database",
+        default_value_t = 30
+    )]
+    secret_retention_days: u64,
+
+    #[clap(
+        long,
+        help = "JSON keyfile with the keys encrypting secrets stored in the database"
+    )]
+    secret_keyfile: Option<std::path::PathBuf>,
+
+    #[clap(
+        long,
+        help = "PEM encoded RSA or Ed25519 private key signing recipient tokens, its public key is served as JWKS"
+    )]
+    token_key_file: Option<std::path::PathBuf>,
+
+    #[clap(
+        long,
+        help = "name of a secret holding a PEM encoded private key signing recipient tokens",
+        conflicts_with = "token_key_file"
+    )]
+    token_key_secret: Option<String>,
+
+    #[clap(
+        long,
+        help = "key id added to tokens signed with the token key",
+        default_value = "default"
+    )]
+    token_key_id: String,
+
+    #[clap(
+        long,
+        help = "JWKS file with the keys validating bearer tokens, requests are anonymous without JWKS"
+    )]
+    jwks_file: Option<std::path::PathBuf>,
+
+    #[clap(
+        long,
+        help = "URL of the JWKS validating bearer tokens, e.g. the `jwks_uri` of an OIDC issuer",
+        conflicts_with = "jwks_file"
+    )]
+    jwks_url: Option<url::Url>,
+
+    #[clap(long, help = "issuer (`iss` claim) required for bearer tokens")]
+    jwt_issuer: Option<String>,
+
+    #[clap(
+        long = "jwt-audience",
+        help = "audience (`aud` claim) accepted for bearer tokens, may be repeated"
+    )]
+    jwt_audiences: Vec<String>,
+
+    #[clap(
+        long,
+        help = "claim of bearer tokens naming the recipient",
+        default_value = "sub"
+    )]
+    jwt_user_claim: String,
+
+    #[clap(long, help = "seconds after which the JWKS is reloaded")]
+    jwks_refresh_secs: Option<u64>,
+
+    #[clap(
+        long,
+        help = "how files that may contain rows or columns hidden from the recipient are served, `reject` or `proxy`",
+        default_value = "reject"
+    )]
+    row_filter_mode: RowFilterMode,
+
+    #[clap(
+        long,
+        help = "URL recipients reach the server at, proxied files are served from it. Defaults to the host and port"
+    )]
+    public_url: Option<url::Url>,
+
+    #[clap(
+        long,
+        help = "file with a secret keying hashes of masked values, a random key is used if not set"
+    )]
+    hash_key_file: Option<std::path::PathBuf>,
+}
+
+/// Get the policy configured for the server.
+///
+/// Without a rules file, all requests are allowed. Rules resolve resources referenced
+/// by id in the given store, decisions are recorded in the audit log if one is configured.
+fn get_policy(
+    args: &ServerArgs,
+    store: Arc<dyn ResourceStore>,
+    audit_log: Option<&AuditLog>,
+) -> Result<Arc<dyn Policy>> {
+    let policy: Arc<dyn Policy> = match &args.policy_file {
+        Some(path) => {
+            let policy = RulesPolicy::try_from_file(path)?.with_store(store);
+            policy.watch(Duration::from_secs(5));
+            Arc::new(policy)
+        }
+        None => Arc::new(ConstantPolicy::default()),
+    };
+    Ok(match audit_log {
+        Some(log) => Arc::new(AuditedPolicy::new(policy, log.clone())),
+        None => policy,
+    })
+}
+
+/// Get the audit log configured for the server.
+///
+/// Values containing a URL scheme (e.g. `s3://bucket/audit`) refer to a Delta table,
+/// any other value except `stdout` is treated as a local file.
+async fn get_audit_log(args: &ServerArgs) -> Result<Option<AuditLog>> {
+    let Some(target) = &args.audit_log else {
+        return Ok(None);
+    };
+    let log = if target == "stdout" {
+        AuditLog::new(StdoutSink)
+    } else if target.contains("://") {
+        let location = url::Url::parse(target)
+            .map_err(|e| Error::Generic(format!("invalid audit table location: {}", e)))?;
+        AuditLog::new(DeltaSink::try_new(&location, std::env::vars()).await?)
+    } else {
+        AuditLog::new(RollingFileSink::new(target))
+    };
+    Ok(Some(log))
 }
 
-async fn get_db_handler() -> Result<ServerHandler> {
-    let db_url = std::env::var("DATABASE_URL")
-        .map_err(|_| Error::Generic("missing DATABASE_URL".to_string()))?;
-    let store = Arc::new(GraphStore::connect(&db_url).await.unwrap());
-    let policy = Arc::new(ConstantPolicy::default());
+/// Configure how the query handler serves files.
+///
+/// Files that may contain hidden data are proxied through the sharing endpoints of the
+/// server, and the data handed out is recorded in the audit log if one is configured.
+/// Masked values are hashed with the configured hash key.
+fn configure_query(
+    args: &ServerArgs,
+    query: KernelQueryHandler,
+    audit_log: Option<&AuditLog>,
+) -> Result<KernelQueryHandler> {
+    let mut endpoint = match &args.public_url {
+        Some(url) => url.clone(),
+        None => url::Url::parse(&format!("http://{}:{}", args.host, args.port))
+            .map_err(|e| Error::Generic(format!("invalid server address: {}", e)))?,
+    };
+    endpoint
+        .path_segments_mut()
+        .map_err(|_| Error::Generic("invalid public url".to_string()))?
+        .pop_if_empty()
+        .extend(SHARING_PREFIX.split('/').filter(|s| !s.is_empty()));
+    let mut query = query
+        .with_row_filter_mode(args.row_filter_mode)
+        .with_data_proxy(endpoint);
+    match &args.hash_key_file {
+        Some(path) => {
+            let secret = std::fs::read(path)
+                .map_err(|e| Error::Generic(format!("failed to read hash key: {}", e)))?;
+            query = query.with_hash_key(&secret);
+        }
+        None => tracing::warn!("no hash key configured, hashes of masked values change on restart"),
+    }
+    Ok(match audit_log {
+        Some(log) => query.with_audit_log(log.clone()),
+        None => query,
+    })
+}
+
+/// Encrypt the secrets stored by a persistent backend with the keys of the configured keyfile.
+///
+/// Without a keyfile, secrets are stored as plain text in the database.
+fn encrypt_secrets<S: SecretManager>(
+    args: &ServerArgs,
+    secrets: S,
+) -> Result<Arc<dyn SecretManager>> {
+    match &args.secret_keyfile {
+        Some(path) => {
+            let keys = LocalKeyProvider::from_file(path)?;
+            Ok(Arc::new(EncryptedSecretManager::new(
+                secrets,
+                Arc::new(keys),
+            )))
+        }
+        None => {
+            tracing::warn!("no secret keyfile configured, secrets are stored unencrypted");
+            Ok(Arc::new(secrets))
+        }
+    }
+}
+
+/// Get the layer enforcing the IP access lists of recipients.
+fn get_ip_access_layer(args: &ServerArgs, handler: &ServerHandler) -> Result<IpAccessLayer> {
+    let proxies = args
+        .trusted_proxies
+        .iter()
+        .map(|value| parse_ip_network(value))
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+    Ok(IpAccessLayer::new(Arc::new(handler.clone())).with_trusted_proxies(proxies))
+}
+
+/// Get the authenticator validating bearer tokens against the configured JWKS.
+///
+/// Returns `None` if no JWKS is configured, requests are then served anonymously.
+async fn get_authenticator(args: &ServerArgs) -> Result<Option<JwksAuthenticator>> {
+    let source = match (&args.jwks_file, &args.jwks_url) {
+        (Some(path), _) => JwksSource::File(path.clone()),
+        (None, Some(url)) => JwksSource::Url(url.clone()),
+        (None, None) => return Ok(None),
+    };
+    let mut config = JwksConfig::new(source)
+        .with_audience(args.jwt_audiences.clone())
+        .with_user_claim(args.jwt_user_claim.clone());
+    if let Some(issuer) = &args.jwt_issuer {
+        config = config.with_issuer(issuer.clone());
+    }
+    if let Some(secs) = args.jwks_refresh_secs {
+        config = config.with_refresh_interval(Duration::from_secs(secs));
+    }
+    Ok(Some(JwksAuthenticator::try_new(config).await?))
+}
+
+/// Get the token manager whose public keys are served as JWKS.
+///
+/// The signing key is read either from a local PEM file or from the secret manager
+/// of the configured backend.
+async fn get_token_manager(
+    args: &ServerArgs,
+    handler: &ServerHandler,
+) -> Result<Option<Arc<TokenManager>>> {
+    let key = match (&args.token_key_file, &args.token_key_secret) {
+        (Some(path), _) => SigningKey::from_pem_file(args.token_key_id.clone(), path)?,
+        (None, Some(name)) => {
+            let (_, pem) = handler.secrets.get_secret(name).await?;
+            SigningKey::from_pem(args.token_key_id.clone(), &pem)?
+        }
+        (None, None) => return Ok(None),
+    };
+    Ok(Some(Arc::new(TokenManager::new_from_key_ring(
+        KeyRing::new(key),
+        None,
+    ))))
+}
+
+/// Get the backend storing the resources managed by the server.
+fn get_backend(args: &ServerArgs) -> Result<Backend> {
+    if args.use_db {
+        let url = std::env::var("DATABASE_URL")
+            .map_err(|_| Error::Generic("missing DATABASE_URL".to_string()))?;
+        return Ok(Backend::Postgres(PostgresBackendConfig { url }));
+    }
+    if let Some(url) = &args.sqlite {
+        return Ok(Backend::Sqlite(SqliteBackendConfig { url: url.clone() }));
+    }
+    Ok(Backend::InMemory(InMemoryBackendConfig {
+        config: args.config.clone(),
+    }))
+}
+
+async fn get_handler(
+    args: &ServerArgs,
+    backend: &Backend,
+    audit_log: Option<&AuditLog>,
+) -> Result<ServerHandler> {
+    match backend {
+        Backend::Postgres(config) => get_db_handler(args, config, audit_log).await,
+        Backend::Sqlite(config) => get_sqlite_handler(args, config, audit_log).await,
+        Backend::InMemory(_) => get_memory_handler(args, audit_log),
+    }
+}
+
+async fn get_db_handler(
+    args: &ServerArgs,
+    config: &PostgresBackendConfig,
+    audit_log: Option<&AuditLog>,
+) -> Result<ServerHandler> {
+    let store = Arc::new(GraphStore::connect(&config.url).await.unwrap());
     store.migrate().await.unwrap();
+    let policy = get_policy(args, store.clone(), audit_log)?;
+    let secrets = PostgresSecretManager::new(store.pool()).with_retention(Duration::from_secs(
+        args.secret_retention_days * 24 * 60 * 60,
+    ));
+    secrets.purge_periodically(Duration::from_secs(60 * 60));
+    let secrets = encrypt_secrets(args, secrets)?;
+    let locations = Arc::new(CachedLocationResolver::new(Arc::new(
+        StoredLocationResolver::new(store.clone(), secrets.clone()),
+    )));
+    let query = KernelQueryHandler::new_multi_thread(store.clone(), locations, policy.clone())
+        .with_restrictions(store.clone());
     let handler = ServerHandler {
-        query: KernelQueryHandler::new_multi_thread(
-            store.clone(),
-            Default::default(),
-            policy.clone(),
-        ),
+        query: Arc::new(configure_query(args, query, audit_log)?),
         store,
         policy,
-        secrets: Arc::new(InMemoryResourceStore::new()),
+        secrets,
     };
     Ok(handler)
 }
 
-fn get_memory_handler() -> ServerHandler {
+async fn get_sqlite_handler(
+    args: &ServerArgs,
+    config: &SqliteBackendConfig,
+    audit_log: Option<&AuditLog>,
+) -> Result<ServerHandler> {
+    let store = SqliteStore::connect(&config.url)
+        .await
+        .map_err(delta_sharing_common::Error::from)?;
+    store
+        .migrate()
+        .await
+        .map_err(delta_sharing_common::Error::from)?;
+    let store = Arc::new(store);
+    let policy = get_policy(args, store.clone(), audit_log)?;
+    let secrets = encrypt_secrets(args, store.clone())?;
+    let locations = Arc::new(CachedLocationResolver::new(Arc::new(
+        StoredLocationResolver::new(store.clone(), secrets.clone()),
+    )));
+    let query = KernelQueryHandler::new_multi_thread(store.clone(), locations, policy.clone())
+        .with_restrictions(store.clone());
+    Ok(ServerHandler {
+        query: Arc::new(configure_query(args, query, audit_log)?),
+        store,
+        policy,
+        secrets,
+    })
+}
+
+fn get_memory_handler(args: &ServerArgs, audit_log: Option<&AuditLog>) -> Result<ServerHandler> {
     let store = Arc::new(InMemoryResourceStore::new());
-    let policy = Arc::new(ConstantPolicy::default());
-    ServerHandler {
+    let policy = get_policy(args, store.clone(), audit_log)?;
+    Ok(ServerHandler {
         secrets: store.clone(),
-        query: KernelQueryHandler::new_multi_thread(
-            store.clone(),
-            Default::default(),
-            policy.clone(),
-        ),
+        query: Arc::new(configure_query(
+            args,
+            KernelQueryHandler::new_multi_thread(
+                store.clone(),
+                Arc::new(CachedLocationResolver::new(store.clone())),
+                policy.clone(),
+            )
+            .with_restrictions(store.clone()),
+            audit_log,
+        )?),
         store,
         policy,
-    }
+    })
 }
 
 fn init_tracing() {
@@ -85,27 +428,40 @@ pub async fn handle_rest(args: &ServerArgs) -> Result<()> {
 
     println!("{}", WELCOME.as_str());
 
-    if args.use_db {
-        let handler = get_db_handler().await?;
-        run_rest_server_full(
-            args.host.clone(),
-            args.port,
-            handler,
-            AnonymousAuthenticator,
-        )
-        .await
-        .map_err(|_| Error::Generic("Server failed".to_string()))
-    } else {
-        let handler = get_memory_handler();
-        run_rest_server_full(
-            args.host.clone(),
-            args.port,
-            handler,
-            AnonymousAuthenticator,
-        )
-        .await
-        .map_err(|_| Error::Generic("Server failed".to_string()))
+    let audit_log = get_audit_log(args).await?;
+    let handler = get_handler(args, &get_backend(args)?, audit_log.as_ref()).await?;
+    let ip_access = get_ip_access_layer(args, &handler)?;
+    let token_manager = get_token_manager(args, &handler).await?;
+    match get_authenticator(args).await? {
+        Some(authenticator) => {
+            run_rest_server_full(
+                args.host.clone(),
+                args.port,
+                handler,
+                authenticator,
+                audit_log,
+                Some(ip_access),
+                token_manager,
+            )
+            .await
+        }
+        None => {
+            // IP access lists belong to recipients, so they can not be enforced
+            // for anonymous requests.
+            tracing::warn!("no JWKS configured, IP access lists of recipients are not enforced");
+            run_rest_server_full(
+                args.host.clone(),
+                args.port,
+                handler,
+                AnonymousAuthenticator,
+                audit_log,
+                Some(ip_access),
+                token_manager,
+            )
+            .await
+        }
     }
+    .map_err(|_| Error::Generic("Server failed".to_string()))
 }
 
 static WELCOME: LazyLock<String> = LazyLock::new(|| {
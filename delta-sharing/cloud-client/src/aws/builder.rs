@@ -26,7 +26,8 @@ use tracing::info;
 
 use super::AmazonConfig;
 use crate::aws::credential::{
-    InstanceCredentialProvider, SessionProvider, TaskCredentialProvider, WebIdentityProvider,
+    AssumeRoleProvider, InstanceCredentialProvider, SessionProvider, TaskCredentialProvider,
+    WebIdentityProvider,
 };
 use crate::aws::{AwsCredential, AwsCredentialProvider, Checksum};
 use crate::config::ConfigValue;
@@ -152,6 +153,14 @@ pub struct AmazonBuilder {
     encryption_customer_key_base64: Option<String>,
     /// When set to true, charge requester for bucket operations
     request_payer: ConfigValue<bool>,
+    /// IAM role to assume with the configured credentials
+    role_arn: Option<String>,
+    /// External id required by the trust policy of the role
+    external_id: Option<String>,
+    /// Name of the session when assuming a role
+    role_session_name: Option<String>,
+    /// STS endpoint used to assume a role
+    sts_endpoint: Option<String>,
 }
 
 /// Configuration keys for [`AmazonBuilder`]
@@ -271,6 +280,40 @@ pub enum AmazonS3ConfigKey {
     /// - `request_payer`
     RequestPayer,
 
+    /// IAM role to assume
+    ///
+    /// See [`AmazonBuilder::with_role_arn`] for details.
+    ///
+    /// Supported keys:
+    /// - `aws_assume_role_arn`
+    /// - `assume_role_arn`
+    AssumeRoleArn,
+
+    /// External id used when assuming a role
+    ///
+    /// See [`AmazonBuilder::with_external_id`] for details.
+    ///
+    /// Supported keys:
+    /// - `aws_assume_role_external_id`
+    /// - `assume_role_external_id`
+    AssumeRoleExternalId,
+
+    /// Session name used when assuming a role
+    ///
+    /// Supported keys:
+    /// - `aws_assume_role_session_name`
+    /// - `assume_role_session_name`
+    AssumeRoleSessionName,
+
+    /// STS endpoint used when assuming a role
+    ///
+    /// See [`AmazonBuilder::with_sts_endpoint`] for details.
+    ///
+    /// Supported keys:
+    /// - `aws_sts_endpoint`
+    /// - `sts_endpoint`
+    StsEndpoint,
+
     /// Client options
     Client(ClientConfigKey),
 
@@ -294,6 +337,10 @@ impl AsRef<str> for AmazonS3ConfigKey {
             Self::SkipSignature => "aws_skip_signature",
             Self::DisableTagging => "aws_disable_tagging",
             Self::RequestPayer => "aws_request_payer",
+            Self::AssumeRoleArn => "aws_assume_role_arn",
+            Self::AssumeRoleExternalId => "aws_assume_role_external_id",
+            Self::AssumeRoleSessionName => "aws_assume_role_session_name",
+            Self::StsEndpoint => "aws_sts_endpoint",
             Self::Client(opt) => opt.as_ref(),
             Self::Encryption(opt) => opt.as_ref(),
         }
@@ -318,6 +365,14 @@ impl FromStr for AmazonS3ConfigKey {
             "aws_skip_signature" | "skip_signature" => Ok(Self::SkipSignature),
             "aws_disable_tagging" | "disable_tagging" => Ok(Self::DisableTagging),
             "aws_request_payer" | "request_payer" => Ok(Self::RequestPayer),
+            "aws_assume_role_arn" | "assume_role_arn" => Ok(Self::AssumeRoleArn),
+            "aws_assume_role_external_id" | "assume_role_external_id" => {
+                Ok(Self::AssumeRoleExternalId)
+            }
+            "aws_assume_role_session_name" | "assume_role_session_name" => {
+                Ok(Self::AssumeRoleSessionName)
+            }
+            "aws_sts_endpoint" | "sts_endpoint" => Ok(Self::StsEndpoint),
             // Backwards compatibility
             "aws_allow_http" => Ok(Self::Client(ClientConfigKey::AllowHttp)),
             "aws_server_side_encryption" => Ok(Self::Encryption(
@@ -429,6 +484,10 @@ impl AmazonBuilder {
             AmazonS3ConfigKey::RequestPayer => {
                 self.request_payer = ConfigValue::Deferred(value.into())
             }
+            AmazonS3ConfigKey::AssumeRoleArn => self.role_arn = Some(value.into()),
+            AmazonS3ConfigKey::AssumeRoleExternalId => self.external_id = Some(value.into()),
+            AmazonS3ConfigKey::AssumeRoleSessionName => self.role_session_name = Some(value.into()),
+            AmazonS3ConfigKey::StsEndpoint => self.sts_endpoint = Some(value.into()),
             AmazonS3ConfigKey::Encryption(key) => match key {
                 S3EncryptionConfigKey::ServerSideEncryption => {
                     self.encryption_type = Some(ConfigValue::Deferred(value.into()))
@@ -465,6 +524,10 @@ impl AmazonBuilder {
             AmazonS3ConfigKey::SkipSignature => Some(self.skip_signature.to_string()),
             AmazonS3ConfigKey::DisableTagging => Some(self.disable_tagging.to_string()),
             AmazonS3ConfigKey::RequestPayer => Some(self.request_payer.to_string()),
+            AmazonS3ConfigKey::AssumeRoleArn => self.role_arn.clone(),
+            AmazonS3ConfigKey::AssumeRoleExternalId => self.external_id.clone(),
+            AmazonS3ConfigKey::AssumeRoleSessionName => self.role_session_name.clone(),
+            AmazonS3ConfigKey::StsEndpoint => self.sts_endpoint.clone(),
             AmazonS3ConfigKey::Encryption(key) => match key {
                 S3EncryptionConfigKey::ServerSideEncryption => {
                     self.encryption_type.as_ref().map(ToString::to_string)
@@ -511,6 +574,28 @@ impl AmazonBuilder {
         self
     }
 
+    /// Assume the given IAM role, using the otherwise configured credentials to call STS.
+    ///
+    /// <https://docs.aws.amazon.com/STS/latest/APIReference/API_AssumeRole.html>
+    pub fn with_role_arn(mut self, role_arn: impl Into<String>) -> Self {
+        self.role_arn = Some(role_arn.into());
+        self
+    }
+
+    /// Set the external id passed when assuming a role
+    ///
+    /// <https://docs.aws.amazon.com/IAM/latest/UserGuide/id_roles_create_for-user_externalid.html>
+    pub fn with_external_id(mut self, external_id: impl Into<String>) -> Self {
+        self.external_id = Some(external_id.into());
+        self
+    }
+
+    /// Set the STS endpoint used to assume a role, defaults to the regional endpoint
+    pub fn with_sts_endpoint(mut self, endpoint: impl Into<String>) -> Self {
+        self.sts_endpoint = Some(endpoint.into());
+        self
+    }
+
     /// Sets what protocol is allowed. If `allow_http` is :
     /// * false (default):  Only HTTPS are allowed
     /// * true:  HTTP and HTTPS are allowed
@@ -735,6 +820,29 @@ impl AmazonBuilder {
             )) as _
         };
 
+        let assume_role = self.role_arn.map(|role_arn| AssumeRoleProvider {
+            role_arn,
+            external_id: self.external_id,
+            session_name: self
+                .role_session_name
+                .unwrap_or_else(|| "DeltaSharingSession".to_string()),
+            endpoint: self
+                .sts_endpoint
+                .unwrap_or_else(|| format!("https://sts.{region}.amazonaws.com")),
+            region: region.clone(),
+            credentials: credentials.clone(),
+        });
+        let credentials = if let Some(token) = &assume_role {
+            info!("Using AssumeRole credential provider");
+            Arc::new(TokenCredentialProvider::new(
+                token.clone(),
+                self.client_options.client()?,
+                self.retry_config.clone(),
+            )) as _
+        } else {
+            credentials
+        };
+
         let encryption_headers = if let Some(encryption_type) = self.encryption_type {
             S3EncryptionHeaders::try_new(
                 &encryption_type.get()?,
@@ -760,6 +868,7 @@ impl AmazonBuilder {
             checksum,
             encryption_headers,
             request_payer: self.request_payer.get()?,
+            assume_role: assume_role.map(Arc::new),
         })
     }
 }
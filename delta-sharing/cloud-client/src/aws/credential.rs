@@ -36,7 +36,7 @@ use url::Url;
 
 #[derive(Debug, thiserror::Error)]
 #[allow(clippy::enum_variant_names)]
-enum Error {
+pub(crate) enum Error {
     #[error("Error performing CreateSession request: {source}")]
     CreateSessionRequest { source: crate::retry::Error },
 
@@ -45,6 +45,18 @@ enum Error {
 
     #[error("Invalid CreateSessionOutput response: {source}")]
     CreateSessionOutput { source: quick_xml::DeError },
+
+    #[error("Error performing AssumeRole request: {source}")]
+    AssumeRoleRequest { source: crate::retry::Error },
+
+    #[error("Error getting AssumeRole response: {source}")]
+    AssumeRoleResponse { source: reqwest::Error },
+
+    #[error("Invalid AssumeRole response: {source}")]
+    AssumeRoleOutput { source: quick_xml::DeError },
+
+    #[error("No IAM role configured to assume")]
+    MissingAssumeRole,
 }
 
 impl From<Error> for crate::Error {
@@ -648,6 +660,94 @@ async fn web_identity(
     })
 }
 
+/// Credentials sourced using AssumeRole
+///
+/// The request to STS is signed with the wrapped `credentials`.
+///
+/// <https://docs.aws.amazon.com/STS/latest/APIReference/API_AssumeRole.html>
+#[derive(Debug, Clone)]
+pub(crate) struct AssumeRoleProvider {
+    pub role_arn: String,
+    pub external_id: Option<String>,
+    pub session_name: String,
+    pub endpoint: String,
+    pub region: String,
+    pub credentials: AwsCredentialProvider,
+}
+
+impl AssumeRoleProvider {
+    /// Assume the role, optionally restricting the session with an inline `policy`.
+    pub(crate) async fn assume_role(
+        &self,
+        client: &Client,
+        retry: &RetryConfig,
+        policy: Option<&str>,
+        duration: Duration,
+    ) -> Result<(AwsCredential, DateTime<Utc>)> {
+        let creds = self.credentials.get_credential().await?;
+        let authorizer = AwsAuthorizer::new(&creds, "sts", &self.region);
+
+        let duration = duration.as_secs().to_string();
+        let mut query = vec![
+            ("Action", "AssumeRole"),
+            ("DurationSeconds", duration.as_str()),
+            ("RoleArn", self.role_arn.as_str()),
+            ("RoleSessionName", self.session_name.as_str()),
+            ("Version", "2011-06-15"),
+        ];
+        if let Some(external_id) = &self.external_id {
+            query.push(("ExternalId", external_id.as_str()));
+        }
+        if let Some(policy) = policy {
+            query.push(("Policy", policy));
+        }
+
+        let bytes = client
+            .request(Method::POST, &self.endpoint)
+            .query(&query)
+            .with_aws_sigv4(Some(authorizer), None)
+            .send_retry(retry)
+            .await
+            .map_err(|source| Error::AssumeRoleRequest { source })?
+            .bytes()
+            .await
+            .map_err(|source| Error::AssumeRoleResponse { source })?;
+
+        let resp: AssumeRoleOutput = quick_xml::de::from_reader(bytes.reader())
+            .map_err(|source| Error::AssumeRoleOutput { source })?;
+
+        let creds = resp.assume_role_result.credentials;
+        let expiration = creds.expiration;
+        Ok((creds.into(), expiration))
+    }
+}
+
+#[async_trait]
+impl TokenProvider for AssumeRoleProvider {
+    type Credential = AwsCredential;
+
+    async fn fetch_token(
+        &self,
+        client: &Client,
+        retry: &RetryConfig,
+    ) -> Result<TemporaryToken<Arc<AwsCredential>>> {
+        let (creds, expiration) = self
+            .assume_role(client, retry, None, Duration::from_secs(3600))
+            .await?;
+        let ttl = (expiration - Utc::now()).to_std().unwrap_or_default();
+        Ok(TemporaryToken {
+            token: Arc::new(creds),
+            expiry: Some(Instant::now() + ttl),
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct AssumeRoleOutput {
+    assume_role_result: AssumeRoleResult,
+}
+
 /// Credentials sourced from a task IAM role
 ///
 /// <https://docs.aws.amazon.com/AmazonECS/latest/developerguide/task-iam-roles.html>
@@ -1119,4 +1219,106 @@ mod tests {
             .await
             .unwrap_err();
     }
+
+    #[tokio::test]
+    async fn test_assume_role() {
+        let server = MockServer::new().await;
+
+        server.push_fn(|req| {
+            assert_eq!(req.method(), &Method::POST);
+            let query = req.uri().query().unwrap();
+            assert!(query.contains("Action=AssumeRole"));
+            assert!(query.contains("RoleArn=arn%3Aaws%3Aiam%3A%3A123456789012%3Arole%2Fdemo"));
+            assert!(query.contains("ExternalId=external"));
+            let auth = req.headers().get(AUTHORIZATION).unwrap().to_str().unwrap();
+            assert!(auth.starts_with("AWS4-HMAC-SHA256 Credential=BASEKEY/"));
+            assert!(auth.contains("/us-east-1/sts/aws4_request"));
+            Response::new(
+                r#"<AssumeRoleResponse xmlns="https://sts.amazonaws.com/doc/2011-06-15/">
+  <AssumeRoleResult>
+    <Credentials>
+      <AccessKeyId>KEYID</AccessKeyId>
+      <SecretAccessKey>SECRET</SecretAccessKey>
+      <SessionToken>TOKEN</SessionToken>
+      <Expiration>2022-08-30T10:51:04Z</Expiration>
+    </Credentials>
+  </AssumeRoleResult>
+</AssumeRoleResponse>"#
+                    .to_string(),
+            )
+        });
+
+        let provider = AssumeRoleProvider {
+            role_arn: "arn:aws:iam::123456789012:role/demo".to_string(),
+            external_id: Some("external".to_string()),
+            session_name: "session".to_string(),
+            endpoint: server.url().to_string(),
+            region: "us-east-1".to_string(),
+            credentials: Arc::new(crate::StaticCredentialProvider::new(AwsCredential {
+                key_id: "BASEKEY".to_string(),
+                secret_key: "BASESECRET".to_string(),
+                token: None,
+            })),
+        };
+        let creds = provider
+            .fetch_token(&Client::new(), &RetryConfig::default())
+            .await
+            .unwrap();
+
+        assert_eq!(&creds.token.key_id, "KEYID");
+        assert_eq!(&creds.token.secret_key, "SECRET");
+        assert_eq!(creds.token.token.as_deref(), Some("TOKEN"));
+    }
+
+    #[tokio::test]
+    async fn test_assume_role_with_policy() {
+        let server = MockServer::new().await;
+
+        server.push_fn(|req| {
+            let query = req.uri().query().unwrap();
+            assert!(query.contains("DurationSeconds=900"));
+            assert!(query.contains("Policy=%7B%22Version%22"));
+            Response::new(
+                r#"<AssumeRoleResponse xmlns="https://sts.amazonaws.com/doc/2011-06-15/">
+  <AssumeRoleResult>
+    <Credentials>
+      <AccessKeyId>KEYID</AccessKeyId>
+      <SecretAccessKey>SECRET</SecretAccessKey>
+      <SessionToken>TOKEN</SessionToken>
+      <Expiration>2022-08-30T10:51:04Z</Expiration>
+    </Credentials>
+  </AssumeRoleResult>
+</AssumeRoleResponse>"#
+                    .to_string(),
+            )
+        });
+
+        let config = crate::aws::AmazonBuilder::new()
+            .with_access_key_id("BASEKEY")
+            .with_secret_access_key("BASESECRET")
+            .with_region("us-east-1")
+            .with_role_arn("arn:aws:iam::123456789012:role/demo")
+            .with_sts_endpoint(server.url())
+            .with_allow_http(true)
+            .build()
+            .unwrap();
+        let scoped = config
+            .assume_role_with_policy(r#"{"Version":"2012-10-17"}"#, Duration::from_secs(900))
+            .await
+            .unwrap();
+
+        assert_eq!(&scoped.credential.key_id, "KEYID");
+        assert_eq!(scoped.credential.token.as_deref(), Some("TOKEN"));
+        assert_eq!(scoped.expires_at.to_rfc3339(), "2022-08-30T10:51:04+00:00");
+
+        let config = crate::aws::AmazonBuilder::new()
+            .with_access_key_id("BASEKEY")
+            .with_secret_access_key("BASESECRET")
+            .build()
+            .unwrap();
+        let result = config
+            .assume_role_with_policy("{}", Duration::from_secs(900))
+            .await;
+        assert!(result.is_err());
+    }
 }
@@ -1,4 +1,5 @@
 use std::sync::Arc;
+use std::time::Duration;
 
 use self::checksum::Checksum;
 use crate::aws::builder::S3EncryptionHeaders;
@@ -10,15 +11,16 @@ use reqwest::{Client as ReqwestClient, IntoUrl, Method, RequestBuilder, Response
 use ring::digest;
 use serde::Serialize;
 
-use self::credential::{AwsAuthorizer, AwsCredential, CredentialExt};
+use self::credential::{AssumeRoleProvider, AwsAuthorizer, CredentialExt};
 use crate::util::STRICT_ENCODE_SET;
-use crate::CredentialProvider;
+use crate::{CredentialProvider, ScopedCredential};
 
 mod builder;
 mod checksum;
 mod credential;
 
 pub use builder::*;
+pub use credential::AwsCredential;
 
 /// This struct is used to maintain the URI path encoding
 const STRICT_PATH_ENCODE_SET: percent_encoding::AsciiSet = STRICT_ENCODE_SET.remove(b'/');
@@ -79,6 +81,7 @@ pub struct AmazonConfig {
     pub checksum: Option<Checksum>,
     pub request_payer: bool,
     pub(crate) encryption_headers: S3EncryptionHeaders,
+    pub(crate) assume_role: Option<Arc<AssumeRoleProvider>>,
 }
 
 impl AmazonConfig {
@@ -105,6 +108,32 @@ impl AmazonConfig {
         })
     }
 
+    /// Assume the configured IAM role with a session restricted by the inline `policy`.
+    ///
+    /// The effective permissions are the intersection of the role's policies and `policy`.
+    pub async fn assume_role_with_policy(
+        &self,
+        policy: &str,
+        duration: Duration,
+    ) -> Result<ScopedCredential<AwsCredential>> {
+        let provider = self
+            .assume_role
+            .as_ref()
+            .ok_or(credential::Error::MissingAssumeRole)?;
+        let (credential, expires_at) = provider
+            .assume_role(
+                &self.client_options.client()?,
+                &self.retry_config,
+                Some(policy),
+                duration,
+            )
+            .await?;
+        Ok(ScopedCredential {
+            credential,
+            expires_at,
+        })
+    }
+
     #[inline]
     pub(crate) fn is_s3_express(&self) -> bool {
         self.session_provider.is_some()
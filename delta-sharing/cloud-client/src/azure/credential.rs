@@ -36,7 +36,7 @@ use std::sync::Arc;
 use std::time::{Duration, Instant, SystemTime};
 use url::Url;
 
-static AZURE_VERSION: HeaderValue = HeaderValue::from_static("2023-11-03");
+pub(crate) static AZURE_VERSION: HeaderValue = HeaderValue::from_static("2023-11-03");
 static VERSION: HeaderName = HeaderName::from_static("x-ms-version");
 pub(crate) static BLOB_TYPE: HeaderName = HeaderName::from_static("x-ms-blob-type");
 pub(crate) static DELETE_SNAPSHOTS: HeaderName = HeaderName::from_static("x-ms-delete-snapshots");
@@ -84,6 +84,18 @@ pub enum Error {
 
     #[error("Generating SAS keys with SAS tokens auth is not supported")]
     SASforSASNotSupported,
+
+    #[error("Error performing user delegation key request: {}", source)]
+    DelegationKeyRequest { source: crate::retry::Error },
+
+    #[error("Error getting user delegation key response body: {}", source)]
+    DelegationKeyResponseBody { source: reqwest::Error },
+
+    #[error("Got invalid user delegation key response: {}", source)]
+    DelegationKeyResponse { source: quick_xml::de::DeError },
+
+    #[error("Requesting user delegation keys requires bearer token auth")]
+    DelegationKeyRequiresBearerToken,
 }
 
 pub(crate) type Result<T, E = Error> = std::result::Result<T, E>;
@@ -9,9 +9,11 @@ use crate::{ClientOptions, CredentialProvider, Result, RetryConfig};
 
 mod builder;
 pub(crate) mod credential;
+mod sas;
 
 pub(crate) use self::credential::*;
 pub use builder::*;
+pub use sas::*;
 
 pub type AzureCredentialProvider = Arc<dyn CredentialProvider<Credential = AzureCredential>>;
 
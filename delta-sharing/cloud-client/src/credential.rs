@@ -2,6 +2,7 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use reqwest::Client;
 
 use crate::{Result, RetryConfig, TemporaryToken, TokenCache};
@@ -43,6 +44,15 @@ where
     }
 }
 
+/// A short-lived credential that was issued for a restricted scope
+#[derive(Debug, Clone)]
+pub struct ScopedCredential<T> {
+    /// The issued credential
+    pub credential: T,
+    /// The time at which the credential is no longer valid
+    pub expires_at: DateTime<Utc>,
+}
+
 /// A [`CredentialProvider`] that uses [`Client`] to fetch temporary tokens
 #[derive(Debug)]
 pub(crate) struct TokenCredentialProvider<T: TokenProvider> {
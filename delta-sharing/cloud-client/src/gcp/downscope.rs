@@ -0,0 +1,162 @@
+//! Downscoped access tokens for Google Cloud Storage.
+//!
+//! <https://cloud.google.com/iam/docs/downscoping-short-lived-credentials>
+use chrono::{Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::{Error, GoogleConfig};
+use crate::retry::RetryExt;
+use crate::{Result, ScopedCredential};
+
+/// The Security Token Service endpoint used to exchange access tokens
+pub const DEFAULT_STS_ENDPOINT: &str = "https://sts.googleapis.com/v1/token";
+
+/// Google access tokens are valid for an hour, unless a shorter lifetime was requested.
+const DEFAULT_TOKEN_LIFETIME: i64 = 3600;
+
+/// A rule restricting the permissions a downscoped token has on a resource.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccessBoundaryRule {
+    /// Full resource name of the bucket, e.g. `//storage.googleapis.com/projects/_/buckets/b`
+    pub available_resource: String,
+    /// IAM roles the token may use, e.g. `inRole:roles/storage.objectViewer`
+    pub available_permissions: Vec<String>,
+    /// An IAM condition that further restricts the objects within the resource
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub availability_condition: Option<AvailabilityCondition>,
+}
+
+/// A condition expression in the Common Expression Language.
+#[derive(Debug, Clone, Serialize)]
+pub struct AvailabilityCondition {
+    /// The condition expression
+    pub expression: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CredentialAccessBoundary<'a> {
+    access_boundary: AccessBoundary<'a>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AccessBoundary<'a> {
+    access_boundary_rules: &'a [AccessBoundaryRule],
+}
+
+/// <https://cloud.google.com/iam/docs/reference/sts/rest/v1/TopLevel/token#response-body>
+#[derive(Deserialize)]
+struct TokenExchangeResponse {
+    access_token: String,
+    expires_in: Option<i64>,
+}
+
+impl GoogleConfig {
+    /// Exchange the configured credential for a token restricted by `rules`.
+    ///
+    /// The token is issued by the Security Token Service at `endpoint`.
+    pub async fn downscoped_token(
+        &self,
+        endpoint: &str,
+        rules: &[AccessBoundaryRule],
+    ) -> Result<ScopedCredential<String>> {
+        let credential = self.get_credential().await?;
+        let options = serde_json::to_string(&CredentialAccessBoundary {
+            access_boundary: AccessBoundary {
+                access_boundary_rules: rules,
+            },
+        })
+        .map_err(|source| Error::InvalidAccessBoundary { source })?;
+
+        let response: TokenExchangeResponse = self
+            .client_options
+            .client()?
+            .post(endpoint)
+            .form(&[
+                (
+                    "grant_type",
+                    "urn:ietf:params:oauth:grant-type:token-exchange",
+                ),
+                (
+                    "subject_token_type",
+                    "urn:ietf:params:oauth:token-type:access_token",
+                ),
+                (
+                    "requested_token_type",
+                    "urn:ietf:params:oauth:token-type:access_token",
+                ),
+                ("subject_token", credential.bearer.as_str()),
+                ("options", options.as_str()),
+            ])
+            .send_retry(&self.retry_config)
+            .await
+            .map_err(|source| Error::TokenExchangeRequest { source })?
+            .json()
+            .await
+            .map_err(|source| Error::TokenExchangeResponse { source })?;
+
+        let lifetime = response.expires_in.unwrap_or(DEFAULT_TOKEN_LIFETIME);
+        Ok(ScopedCredential {
+            credential: response.access_token,
+            expires_at: Utc::now() + Duration::seconds(lifetime),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use http_body_util::BodyExt;
+    use hyper::Response;
+
+    use super::*;
+    use crate::gcp::credential::GcpCredential;
+    use crate::mock_server::MockServer;
+    use crate::{ClientOptions, RetryConfig, StaticCredentialProvider};
+
+    #[tokio::test]
+    async fn test_downscoped_token() {
+        let server = MockServer::new().await;
+        server.push_async_fn(|req| async move {
+            let body = req.into_body().collect().await.unwrap().to_bytes();
+            let form: Vec<(String, String)> = url::form_urlencoded::parse(&body)
+                .into_owned()
+                .collect();
+            let get = |k: &str| form.iter().find(|(key, _)| key == k).unwrap().1.clone();
+            assert_eq!(get("subject_token"), "source");
+            let options: serde_json::Value = serde_json::from_str(&get("options")).unwrap();
+            let rule = &options["accessBoundary"]["accessBoundaryRules"][0];
+            assert_eq!(
+                rule["availableResource"],
+                "//storage.googleapis.com/projects/_/buckets/bucket"
+            );
+            assert!(rule["availabilityCondition"]["expression"].is_string());
+            Response::new(
+                r#"{"access_token":"downscoped","issued_token_type":"urn:ietf:params:oauth:token-type:access_token","token_type":"Bearer","expires_in":600}"#
+                    .to_string(),
+            )
+        });
+
+        let config = GoogleConfig::new(
+            Arc::new(StaticCredentialProvider::new(GcpCredential {
+                bearer: "source".to_string(),
+            })),
+            RetryConfig::default(),
+            ClientOptions::new().with_allow_http(true),
+        );
+        let rules = [AccessBoundaryRule {
+            available_resource: "//storage.googleapis.com/projects/_/buckets/bucket".to_string(),
+            available_permissions: vec!["inRole:roles/storage.objectViewer".to_string()],
+            availability_condition: Some(AvailabilityCondition {
+                expression: "resource.name.startsWith('projects/_/buckets/bucket/objects/t/')"
+                    .to_string(),
+            }),
+        }];
+        let token = config.downscoped_token(server.url(), &rules).await.unwrap();
+        assert_eq!(token.credential, "downscoped");
+        assert!(token.expires_at <= Utc::now() + Duration::seconds(600));
+    }
+}
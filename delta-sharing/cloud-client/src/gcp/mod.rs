@@ -9,9 +9,11 @@ use crate::CredentialProvider;
 use crate::{ClientOptions, Result, RetryConfig};
 
 pub use builder::*;
+pub use downscope::*;
 
 mod builder;
 mod credential;
+mod downscope;
 
 /// [`CredentialProvider`] for [`GoogleCloudStorage`]
 pub type GcpCredentialProvider = Arc<dyn CredentialProvider<Credential = GcpCredential>>;
@@ -44,6 +46,15 @@ enum Error {
 
     #[error("Got invalid signing blob signature: {}", source)]
     InvalidSignBlobSignature { source: base64::DecodeError },
+
+    #[error("Invalid credential access boundary: {}", source)]
+    InvalidAccessBoundary { source: serde_json::Error },
+
+    #[error("Error performing token exchange request: {}", source)]
+    TokenExchangeRequest { source: crate::retry::Error },
+
+    #[error("Got invalid token exchange response: {}", source)]
+    TokenExchangeResponse { source: reqwest::Error },
 }
 
 impl From<Error> for crate::Error {
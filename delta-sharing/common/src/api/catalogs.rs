@@ -3,7 +3,7 @@ use itertools::Itertools;
 
 use super::{RequestContext, SecuredAction};
 use crate::models::catalogs::v1::*;
-use crate::policy::{process_resources, Permission, Policy};
+use crate::policy::{list_visible, Permission, Policy};
 use crate::resources::{ResourceIdent, ResourceName, ResourceRef};
 use crate::{Error, ObjectLabel, Recipient, ResourceStore, Result};
 
@@ -139,15 +139,16 @@ impl<T: ResourceStore + Policy> CatalogHandler for T {
         context: RequestContext,
     ) -> Result<ListCatalogsResponse> {
         self.check_required(&request, context.as_ref()).await?;
-        let (mut resources, next_page_token) = self
-            .list(
-                &ObjectLabel::CatalogInfo,
-                None,
-                request.max_results.map(|v| v as usize),
-                request.page_token,
-            )
-            .await?;
-        process_resources(self, context.as_ref(), &Permission::Read, &mut resources).await?;
+        let (resources, next_page_token) = list_visible(
+            self,
+            context.as_ref(),
+            &Permission::Read,
+            &ObjectLabel::CatalogInfo,
+            None,
+            request.max_results.map(|v| v as usize),
+            request.page_token,
+        )
+        .await?;
         Ok(ListCatalogsResponse {
             catalogs: resources.into_iter().map(|r| r.try_into()).try_collect()?,
             next_page_token,
@@ -165,6 +166,7 @@ impl<T: ResourceStore + Policy> CatalogHandler for T {
             name: request.new_name,
             comment: request.comment,
             properties: request.properties,
+            etag: request.etag,
             ..Default::default()
         };
         // TODO:
@@ -1,10 +1,18 @@
+use cloud_client::aws::AmazonBuilder;
+use cloud_client::azure::{AzureBuilder, AzureConfigKey};
+use cloud_client::gcp::GoogleBuilder;
 use delta_sharing_derive::rest_handlers;
 use itertools::Itertools;
+use object_store::path::Path;
+use object_store::{ObjectStore, PutPayload};
 use serde::{Deserialize, Serialize};
+use url::Url;
+use uuid::Uuid;
 
+use super::external_locations::{parse_normalized_url, ExternalLocationResolver};
 use super::{RequestContext, SecuredAction};
 use crate::models::credentials::v1::*;
-use crate::policy::{process_resources, Permission, Policy};
+use crate::policy::{list_visible, Permission, Policy};
 use crate::resources::{ResourceIdent, ResourceName, ResourceRef};
 use crate::{Error, ObjectLabel, Recipient, ResourceExt, ResourceStore, Result, SecretManager};
 
@@ -24,6 +32,8 @@ rest_handlers!(
         DeleteCredentialRequest, Credential, Manage with [
             name: path as String,
         ];
+        // the required permission depends on the credential and probes, see the handler.
+        ValidateCredentialRequest, _, Read, ValidateCredentialResponse at "validate-credentials";
     ]
 );
 
@@ -63,61 +73,101 @@ pub trait CredentialsHandler: Send + Sync + 'static {
         request: DeleteCredentialRequest,
         context: RequestContext,
     ) -> Result<()>;
+
+    /// Validate a credential against a storage location.
+    async fn validate_credential(
+        &self,
+        request: ValidateCredentialRequest,
+        context: RequestContext,
+    ) -> Result<ValidateCredentialResponse>;
 }
 
-#[derive(Clone, Serialize, Deserialize)]
+/// Secret material of a credential, as stored in the [`SecretManager`].
+#[derive(Clone, Default, Serialize, Deserialize)]
 struct CredentialContainer {
     pub azure_sp: Option<AzureServicePrincipal>,
     pub azure_msi: Option<AzureManagedIdentity>,
     pub azure_key: Option<AzureStorageKey>,
+    pub aws_role: Option<AwsIamRole>,
+    pub aws_key: Option<AwsAccessKey>,
+    pub gcp_key: Option<GcpServiceAccountKey>,
 }
 
 impl CredentialContainer {
     fn from_get(cred: create_credential_request::Credential) -> Self {
+        use create_credential_request::Credential;
         match cred {
-            create_credential_request::Credential::AzureServicePrincipal(azure_sp) => Self {
+            Credential::AzureServicePrincipal(azure_sp) => Self {
                 azure_sp: Some(azure_sp),
-                azure_msi: None,
-                azure_key: None,
+                ..Default::default()
             },
-            create_credential_request::Credential::AzureManagedIdentity(azure_msi) => Self {
-                azure_sp: None,
+            Credential::AzureManagedIdentity(azure_msi) => Self {
                 azure_msi: Some(azure_msi),
-                azure_key: None,
+                ..Default::default()
             },
-            create_credential_request::Credential::AzureStorageKey(azure_key) => Self {
-                azure_sp: None,
-                azure_msi: None,
+            Credential::AzureStorageKey(azure_key) => Self {
                 azure_key: Some(azure_key),
+                ..Default::default()
+            },
+            Credential::AwsIamRole(aws_role) => Self {
+                aws_role: Some(aws_role),
+                ..Default::default()
+            },
+            Credential::AwsAccessKey(aws_key) => Self {
+                aws_key: Some(aws_key),
+                ..Default::default()
+            },
+            Credential::GcpServiceAccountKey(gcp_key) => Self {
+                gcp_key: Some(gcp_key),
+                ..Default::default()
             },
         }
     }
 
     fn from_update(cred: update_credential_request::Credential) -> Self {
+        use update_credential_request::Credential;
         match cred {
-            update_credential_request::Credential::AzureServicePrincipal(azure_sp) => Self {
+            Credential::AzureServicePrincipal(azure_sp) => Self {
                 azure_sp: Some(azure_sp),
-                azure_msi: None,
-                azure_key: None,
+                ..Default::default()
             },
-            update_credential_request::Credential::AzureManagedIdentity(azure_msi) => Self {
-                azure_sp: None,
+            Credential::AzureManagedIdentity(azure_msi) => Self {
                 azure_msi: Some(azure_msi),
-                azure_key: None,
+                ..Default::default()
             },
-            update_credential_request::Credential::AzureStorageKey(azure_key) => Self {
-                azure_sp: None,
-                azure_msi: None,
+            Credential::AzureStorageKey(azure_key) => Self {
                 azure_key: Some(azure_key),
+                ..Default::default()
+            },
+            Credential::AwsIamRole(aws_role) => Self {
+                aws_role: Some(aws_role),
+                ..Default::default()
+            },
+            Credential::AwsAccessKey(aws_key) => Self {
+                aws_key: Some(aws_key),
+                ..Default::default()
+            },
+            Credential::GcpServiceAccountKey(gcp_key) => Self {
+                gcp_key: Some(gcp_key),
+                ..Default::default()
             },
         }
     }
 
     fn into_cred(self) -> Result<credential_info::Credential> {
+        use credential_info::Credential;
         if let Some(azure_sp) = self.azure_sp {
-            Ok(credential_info::Credential::AzureServicePrincipal(azure_sp))
+            Ok(Credential::AzureServicePrincipal(azure_sp))
         } else if let Some(azure_msi) = self.azure_msi {
-            Ok(credential_info::Credential::AzureManagedIdentity(azure_msi))
+            Ok(Credential::AzureManagedIdentity(azure_msi))
+        } else if let Some(azure_key) = self.azure_key {
+            Ok(Credential::AzureStorageKey(azure_key))
+        } else if let Some(aws_role) = self.aws_role {
+            Ok(Credential::AwsIamRole(aws_role))
+        } else if let Some(aws_key) = self.aws_key {
+            Ok(Credential::AwsAccessKey(aws_key))
+        } else if let Some(gcp_key) = self.gcp_key {
+            Ok(Credential::GcpServiceAccountKey(gcp_key))
         } else {
             Err(Error::invalid_argument("credential is required"))
         }
@@ -128,6 +178,302 @@ impl CredentialContainer {
     }
 }
 
+/// Configure an [`AmazonBuilder`] to authenticate with an AWS credential.
+///
+/// IAM roles are assumed with the credentials found in the environment of the server.
+impl TryFrom<&credential_info::Credential> for AmazonBuilder {
+    type Error = Error;
+
+    fn try_from(credential: &credential_info::Credential) -> Result<Self> {
+        match credential {
+            credential_info::Credential::AwsIamRole(role) => {
+                let builder = AmazonBuilder::from_env().with_role_arn(&role.role_arn);
+                Ok(match &role.external_id {
+                    Some(external_id) => builder.with_external_id(external_id),
+                    None => builder,
+                })
+            }
+            credential_info::Credential::AwsAccessKey(key) => {
+                let builder = AmazonBuilder::new()
+                    .with_access_key_id(&key.access_key_id)
+                    .with_secret_access_key(&key.secret_access_key);
+                Ok(match &key.session_token {
+                    Some(token) => builder.with_token(token),
+                    None => builder,
+                })
+            }
+            _ => Err(Error::invalid_argument("not an AWS credential")),
+        }
+    }
+}
+
+/// Configure an [`AzureBuilder`] to authenticate with an Azure identity.
+///
+/// Storage account keys are not identities, they are used to sign requests directly.
+impl TryFrom<&credential_info::Credential> for AzureBuilder {
+    type Error = Error;
+
+    fn try_from(credential: &credential_info::Credential) -> Result<Self> {
+        match credential {
+            credential_info::Credential::AzureServicePrincipal(sp) => {
+                let builder = AzureBuilder::new()
+                    .with_client_id(&sp.application_id)
+                    .with_tenant_id(&sp.directory_id);
+                match &sp.credential {
+                    Some(azure_service_principal::Credential::ClientSecret(secret)) => {
+                        Ok(builder.with_client_secret(secret))
+                    }
+                    Some(azure_service_principal::Credential::FederatedTokenFile(path)) => {
+                        Ok(builder.with_federated_token_file(path))
+                    }
+                    None => Err(Error::invalid_argument(
+                        "service principal requires a client secret or federated token file",
+                    )),
+                }
+            }
+            credential_info::Credential::AzureManagedIdentity(msi) => {
+                let builder = AzureBuilder::new();
+                Ok(match &msi.identifier {
+                    Some(azure_managed_identity::Identifier::ObjectId(id)) => {
+                        builder.with_config(AzureConfigKey::ObjectId, id)
+                    }
+                    Some(azure_managed_identity::Identifier::ApplicationId(id)) => {
+                        builder.with_client_id(id)
+                    }
+                    Some(azure_managed_identity::Identifier::MsiResourceId(id)) => {
+                        builder.with_config(AzureConfigKey::MsiResourceId, id)
+                    }
+                    None => builder,
+                })
+            }
+            _ => Err(Error::invalid_argument("not an Azure identity")),
+        }
+    }
+}
+
+/// Configure a [`GoogleBuilder`] to authenticate with a GCP credential.
+impl TryFrom<&credential_info::Credential> for GoogleBuilder {
+    type Error = Error;
+
+    fn try_from(credential: &credential_info::Credential) -> Result<Self> {
+        match credential {
+            credential_info::Credential::GcpServiceAccountKey(key) => {
+                Ok(GoogleBuilder::new().with_service_account_key(service_account_key(key)))
+            }
+            _ => Err(Error::invalid_argument("not a GCP credential")),
+        }
+    }
+}
+
+/// The JSON key file of a GCP service account.
+fn service_account_key(key: &GcpServiceAccountKey) -> String {
+    serde_json::json!({
+        "client_email": key.email,
+        "private_key_id": key.private_key_id,
+        "private_key": key.private_key,
+    })
+    .to_string()
+}
+
+/// Options to configure an [`ObjectStore`] that authenticates with a credential.
+///
+/// IAM roles are assumed right away and the store is configured with the temporary keys.
+pub(crate) async fn storage_options(
+    credential: &credential_info::Credential,
+) -> Result<Vec<(&'static str, String)>> {
+    use credential_info::Credential;
+    Ok(match credential {
+        Credential::AwsAccessKey(key) => {
+            let mut options = vec![
+                ("aws_access_key_id", key.access_key_id.clone()),
+                ("aws_secret_access_key", key.secret_access_key.clone()),
+            ];
+            options.extend(key.session_token.clone().map(|t| ("aws_session_token", t)));
+            options
+        }
+        Credential::AwsIamRole(_) => {
+            let config = AmazonBuilder::try_from(credential)?.build()?;
+            let key = config.credentials.get_credential().await?;
+            let mut options = vec![
+                ("aws_access_key_id", key.key_id.clone()),
+                ("aws_secret_access_key", key.secret_key.clone()),
+            ];
+            options.extend(key.token.clone().map(|t| ("aws_session_token", t)));
+            options
+        }
+        Credential::AzureStorageKey(key) => vec![
+            ("azure_storage_account_name", key.account_name.clone()),
+            ("azure_storage_account_key", key.account_key.clone()),
+        ],
+        Credential::AzureServicePrincipal(sp) => {
+            let mut options = vec![
+                ("azure_client_id", sp.application_id.clone()),
+                ("azure_tenant_id", sp.directory_id.clone()),
+            ];
+            match &sp.credential {
+                Some(azure_service_principal::Credential::ClientSecret(secret)) => {
+                    options.push(("azure_client_secret", secret.clone()))
+                }
+                Some(azure_service_principal::Credential::FederatedTokenFile(path)) => {
+                    options.push(("azure_federated_token_file", path.clone()))
+                }
+                None => {
+                    return Err(Error::invalid_argument(
+                        "service principal requires a client secret or federated token file",
+                    ))
+                }
+            }
+            options
+        }
+        Credential::AzureManagedIdentity(msi) => match &msi.identifier {
+            Some(azure_managed_identity::Identifier::ObjectId(id)) => {
+                vec![("azure_object_id", id.clone())]
+            }
+            Some(azure_managed_identity::Identifier::ApplicationId(id)) => {
+                vec![("azure_client_id", id.clone())]
+            }
+            Some(azure_managed_identity::Identifier::MsiResourceId(id)) => {
+                vec![("azure_msi_resource_id", id.clone())]
+            }
+            None => vec![],
+        },
+        Credential::GcpServiceAccountKey(key) => {
+            vec![("google_service_account_key", service_account_key(key))]
+        }
+    })
+}
+
+fn probe_result<T>(
+    operation: ValidationOperation,
+    outcome: &object_store::Result<T>,
+) -> ValidationResult {
+    match outcome {
+        Ok(_) => ValidationResult {
+            operation: operation.into(),
+            result: ValidationResultStatus::Pass.into(),
+            message: None,
+        },
+        Err(err) => ValidationResult {
+            operation: operation.into(),
+            result: ValidationResultStatus::Fail.into(),
+            message: Some(err.to_string()),
+        },
+    }
+}
+
+fn probe_skipped(operation: ValidationOperation, reason: &str) -> ValidationResult {
+    ValidationResult {
+        operation: operation.into(),
+        result: ValidationResultStatus::Skip.into(),
+        message: Some(reason.to_string()),
+    }
+}
+
+/// Reject urls that are not served by the cloud the credential belongs to.
+///
+/// Otherwise a credential could be probed against any store supported by `object_store`,
+/// including the local file system of the server.
+fn check_storage_scheme(credential: &credential_info::Credential, url: &Url) -> Result<()> {
+    use credential_info::Credential::*;
+    let supported = match credential {
+        AwsIamRole(_) | AwsAccessKey(_) => matches!(url.scheme(), "s3" | "s3a"),
+        GcpServiceAccountKey(_) => url.scheme() == "gs",
+        AzureServicePrincipal(_) | AzureManagedIdentity(_) | AzureStorageKey(_) => {
+            match url.scheme() {
+                "abfs" | "abfss" => true,
+                "https" => url
+                    .host_str()
+                    .is_some_and(|host| host.ends_with(".core.windows.net")),
+                _ => false,
+            }
+        }
+    };
+    if !supported {
+        return Err(Error::invalid_argument(format!(
+            "url '{}' is not supported by the credential",
+            url
+        )));
+    }
+    Ok(())
+}
+
+/// Probe which operations a credential permits on a storage location.
+///
+/// The write and delete probes create and remove a marker object below `url`. If these are
+/// skipped, the read probe reads the first object listed instead.
+pub(crate) async fn validate_storage(
+    credential: &credential_info::Credential,
+    url: &str,
+    read_only: bool,
+) -> Result<Vec<ValidationResult>> {
+    let url = parse_normalized_url(url)?;
+    check_storage_scheme(credential, &url)?;
+    let options = storage_options(credential).await?;
+    let (store, prefix) = object_store::parse_url_opts(&url, options)
+        .map_err(|e| Error::invalid_argument(e.to_string()))?;
+    Ok(probe_storage(store.as_ref(), &prefix, read_only).await)
+}
+
+async fn probe_storage(
+    store: &dyn ObjectStore,
+    prefix: &Path,
+    read_only: bool,
+) -> Vec<ValidationResult> {
+    let listing = store.list_with_delimiter(Some(prefix)).await;
+    let sample = listing
+        .as_ref()
+        .ok()
+        .and_then(|l| l.objects.first().map(|o| o.location.clone()));
+    let mut results = vec![probe_result(ValidationOperation::List, &listing)];
+
+    let marker = prefix.child(format!("_validation_{}", Uuid::new_v4()));
+    let written = if read_only {
+        None
+    } else {
+        Some(
+            store
+                .put(&marker, PutPayload::from_static(b"validation"))
+                .await,
+        )
+    };
+
+    let readable = match &written {
+        Some(Ok(_)) => Some(marker.clone()),
+        _ => sample,
+    };
+    results.push(match readable {
+        Some(location) => probe_result(ValidationOperation::Read, &store.get(&location).await),
+        None => probe_skipped(ValidationOperation::Read, "no object to read"),
+    });
+
+    match written {
+        Some(outcome) => {
+            results.push(probe_result(ValidationOperation::Write, &outcome));
+            results.push(match outcome {
+                Ok(_) => probe_result(ValidationOperation::Delete, &store.delete(&marker).await),
+                Err(_) => probe_skipped(ValidationOperation::Delete, "no object was written"),
+            });
+        }
+        None => {
+            results.push(probe_skipped(ValidationOperation::Write, "read only"));
+            results.push(probe_skipped(ValidationOperation::Delete, "read only"));
+        }
+    }
+    results
+}
+
+/// Load the secret material of a credential.
+///
+/// This does not check any permissions, callers must authorize the use of the credential.
+pub(crate) async fn load_credential<T: SecretManager + ?Sized>(
+    secrets: &T,
+    name: &str,
+) -> Result<credential_info::Credential> {
+    let (_, secret_data) = secrets.get_secret(name).await?;
+    let secret: CredentialContainer = serde_json::from_slice(&secret_data)?;
+    secret.into_cred()
+}
+
 #[async_trait::async_trait]
 impl<T: ResourceStore + Policy + SecretManager> CredentialsHandler for T {
     async fn list_credentials(
@@ -136,15 +482,16 @@ impl<T: ResourceStore + Policy + SecretManager> CredentialsHandler for T {
         context: RequestContext,
     ) -> Result<ListCredentialsResponse> {
         self.check_required(&request, context.as_ref()).await?;
-        let (mut resources, next_page_token) = self
-            .list(
-                &ObjectLabel::CredentialInfo,
-                None,
-                request.max_results.map(|v| v as usize),
-                request.page_token,
-            )
-            .await?;
-        process_resources(self, context.as_ref(), &Permission::Read, &mut resources).await?;
+        let (resources, next_page_token) = list_visible(
+            self,
+            context.as_ref(),
+            &Permission::Read,
+            &ObjectLabel::CredentialInfo,
+            None,
+            request.max_results.map(|v| v as usize),
+            request.page_token,
+        )
+        .await?;
         Ok(ListCredentialsResponse {
             credentials: resources.into_iter().map(|r| r.try_into()).try_collect()?,
             next_page_token,
@@ -178,6 +525,7 @@ impl<T: ResourceStore + Policy + SecretManager> CredentialsHandler for T {
             owner: None,
             created_by: None,
             updated_by: None,
+            etag: None,
         };
         self.create(cred.into()).await?.0.try_into()
     }
@@ -189,9 +537,7 @@ impl<T: ResourceStore + Policy + SecretManager> CredentialsHandler for T {
     ) -> Result<CredentialInfo> {
         self.check_required(&request, context.recipient()).await?;
         let mut cred: CredentialInfo = self.get(&request.resource()).await?.0.try_into()?;
-        let (_, secret_data) = self.get_secret(&cred.name).await?;
-        let secret: CredentialContainer = serde_json::from_slice(&secret_data)?;
-        cred.credential = Some(secret.into_cred()?);
+        cred.credential = Some(load_credential(self, &cred.name).await?);
         Ok(cred)
     }
 
@@ -201,6 +547,18 @@ impl<T: ResourceStore + Policy + SecretManager> CredentialsHandler for T {
         context: RequestContext,
     ) -> Result<CredentialInfo> {
         self.check_required(&request, context.recipient()).await?;
+        let curr = self
+            .get_credential(
+                GetCredentialRequest {
+                    name: request.name.clone(),
+                },
+                context.clone(),
+            )
+            .await?;
+        // check the precondition before the secret is replaced.
+        if request.etag.is_some() && request.etag != curr.etag {
+            return Err(Error::Conflict);
+        }
         if let Some(credential) = request.credential {
             self.update_secret(
                 &request.name,
@@ -210,14 +568,6 @@ impl<T: ResourceStore + Policy + SecretManager> CredentialsHandler for T {
             )
             .await?;
         }
-        let curr = self
-            .get_credential(
-                GetCredentialRequest {
-                    name: request.name.clone(),
-                },
-                context.clone(),
-            )
-            .await?;
         let cred = CredentialInfo {
             name: request.name.clone(),
             full_name: Some(request.name),
@@ -232,6 +582,7 @@ impl<T: ResourceStore + Policy + SecretManager> CredentialsHandler for T {
             owner: None,
             created_by: None,
             updated_by: None,
+            etag: curr.etag.clone(),
         };
         self.update(&curr.resource_ident(), cred.into())
             .await?
@@ -248,4 +599,317 @@ impl<T: ResourceStore + Policy + SecretManager> CredentialsHandler for T {
         self.delete_secret(&request.name).await?;
         self.delete(&request.resource()).await
     }
+
+    async fn validate_credential(
+        &self,
+        request: ValidateCredentialRequest,
+        context: RequestContext,
+    ) -> Result<ValidateCredentialResponse> {
+        let info = request
+            .credential
+            .ok_or_else(|| Error::invalid_argument("credential is required"))?;
+        let url = parse_normalized_url(&request.url)?;
+        let mut read_only = request.read_only.unwrap_or(info.read_only);
+        // validating secret material that is not registered yet is as privileged as
+        // registering it. Registered credentials must be readable for read probes and
+        // manageable for write probes, which modify the storage location.
+        let credential = match info.credential {
+            Some(credential) => {
+                self.authorize_checked(
+                    &ResourceIdent::credential(ResourceRef::Undefined),
+                    &Permission::Create,
+                    context.recipient(),
+                )
+                .await?;
+                credential
+            }
+            None => {
+                let permission = if read_only {
+                    &Permission::Read
+                } else {
+                    &Permission::Manage
+                };
+                self.authorize_checked(
+                    &ResourceIdent::credential(ResourceName::from_naive_str_split(&info.name)),
+                    permission,
+                    context.recipient(),
+                )
+                .await?;
+                // registered credentials are only used within their external locations.
+                let location = self
+                    .resolve_location(url.as_str())
+                    .await?
+                    .filter(|location| location.credential_name == info.name)
+                    .ok_or_else(|| {
+                        Error::invalid_argument(format!(
+                            "'{}' is not within an external location using credential '{}'",
+                            url, info.name
+                        ))
+                    })?;
+                read_only |= location.read_only;
+                load_credential(self, &info.name).await?
+            }
+        };
+        Ok(ValidateCredentialResponse {
+            results: validate_storage(&credential, url.as_str(), read_only).await?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cloud_client::aws::AmazonS3ConfigKey;
+    use cloud_client::gcp::GoogleConfigKey;
+
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::models::external_locations::v1::ExternalLocationInfo;
+    use crate::policy::Decision;
+    use crate::testing::{context, TestHandler};
+
+    fn storage_key() -> credential_info::Credential {
+        credential_info::Credential::AzureStorageKey(AzureStorageKey {
+            account_name: "account".to_string(),
+            account_key: "c2VjcmV0".to_string(),
+        })
+    }
+
+    fn statuses(
+        results: &[ValidationResult],
+    ) -> Vec<(ValidationOperation, ValidationResultStatus)> {
+        results
+            .iter()
+            .map(|r| (r.operation(), r.result()))
+            .collect()
+    }
+
+    #[test]
+    fn test_credential_container() {
+        let role = AwsIamRole {
+            role_arn: "arn:aws:iam::123456789012:role/sharing".to_string(),
+            external_id: Some("external".to_string()),
+        };
+        let container = CredentialContainer::from_get(
+            create_credential_request::Credential::AwsIamRole(role.clone()),
+        );
+        let container: CredentialContainer =
+            serde_json::from_slice(&container.to_vec().unwrap()).unwrap();
+        let credential = container.into_cred().unwrap();
+        assert_eq!(
+            credential,
+            credential_info::Credential::AwsIamRole(role.clone())
+        );
+
+        let builder = AmazonBuilder::try_from(&credential).unwrap();
+        assert_eq!(
+            builder.get_config_value(&AmazonS3ConfigKey::AssumeRoleArn),
+            Some(role.role_arn)
+        );
+        assert_eq!(
+            builder.get_config_value(&AmazonS3ConfigKey::AssumeRoleExternalId),
+            role.external_id
+        );
+        assert!(GoogleBuilder::try_from(&credential).is_err());
+
+        let credential = credential_info::Credential::AwsAccessKey(AwsAccessKey {
+            access_key_id: "key".to_string(),
+            secret_access_key: "secret".to_string(),
+            session_token: None,
+        });
+        let builder = AmazonBuilder::try_from(&credential).unwrap();
+        assert_eq!(
+            builder.get_config_value(&AmazonS3ConfigKey::AccessKeyId),
+            Some("key".to_string())
+        );
+
+        let credential = credential_info::Credential::GcpServiceAccountKey(GcpServiceAccountKey {
+            email: "sharing@project.iam.gserviceaccount.com".to_string(),
+            private_key_id: "id".to_string(),
+            private_key: "key".to_string(),
+        });
+        let builder = GoogleBuilder::try_from(&credential).unwrap();
+        let key = builder
+            .get_config_value(&GoogleConfigKey::ServiceAccountKey)
+            .unwrap();
+        let key: serde_json::Value = serde_json::from_str(&key).unwrap();
+        assert_eq!(
+            key["client_email"],
+            "sharing@project.iam.gserviceaccount.com"
+        );
+        assert!(AmazonBuilder::try_from(&credential).is_err());
+    }
+
+    /// Probe a local directory, local paths can not be validated with a credential.
+    async fn probe_local(url: &Url, read_only: bool) -> Vec<ValidationResult> {
+        let store = object_store::local::LocalFileSystem::new();
+        let prefix = Path::from_url_path(url.path()).unwrap();
+        probe_storage(&store, &prefix, read_only).await
+    }
+
+    #[tokio::test]
+    async fn test_probe_storage() {
+        use ValidationOperation::*;
+        use ValidationResultStatus::*;
+
+        let dir = tempfile::tempdir().unwrap();
+        let url = Url::from_directory_path(dir.path()).unwrap();
+
+        let results = probe_local(&url, false).await;
+        assert_eq!(
+            statuses(&results),
+            vec![(List, Pass), (Read, Pass), (Write, Pass), (Delete, Pass)]
+        );
+        // the marker object is removed again
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 0);
+
+        let results = probe_local(&url, true).await;
+        assert_eq!(
+            statuses(&results),
+            vec![(List, Pass), (Read, Skip), (Write, Skip), (Delete, Skip)]
+        );
+
+        std::fs::write(dir.path().join("data.parquet"), b"data").unwrap();
+        let results = probe_local(&url, true).await;
+        assert_eq!(statuses(&results)[1], (Read, Pass));
+
+        // nothing can be written below a regular file
+        let url = Url::from_directory_path(dir.path().join("data.parquet")).unwrap();
+        let results = probe_local(&url, false).await;
+        assert_eq!(statuses(&results)[2], (Write, Fail));
+        assert_eq!(statuses(&results)[3], (Delete, Skip));
+        assert!(results[2].message.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_validate_storage() {
+        // urls outside the cloud of the credential, including local paths, are never probed.
+        for url in [
+            "file:///tmp/table",
+            "memory:///table",
+            "s3://bucket/table",
+            "gs://bucket/table",
+            "https://example.com/table",
+            "ftp://host/path",
+            "abfss://container@account.dfs.core.windows.net/root/../other",
+        ] {
+            let result = validate_storage(&storage_key(), url, false).await;
+            assert!(matches!(result, Err(Error::InvalidArgument(_))), "{url}");
+        }
+
+        let check = |credential: &credential_info::Credential, url: &str| {
+            check_storage_scheme(credential, &Url::parse(url).unwrap()).is_ok()
+        };
+        assert!(check(
+            &storage_key(),
+            "abfss://container@account.dfs.core.windows.net/root"
+        ));
+        assert!(check(
+            &storage_key(),
+            "https://account.blob.core.windows.net/container/root"
+        ));
+        let role = credential_info::Credential::AwsIamRole(AwsIamRole {
+            role_arn: "arn:aws:iam::123456789012:role/sharing".to_string(),
+            external_id: None,
+        });
+        assert!(check(&role, "s3://bucket/root"));
+        assert!(check(&role, "s3a://bucket/root"));
+        assert!(!check(&role, "gs://bucket/root"));
+        assert!(!check(&role, "file:///tmp/root"));
+    }
+
+    #[tokio::test]
+    async fn test_validate_credential() {
+        let handler = TestHandler::new(Decision::Allow);
+        let request = CreateCredentialRequest {
+            name: "azure_key".to_string(),
+            purpose: Purpose::Storage.into(),
+            credential: Some(create_credential_request::Credential::AzureStorageKey(
+                AzureStorageKey {
+                    account_name: "account".to_string(),
+                    account_key: "c2VjcmV0".to_string(),
+                },
+            )),
+            ..Default::default()
+        };
+        handler.create_credential(request, context()).await.unwrap();
+
+        let dir = tempfile::tempdir().unwrap();
+        let url = Url::from_directory_path(dir.path()).unwrap();
+        let request = ValidateCredentialRequest {
+            credential: Some(CredentialInfo {
+                name: "azure_key".to_string(),
+                ..Default::default()
+            }),
+            url: url.to_string(),
+            read_only: None,
+        };
+        // the credential is not used by an external location yet.
+        let result = handler
+            .validate_credential(request.clone(), context())
+            .await;
+        assert!(matches!(result, Err(Error::InvalidArgument(msg)) if msg.contains("not within")));
+
+        // local paths are never probed, even within a location using the credential.
+        let location = ExternalLocationInfo {
+            name: "location".to_string(),
+            url: url.to_string(),
+            credential_name: "azure_key".to_string(),
+            ..Default::default()
+        };
+        handler.store.create(location.into()).await.unwrap();
+        let unsupported = |result: Result<ValidateCredentialResponse>| matches!(result, Err(Error::InvalidArgument(msg)) if msg.contains("not supported"));
+        let result = handler
+            .validate_credential(request.clone(), context())
+            .await;
+        assert!(unsupported(result));
+
+        // urls are resolved to their location only in normalized form.
+        let escaping = ValidateCredentialRequest {
+            url: format!("{}../other", url),
+            ..request.clone()
+        };
+        let result = handler.validate_credential(escaping, context()).await;
+        assert!(
+            matches!(result, Err(Error::InvalidArgument(msg)) if msg.contains("not normalized"))
+        );
+
+        // write probes require managing the credential.
+        let handler = TestHandler {
+            store: handler.store,
+            policy: Arc::new(ReadPolicy),
+        };
+        let result = handler
+            .validate_credential(request.clone(), context())
+            .await;
+        assert!(matches!(result, Err(Error::NotAllowed)));
+        let read_request = ValidateCredentialRequest {
+            read_only: Some(true),
+            ..request.clone()
+        };
+        let result = handler.validate_credential(read_request, context()).await;
+        assert!(unsupported(result));
+
+        let handler = handler.with_decision(Decision::Deny);
+        let result = handler.validate_credential(request, context()).await;
+        assert!(matches!(result, Err(Error::NotAllowed)));
+    }
+
+    /// Policy granting only the read permission.
+    struct ReadPolicy;
+
+    #[async_trait::async_trait]
+    impl Policy for ReadPolicy {
+        async fn authorize(
+            &self,
+            _: &ResourceIdent,
+            permission: &Permission,
+            _: &Recipient,
+        ) -> Result<Decision> {
+            Ok(match permission {
+                Permission::Read => Decision::Allow,
+                _ => Decision::Deny,
+            })
+        }
+    }
 }
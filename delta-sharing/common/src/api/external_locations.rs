@@ -1,11 +1,22 @@
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+
 use delta_sharing_derive::rest_handlers;
+use hashlink::LruCache;
 use itertools::Itertools;
+use url::Url;
 
+use super::credentials::{load_credential, storage_options, validate_storage};
 use super::{RequestContext, SecuredAction};
+use crate::models::credentials::v1::ValidationResultStatus;
 use crate::models::external_locations::v1::*;
-use crate::policy::{process_resources, Permission, Policy};
+use crate::policy::{list_visible, Permission, Policy};
 use crate::resources::{ResourceIdent, ResourceName, ResourceRef};
-use crate::{Error, ObjectLabel, Recipient, ResourceExt, ResourceStore, Result};
+use crate::{
+    Error, ObjectLabel, ProvidesResourceStore, ProvidesSecretManager, Recipient, ResourceExt,
+    ResourceStore, Result, SecretManager,
+};
 
 rest_handlers!(
     ExternalLocationsHandler, "external-locations",
@@ -30,6 +41,9 @@ rest_handlers!(
 #[async_trait::async_trait]
 pub trait ExternalLocationsHandler: Send + Sync + 'static {
     /// Create a new external location.
+    ///
+    /// When `skip_validation` is explicitly false, the location is only accepted if the
+    /// credential passes all validation probes on the url.
     async fn create_external_location(
         &self,
         request: CreateExternalLocationRequest,
@@ -65,8 +79,211 @@ pub trait ExternalLocationsHandler: Send + Sync + 'static {
     ) -> Result<ExternalLocationInfo>;
 }
 
+/// Resolver for the external location and storage options that give access to a url.
+#[async_trait::async_trait]
+pub trait ExternalLocationResolver: Send + Sync {
+    /// Find the external location with the longest url that contains `url`.
+    async fn resolve_location(&self, url: &str) -> Result<Option<ExternalLocationInfo>>;
+
+    /// Options to configure an object store for `url` with the credential of its location.
+    ///
+    /// Urls that are not within any external location are accessed without options.
+    async fn resolve_storage_options(&self, url: &str) -> Result<HashMap<String, String>>;
+}
+
+#[async_trait::async_trait]
+impl<T: ResourceStore + SecretManager> ExternalLocationResolver for T {
+    async fn resolve_location(&self, url: &str) -> Result<Option<ExternalLocationInfo>> {
+        Ok(list_all_locations(self)
+            .await?
+            .into_iter()
+            .filter(|location| contains(&location.url, url))
+            .max_by_key(|location| location.url.trim_end_matches('/').len()))
+    }
+
+    async fn resolve_storage_options(&self, url: &str) -> Result<HashMap<String, String>> {
+        let Some(location) = self.resolve_location(url).await? else {
+            return Ok(HashMap::new());
+        };
+        let credential = load_credential(self, &location.credential_name).await?;
+        Ok(storage_options(&credential)
+            .await?
+            .into_iter()
+            .map(|(key, value)| (key.to_string(), value))
+            .collect())
+    }
+}
+
+/// Resolves external locations from a resource store and the secrets of their credentials.
+#[derive(Clone)]
+pub struct StoredLocationResolver {
+    store: Arc<dyn ResourceStore>,
+    secrets: Arc<dyn SecretManager>,
+}
+
+impl StoredLocationResolver {
+    pub fn new(store: Arc<dyn ResourceStore>, secrets: Arc<dyn SecretManager>) -> Self {
+        Self { store, secrets }
+    }
+}
+
+impl ProvidesResourceStore for StoredLocationResolver {
+    fn store(&self) -> &dyn ResourceStore {
+        self.store.as_ref()
+    }
+}
+
+impl ProvidesSecretManager for StoredLocationResolver {
+    fn secret_manager(&self) -> &dyn SecretManager {
+        self.secrets.as_ref()
+    }
+}
+
+const DEFAULT_LOCATION_TTL: Duration = Duration::from_secs(60);
+const DEFAULT_MAX_LOCATIONS: usize = 1_000;
+
+type LocationCache = LruCache<String, (Option<ExternalLocationInfo>, Instant)>;
+type OptionsCache = LruCache<String, (HashMap<String, String>, Instant)>;
+
+/// Resolver caching the locations and storage options another resolver returns per url.
+///
+/// Resolving a url lists all external locations and loads the secret of a credential,
+/// which is too expensive to do whenever a table is accessed. Changes to locations and
+/// credentials take effect once the cached entries expire.
+pub struct CachedLocationResolver {
+    inner: Arc<dyn ExternalLocationResolver>,
+    locations: RwLock<LocationCache>,
+    options: RwLock<OptionsCache>,
+    ttl: Duration,
+}
+
+impl CachedLocationResolver {
+    /// Create a new instance of [`CachedLocationResolver`] wrapping the given resolver.
+    pub fn new(inner: Arc<dyn ExternalLocationResolver>) -> Self {
+        Self {
+            inner,
+            locations: RwLock::new(LruCache::new(DEFAULT_MAX_LOCATIONS)),
+            options: RwLock::new(LruCache::new(DEFAULT_MAX_LOCATIONS)),
+            ttl: DEFAULT_LOCATION_TTL,
+        }
+    }
+
+    /// Set the time resolved locations and storage options are cached.
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// Set the maximum number of urls locations and storage options are cached for.
+    pub fn with_max_entries(self, max_entries: usize) -> Self {
+        self.locations.write().unwrap().set_capacity(max_entries);
+        self.options.write().unwrap().set_capacity(max_entries);
+        self
+    }
+}
+
+/// Look up an entry that has not expired yet.
+fn lookup<V: Clone>(cache: &RwLock<LruCache<String, (V, Instant)>>, url: &str) -> Option<V> {
+    let cache = cache.read().unwrap();
+    let (value, expires_at) = cache.peek(url)?;
+    (*expires_at > Instant::now()).then(|| value.clone())
+}
+
+#[async_trait::async_trait]
+impl ExternalLocationResolver for CachedLocationResolver {
+    async fn resolve_location(&self, url: &str) -> Result<Option<ExternalLocationInfo>> {
+        if let Some(location) = lookup(&self.locations, url) {
+            return Ok(location);
+        }
+        let location = self.inner.resolve_location(url).await?;
+        self.locations.write().unwrap().insert(
+            url.to_string(),
+            (location.clone(), Instant::now() + self.ttl),
+        );
+        Ok(location)
+    }
+
+    async fn resolve_storage_options(&self, url: &str) -> Result<HashMap<String, String>> {
+        if let Some(options) = lookup(&self.options, url) {
+            return Ok(options);
+        }
+        let options = self.inner.resolve_storage_options(url).await?;
+        self.options.write().unwrap().insert(
+            url.to_string(),
+            (options.clone(), Instant::now() + self.ttl),
+        );
+        Ok(options)
+    }
+}
+
+/// Parse `url`, rejecting urls that differ from their normalized form.
+///
+/// Parsing resolves dot segments like `..` or `%2e%2e`, so a raw url could otherwise
+/// appear to be within a location it escapes. Urls are compared as strings, e.g. by
+/// [`contains`], only once they are normalized.
+pub(crate) fn parse_normalized_url(url: &str) -> Result<Url> {
+    let parsed = Url::parse(url)
+        .map_err(|e| Error::invalid_argument(format!("invalid url '{}': {}", url, e)))?;
+    if parsed.as_str() != url {
+        return Err(Error::invalid_argument(format!(
+            "url '{}' is not normalized, expected '{}'",
+            url, parsed
+        )));
+    }
+    Ok(parsed)
+}
+
+/// Whether `url` is `location` or a path within it.
+pub(crate) fn contains(location: &str, url: &str) -> bool {
+    let location = location.trim_end_matches('/');
+    url.strip_prefix(location)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+}
+
+async fn list_all_locations<T: ResourceStore + ?Sized>(
+    store: &T,
+) -> Result<Vec<ExternalLocationInfo>> {
+    let mut locations = Vec::new();
+    let mut page_token = None;
+    loop {
+        let (resources, next_page_token) = store
+            .list(
+                &ObjectLabel::ExternalLocationInfo,
+                None,
+                None,
+                None,
+                page_token,
+            )
+            .await?;
+        for resource in resources {
+            locations.push(resource.try_into()?);
+        }
+        page_token = next_page_token;
+        if page_token.is_none() {
+            return Ok(locations);
+        }
+    }
+}
+
+/// Reject a url that contains, or is contained in, the url of another external location.
+///
+/// Concurrent writes may each pass the check before the other location is stored, so
+/// writes check again once their location is stored and undo the write if it overlaps.
+/// Of two overlapping locations at most one remains.
+async fn check_overlap<T: ResourceStore + ?Sized>(store: &T, name: &str, url: &str) -> Result<()> {
+    for location in list_all_locations(store).await? {
+        if location.name != name && (contains(&location.url, url) || contains(url, &location.url)) {
+            return Err(Error::invalid_argument(format!(
+                "url '{}' overlaps with external location '{}'",
+                url, location.name
+            )));
+        }
+    }
+    Ok(())
+}
+
 #[async_trait::async_trait]
-impl<T: ResourceStore + Policy> ExternalLocationsHandler for T {
+impl<T: ResourceStore + Policy + SecretManager> ExternalLocationsHandler for T {
     async fn create_external_location(
         &self,
         request: CreateExternalLocationRequest,
@@ -81,18 +298,40 @@ impl<T: ResourceStore + Policy> ExternalLocationsHandler for T {
             comment: request.comment,
             ..Default::default()
         };
+        parse_normalized_url(&resource.url)?;
         let cred_ident = ResourceIdent::Credential(
             ResourceName::from_naive_str_split(&resource.credential_name).into(),
         );
+        check_overlap(self, &resource.name, &resource.url).await?;
         let (_credential, credential_ref) = self.get(&cred_ident).await?;
         if let ResourceRef::Uuid(uuid) = credential_ref {
             resource.credential_id = uuid.hyphenated().to_string();
         }
 
-        // TODO: validate we can access the url with the provide credential
+        if request.skip_validation == Some(false) {
+            let credential = load_credential(self, &resource.credential_name).await?;
+            let failed = validate_storage(&credential, &resource.url, resource.read_only)
+                .await?
+                .into_iter()
+                .filter(|r| r.result() == ValidationResultStatus::Fail)
+                .map(|r| r.operation().as_str_name())
+                .join(", ");
+            if !failed.is_empty() {
+                return Err(Error::invalid_argument(format!(
+                    "credential failed validation for operations: {failed}"
+                )));
+            }
+        }
 
-        let info = self.create(resource.into()).await?.0.try_into()?;
-        Ok(info)
+        let name = resource.name.clone();
+        let url = resource.url.clone();
+        let (created, created_ref) = self.create(resource.into()).await?;
+        if let Err(err) = check_overlap(self, &name, &url).await {
+            self.delete(&ResourceIdent::external_location(created_ref))
+                .await?;
+            return Err(err);
+        }
+        created.try_into()
     }
 
     async fn delete_external_location(
@@ -123,15 +362,16 @@ impl<T: ResourceStore + Policy> ExternalLocationsHandler for T {
         context: RequestContext,
     ) -> Result<ListExternalLocationsResponse> {
         self.check_required(&request, context.recipient()).await?;
-        let (mut resources, next_page_token) = self
-            .list(
-                &ObjectLabel::ExternalLocationInfo,
-                None,
-                request.max_results.map(|v| v as usize),
-                request.page_token,
-            )
-            .await?;
-        process_resources(self, context.as_ref(), &Permission::Read, &mut resources).await?;
+        let (resources, next_page_token) = list_visible(
+            self,
+            context.as_ref(),
+            &Permission::Read,
+            &ObjectLabel::ExternalLocationInfo,
+            None,
+            request.max_results.map(|v| v as usize),
+            request.page_token,
+        )
+        .await?;
         Ok(ListExternalLocationsResponse {
             external_locations: resources.into_iter().map(|r| r.try_into()).try_collect()?,
             next_page_token,
@@ -148,13 +388,21 @@ impl<T: ResourceStore + Policy> ExternalLocationsHandler for T {
         let (current, _) = self.get(&request.resource()).await?;
         let curr_ident = current.resource_ident();
         let mut current: ExternalLocationInfo = current.try_into()?;
+        let previous = current.clone();
+        // the update is based on the revision read above, unless the caller provided one.
+        if request.etag.is_some() {
+            current.etag = request.etag;
+        }
 
+        // the location is still stored under its current name while checking the url.
+        if let Some(url) = &request.url {
+            parse_normalized_url(url)?;
+            check_overlap(self, &previous.name, url).await?;
+            current.url = url.clone();
+        }
         if let Some(name) = request.new_name {
             current.name = name;
         }
-        if let Some(url) = request.url {
-            current.url = url;
-        }
         if let Some(credential_name) = request.credential_name {
             current.credential_name = credential_name;
         }
@@ -169,6 +417,310 @@ impl<T: ResourceStore + Policy> ExternalLocationsHandler for T {
         // - add update_* relations
         // - update owner if necessary
 
-        self.update(&curr_ident, current.into()).await?.0.try_into()
+        let (updated, updated_ref) = self.update(&curr_ident, current.into()).await?;
+        let updated: ExternalLocationInfo = updated.try_into()?;
+        if request.url.is_some() {
+            if let Err(err) = check_overlap(self, &updated.name, &updated.url).await {
+                let restored = ExternalLocationInfo {
+                    etag: updated.etag,
+                    ..previous
+                };
+                self.update(
+                    &ResourceIdent::external_location(updated_ref),
+                    restored.into(),
+                )
+                .await?;
+                return Err(err);
+            }
+        }
+        Ok(updated)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use url::Url;
+
+    use super::*;
+    use crate::api::credentials::CredentialsHandler;
+    use crate::models::credentials::v1::{
+        create_credential_request, AzureStorageKey, CreateCredentialRequest, Purpose,
+    };
+    use crate::policy::Decision;
+    use crate::testing::{context, TestHandler};
+
+    #[tokio::test]
+    async fn test_create_validated_location() {
+        let handler = TestHandler::new(Decision::Allow);
+        let request = CreateCredentialRequest {
+            name: "azure_key".to_string(),
+            purpose: Purpose::Storage.into(),
+            credential: Some(create_credential_request::Credential::AzureStorageKey(
+                AzureStorageKey {
+                    account_name: "account".to_string(),
+                    account_key: "c2VjcmV0".to_string(),
+                },
+            )),
+            ..Default::default()
+        };
+        handler.create_credential(request, context()).await.unwrap();
+
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("file"), b"data").unwrap();
+        let request = CreateExternalLocationRequest {
+            name: "location".to_string(),
+            url: Url::from_directory_path(dir.path().join("file"))
+                .unwrap()
+                .to_string(),
+            credential_name: "azure_key".to_string(),
+            skip_validation: Some(false),
+            ..Default::default()
+        };
+        let result = handler
+            .create_external_location(request.clone(), context())
+            .await;
+        assert!(matches!(result, Err(Error::InvalidArgument(_))));
+
+        // validation is opt-in
+        let request = CreateExternalLocationRequest {
+            skip_validation: None,
+            ..request
+        };
+        handler
+            .create_external_location(request, context())
+            .await
+            .unwrap();
+
+        // local paths never pass validation with a storage credential.
+        let request = CreateExternalLocationRequest {
+            name: "validated".to_string(),
+            url: Url::from_directory_path(dir.path().join("validated"))
+                .unwrap()
+                .to_string(),
+            credential_name: "azure_key".to_string(),
+            skip_validation: Some(false),
+            ..Default::default()
+        };
+        let result = handler.create_external_location(request, context()).await;
+        assert!(matches!(result, Err(Error::InvalidArgument(_))));
+        let result = handler
+            .store
+            .get(&ResourceIdent::external_location(ResourceName::new([
+                "validated",
+            ])))
+            .await;
+        assert!(matches!(result, Err(Error::NotFound)));
+    }
+
+    #[test]
+    fn test_contains() {
+        assert!(contains("s3://bucket/root/", "s3://bucket/root/table"));
+        assert!(contains("s3://bucket/root", "s3://bucket/root"));
+        assert!(!contains("s3://bucket/root", "s3://bucket/root2/table"));
+    }
+
+    #[test]
+    fn test_parse_normalized_url() {
+        let url = parse_normalized_url("s3://bucket/root/table").unwrap();
+        assert_eq!(url.as_str(), "s3://bucket/root/table");
+        for url in [
+            "s3://bucket/root/../other",
+            "s3://bucket/root/%2e%2e/other",
+            "s3://bucket/root/./table",
+            "not a url",
+        ] {
+            let result = parse_normalized_url(url);
+            assert!(matches!(result, Err(Error::InvalidArgument(_))), "{url}");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_resolve_location() {
+        let handler = TestHandler::new(Decision::Allow);
+        let request = CreateCredentialRequest {
+            name: "azure_key".to_string(),
+            purpose: Purpose::Storage.into(),
+            credential: Some(create_credential_request::Credential::AzureStorageKey(
+                AzureStorageKey {
+                    account_name: "account".to_string(),
+                    account_key: "c2VjcmV0".to_string(),
+                },
+            )),
+            ..Default::default()
+        };
+        handler.create_credential(request, context()).await.unwrap();
+        // nested locations can only exist if they were created before overlaps were rejected
+        for (name, url) in [
+            (
+                "root",
+                "abfss://container@account.dfs.core.windows.net/root",
+            ),
+            (
+                "nested",
+                "abfss://container@account.dfs.core.windows.net/root/nested/",
+            ),
+        ] {
+            let location = ExternalLocationInfo {
+                name: name.to_string(),
+                url: url.to_string(),
+                credential_name: "azure_key".to_string(),
+                ..Default::default()
+            };
+            handler.store.create(location.into()).await.unwrap();
+        }
+
+        let resolve = |url: &'static str| handler.store.resolve_location(url);
+        let location = resolve("abfss://container@account.dfs.core.windows.net/root/nested/t")
+            .await
+            .unwrap();
+        assert_eq!(location.unwrap().name, "nested");
+        let location = resolve("abfss://container@account.dfs.core.windows.net/root/t")
+            .await
+            .unwrap();
+        assert_eq!(location.unwrap().name, "root");
+        let location = resolve("abfss://container@account.dfs.core.windows.net/other")
+            .await
+            .unwrap();
+        assert!(location.is_none());
+
+        let options = handler
+            .store
+            .resolve_storage_options("abfss://container@account.dfs.core.windows.net/root/t")
+            .await
+            .unwrap();
+        assert_eq!(options["azure_storage_account_name"], "account");
+        let options = handler
+            .store
+            .resolve_storage_options("file:///tmp/table")
+            .await
+            .unwrap();
+        assert!(options.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_reject_overlapping_locations() {
+        let handler = TestHandler::new(Decision::Allow);
+        let request = CreateCredentialRequest {
+            name: "azure_key".to_string(),
+            purpose: Purpose::Storage.into(),
+            credential: Some(create_credential_request::Credential::AzureStorageKey(
+                AzureStorageKey {
+                    account_name: "account".to_string(),
+                    account_key: "c2VjcmV0".to_string(),
+                },
+            )),
+            ..Default::default()
+        };
+        handler.create_credential(request, context()).await.unwrap();
+
+        let create = |name: &str, url: &str| CreateExternalLocationRequest {
+            name: name.to_string(),
+            url: url.to_string(),
+            credential_name: "azure_key".to_string(),
+            ..Default::default()
+        };
+        handler
+            .create_external_location(create("root", "s3://bucket/root/"), context())
+            .await
+            .unwrap();
+        handler
+            .create_external_location(create("sibling", "s3://bucket/root2"), context())
+            .await
+            .unwrap();
+
+        for url in ["s3://bucket/root/nested", "s3://bucket", "s3://bucket/root"] {
+            let result = handler
+                .create_external_location(create("other", url), context())
+                .await;
+            assert!(matches!(result, Err(Error::InvalidArgument(_))), "{url}");
+        }
+
+        let request = UpdateExternalLocationRequest {
+            name: "sibling".to_string(),
+            url: Some("s3://bucket/root/sibling".to_string()),
+            ..Default::default()
+        };
+        let result = handler.update_external_location(request, context()).await;
+        assert!(matches!(result, Err(Error::InvalidArgument(_))));
+
+        // a location may be moved within its own url
+        let request = UpdateExternalLocationRequest {
+            name: "root".to_string(),
+            url: Some("s3://bucket/root/moved".to_string()),
+            ..Default::default()
+        };
+        handler
+            .update_external_location(request, context())
+            .await
+            .unwrap();
+
+        // also when it is renamed at the same time
+        let request = UpdateExternalLocationRequest {
+            name: "sibling".to_string(),
+            new_name: Some("renamed".to_string()),
+            url: Some("s3://bucket/root2/moved".to_string()),
+            ..Default::default()
+        };
+        let location = handler
+            .update_external_location(request, context())
+            .await
+            .unwrap();
+        assert_eq!(location.name, "renamed");
+
+        // of concurrently created overlapping locations at most one remains
+        let (first, second) = tokio::join!(
+            handler.create_external_location(create("first", "s3://bucket/other"), context()),
+            handler.create_external_location(create("second", "s3://bucket/other/x"), context()),
+        );
+        assert!(first.is_err() || second.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_cached_location_resolver() {
+        #[derive(Default)]
+        struct Counting(AtomicUsize);
+
+        #[async_trait::async_trait]
+        impl ExternalLocationResolver for Counting {
+            async fn resolve_location(&self, _: &str) -> Result<Option<ExternalLocationInfo>> {
+                self.0.fetch_add(1, Ordering::SeqCst);
+                Ok(None)
+            }
+
+            async fn resolve_storage_options(&self, _: &str) -> Result<HashMap<String, String>> {
+                self.0.fetch_add(1, Ordering::SeqCst);
+                Ok(HashMap::new())
+            }
+        }
+
+        let inner = Arc::new(Counting::default());
+        let resolver = CachedLocationResolver::new(inner.clone());
+        for _ in 0..2 {
+            resolver
+                .resolve_location("s3://bucket/table")
+                .await
+                .unwrap();
+            resolver
+                .resolve_storage_options("s3://bucket/table")
+                .await
+                .unwrap();
+        }
+        assert_eq!(inner.0.load(Ordering::SeqCst), 2);
+        resolver
+            .resolve_location("s3://bucket/other")
+            .await
+            .unwrap();
+        assert_eq!(inner.0.load(Ordering::SeqCst), 3);
+
+        let resolver = CachedLocationResolver::new(inner.clone()).with_ttl(Duration::ZERO);
+        for _ in 0..2 {
+            resolver
+                .resolve_location("s3://bucket/table")
+                .await
+                .unwrap();
+        }
+        assert_eq!(inner.0.load(Ordering::SeqCst), 5);
     }
 }
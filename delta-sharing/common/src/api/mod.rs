@@ -1,20 +1,27 @@
 pub mod catalogs;
 pub mod credentials;
 pub mod external_locations;
+pub mod permissions;
 pub mod recipients;
 pub mod schemas;
 pub mod shares;
 pub mod sharing;
 pub mod tables;
+pub mod temporary_credentials;
 
 pub use catalogs::CatalogHandler;
 pub use credentials::CredentialsHandler;
-pub use external_locations::ExternalLocationsHandler;
+pub use external_locations::{
+    CachedLocationResolver, ExternalLocationResolver, ExternalLocationsHandler,
+    StoredLocationResolver,
+};
+pub use permissions::PermissionsHandler;
 pub use recipients::RecipientsHandler;
 pub use schemas::SchemasHandler;
 pub use shares::SharesHandler;
 pub use sharing::{SharingDiscoveryHandler, SharingQueryHandler};
 pub use tables::TablesHandler;
+pub use temporary_credentials::TemporaryCredentialsHandler;
 
 use crate::{Permission, Recipient, ResourceIdent};
 
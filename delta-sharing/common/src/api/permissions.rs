@@ -0,0 +1,691 @@
+//! Unity Catalog style permissions API.
+//!
+//! Privileges are stored as [`HasGrant`](AssociationLabel::HasGrant) associations from the
+//! principal - a recipient - to the securable. Next to the privileges themselves, the
+//! association records the [`Permission`]s the privileges map to, so that policies
+//! evaluating grants in the resource graph do not need to know about privileges.
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
+
+use delta_sharing_derive::rest_handlers;
+
+use super::RequestContext;
+use crate::models::permissions::v1::*;
+use crate::policy::{Decision, Permission, Policy};
+use crate::resources::{ResourceIdent, ResourceName};
+use crate::{
+    AssociationChange, AssociationLabel, Error, ObjectLabel, PropertyMap, Recipient, ResourceExt,
+    ResourceStore, Result,
+};
+
+// securables are identified by type and name, so requests are authorized by the handler.
+rest_handlers!(
+    PermissionsHandler, "permissions",
+    [
+        GetPermissionsRequest, _, Read, GetPermissionsResponse with [
+            securable_type: path as String,
+            full_name: path as String,
+            principal: query as Option<String>,
+        ];
+        UpdatePermissionsRequest, _, Manage, UpdatePermissionsResponse with [
+            securable_type: path as String,
+            full_name: path as String,
+        ];
+        GetEffectivePermissionsRequest, _, Read, GetEffectivePermissionsResponse
+            at "effective-permissions" with [
+            securable_type: path as String,
+            full_name: path as String,
+            principal: query as Option<String>,
+        ];
+    ]
+);
+
+/// Name of the association property holding the granted permissions.
+pub const GRANT_PERMISSIONS_PROPERTY: &str = "permissions";
+
+/// Name of the association property holding the granted privileges.
+pub const GRANT_PRIVILEGES_PROPERTY: &str = "privileges";
+
+#[async_trait::async_trait]
+pub trait PermissionsHandler: Send + Sync + 'static {
+    /// Get the permissions on a securable.
+    ///
+    /// Only privileges granted directly on the securable are returned.
+    async fn get_permissions(
+        &self,
+        request: GetPermissionsRequest,
+        context: RequestContext,
+    ) -> Result<GetPermissionsResponse>;
+
+    /// Update the permissions on a securable.
+    ///
+    /// The caller must hold the manage permission on the securable.
+    async fn update_permissions(
+        &self,
+        request: UpdatePermissionsRequest,
+        context: RequestContext,
+    ) -> Result<UpdatePermissionsResponse>;
+
+    /// Get the effective permissions on a securable.
+    ///
+    /// Next to the privileges granted directly on the securable, this includes
+    /// the privileges inherited from its parents.
+    async fn get_effective_permissions(
+        &self,
+        request: GetEffectivePermissionsRequest,
+        context: RequestContext,
+    ) -> Result<GetEffectivePermissionsResponse>;
+}
+
+impl Privilege {
+    /// The permission a grant of this privilege corresponds to.
+    pub fn permission(&self) -> Option<Permission> {
+        match self {
+            Privilege::Unspecified => None,
+            Privilege::Select => Some(Permission::Select),
+            Privilege::Modify | Privilege::WriteFiles => Some(Permission::Write),
+            Privilege::UseCatalog | Privilege::UseSchema => Some(Permission::Use),
+            Privilege::CreateCatalog
+            | Privilege::CreateSchema
+            | Privilege::CreateTable
+            | Privilege::CreateExternalLocation => Some(Permission::Create),
+            Privilege::ReadFiles => Some(Permission::Read),
+            Privilege::Browse => Some(Permission::Browse),
+            Privilege::Manage | Privilege::AllPrivileges => Some(Permission::Manage),
+        }
+    }
+}
+
+impl SecurableType {
+    fn parse(value: &str) -> Result<Self> {
+        match Self::from_str_name(&value.to_ascii_uppercase()) {
+            None | Some(SecurableType::Unspecified) => Err(Error::invalid_argument(format!(
+                "unknown securable type: {value}"
+            ))),
+            Some(securable_type) => Ok(securable_type),
+        }
+    }
+
+    fn ident(&self, name: ResourceName) -> ResourceIdent {
+        match self {
+            SecurableType::Catalog => ResourceIdent::catalog(name),
+            SecurableType::Schema => ResourceIdent::schema(name),
+            SecurableType::Table => ResourceIdent::table(name),
+            SecurableType::Credential => ResourceIdent::credential(name),
+            SecurableType::ExternalLocation => ResourceIdent::external_location(name),
+            SecurableType::Share | SecurableType::Unspecified => ResourceIdent::share(name),
+        }
+    }
+
+    /// The securable type of resources with the given label, if they are securables.
+    fn from_label(label: &ObjectLabel) -> Option<Self> {
+        match label {
+            ObjectLabel::CatalogInfo => Some(SecurableType::Catalog),
+            ObjectLabel::SchemaInfo => Some(SecurableType::Schema),
+            ObjectLabel::TableInfo => Some(SecurableType::Table),
+            ObjectLabel::CredentialInfo => Some(SecurableType::Credential),
+            ObjectLabel::ExternalLocationInfo => Some(SecurableType::ExternalLocation),
+            ObjectLabel::ShareInfo => Some(SecurableType::Share),
+            _ => None,
+        }
+    }
+}
+
+fn parse_privileges(privileges: &[i32]) -> Result<Vec<Privilege>> {
+    privileges
+        .iter()
+        .map(|p| match Privilege::try_from(*p) {
+            Ok(Privilege::Unspecified) | Err(_) => {
+                Err(Error::invalid_argument(format!("unknown privilege: {p}")))
+            }
+            Ok(privilege) => Ok(privilege),
+        })
+        .collect()
+}
+
+fn grant_properties(privileges: &BTreeSet<Privilege>) -> PropertyMap {
+    let permissions = privileges
+        .iter()
+        .filter_map(|p| p.permission())
+        .map(|p| p.as_ref().to_string())
+        .collect::<BTreeSet<_>>();
+    let privileges = privileges
+        .iter()
+        .map(|p| p.as_str_name().to_string())
+        .collect::<Vec<_>>();
+    PropertyMap::from([
+        (
+            GRANT_PERMISSIONS_PROPERTY.to_string(),
+            Vec::from_iter(permissions).into(),
+        ),
+        (GRANT_PRIVILEGES_PROPERTY.to_string(), privileges.into()),
+    ])
+}
+
+fn grant_privileges(properties: Option<PropertyMap>) -> BTreeSet<Privilege> {
+    properties
+        .and_then(|mut props| props.remove(GRANT_PRIVILEGES_PROPERTY))
+        .and_then(|value| serde_json::from_value::<Vec<String>>(value).ok())
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|p| Privilege::from_str_name(p))
+        .collect()
+}
+
+async fn authorize_securable<T: Policy + ?Sized>(
+    policy: &T,
+    resource: &ResourceIdent,
+    permission: &Permission,
+    context: &RequestContext,
+) -> Result<()> {
+    match policy
+        .authorize(resource, permission, context.recipient())
+        .await?
+    {
+        Decision::Allow => Ok(()),
+        Decision::Deny => Err(Error::NotAllowed),
+    }
+}
+
+/// Get the privileges currently granted to a principal on a securable.
+///
+/// # Errors
+/// - [InvalidArgument](Error::InvalidArgument) if the principal does not exist.
+async fn current_grant<T: ResourceStore + ?Sized>(
+    store: &T,
+    securable: &ResourceIdent,
+    principal: &str,
+) -> Result<Option<BTreeSet<Privilege>>> {
+    let ident = ResourceIdent::recipient(ResourceName::new([principal]));
+    match store
+        .get_association(&ident, securable, &AssociationLabel::HasGrant)
+        .await
+    {
+        Ok(properties) => Ok(Some(grant_privileges(properties))),
+        Err(Error::NotFound) => match store.get(&ident).await {
+            Ok(_) => Ok(None),
+            Err(Error::NotFound) => Err(Error::invalid_argument(format!(
+                "unknown principal: {principal}"
+            ))),
+            Err(err) => Err(err),
+        },
+        Err(err) => Err(err),
+    }
+}
+
+/// Get the resources a resource directly inherits privileges from.
+async fn list_parents<T: ResourceStore + ?Sized>(
+    store: &T,
+    resource: &ResourceIdent,
+) -> Result<Vec<ResourceIdent>> {
+    let mut parents = Vec::new();
+    let mut page_token = None;
+    loop {
+        let (idents, next_page_token) = store
+            .list_associations(
+                resource,
+                &AssociationLabel::ChildOf,
+                None,
+                None,
+                None,
+                page_token,
+            )
+            .await?;
+        parents.extend(idents);
+        match next_page_token {
+            Some(token) => page_token = Some(token),
+            None => return Ok(parents),
+        }
+    }
+}
+
+/// Get the privileges granted directly on a securable, keyed by principal.
+async fn list_grants<T: ResourceStore + ?Sized>(
+    store: &T,
+    securable: &ResourceIdent,
+    principal: Option<&str>,
+) -> Result<HashMap<String, BTreeSet<Privilege>>> {
+    if let Some(principal) = principal {
+        let ident = ResourceIdent::recipient(ResourceName::new([principal]));
+        return match store
+            .get_association(&ident, securable, &AssociationLabel::HasGrant)
+            .await
+        {
+            Ok(properties) => Ok(HashMap::from([(
+                principal.to_string(),
+                grant_privileges(properties),
+            )])),
+            // surface missing securables, a principal without grants is not an error.
+            Err(Error::NotFound) => store.get(securable).await.map(|_| HashMap::new()),
+            Err(err) => Err(err),
+        };
+    }
+
+    let mut grants = HashMap::new();
+    let mut page_token = None;
+    loop {
+        let (principals, next_page_token) = store
+            .list_associations(
+                securable,
+                &AssociationLabel::GrantedTo,
+                None,
+                None,
+                None,
+                page_token,
+            )
+            .await?;
+        let resources = store.get_many(&principals).await?;
+        let inverse = store
+            .get_associations(securable, &principals, &AssociationLabel::GrantedTo)
+            .await?;
+        for ((resource, _), properties) in resources.into_iter().zip(inverse) {
+            grants.insert(
+                resource.resource_name().to_string(),
+                grant_privileges(properties),
+            );
+        }
+        match next_page_token {
+            Some(token) => page_token = Some(token),
+            None => break,
+        }
+    }
+    Ok(grants)
+}
+
+fn to_assignments(grants: HashMap<String, BTreeSet<Privilege>>) -> Vec<PrivilegeAssignment> {
+    let mut assignments = grants
+        .into_iter()
+        .map(|(principal, privileges)| PrivilegeAssignment {
+            principal,
+            privileges: privileges.into_iter().map(|p| p as i32).collect(),
+        })
+        .collect::<Vec<_>>();
+    assignments.sort_by(|a, b| a.principal.cmp(&b.principal));
+    assignments
+}
+
+#[async_trait::async_trait]
+impl<T: ResourceStore + Policy> PermissionsHandler for T {
+    async fn get_permissions(
+        &self,
+        request: GetPermissionsRequest,
+        context: RequestContext,
+    ) -> Result<GetPermissionsResponse> {
+        let securable_type = SecurableType::parse(&request.securable_type)?;
+        let securable =
+            securable_type.ident(ResourceName::from_naive_str_split(&request.full_name));
+        authorize_securable(self, &securable, &Permission::Read, &context).await?;
+        let grants = list_grants(self, &securable, request.principal.as_deref()).await?;
+        Ok(GetPermissionsResponse {
+            privilege_assignments: to_assignments(grants),
+        })
+    }
+
+    async fn update_permissions(
+        &self,
+        request: UpdatePermissionsRequest,
+        context: RequestContext,
+    ) -> Result<UpdatePermissionsResponse> {
+        let securable_type = SecurableType::parse(&request.securable_type)?;
+        let securable =
+            securable_type.ident(ResourceName::from_naive_str_split(&request.full_name));
+        authorize_securable(self, &securable, &Permission::Manage, &context).await?;
+
+        // all changes are validated before any is applied, so a request is either
+        // applied as a whole or not at all.
+        let mut grants: Vec<(String, Option<BTreeSet<Privilege>>, BTreeSet<Privilege>)> =
+            Vec::new();
+        for change in &request.changes {
+            let add = parse_privileges(&change.add)?;
+            let remove = parse_privileges(&change.remove)?;
+            let idx = match grants.iter().position(|(p, _, _)| p == &change.principal) {
+                Some(idx) => idx,
+                None => {
+                    let current = current_grant(self, &securable, &change.principal).await?;
+                    let privileges = current.clone().unwrap_or_default();
+                    grants.push((change.principal.clone(), current, privileges));
+                    grants.len() - 1
+                }
+            };
+            let privileges = &mut grants[idx].2;
+            privileges.extend(add);
+            privileges.retain(|p| !remove.contains(p));
+        }
+
+        let changes = grants
+            .into_iter()
+            .filter_map(|(principal, current, privileges)| {
+                let from = ResourceIdent::recipient(ResourceName::new([principal]));
+                let to = securable.clone();
+                let label = AssociationLabel::HasGrant;
+                let properties = (!privileges.is_empty()).then(|| grant_properties(&privileges));
+                match (current, properties) {
+                    (Some(current), _) if current == privileges => None,
+                    (Some(_), None) => Some(AssociationChange::Remove { from, to, label }),
+                    (Some(_), properties) => Some(AssociationChange::Update {
+                        from,
+                        to,
+                        label,
+                        properties,
+                    }),
+                    (None, None) => None,
+                    (None, properties) => Some(AssociationChange::Add {
+                        from,
+                        to,
+                        label,
+                        properties,
+                    }),
+                }
+            })
+            .collect::<Vec<_>>();
+        self.apply_association_changes(&changes).await?;
+
+        let grants = list_grants(self, &securable, None).await?;
+        Ok(UpdatePermissionsResponse {
+            privilege_assignments: to_assignments(grants),
+        })
+    }
+
+    async fn get_effective_permissions(
+        &self,
+        request: GetEffectivePermissionsRequest,
+        context: RequestContext,
+    ) -> Result<GetEffectivePermissionsResponse> {
+        let securable_type = SecurableType::parse(&request.securable_type)?;
+        let securable =
+            securable_type.ident(ResourceName::from_naive_str_split(&request.full_name));
+        authorize_securable(self, &securable, &Permission::Read, &context).await?;
+
+        // privileges are inherited along the same parent links policies evaluate,
+        // starting with the closest ancestors.
+        let mut effective = HashMap::<String, Vec<EffectivePrivilege>>::new();
+        let mut pending: VecDeque<(ResourceIdent, Option<(String, String)>)> =
+            VecDeque::from([(securable, None)]);
+        let mut visited = HashSet::new();
+        while let Some((ident, inherited)) = pending.pop_front() {
+            let grants = list_grants(self, &ident, request.principal.as_deref()).await?;
+            for (principal, privileges) in grants {
+                effective
+                    .entry(principal)
+                    .or_default()
+                    .extend(privileges.into_iter().map(|privilege| EffectivePrivilege {
+                        privilege: privilege as i32,
+                        inherited_from_type: inherited.as_ref().map(|(t, _)| t.clone()),
+                        inherited_from_name: inherited.as_ref().map(|(_, n)| n.clone()),
+                    }));
+            }
+            for parent in list_parents(self, &ident).await? {
+                if !visited.insert(parent.clone()) {
+                    continue;
+                }
+                let (resource, _) = self.get(&parent).await?;
+                let label = resource.resource_label();
+                let parent_type = SecurableType::from_label(label)
+                    .map(|t| t.as_str_name().to_ascii_lowercase())
+                    .unwrap_or_else(|| label.to_string());
+                pending.push_back((
+                    parent,
+                    Some((parent_type, resource.resource_name().to_string())),
+                ));
+            }
+        }
+
+        let mut privilege_assignments = effective
+            .into_iter()
+            .map(|(principal, privileges)| EffectivePrivilegeAssignment {
+                principal,
+                privileges,
+            })
+            .collect::<Vec<_>>();
+        privilege_assignments.sort_by(|a, b| a.principal.cmp(&b.principal));
+        Ok(GetEffectivePermissionsResponse {
+            privilege_assignments,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{CatalogInfo, RecipientInfo, SchemaInfo};
+    use crate::testing::{context, TestHandler};
+
+    async fn handler(decision: Decision) -> TestHandler {
+        let handler = TestHandler::new(decision);
+        let store = &handler.store;
+        for name in ["alice", "bob"] {
+            let recipient = RecipientInfo {
+                name: name.to_string(),
+                ..Default::default()
+            };
+            store.create(recipient.into()).await.unwrap();
+        }
+        let catalog = CatalogInfo {
+            name: "catalog".to_string(),
+            ..Default::default()
+        };
+        store.create(catalog.into()).await.unwrap();
+        let schema = SchemaInfo {
+            name: "schema".to_string(),
+            catalog_name: "catalog".to_string(),
+            ..Default::default()
+        };
+        store.create(schema.into()).await.unwrap();
+        handler
+    }
+
+    fn change(principal: &str, add: &[Privilege], remove: &[Privilege]) -> PermissionsChange {
+        PermissionsChange {
+            principal: principal.to_string(),
+            add: add.iter().map(|p| *p as i32).collect(),
+            remove: remove.iter().map(|p| *p as i32).collect(),
+        }
+    }
+
+    fn update(
+        securable_type: &str,
+        full_name: &str,
+        changes: Vec<PermissionsChange>,
+    ) -> UpdatePermissionsRequest {
+        UpdatePermissionsRequest {
+            securable_type: securable_type.to_string(),
+            full_name: full_name.to_string(),
+            changes,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_update_permissions() {
+        let handler = handler(Decision::Allow).await;
+
+        let request = update(
+            "catalog",
+            "catalog",
+            vec![
+                change("alice", &[Privilege::UseCatalog, Privilege::Select], &[]),
+                change("bob", &[Privilege::Browse], &[]),
+            ],
+        );
+        let response = handler
+            .update_permissions(request, context())
+            .await
+            .unwrap();
+        assert_eq!(response.privilege_assignments.len(), 2);
+        assert_eq!(
+            response.privilege_assignments[0].privileges,
+            vec![Privilege::Select as i32, Privilege::UseCatalog as i32]
+        );
+
+        let request = update(
+            "CATALOG",
+            "catalog",
+            vec![
+                change("alice", &[Privilege::Modify], &[Privilege::Select]),
+                change("bob", &[], &[Privilege::Browse]),
+            ],
+        );
+        let response = handler
+            .update_permissions(request, context())
+            .await
+            .unwrap();
+        assert_eq!(
+            response.privilege_assignments,
+            vec![PrivilegeAssignment {
+                principal: "alice".to_string(),
+                privileges: vec![Privilege::Modify as i32, Privilege::UseCatalog as i32],
+            }]
+        );
+
+        // grants record the permissions the privileges map to.
+        let properties = handler
+            .get_association(
+                &ResourceIdent::recipient(ResourceName::new(["alice"])),
+                &ResourceIdent::catalog(ResourceName::new(["catalog"])),
+                &AssociationLabel::HasGrant,
+            )
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            properties[GRANT_PERMISSIONS_PROPERTY],
+            serde_json::json!(["use", "write"])
+        );
+
+        let request = GetPermissionsRequest {
+            securable_type: "catalog".to_string(),
+            full_name: "catalog".to_string(),
+            principal: Some("bob".to_string()),
+        };
+        let response = handler.get_permissions(request, context()).await.unwrap();
+        assert!(response.privilege_assignments.is_empty());
+
+        let request = update("volume", "catalog", vec![]);
+        let result = handler.update_permissions(request, context()).await;
+        assert!(matches!(result, Err(Error::InvalidArgument(_))));
+
+        let request = update("catalog", "catalog", vec![change("alice", &[], &[])]);
+        let mut request = request;
+        request.changes[0].add = vec![Privilege::Unspecified as i32];
+        let result = handler.update_permissions(request, context()).await;
+        assert!(matches!(result, Err(Error::InvalidArgument(_))));
+    }
+
+    #[tokio::test]
+    async fn test_effective_permissions() {
+        let handler = handler(Decision::Allow).await;
+        for (securable_type, full_name, privilege) in [
+            ("catalog", "catalog", Privilege::UseCatalog),
+            ("schema", "catalog.schema", Privilege::UseSchema),
+        ] {
+            let request = update(
+                securable_type,
+                full_name,
+                vec![change("alice", &[privilege], &[])],
+            );
+            handler
+                .update_permissions(request, context())
+                .await
+                .unwrap();
+        }
+
+        let request = GetEffectivePermissionsRequest {
+            securable_type: "schema".to_string(),
+            full_name: "catalog.schema".to_string(),
+            principal: None,
+        };
+        let response = handler
+            .get_effective_permissions(request, context())
+            .await
+            .unwrap();
+        assert_eq!(
+            response.privilege_assignments,
+            vec![EffectivePrivilegeAssignment {
+                principal: "alice".to_string(),
+                privileges: vec![
+                    EffectivePrivilege {
+                        privilege: Privilege::UseSchema as i32,
+                        inherited_from_type: None,
+                        inherited_from_name: None,
+                    },
+                    EffectivePrivilege {
+                        privilege: Privilege::UseCatalog as i32,
+                        inherited_from_type: Some("catalog".to_string()),
+                        inherited_from_name: Some("catalog".to_string()),
+                    },
+                ],
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_update_permissions_atomic() {
+        let handler = handler(Decision::Allow).await;
+        let request = update(
+            "catalog",
+            "catalog",
+            vec![
+                change("alice", &[Privilege::Select], &[]),
+                change("carol", &[Privilege::Select], &[]),
+            ],
+        );
+        let result = handler.update_permissions(request, context()).await;
+        assert!(matches!(result, Err(Error::InvalidArgument(_))));
+
+        // the valid change for alice was not applied either.
+        let request = GetPermissionsRequest {
+            securable_type: "catalog".to_string(),
+            full_name: "catalog".to_string(),
+            principal: None,
+        };
+        let response = handler.get_permissions(request, context()).await.unwrap();
+        assert!(response.privilege_assignments.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_effective_permissions_follow_parent_links() {
+        let handler = handler(Decision::Allow).await;
+        // the schema is created before its catalog, so it is not linked to it.
+        let schema = SchemaInfo {
+            name: "schema".to_string(),
+            catalog_name: "other".to_string(),
+            ..Default::default()
+        };
+        handler.store.create(schema.into()).await.unwrap();
+        let catalog = CatalogInfo {
+            name: "other".to_string(),
+            ..Default::default()
+        };
+        handler.store.create(catalog.into()).await.unwrap();
+        let request = update(
+            "catalog",
+            "other",
+            vec![change("alice", &[Privilege::UseCatalog], &[])],
+        );
+        handler
+            .update_permissions(request, context())
+            .await
+            .unwrap();
+
+        let request = GetEffectivePermissionsRequest {
+            securable_type: "schema".to_string(),
+            full_name: "other.schema".to_string(),
+            principal: None,
+        };
+        let response = handler
+            .get_effective_permissions(request, context())
+            .await
+            .unwrap();
+        assert!(response.privilege_assignments.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_permissions_not_allowed() {
+        let handler = handler(Decision::Deny).await;
+        let request = update(
+            "catalog",
+            "catalog",
+            vec![change("alice", &[Privilege::Manage], &[])],
+        );
+        let result = handler.update_permissions(request, context()).await;
+        assert!(matches!(result, Err(Error::NotAllowed)));
+    }
+}
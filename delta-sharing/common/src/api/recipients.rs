@@ -1,9 +1,12 @@
+use std::net::IpAddr;
+
 use delta_sharing_derive::rest_handlers;
+use ipnet::IpNet;
 use itertools::Itertools;
 
 use super::{RequestContext, SecuredAction};
 use crate::models::recipients::v1::*;
-use crate::policy::{process_resources, Permission, Policy};
+use crate::policy::{list_visible, Permission, Policy};
 use crate::resources::{ResourceIdent, ResourceName, ResourceRef};
 use crate::{Error, ObjectLabel, Recipient, ResourceStore, Result};
 
@@ -69,11 +72,17 @@ impl<T: ResourceStore + Policy> RecipientsHandler for T {
         context: RequestContext,
     ) -> Result<RecipientInfo> {
         self.check_required(&request, context.as_ref()).await?;
+        if let Some(list) = &request.ip_access_list {
+            list.allowed_ip_addresses
+                .iter()
+                .try_for_each(|value| parse_ip_network(value).map(|_| ()))?;
+        }
         let resource = RecipientInfo {
             name: request.name,
             authentication_type: request.authentication_type,
             comment: request.comment,
             properties: request.properties,
+            ip_access_list: request.ip_access_list,
             ..Default::default()
         };
 
@@ -108,15 +117,16 @@ impl<T: ResourceStore + Policy> RecipientsHandler for T {
         context: RequestContext,
     ) -> Result<ListRecipientsResponse> {
         self.check_required(&request, context.as_ref()).await?;
-        let (mut resources, next_page_token) = self
-            .list(
-                &ObjectLabel::RecipientInfo,
-                None,
-                request.max_results.map(|v| v as usize),
-                request.page_token,
-            )
-            .await?;
-        process_resources(self, context.as_ref(), &Permission::Read, &mut resources).await?;
+        let (resources, next_page_token) = list_visible(
+            self,
+            context.as_ref(),
+            &Permission::Read,
+            &ObjectLabel::RecipientInfo,
+            None,
+            request.max_results.map(|v| v as usize),
+            request.page_token,
+        )
+        .await?;
         Ok(ListRecipientsResponse {
             recipients: resources.into_iter().map(|r| r.try_into()).try_collect()?,
             next_page_token,
@@ -132,3 +142,14 @@ impl<T: ResourceStore + Policy> RecipientsHandler for T {
         todo!("update_recipient")
     }
 }
+
+/// Parse an entry of an [`IpAccessList`].
+///
+/// Entries are networks in CIDR notation, single addresses match only themselves.
+pub fn parse_ip_network(value: &str) -> Result<IpNet> {
+    let value = value.trim();
+    value
+        .parse::<IpNet>()
+        .or_else(|_| value.parse::<IpAddr>().map(IpNet::from))
+        .map_err(|_| Error::invalid_argument(format!("invalid IP address or network: {}", value)))
+}
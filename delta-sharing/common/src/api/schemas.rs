@@ -3,7 +3,7 @@ use itertools::Itertools;
 
 use super::{RequestContext, SecuredAction};
 use crate::models::schemas::v1::*;
-use crate::policy::{process_resources, Permission, Policy};
+use crate::policy::{list_visible, Permission, Policy};
 use crate::resources::{ResourceIdent, ResourceName, ResourceRef};
 use crate::{Error, ObjectLabel, Recipient, ResourceStore, Result};
 
@@ -101,15 +101,16 @@ impl<T: ResourceStore + Policy> SchemasHandler for T {
         context: RequestContext,
     ) -> Result<ListSchemasResponse> {
         self.check_required(&request, context.as_ref()).await?;
-        let (mut resources, next_page_token) = self
-            .list(
-                &ObjectLabel::SchemaInfo,
-                Some(&ResourceName::new([&request.catalog_name])),
-                request.max_results.map(|v| v as usize),
-                request.page_token,
-            )
-            .await?;
-        process_resources(self, context.as_ref(), &Permission::Read, &mut resources).await?;
+        let (resources, next_page_token) = list_visible(
+            self,
+            context.as_ref(),
+            &Permission::Read,
+            &ObjectLabel::SchemaInfo,
+            Some(&ResourceName::new([&request.catalog_name])),
+            request.max_results.map(|v| v as usize),
+            request.page_token,
+        )
+        .await?;
         Ok(ListSchemasResponse {
             schemas: resources.into_iter().map(|r| r.try_into()).try_collect()?,
             next_page_token,
@@ -144,6 +145,7 @@ impl<T: ResourceStore + Policy> SchemasHandler for T {
             properties: request.properties,
             catalog_name: catalog_name.to_owned(),
             full_name: Some(format!("{}.{}", catalog_name, request.new_name)),
+            etag: request.etag,
             ..Default::default()
         };
         // TODO:
@@ -3,7 +3,7 @@ use itertools::Itertools;
 
 use super::{RequestContext, SecuredAction};
 use crate::models::shares::v1::*;
-use crate::policy::{process_resources, Permission, Policy};
+use crate::policy::{list_visible, Permission, Policy};
 use crate::resources::{ResourceIdent, ResourceName, ResourceRef};
 use crate::{Error, ObjectLabel, Recipient, ResourceStore, Result};
 
@@ -96,15 +96,16 @@ impl<T: ResourceStore + Policy> SharesHandler for T {
         context: RequestContext,
     ) -> Result<ListSharesResponse> {
         self.check_required(&request, context.as_ref()).await?;
-        let (mut resources, next_page_token) = self
-            .list(
-                &ObjectLabel::ShareInfo,
-                None,
-                request.max_results.map(|v| v as usize),
-                request.page_token,
-            )
-            .await?;
-        process_resources(self, context.as_ref(), &Permission::Read, &mut resources).await?;
+        let (resources, next_page_token) = list_visible(
+            self,
+            context.as_ref(),
+            &Permission::Read,
+            &ObjectLabel::ShareInfo,
+            None,
+            request.max_results.map(|v| v as usize),
+            request.page_token,
+        )
+        .await?;
         Ok(ListSharesResponse {
             shares: resources.into_iter().map(|r| r.try_into()).try_collect()?,
             next_page_token,
@@ -131,6 +132,7 @@ impl<T: ResourceStore + Policy> SharesHandler for T {
             name: request.new_name.unwrap_or_else(|| request.name.clone()),
             comment: request.comment,
             owner: request.owner,
+            etag: request.etag,
             ..Default::default()
         };
         // TODO:
@@ -1,9 +1,10 @@
+use bytes::Bytes;
 use delta_sharing_derive::rest_handlers;
 use itertools::Itertools;
 
 use super::{RequestContext, SecuredAction};
 use crate::models::sharing::v1::*;
-use crate::policy::{process_resources, Permission, Policy};
+use crate::policy::{list_visible, process_resources, Permission, Policy};
 use crate::resources::{ResourceIdent, ResourceName, ResourceRef};
 use crate::{AssociationLabel, Error, ObjectLabel, Recipient, Resource, ResourceStore, Result};
 
@@ -73,9 +74,28 @@ rest_handlers!(
             schema: path as String,
             name: path as String,
         ];
+        QueryTableRequest, SharingTable, Read, QueryResponse with [
+            share: path as String,
+            schema: path as String,
+            name: path as String,
+        ];
     ]
 );
 
+// files are returned as raw parquet, so the request is not part of the generated handlers.
+impl SecuredAction for GetTableFileRequest {
+    fn resource(&self) -> ResourceIdent {
+        ResourceIdent::SharingTable(ResourceRef::Name(ResourceName::new([
+            &self.share,
+            &self.schema,
+            &self.name,
+        ])))
+    }
+    fn permission(&self) -> &'static Permission {
+        &Permission::Read
+    }
+}
+
 #[async_trait::async_trait]
 pub trait SharingQueryHandler: Send + Sync + 'static {
     async fn get_table_version(
@@ -89,6 +109,23 @@ pub trait SharingQueryHandler: Send + Sync + 'static {
         request: GetTableMetadataRequest,
         context: RequestContext,
     ) -> Result<QueryResponse>;
+
+    /// Query the data files of a table.
+    ///
+    /// Files are returned with a URL they can be read from, either signed for direct
+    /// access to storage, or pointing to [`get_table_file`](Self::get_table_file).
+    async fn query_table(
+        &self,
+        request: QueryTableRequest,
+        context: RequestContext,
+    ) -> Result<QueryResponse>;
+
+    /// Read a data file of a table as parquet, with all data hidden from the recipient removed.
+    async fn get_table_file(
+        &self,
+        request: GetTableFileRequest,
+        context: RequestContext,
+    ) -> Result<Bytes>;
 }
 
 #[async_trait::async_trait]
@@ -99,21 +136,16 @@ impl<T: ResourceStore + Policy> SharingDiscoveryHandler for T {
         context: RequestContext,
     ) -> Result<ListSharesResponse> {
         self.check_required(&request, context.as_ref()).await?;
-        let (mut resources, next_page_token) = self
-            .list(
-                &ObjectLabel::ShareInfo,
-                None,
-                request.max_results.map(|v| v as usize),
-                request.page_token.clone(),
-            )
-            .await?;
-        process_resources(self, context.as_ref(), &Permission::Read, &mut resources).await?;
-
-        // if all resources gor filtered, but there are more pages, try again
-        if resources.is_empty() && next_page_token.is_some() {
-            return self.list_shares(request, context).await;
-        }
-
+        let (resources, next_page_token) = list_visible(
+            self,
+            context.as_ref(),
+            &Permission::Read,
+            &ObjectLabel::ShareInfo,
+            None,
+            request.max_results.map(|v| v as usize),
+            request.page_token,
+        )
+        .await?;
         Ok(ListSharesResponse {
             items: resources.into_iter().map(|r| r.try_into()).try_collect()?,
             next_page_token,
@@ -131,18 +163,28 @@ impl<T: ResourceStore + Policy> SharingDiscoveryHandler for T {
         context: RequestContext,
     ) -> Result<ListSharingSchemasResponse> {
         self.check_required(&request, context.recipient()).await?;
+        let filter = self
+            .visibility_filter(
+                &ObjectLabel::SharingSchemaInfo,
+                &Permission::Read,
+                context.recipient(),
+            )
+            .await?;
         let (idents, next_page_token) = self
             .list_associations(
                 &request.resource(),
                 &AssociationLabel::ParentOf,
                 Some(&ResourceIdent::SharingSchema(ResourceRef::Undefined)),
+                filter.as_ref(),
                 request.max_results.map(|m| m as usize),
                 request.page_token,
             )
             .await?;
         let (mut resources, _): (Vec<Resource>, Vec<ResourceRef>) =
             self.get_many(&idents).await?.into_iter().unzip();
-        process_resources(self, context.as_ref(), &Permission::Read, &mut resources).await?;
+        if filter.is_none() {
+            process_resources(self, context.as_ref(), &Permission::Read, &mut resources).await?;
+        }
         Ok(ListSharingSchemasResponse {
             items: resources.into_iter().map(|r| r.try_into()).try_collect()?,
             next_page_token,
@@ -4,8 +4,8 @@ use itertools::Itertools;
 use super::{RequestContext, SecuredAction};
 use crate::models::tables::v1::*;
 use crate::{
-    process_resources, Error, ObjectLabel, Permission, Policy, Recipient, ResourceIdent,
-    ResourceName, ResourceRef, ResourceStore, Result,
+    list_visible, Error, ObjectLabel, Permission, Policy, Recipient, ResourceIdent, ResourceName,
+    ResourceRef, ResourceStore, Result,
 };
 
 const MAX_RESULTS_TABLES: usize = 50;
@@ -98,15 +98,16 @@ impl<T: ResourceStore + Policy> TablesHandler for T {
     ) -> Result<ListTableSummariesResponse> {
         self.check_required(&request, context.as_ref()).await?;
         // TODO: handle like operators for schema and table name
-        let (mut resources, next_page_token) = self
-            .list(
-                &ObjectLabel::TableInfo,
-                Some(&ResourceName::new([&request.catalog_name])),
-                request.max_results.map(|v| v as usize),
-                request.page_token,
-            )
-            .await?;
-        process_resources(self, context.as_ref(), &Permission::Read, &mut resources).await?;
+        let (resources, next_page_token) = list_visible(
+            self,
+            context.as_ref(),
+            &Permission::Read,
+            &ObjectLabel::TableInfo,
+            Some(&ResourceName::new([&request.catalog_name])),
+            request.max_results.map(|v| v as usize),
+            request.page_token,
+        )
+        .await?;
         let infos: Vec<TableInfo> = resources.into_iter().map(|r| r.try_into()).try_collect()?;
         Ok(ListTableSummariesResponse {
             tables: infos.into_iter().map(|r| r.into()).collect(),
@@ -122,20 +123,21 @@ impl<T: ResourceStore + Policy> TablesHandler for T {
         // TODO: assert max_results is within bounds <= 50
         self.check_required(&request, context.as_ref()).await?;
         // TODO: handle like operators for schema and table name
-        let (mut resources, next_page_token) = self
-            .list(
-                &ObjectLabel::TableInfo,
-                Some(&ResourceName::new([
-                    &request.catalog_name,
-                    &request.schema_name,
-                ])),
-                request
-                    .max_results
-                    .map(|v| usize::min(v as usize, MAX_RESULTS_TABLES)),
-                request.page_token,
-            )
-            .await?;
-        process_resources(self, context.as_ref(), &Permission::Read, &mut resources).await?;
+        let (resources, next_page_token) = list_visible(
+            self,
+            context.as_ref(),
+            &Permission::Read,
+            &ObjectLabel::TableInfo,
+            Some(&ResourceName::new([
+                &request.catalog_name,
+                &request.schema_name,
+            ])),
+            request
+                .max_results
+                .map(|v| usize::min(v as usize, MAX_RESULTS_TABLES)),
+            request.page_token,
+        )
+        .await?;
         Ok(ListTablesResponse {
             tables: resources.into_iter().map(|r| r.try_into()).try_collect()?,
             next_page_token,
@@ -0,0 +1,628 @@
+//! Temporary credentials for engines that read tables directly from storage.
+//!
+//! Credentials are issued with the storage credential of the external location that
+//! contains the requested location, and are restricted to that location.
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use cloud_client::aws::AmazonBuilder;
+use cloud_client::azure::{service_sas, AzureBuilder, SasScope};
+use cloud_client::gcp::{
+    AccessBoundaryRule, AvailabilityCondition, GoogleBuilder, DEFAULT_STS_ENDPOINT,
+};
+use url::Url;
+use uuid::Uuid;
+
+use super::credentials::load_credential;
+use super::external_locations::{parse_normalized_url, ExternalLocationResolver};
+use super::RequestContext;
+use crate::models::credentials::v1::credential_info::Credential;
+use crate::models::external_locations::v1::ExternalLocationInfo;
+use crate::models::tables::v1::TableInfo;
+use crate::models::temporary_credentials::v1::temporary_credential::Credentials;
+use crate::models::temporary_credentials::v1::*;
+use crate::policy::{Permission, Policy};
+use crate::resources::{ResourceIdent, ResourceName};
+use crate::{Error, ResourceStore, Result, SecretManager};
+
+/// Lifetime of the issued credentials.
+const CREDENTIAL_LIFETIME: Duration = Duration::from_secs(3600);
+
+#[async_trait::async_trait]
+pub trait TemporaryCredentialsHandler: Send + Sync + 'static {
+    /// Generate temporary credentials for the storage location of a table.
+    ///
+    /// Reading requires the select permission on the table, writing the write permission.
+    async fn generate_temporary_table_credentials(
+        &self,
+        request: GenerateTemporaryTableCredentialsRequest,
+        context: RequestContext,
+    ) -> Result<TemporaryCredential>;
+
+    /// Generate temporary credentials for a path within an external location.
+    ///
+    /// Reading requires the read permission on the external location, writing the write
+    /// permission and creating a table the create permission.
+    async fn generate_temporary_path_credentials(
+        &self,
+        request: GenerateTemporaryPathCredentialsRequest,
+        context: RequestContext,
+    ) -> Result<TemporaryCredential>;
+}
+
+#[async_trait::async_trait]
+impl<T: ResourceStore + Policy + SecretManager> TemporaryCredentialsHandler for T {
+    async fn generate_temporary_table_credentials(
+        &self,
+        request: GenerateTemporaryTableCredentialsRequest,
+        context: RequestContext,
+    ) -> Result<TemporaryCredential> {
+        let (permission, write) = match request.operation() {
+            TableOperation::Read => (Permission::Select, false),
+            TableOperation::ReadWrite => (Permission::Write, true),
+            TableOperation::Unspecified => {
+                return Err(Error::invalid_argument("operation is required"))
+            }
+        };
+        let table_id = Uuid::parse_str(&request.table_id)
+            .map_err(|_| Error::invalid_argument("table_id must be a UUID"))?;
+        // authorize before loading the table, so unknown ids are not revealed to
+        // recipients without access.
+        let ident = ResourceIdent::table(table_id);
+        self.authorize_checked(&ident, &permission, context.as_ref())
+            .await?;
+        let table: TableInfo = self.get(&ident).await?.0.try_into()?;
+
+        let url = table
+            .storage_location
+            .ok_or_else(|| Error::invalid_argument("table has no storage location"))?;
+        let url = parse_normalized_url(&url)?;
+        let location = find_external_location(self, &url).await?;
+        issue_credential(self, &location, &url, write).await
+    }
+
+    async fn generate_temporary_path_credentials(
+        &self,
+        request: GenerateTemporaryPathCredentialsRequest,
+        context: RequestContext,
+    ) -> Result<TemporaryCredential> {
+        let (permission, write) = match request.operation() {
+            PathOperation::PathRead => (Permission::Read, false),
+            PathOperation::PathReadWrite => (Permission::Write, true),
+            PathOperation::PathCreateTable => (Permission::Create, true),
+            PathOperation::Unspecified => {
+                return Err(Error::invalid_argument("operation is required"))
+            }
+        };
+        let url = parse_normalized_url(&request.url)?;
+        let location = find_external_location(self, &url).await?;
+        self.authorize_checked(
+            &ResourceIdent::external_location(ResourceName::new([&location.name])),
+            &permission,
+            context.as_ref(),
+        )
+        .await?;
+
+        if request.dry_run.unwrap_or(false) {
+            return Ok(TemporaryCredential {
+                url: url.to_string(),
+                ..Default::default()
+            });
+        }
+        issue_credential(self, &location, &url, write).await
+    }
+}
+
+/// Find the external location that contains `url`.
+async fn find_external_location<T: ExternalLocationResolver + ?Sized>(
+    locations: &T,
+    url: &Url,
+) -> Result<ExternalLocationInfo> {
+    locations
+        .resolve_location(url.as_str())
+        .await?
+        .ok_or_else(|| Error::invalid_argument(format!("no external location contains '{}'", url)))
+}
+
+async fn issue_credential<T: SecretManager + ?Sized>(
+    secrets: &T,
+    location: &ExternalLocationInfo,
+    url: &Url,
+    write: bool,
+) -> Result<TemporaryCredential> {
+    if write && location.read_only {
+        tracing::warn!(
+            "write credentials requested for read only location {}",
+            location.name
+        );
+        return Err(Error::NotAllowed);
+    }
+    let credential = load_credential(secrets, &location.credential_name).await?;
+    let (credentials, expires_at) = StorageScope::parse(url)?.issue(&credential, write).await?;
+    Ok(TemporaryCredential {
+        expiration_time: expires_at.timestamp_millis(),
+        url: url.to_string(),
+        credentials: Some(credentials),
+    })
+}
+
+/// The part of a cloud storage service credentials are restricted to.
+#[derive(Debug, PartialEq)]
+enum StorageScope {
+    S3 {
+        bucket: String,
+        prefix: String,
+    },
+    Azure {
+        account: String,
+        container: String,
+        directory: String,
+    },
+    Gcs {
+        bucket: String,
+        prefix: String,
+    },
+}
+
+impl StorageScope {
+    fn parse(url: &Url) -> Result<Self> {
+        let host = url.host_str().unwrap_or_default().to_string();
+        let path = url.path().trim_matches('/').to_string();
+        let unsupported =
+            || Error::invalid_argument(format!("unsupported storage location: {url}"));
+        match url.scheme() {
+            "s3" | "s3a" => Ok(Self::S3 {
+                bucket: host,
+                prefix: path,
+            }),
+            "gs" => Ok(Self::Gcs {
+                bucket: host,
+                prefix: path,
+            }),
+            // abfss://<container>@<account>.dfs.core.windows.net/<path>
+            "abfs" | "abfss" if !url.username().is_empty() => Ok(Self::Azure {
+                account: host.split('.').next().unwrap_or_default().to_string(),
+                container: url.username().to_string(),
+                directory: path,
+            }),
+            // https://<account>.blob.core.windows.net/<container>/<path>
+            "https"
+                if host.ends_with(".blob.core.windows.net")
+                    || host.ends_with(".dfs.core.windows.net") =>
+            {
+                let (container, directory) = path.split_once('/').unwrap_or((&path, ""));
+                Ok(Self::Azure {
+                    account: host.split('.').next().unwrap_or_default().to_string(),
+                    container: container.to_string(),
+                    directory: directory.to_string(),
+                })
+            }
+            _ => Err(unsupported()),
+        }
+    }
+
+    async fn issue(
+        &self,
+        credential: &Credential,
+        write: bool,
+    ) -> Result<(Credentials, DateTime<Utc>)> {
+        match (self, credential) {
+            (Self::S3 { bucket, prefix }, Credential::AwsIamRole(_)) => {
+                let config = AmazonBuilder::try_from(credential)?.build()?;
+                let policy = s3_session_policy(bucket, prefix, write);
+                let scoped = config
+                    .assume_role_with_policy(&policy, CREDENTIAL_LIFETIME)
+                    .await?;
+                let credentials = AwsTemporaryCredentials {
+                    access_key_id: scoped.credential.key_id,
+                    secret_access_key: scoped.credential.secret_key,
+                    session_token: scoped.credential.token.unwrap_or_default(),
+                };
+                Ok((
+                    Credentials::AwsTempCredentials(credentials),
+                    scoped.expires_at,
+                ))
+            }
+            (Self::Azure { account, .. }, Credential::AzureStorageKey(key)) => {
+                if &key.account_name != account {
+                    return Err(Error::invalid_argument(format!(
+                        "storage key is not valid for account '{}'",
+                        account
+                    )));
+                }
+                let start = Utc::now();
+                let expiry = start + CREDENTIAL_LIFETIME;
+                let pairs = service_sas(&self.sas_scope(write)?, &key.account_key, start, expiry)?;
+                Ok((sas_credentials(pairs), expiry))
+            }
+            (
+                Self::Azure { account, .. },
+                Credential::AzureServicePrincipal(_) | Credential::AzureManagedIdentity(_),
+            ) => {
+                let config = AzureBuilder::try_from(credential)?.build()?;
+                let service = Url::parse(&format!("https://{account}.blob.core.windows.net"))?;
+                let expiry = Utc::now() + CREDENTIAL_LIFETIME;
+                let scoped = config
+                    .user_delegation_sas(&service, &self.sas_scope(write)?, expiry)
+                    .await?;
+                Ok((sas_credentials(scoped.credential), scoped.expires_at))
+            }
+            (Self::Gcs { bucket, prefix }, Credential::GcpServiceAccountKey(_)) => {
+                let config = GoogleBuilder::try_from(credential)?.build()?;
+                let rules = [gcs_access_boundary(bucket, prefix, write)];
+                let scoped = config
+                    .downscoped_token(DEFAULT_STS_ENDPOINT, &rules)
+                    .await?;
+                let credentials = GcpOauthToken {
+                    oauth_token: scoped.credential,
+                };
+                Ok((Credentials::GcpOauthToken(credentials), scoped.expires_at))
+            }
+            // static keys can not be restricted to a location.
+            _ => Err(Error::invalid_argument(
+                "the credential of the external location can not issue temporary credentials",
+            )),
+        }
+    }
+
+    fn sas_scope(&self, write: bool) -> Result<SasScope> {
+        let Self::Azure {
+            account,
+            container,
+            directory,
+        } = self
+        else {
+            unreachable!("only called for azure locations")
+        };
+        if directory.is_empty() {
+            return Err(Error::invalid_argument(
+                "temporary credentials can only be issued for directories within a container",
+            ));
+        }
+        Ok(SasScope {
+            account: account.clone(),
+            container: container.clone(),
+            directory: directory.clone(),
+            permissions: if write { "racwdl" } else { "rl" }.to_string(),
+        })
+    }
+}
+
+fn sas_credentials(pairs: Vec<(String, String)>) -> Credentials {
+    let sas_token = url::form_urlencoded::Serializer::new(String::new())
+        .extend_pairs(pairs)
+        .finish();
+    Credentials::AzureUserDelegationSas(AzureUserDelegationSas { sas_token })
+}
+
+/// Session policy restricting an assumed role to the objects below `prefix`.
+///
+/// <https://docs.aws.amazon.com/IAM/latest/UserGuide/access_policies.html#policies_session>
+fn s3_session_policy(bucket: &str, prefix: &str, write: bool) -> String {
+    let mut actions = vec!["s3:GetObject", "s3:GetObjectVersion"];
+    if write {
+        actions.extend([
+            "s3:PutObject",
+            "s3:DeleteObject",
+            "s3:AbortMultipartUpload",
+            "s3:ListMultipartUploadParts",
+        ]);
+    }
+    let (objects, list_prefixes) = if prefix.is_empty() {
+        (format!("arn:aws:s3:::{bucket}/*"), vec!["*".to_string()])
+    } else {
+        (
+            format!("arn:aws:s3:::{bucket}/{prefix}/*"),
+            vec![prefix.to_string(), format!("{prefix}/*")],
+        )
+    };
+    serde_json::json!({
+        "Version": "2012-10-17",
+        "Statement": [
+            {
+                "Effect": "Allow",
+                "Action": actions,
+                "Resource": [objects],
+            },
+            {
+                "Effect": "Allow",
+                "Action": ["s3:ListBucket", "s3:GetBucketLocation"],
+                "Resource": [format!("arn:aws:s3:::{bucket}")],
+                "Condition": { "StringLike": { "s3:prefix": list_prefixes } },
+            },
+        ],
+    })
+    .to_string()
+}
+
+/// Credential access boundary restricting a token to the objects below `prefix`.
+fn gcs_access_boundary(bucket: &str, prefix: &str, write: bool) -> AccessBoundaryRule {
+    let role = if write {
+        "inRole:roles/storage.objectAdmin"
+    } else {
+        "inRole:roles/storage.objectViewer"
+    };
+    let availability_condition = (!prefix.is_empty()).then(|| AvailabilityCondition {
+        expression: format!(
+            "resource.name.startsWith('projects/_/buckets/{bucket}/objects/{prefix}/') || \
+             api.getAttribute('storage.googleapis.com/objectListPrefix', '').startsWith('{prefix}/')"
+        ),
+    });
+    AccessBoundaryRule {
+        available_resource: format!("//storage.googleapis.com/projects/_/buckets/{bucket}"),
+        available_permissions: vec![role.to_string()],
+        availability_condition,
+    }
+}
+
+#[cfg(feature = "axum")]
+pub(crate) use temporary_credentials_handler::*;
+
+#[cfg(feature = "axum")]
+mod temporary_credentials_handler {
+    use axum::extract::{Json, State};
+    use axum::Extension;
+
+    use super::{
+        GenerateTemporaryPathCredentialsRequest, GenerateTemporaryTableCredentialsRequest,
+        RequestContext, Result, TemporaryCredential, TemporaryCredentialsHandler,
+    };
+    use crate::Recipient;
+
+    pub(crate) async fn generate_temporary_table_credentials<T: TemporaryCredentialsHandler>(
+        State(handler): State<T>,
+        Extension(recipient): Extension<Recipient>,
+        Json(request): Json<GenerateTemporaryTableCredentialsRequest>,
+    ) -> Result<Json<TemporaryCredential>> {
+        let ctx = RequestContext { recipient };
+        Ok(Json(
+            handler
+                .generate_temporary_table_credentials(request, ctx)
+                .await?,
+        ))
+    }
+
+    pub(crate) async fn generate_temporary_path_credentials<T: TemporaryCredentialsHandler>(
+        State(handler): State<T>,
+        Extension(recipient): Extension<Recipient>,
+        Json(request): Json<GenerateTemporaryPathCredentialsRequest>,
+    ) -> Result<Json<TemporaryCredential>> {
+        let ctx = RequestContext { recipient };
+        Ok(Json(
+            handler
+                .generate_temporary_path_credentials(request, ctx)
+                .await?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::credentials::CredentialsHandler;
+    use crate::models::credentials::v1::{
+        create_credential_request, AzureStorageKey, CreateCredentialRequest, Purpose,
+    };
+    use crate::models::{CatalogInfo, SchemaInfo};
+    use crate::policy::Decision;
+    use crate::resources::ResourceRef;
+    use crate::testing::{context, TestHandler};
+
+    /// Set up a table within an azure location and return its id.
+    async fn setup(handler: &TestHandler, read_only: bool) -> String {
+        let request = CreateCredentialRequest {
+            name: "azure_key".to_string(),
+            purpose: Purpose::Storage.into(),
+            credential: Some(create_credential_request::Credential::AzureStorageKey(
+                AzureStorageKey {
+                    account_name: "account".to_string(),
+                    account_key: "c2VjcmV0".to_string(),
+                },
+            )),
+            ..Default::default()
+        };
+        handler.create_credential(request, context()).await.unwrap();
+        let location = ExternalLocationInfo {
+            name: "location".to_string(),
+            url: "abfss://container@account.dfs.core.windows.net/root/".to_string(),
+            credential_name: "azure_key".to_string(),
+            read_only,
+            ..Default::default()
+        };
+        handler.store.create(location.into()).await.unwrap();
+        let catalog = CatalogInfo {
+            name: "catalog".to_string(),
+            ..Default::default()
+        };
+        handler.store.create(catalog.into()).await.unwrap();
+        let schema = SchemaInfo {
+            name: "schema".to_string(),
+            catalog_name: "catalog".to_string(),
+            ..Default::default()
+        };
+        handler.store.create(schema.into()).await.unwrap();
+        let table = TableInfo {
+            name: "table".to_string(),
+            schema_name: "schema".to_string(),
+            catalog_name: "catalog".to_string(),
+            storage_location: Some(
+                "abfss://container@account.dfs.core.windows.net/root/schema/table".to_string(),
+            ),
+            ..Default::default()
+        };
+        let (_, table_ref) = handler.store.create(table.into()).await.unwrap();
+        let ResourceRef::Uuid(table_id) = table_ref else {
+            panic!("expected a UUID reference");
+        };
+        table_id.hyphenated().to_string()
+    }
+
+    fn handler() -> TestHandler {
+        TestHandler::new(Decision::Allow)
+    }
+
+    #[test]
+    fn test_storage_scope() {
+        let parse = |url: &str| StorageScope::parse(&Url::parse(url).unwrap());
+        let scope = parse("s3://bucket/path/to/table/").unwrap();
+        assert_eq!(
+            scope,
+            StorageScope::S3 {
+                bucket: "bucket".to_string(),
+                prefix: "path/to/table".to_string(),
+            }
+        );
+        let scope = parse("https://account.blob.core.windows.net/container/dir").unwrap();
+        assert_eq!(
+            scope,
+            StorageScope::Azure {
+                account: "account".to_string(),
+                container: "container".to_string(),
+                directory: "dir".to_string(),
+            }
+        );
+        assert!(parse("file:///tmp/table").is_err());
+    }
+
+    #[test]
+    fn test_s3_session_policy() {
+        let policy: serde_json::Value =
+            serde_json::from_str(&s3_session_policy("bucket", "root/table", false)).unwrap();
+        let statements = policy["Statement"].as_array().unwrap();
+        assert_eq!(
+            statements[0]["Resource"][0],
+            "arn:aws:s3:::bucket/root/table/*"
+        );
+        assert!(!statements[0]["Action"]
+            .as_array()
+            .unwrap()
+            .contains(&"s3:PutObject".into()));
+        assert_eq!(
+            statements[1]["Condition"]["StringLike"]["s3:prefix"][1],
+            "root/table/*"
+        );
+
+        let policy: serde_json::Value =
+            serde_json::from_str(&s3_session_policy("bucket", "root", true)).unwrap();
+        assert!(policy["Statement"][0]["Action"]
+            .as_array()
+            .unwrap()
+            .contains(&"s3:PutObject".into()));
+    }
+
+    #[tokio::test]
+    async fn test_temporary_table_credentials() {
+        let handler = handler();
+        let table_id = setup(&handler, false).await;
+
+        let request = GenerateTemporaryTableCredentialsRequest {
+            table_id: table_id.clone(),
+            operation: TableOperation::Read.into(),
+        };
+        let credential = handler
+            .generate_temporary_table_credentials(request.clone(), context())
+            .await
+            .unwrap();
+        assert_eq!(
+            credential.url,
+            "abfss://container@account.dfs.core.windows.net/root/schema/table"
+        );
+        assert!(credential.expiration_time > Utc::now().timestamp_millis());
+        let Some(Credentials::AzureUserDelegationSas(sas)) = credential.credentials else {
+            panic!("expected a SAS token");
+        };
+        let pairs: Vec<(String, String)> = url::form_urlencoded::parse(sas.sas_token.as_bytes())
+            .into_owned()
+            .collect();
+        assert!(pairs.contains(&("sr".to_string(), "d".to_string())));
+        assert!(pairs.contains(&("sdd".to_string(), "3".to_string())));
+        assert!(pairs.contains(&("sp".to_string(), "rl".to_string())));
+
+        let invalid = GenerateTemporaryTableCredentialsRequest {
+            table_id: "not-a-uuid".to_string(),
+            operation: TableOperation::Read.into(),
+        };
+        let result = handler
+            .generate_temporary_table_credentials(invalid, context())
+            .await;
+        assert!(matches!(result, Err(Error::InvalidArgument(_))));
+
+        let handler = handler.with_decision(Decision::Deny);
+        let result = handler
+            .generate_temporary_table_credentials(request, context())
+            .await;
+        assert!(matches!(result, Err(Error::NotAllowed)));
+
+        // unknown tables are indistinguishable from tables the recipient can not access.
+        let unknown = GenerateTemporaryTableCredentialsRequest {
+            table_id: Uuid::now_v7().to_string(),
+            operation: TableOperation::Read.into(),
+        };
+        let result = handler
+            .generate_temporary_table_credentials(unknown, context())
+            .await;
+        assert!(matches!(result, Err(Error::NotAllowed)));
+    }
+
+    #[tokio::test]
+    async fn test_temporary_path_credentials() {
+        let handler = handler();
+        setup(&handler, true).await;
+
+        let request = GenerateTemporaryPathCredentialsRequest {
+            url: "abfss://container@account.dfs.core.windows.net/root/other".to_string(),
+            operation: PathOperation::PathRead.into(),
+            dry_run: None,
+        };
+        let credential = handler
+            .generate_temporary_path_credentials(request.clone(), context())
+            .await
+            .unwrap();
+        assert!(credential.credentials.is_some());
+
+        let dry_run = GenerateTemporaryPathCredentialsRequest {
+            dry_run: Some(true),
+            ..request.clone()
+        };
+        let credential = handler
+            .generate_temporary_path_credentials(dry_run, context())
+            .await
+            .unwrap();
+        assert!(credential.credentials.is_none());
+
+        // the location is read only.
+        let write = GenerateTemporaryPathCredentialsRequest {
+            operation: PathOperation::PathReadWrite.into(),
+            ..request.clone()
+        };
+        let result = handler
+            .generate_temporary_path_credentials(write, context())
+            .await;
+        assert!(matches!(result, Err(Error::NotAllowed)));
+
+        let outside = GenerateTemporaryPathCredentialsRequest {
+            url: "abfss://container@account.dfs.core.windows.net/other".to_string(),
+            ..request.clone()
+        };
+        let result = handler
+            .generate_temporary_path_credentials(outside, context())
+            .await;
+        assert!(matches!(result, Err(Error::InvalidArgument(_))));
+
+        // urls escaping the location with dot segments are rejected, not resolved.
+        for url in [
+            "abfss://container@account.dfs.core.windows.net/root/../other",
+            "abfss://container@account.dfs.core.windows.net/root/%2e%2e/other",
+            "abfss://container@account.dfs.core.windows.net/root/%2E%2E/other",
+        ] {
+            let escaping = GenerateTemporaryPathCredentialsRequest {
+                url: url.to_string(),
+                ..request.clone()
+            };
+            let result = handler
+                .generate_temporary_path_credentials(escaping, context())
+                .await;
+            assert!(matches!(result, Err(Error::InvalidArgument(_))), "{url}");
+        }
+    }
+}
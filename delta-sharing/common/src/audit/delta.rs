@@ -0,0 +1,238 @@
+use std::collections::HashMap;
+use std::sync::{Arc, LazyLock};
+
+use delta_kernel::arrow::array::{
+    ArrayRef, Int32Array, Int64Array, MapBuilder, MapFieldNames, RecordBatch, StringArray,
+    StringBuilder, TimestampMicrosecondArray,
+};
+use delta_kernel::arrow::datatypes::{DataType as ArrowDataType, Field, Schema as ArrowSchema};
+use delta_kernel::engine::arrow_data::ArrowEngineData;
+use delta_kernel::engine::default::executor::tokio::TokioBackgroundExecutor;
+use delta_kernel::engine::default::DefaultEngine;
+use delta_kernel::schema::{DataType, StructField, StructType};
+use delta_kernel::transaction::CommitResult;
+use delta_kernel::Table;
+use object_store::path::Path;
+use object_store::{ObjectStore, PutMode, PutOptions};
+use url::Url;
+
+use super::{AuditEvent, AuditSink};
+use crate::{Error, Result};
+
+static AUDIT_SCHEMA: LazyLock<StructType> = LazyLock::new(|| {
+    StructType::new([
+        StructField::not_null("timestamp", DataType::TIMESTAMP),
+        StructField::not_null("kind", DataType::STRING),
+        StructField::not_null("recipient_type", DataType::STRING),
+        StructField::nullable("recipient", DataType::STRING),
+        StructField::nullable("resource_label", DataType::STRING),
+        StructField::nullable("resource_name", DataType::STRING),
+        StructField::nullable("permission", DataType::STRING),
+        StructField::nullable("decision", DataType::STRING),
+        StructField::nullable("table_version", DataType::LONG),
+        StructField::nullable("num_files", DataType::LONG),
+        StructField::nullable("bytes_signed", DataType::LONG),
+        StructField::nullable("method", DataType::STRING),
+        StructField::nullable("path", DataType::STRING),
+        StructField::nullable("status", DataType::INTEGER),
+    ])
+});
+
+/// Sink appending events to a Delta table.
+///
+/// The table is created if it does not exist. Every batch of events is written as a new
+/// parquet file and committed as a blind append, so only a single server should write
+/// to a given table.
+pub struct DeltaSink {
+    table: Table,
+    engine: Arc<DefaultEngine<TokioBackgroundExecutor>>,
+}
+
+impl DeltaSink {
+    /// Create a new [`DeltaSink`] writing to the table at the given location.
+    ///
+    /// The options are passed to the object store, e.g. to provide credentials.
+    pub async fn try_new(
+        location: &Url,
+        options: impl IntoIterator<Item = (impl AsRef<str>, impl Into<String>)>,
+    ) -> Result<Self> {
+        let mut location = location.clone();
+        if !location.path().ends_with('/') {
+            location.set_path(&format!("{}/", location.path()));
+        }
+        let (store, root) = object_store::parse_url_opts(&location, options)
+            .map_err(|e| Error::InvalidTableLocation(e.to_string()))?;
+        let store: Arc<dyn ObjectStore> = Arc::from(store);
+        create_table(store.as_ref(), &root).await?;
+        let engine = DefaultEngine::new(store, root, Arc::new(TokioBackgroundExecutor::new()));
+        Ok(Self {
+            table: Table::new(location),
+            engine: Arc::new(engine),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl AuditSink for DeltaSink {
+    async fn write(&self, events: &[AuditEvent]) -> Result<()> {
+        let mut txn = self
+            .table
+            .new_transaction(self.engine.as_ref())?
+            .with_operation("WRITE".to_string())
+            .with_commit_info(commit_info()?);
+        let data = ArrowEngineData::new(to_record_batch(events)?);
+        let write_metadata = self
+            .engine
+            .write_parquet(&data, &txn.get_write_context(), HashMap::new(), true)
+            .await?;
+        txn.add_write_metadata(write_metadata);
+        match txn.commit(self.engine.as_ref())? {
+            CommitResult::Committed(_) => Ok(()),
+            CommitResult::Conflict(_, version) => Err(Error::generic(format!(
+                "conflicting commit in audit table at version {}",
+                version
+            ))),
+        }
+    }
+}
+
+/// Write the initial commit of the audit table, unless the table already exists.
+async fn create_table(store: &dyn ObjectStore, root: &Path) -> Result<()> {
+    let protocol = serde_json::json!({
+        "protocol": { "minReaderVersion": 1, "minWriterVersion": 1 }
+    });
+    let metadata = serde_json::json!({
+        "metaData": {
+            "id": uuid::Uuid::now_v7().to_string(),
+            "format": { "provider": "parquet", "options": {} },
+            "schemaString": serde_json::to_string(&*AUDIT_SCHEMA)?,
+            "partitionColumns": [],
+            "configuration": {},
+            "createdTime": chrono::Utc::now().timestamp_millis(),
+        }
+    });
+    let mut data = serde_json::to_vec(&protocol)?;
+    data.push(b'\n');
+    data.extend(serde_json::to_vec(&metadata)?);
+
+    let path = root.child("_delta_log").child("00000000000000000000.json");
+    let options = PutOptions::from(PutMode::Create);
+    match store.put_opts(&path, data.into(), options).await {
+        Ok(_) | Err(object_store::Error::AlreadyExists { .. }) => Ok(()),
+        Err(e) => Err(Error::generic(format!(
+            "failed to create audit table: {}",
+            e
+        ))),
+    }
+}
+
+fn to_record_batch(events: &[AuditEvent]) -> Result<RecordBatch> {
+    let schema = ArrowSchema::try_from(&*AUDIT_SCHEMA)
+        .map_err(|e| Error::generic(format!("invalid audit schema: {}", e)))?;
+    let strings = |f: fn(&AuditEvent) -> Option<&str>| -> ArrayRef {
+        Arc::new(events.iter().map(f).collect::<StringArray>())
+    };
+    let longs = |f: fn(&AuditEvent) -> Option<i64>| -> ArrayRef {
+        Arc::new(events.iter().map(f).collect::<Int64Array>())
+    };
+    let timestamps = TimestampMicrosecondArray::from_iter_values(
+        events.iter().map(|e| e.timestamp.timestamp_micros()),
+    )
+    .with_timezone("UTC");
+    let columns = vec![
+        Arc::new(timestamps) as ArrayRef,
+        strings(|e| Some(e.kind.as_ref())),
+        strings(|e| Some(e.recipient_type.as_str())),
+        strings(|e| e.recipient.as_deref()),
+        strings(|e| e.resource_label.as_deref()),
+        strings(|e| e.resource_name.as_deref()),
+        strings(|e| e.permission.as_deref()),
+        strings(|e| e.decision.as_ref().map(|d| d.as_ref())),
+        longs(|e| e.table_version),
+        longs(|e| e.num_files),
+        longs(|e| e.bytes_signed),
+        strings(|e| e.method.as_deref()),
+        strings(|e| e.path.as_deref()),
+        Arc::new(events.iter().map(|e| e.status).collect::<Int32Array>()),
+    ];
+    RecordBatch::try_new(Arc::new(schema), columns)
+        .map_err(|e| Error::generic(format!("failed to build audit batch: {}", e)))
+}
+
+/// Commit info of the form `{engineCommitInfo: {engineInfo: "delta-sharing-rs"}}`.
+fn commit_info() -> Result<Box<ArrowEngineData>> {
+    let entries = Field::new(
+        "entries",
+        ArrowDataType::Struct(
+            vec![
+                Field::new("key", ArrowDataType::Utf8, false),
+                Field::new("value", ArrowDataType::Utf8, true),
+            ]
+            .into(),
+        ),
+        false,
+    );
+    let schema = ArrowSchema::new(vec![Field::new(
+        "engineCommitInfo",
+        ArrowDataType::Map(Arc::new(entries), false),
+        false,
+    )]);
+
+    let names = MapFieldNames {
+        entry: "entries".to_string(),
+        key: "key".to_string(),
+        value: "value".to_string(),
+    };
+    let mut builder = MapBuilder::new(Some(names), StringBuilder::new(), StringBuilder::new());
+    builder.keys().append_value("engineInfo");
+    builder.values().append_value("delta-sharing-rs");
+    builder
+        .append(true)
+        .map_err(|e| Error::generic(e.to_string()))?;
+
+    let batch = RecordBatch::try_new(Arc::new(schema), vec![Arc::new(builder.finish())])
+        .map_err(|e| Error::generic(e.to_string()))?;
+    Ok(Box::new(ArrowEngineData::new(batch)))
+}
+
+#[cfg(test)]
+mod tests {
+    use delta_kernel::engine::default::DefaultEngine;
+
+    use super::*;
+    use crate::{Decision, Permission, Recipient, ResourceIdent, ResourceName};
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_delta_sink() {
+        let dir = tempfile::tempdir().unwrap();
+        let location = Url::from_directory_path(dir.path()).unwrap();
+        let sink = DeltaSink::try_new(&location, HashMap::<String, String>::new())
+            .await
+            .unwrap();
+
+        let recipient = Recipient::user("alice");
+        let table = ResourceIdent::sharing_table(ResourceName::new(["share", "schema", "table"]));
+        let events = vec![
+            AuditEvent::decision(&recipient, &table, &Permission::Read, Decision::Allow),
+            AuditEvent::data_access(&recipient, &table, 1, 2, 1024),
+        ];
+        sink.write(&events).await.unwrap();
+        sink.write(&events[..1]).await.unwrap();
+
+        // opening an existing table does not re-create it.
+        let sink = DeltaSink::try_new(&location, HashMap::<String, String>::new())
+            .await
+            .unwrap();
+        sink.write(&events[1..]).await.unwrap();
+
+        let engine = DefaultEngine::try_new(
+            &location,
+            HashMap::<String, String>::new(),
+            Arc::new(TokioBackgroundExecutor::new()),
+        )
+        .unwrap();
+        let snapshot = Table::new(location).snapshot(&engine, None).unwrap();
+        assert_eq!(snapshot.version(), 3);
+        assert_eq!(snapshot.schema(), &*AUDIT_SCHEMA);
+    }
+}
@@ -0,0 +1,390 @@
+//! Audit logging for authorization decisions and data access.
+//!
+//! Events are recorded through an [`AuditLog`] handle, which hands them to a background
+//! task that writes them in batches to an [`AuditSink`]. Authorization decisions are captured
+//! by wrapping a policy in an [`AuditedPolicy`], requests by the audit middleware in the
+//! `rest` module.
+
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tokio::sync::{mpsc, oneshot};
+
+use crate::{
+    Decision, ObjectLabel, Permission, Policy, Recipient, ResourceIdent, Result, VisibilityFilter,
+};
+
+pub use delta::*;
+pub use sinks::*;
+
+mod delta;
+mod sinks;
+
+/// Default number of events that can be buffered before recording applies back pressure.
+const DEFAULT_CAPACITY: usize = 1024;
+/// Maximum number of events written to a sink in a single batch.
+const MAX_BATCH_SIZE: usize = 512;
+
+/// Kind of an [`AuditEvent`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, strum::AsRefStr)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AuditEventKind {
+    /// A policy decided on a requested permission.
+    Decision,
+    /// A request was served by the server.
+    Request,
+    /// Table data was made available to a recipient.
+    DataAccess,
+}
+
+/// Structured audit event.
+///
+/// Fields that do not apply to the kind of event are left empty.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuditEvent {
+    pub timestamp: DateTime<Utc>,
+    pub kind: AuditEventKind,
+    /// Kind of the recipient, one of `anonymous`, `user` or `custom`.
+    pub recipient_type: String,
+    /// User name or claims of the recipient.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub recipient: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resource_label: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resource_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub permission: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub decision: Option<Decision>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub table_version: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub num_files: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bytes_signed: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub method: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<i32>,
+}
+
+impl AuditEvent {
+    fn new(kind: AuditEventKind, recipient: &Recipient) -> Self {
+        let (recipient_type, recipient) = match recipient {
+            Recipient::Anonymous => ("anonymous", None),
+            Recipient::User(name) => ("user", Some(name.clone())),
+            Recipient::Custom(data) => ("custom", Some(String::from_utf8_lossy(data).into())),
+        };
+        Self {
+            timestamp: Utc::now(),
+            kind,
+            recipient_type: recipient_type.to_string(),
+            recipient,
+            resource_label: None,
+            resource_name: None,
+            permission: None,
+            decision: None,
+            table_version: None,
+            num_files: None,
+            bytes_signed: None,
+            method: None,
+            path: None,
+            status: None,
+        }
+    }
+
+    fn with_resource(mut self, resource: &ResourceIdent) -> Self {
+        self.resource_label = Some(resource.label().as_ref().to_string());
+        self.resource_name = Some(resource.reference().to_string());
+        self
+    }
+
+    /// Event for a decision made by a policy.
+    pub fn decision(
+        recipient: &Recipient,
+        resource: &ResourceIdent,
+        permission: &Permission,
+        decision: Decision,
+    ) -> Self {
+        let mut event = Self::new(AuditEventKind::Decision, recipient).with_resource(resource);
+        event.permission = Some(permission.as_ref().to_string());
+        event.decision = Some(decision);
+        event
+    }
+
+    /// Event for a request served by the server.
+    pub fn request(
+        recipient: &Recipient,
+        method: impl Into<String>,
+        path: impl Into<String>,
+        status: u16,
+    ) -> Self {
+        let mut event = Self::new(AuditEventKind::Request, recipient);
+        event.method = Some(method.into());
+        event.path = Some(path.into());
+        event.status = Some(status as i32);
+        event
+    }
+
+    /// Event for table data handed out to a recipient.
+    pub fn data_access(
+        recipient: &Recipient,
+        resource: &ResourceIdent,
+        table_version: i64,
+        num_files: i64,
+        bytes_signed: i64,
+    ) -> Self {
+        let mut event = Self::new(AuditEventKind::DataAccess, recipient).with_resource(resource);
+        event.table_version = Some(table_version);
+        event.num_files = Some(num_files);
+        event.bytes_signed = Some(bytes_signed);
+        event
+    }
+
+    /// Set the version of the table the event refers to.
+    pub fn with_table_version(mut self, version: i64) -> Self {
+        self.table_version = Some(version);
+        self
+    }
+}
+
+/// Destination for audit events.
+///
+/// Sinks are driven by a single background task, so batches are never written concurrently.
+#[async_trait::async_trait]
+pub trait AuditSink: Send + Sync + 'static {
+    /// Write a batch of events.
+    async fn write(&self, events: &[AuditEvent]) -> Result<()>;
+}
+
+#[async_trait::async_trait]
+impl<T: AuditSink> AuditSink for Arc<T> {
+    async fn write(&self, events: &[AuditEvent]) -> Result<()> {
+        T::write(self, events).await
+    }
+}
+
+enum Message {
+    Event(Box<AuditEvent>),
+    Flush(oneshot::Sender<()>),
+}
+
+/// Handle for recording audit events.
+///
+/// Recording only enqueues the event, a background task writes queued events to the sink.
+/// Failures to write are logged and the affected events are dropped.
+#[derive(Clone)]
+pub struct AuditLog {
+    sender: mpsc::Sender<Message>,
+}
+
+impl AuditLog {
+    /// Create a new [`AuditLog`] writing to the given sink.
+    ///
+    /// Must be called from within a tokio runtime.
+    pub fn new(sink: impl AuditSink) -> Self {
+        Self::with_capacity(sink, DEFAULT_CAPACITY)
+    }
+
+    /// Create a new [`AuditLog`] buffering up to `capacity` events.
+    pub fn with_capacity(sink: impl AuditSink, capacity: usize) -> Self {
+        let (sender, receiver) = mpsc::channel(capacity);
+        tokio::spawn(write_events(sink, receiver));
+        Self { sender }
+    }
+
+    /// Record an audit event.
+    pub async fn record(&self, event: AuditEvent) {
+        if self
+            .sender
+            .send(Message::Event(Box::new(event)))
+            .await
+            .is_err()
+        {
+            tracing::error!("audit log is closed, dropping event");
+        }
+    }
+
+    /// Wait until all previously recorded events are written to the sink.
+    pub async fn flush(&self) {
+        let (sender, receiver) = oneshot::channel();
+        if self.sender.send(Message::Flush(sender)).await.is_ok() {
+            let _ = receiver.await;
+        }
+    }
+}
+
+async fn write_events(sink: impl AuditSink, mut receiver: mpsc::Receiver<Message>) {
+    let mut batch = Vec::new();
+    while let Some(message) = receiver.recv().await {
+        let mut flushed = Vec::new();
+        let mut next = Some(message);
+        while let Some(message) = next.take() {
+            match message {
+                Message::Event(event) => batch.push(*event),
+                Message::Flush(sender) => flushed.push(sender),
+            }
+            if batch.len() < MAX_BATCH_SIZE {
+                next = receiver.try_recv().ok();
+            }
+        }
+        if !batch.is_empty() {
+            if let Err(e) = sink.write(&batch).await {
+                tracing::error!("failed to write {} audit events: {}", batch.len(), e);
+            }
+            batch.clear();
+        }
+        for sender in flushed {
+            let _ = sender.send(());
+        }
+    }
+}
+
+/// Policy that records every decision of the wrapped policy in an [`AuditLog`].
+pub struct AuditedPolicy<P> {
+    inner: P,
+    log: AuditLog,
+}
+
+impl<P: Policy> AuditedPolicy<P> {
+    /// Create a new [`AuditedPolicy`].
+    pub fn new(inner: P, log: AuditLog) -> Self {
+        Self { inner, log }
+    }
+}
+
+#[async_trait::async_trait]
+impl<P: Policy> Policy for AuditedPolicy<P> {
+    async fn authorize(
+        &self,
+        resource: &ResourceIdent,
+        permission: &Permission,
+        recipient: &Recipient,
+    ) -> Result<Decision> {
+        let decision = self
+            .inner
+            .authorize(resource, permission, recipient)
+            .await?;
+        self.log
+            .record(AuditEvent::decision(
+                recipient, resource, permission, decision,
+            ))
+            .await;
+        Ok(decision)
+    }
+
+    async fn authorize_many(
+        &self,
+        resources: &[ResourceIdent],
+        permission: &Permission,
+        recipient: &Recipient,
+    ) -> Result<Vec<Decision>> {
+        let decisions = self
+            .inner
+            .authorize_many(resources, permission, recipient)
+            .await?;
+        for (resource, decision) in resources.iter().zip(decisions.iter()) {
+            self.log
+                .record(AuditEvent::decision(
+                    recipient, resource, permission, *decision,
+                ))
+                .await;
+        }
+        Ok(decisions)
+    }
+
+    async fn visibility_filter(
+        &self,
+        label: &ObjectLabel,
+        permission: &Permission,
+        recipient: &Recipient,
+    ) -> Result<Option<VisibilityFilter>> {
+        self.inner
+            .visibility_filter(label, permission, recipient)
+            .await
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+    use crate::{ConstantPolicy, ResourceName};
+
+    /// Sink collecting events in memory.
+    #[derive(Default)]
+    pub(crate) struct MemorySink {
+        pub(crate) events: Mutex<Vec<AuditEvent>>,
+    }
+
+    #[async_trait::async_trait]
+    impl AuditSink for MemorySink {
+        async fn write(&self, events: &[AuditEvent]) -> Result<()> {
+            self.events.lock().unwrap().extend_from_slice(events);
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_audited_policy() {
+        let sink = Arc::new(MemorySink::default());
+        let log = AuditLog::new(sink.clone());
+        let policy = AuditedPolicy::new(ConstantPolicy::new(Decision::Deny), log.clone());
+
+        let share = ResourceIdent::share(ResourceName::new(["share"]));
+        let table = ResourceIdent::sharing_table(ResourceName::new(["share", "schema", "table"]));
+        let recipient = Recipient::user("alice");
+
+        let decision = policy
+            .authorize(&share, &Permission::Read, &recipient)
+            .await
+            .unwrap();
+        assert_eq!(decision, Decision::Deny);
+        policy
+            .authorize_many(&[share, table], &Permission::Read, &Recipient::anonymous())
+            .await
+            .unwrap();
+        log.flush().await;
+
+        let events = sink.events.lock().unwrap();
+        assert_eq!(events.len(), 3);
+        assert_eq!(events[0].kind, AuditEventKind::Decision);
+        assert_eq!(events[0].recipient_type, "user");
+        assert_eq!(events[0].recipient.as_deref(), Some("alice"));
+        assert_eq!(events[0].resource_label.as_deref(), Some("share_info"));
+        assert_eq!(events[0].resource_name.as_deref(), Some("share"));
+        assert_eq!(events[0].permission.as_deref(), Some("read"));
+        assert_eq!(events[0].decision, Some(Decision::Deny));
+        assert_eq!(events[2].recipient_type, "anonymous");
+        assert_eq!(
+            events[2].resource_name.as_deref(),
+            Some("share.schema.table")
+        );
+    }
+
+    #[test]
+    fn test_event_serialization() {
+        let event = AuditEvent::data_access(
+            &Recipient::user("alice"),
+            &ResourceIdent::sharing_table(ResourceName::new(["share", "schema", "table"])),
+            3,
+            2,
+            1024,
+        );
+        let json = serde_json::to_value(&event).unwrap();
+        assert_eq!(json["kind"], "data_access");
+        assert_eq!(json["table_version"], 3);
+        assert_eq!(json["num_files"], 2);
+        assert_eq!(json["bytes_signed"], 1024);
+        assert!(json.get("decision").is_none());
+
+        let parsed: AuditEvent = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed, event);
+    }
+}
@@ -0,0 +1,171 @@
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use tokio::io::AsyncWriteExt;
+
+use super::{AuditEvent, AuditSink};
+use crate::{Error, Result};
+
+fn to_json_lines(events: &[AuditEvent]) -> Result<Vec<u8>> {
+    let mut buffer = Vec::new();
+    for event in events {
+        serde_json::to_writer(&mut buffer, event)?;
+        buffer.push(b'\n');
+    }
+    Ok(buffer)
+}
+
+fn io_error(path: &Path, err: std::io::Error) -> Error {
+    Error::generic(format!(
+        "failed to write audit log {}: {}",
+        path.display(),
+        err
+    ))
+}
+
+/// Sink writing events as JSON lines to standard output.
+#[derive(Debug, Clone, Default)]
+pub struct StdoutSink;
+
+#[async_trait::async_trait]
+impl AuditSink for StdoutSink {
+    async fn write(&self, events: &[AuditEvent]) -> Result<()> {
+        let buffer = to_json_lines(events)?;
+        let mut stdout = std::io::stdout().lock();
+        stdout
+            .write_all(&buffer)
+            .and_then(|_| stdout.flush())
+            .map_err(|e| Error::generic(format!("failed to write audit events: {}", e)))
+    }
+}
+
+/// Sink writing events as JSON lines to a local file.
+///
+/// Once the file exceeds the configured size, it is rotated to `<path>.1`, shifting previously
+/// rotated files by one. Only the configured number of rotated files is kept.
+#[derive(Debug, Clone)]
+pub struct RollingFileSink {
+    path: PathBuf,
+    max_bytes: u64,
+    max_files: usize,
+}
+
+impl RollingFileSink {
+    /// Create a new [`RollingFileSink`].
+    ///
+    /// By default files are rotated after 100 MiB and 5 rotated files are kept.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            max_bytes: 100 * 1024 * 1024,
+            max_files: 5,
+        }
+    }
+
+    /// Rotate the file once it exceeds the given number of bytes.
+    pub fn with_max_bytes(mut self, max_bytes: u64) -> Self {
+        self.max_bytes = max_bytes;
+        self
+    }
+
+    /// Number of rotated files to keep.
+    pub fn with_max_files(mut self, max_files: usize) -> Self {
+        self.max_files = max_files;
+        self
+    }
+
+    fn rotated_path(&self, index: usize) -> PathBuf {
+        let mut path = self.path.clone().into_os_string();
+        path.push(format!(".{}", index));
+        path.into()
+    }
+
+    async fn rotate(&self) -> Result<()> {
+        if self.max_files == 0 {
+            return tokio::fs::remove_file(&self.path)
+                .await
+                .map_err(|e| io_error(&self.path, e));
+        }
+        for index in (1..self.max_files).rev() {
+            let from = self.rotated_path(index);
+            if tokio::fs::try_exists(&from).await.unwrap_or(false) {
+                let to = self.rotated_path(index + 1);
+                tokio::fs::rename(&from, &to)
+                    .await
+                    .map_err(|e| io_error(&to, e))?;
+            }
+        }
+        let to = self.rotated_path(1);
+        tokio::fs::rename(&self.path, &to)
+            .await
+            .map_err(|e| io_error(&to, e))
+    }
+}
+
+#[async_trait::async_trait]
+impl AuditSink for RollingFileSink {
+    async fn write(&self, events: &[AuditEvent]) -> Result<()> {
+        let buffer = to_json_lines(events)?;
+        let size = match tokio::fs::metadata(&self.path).await {
+            Ok(meta) => meta.len(),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => 0,
+            Err(e) => return Err(io_error(&self.path, e)),
+        };
+        if size > 0 && size + buffer.len() as u64 > self.max_bytes {
+            self.rotate().await?;
+        }
+        let mut file = tokio::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .await
+            .map_err(|e| io_error(&self.path, e))?;
+        file.write_all(&buffer)
+            .await
+            .map_err(|e| io_error(&self.path, e))?;
+        file.flush().await.map_err(|e| io_error(&self.path, e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Recipient;
+
+    fn events(count: usize) -> Vec<AuditEvent> {
+        (0..count)
+            .map(|i| AuditEvent::request(&Recipient::user("alice"), "GET", format!("/{i}"), 200))
+            .collect()
+    }
+
+    fn read_events(path: &Path) -> Vec<AuditEvent> {
+        std::fs::read_to_string(path)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_rolling_file_sink() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("audit.log");
+        let line_size = to_json_lines(&events(1)).unwrap().len() as u64;
+        let sink = RollingFileSink::new(&path)
+            .with_max_bytes(line_size * 3 / 2)
+            .with_max_files(2);
+
+        sink.write(&events(2)).await.unwrap();
+        assert_eq!(read_events(&path).len(), 2);
+        assert!(!sink.rotated_path(1).exists());
+
+        // every further batch exceeds the size limit and rotates the file.
+        for _ in 0..3 {
+            sink.write(&events(1)).await.unwrap();
+        }
+        assert_eq!(read_events(&path).len(), 1);
+        assert_eq!(read_events(&sink.rotated_path(1)).len(), 1);
+        assert_eq!(read_events(&sink.rotated_path(2)).len(), 1);
+        assert!(!sink.rotated_path(3).exists());
+    }
+}
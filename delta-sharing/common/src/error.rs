@@ -23,6 +23,9 @@ pub enum Error {
     #[error("Already exists")]
     AlreadyExists,
 
+    #[error("The resource was modified concurrently")]
+    Conflict,
+
     #[error("Invalid table location: {0}")]
     InvalidTableLocation(String),
 
@@ -97,6 +100,7 @@ impl From<Error> for Status {
             }
             Error::InvalidPredicate(msg) => Status::invalid_argument(msg),
             Error::AlreadyExists => Status::already_exists("The resource already exists."),
+            Error::Conflict => Status::aborted("The resource was modified concurrently."),
             Error::InvalidIdentifier(_) => Status::internal("Invalid uuid identifier"),
             Error::InvalidArgument(message) => Status::invalid_argument(message),
             Error::Generic(message) => Status::internal(message),
@@ -143,6 +147,10 @@ mod server {
                     "The request is forbidden from being fulfilled.",
                 ),
                 Error::AlreadyExists => (StatusCode::CONFLICT, "The resource already exists."),
+                Error::Conflict => (
+                    StatusCode::CONFLICT,
+                    "The resource was modified concurrently.",
+                ),
                 Error::Unauthenticated => (
                     StatusCode::UNAUTHORIZED,
                     "The request is unauthenticated. The bearer token is missing or incorrect.",
@@ -50,6 +50,11 @@ pub struct CatalogInfo {
     /// is enabled in the request.
     #[prost(bool, optional, tag="1004")]
     pub browse_only: ::core::option::Option<bool>,
+    /// Opaque identifier of the current revision of the catalog.
+    ///
+    /// Pass it as precondition when updating the catalog to detect concurrent modifications.
+    #[prost(string, optional, tag="1005")]
+    pub etag: ::core::option::Option<::prost::alloc::string::String>,
 }
 /// The type of the catalog.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
@@ -162,6 +167,12 @@ pub struct UpdateCatalogRequest {
     /// Name of catalog.
     #[prost(string, tag="5")]
     pub new_name: ::prost::alloc::string::String,
+    /// Revision of the catalog the update is based on.
+    ///
+    /// If set, the update fails with a conflict if the catalog was modified since.
+    /// May also be provided via the If-Match header.
+    #[prost(string, optional, tag="6")]
+    pub etag: ::core::option::Option<::prost::alloc::string::String>,
 }
 /// Delete a catalog
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -49,6 +49,9 @@ impl serde::Serialize for CatalogInfo {
         if self.browse_only.is_some() {
             len += 1;
         }
+        if self.etag.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("delta_sharing.catalogs.v1.CatalogInfo", len)?;
         if let Some(v) = self.id.as_ref() {
             struct_ser.serialize_field("id", v)?;
@@ -98,6 +101,9 @@ impl serde::Serialize for CatalogInfo {
         if let Some(v) = self.browse_only.as_ref() {
             struct_ser.serialize_field("browseOnly", v)?;
         }
+        if let Some(v) = self.etag.as_ref() {
+            struct_ser.serialize_field("etag", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -131,6 +137,7 @@ impl<'de> serde::Deserialize<'de> for CatalogInfo {
             "updatedBy",
             "browse_only",
             "browseOnly",
+            "etag",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -149,6 +156,7 @@ impl<'de> serde::Deserialize<'de> for CatalogInfo {
             UpdatedAt,
             UpdatedBy,
             BrowseOnly,
+            Etag,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -185,6 +193,7 @@ impl<'de> serde::Deserialize<'de> for CatalogInfo {
                             "updatedAt" | "updated_at" => Ok(GeneratedField::UpdatedAt),
                             "updatedBy" | "updated_by" => Ok(GeneratedField::UpdatedBy),
                             "browseOnly" | "browse_only" => Ok(GeneratedField::BrowseOnly),
+                            "etag" => Ok(GeneratedField::Etag),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -218,6 +227,7 @@ impl<'de> serde::Deserialize<'de> for CatalogInfo {
                 let mut updated_at__ = None;
                 let mut updated_by__ = None;
                 let mut browse_only__ = None;
+                let mut etag__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Id => {
@@ -308,6 +318,12 @@ impl<'de> serde::Deserialize<'de> for CatalogInfo {
                             }
                             browse_only__ = map_.next_value()?;
                         }
+                        GeneratedField::Etag => {
+                            if etag__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("etag"));
+                            }
+                            etag__ = map_.next_value()?;
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -328,6 +344,7 @@ impl<'de> serde::Deserialize<'de> for CatalogInfo {
                     updated_at: updated_at__,
                     updated_by: updated_by__,
                     browse_only: browse_only__,
+                    etag: etag__,
                 })
             }
         }
@@ -1068,6 +1085,9 @@ impl serde::Serialize for UpdateCatalogRequest {
         if !self.new_name.is_empty() {
             len += 1;
         }
+        if self.etag.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("delta_sharing.catalogs.v1.UpdateCatalogRequest", len)?;
         if !self.name.is_empty() {
             struct_ser.serialize_field("name", &self.name)?;
@@ -1084,6 +1104,9 @@ impl serde::Serialize for UpdateCatalogRequest {
         if !self.new_name.is_empty() {
             struct_ser.serialize_field("newName", &self.new_name)?;
         }
+        if let Some(v) = self.etag.as_ref() {
+            struct_ser.serialize_field("etag", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -1100,6 +1123,7 @@ impl<'de> serde::Deserialize<'de> for UpdateCatalogRequest {
             "properties",
             "new_name",
             "newName",
+            "etag",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -1109,6 +1133,7 @@ impl<'de> serde::Deserialize<'de> for UpdateCatalogRequest {
             Comment,
             Properties,
             NewName,
+            Etag,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1136,6 +1161,7 @@ impl<'de> serde::Deserialize<'de> for UpdateCatalogRequest {
                             "comment" => Ok(GeneratedField::Comment),
                             "properties" => Ok(GeneratedField::Properties),
                             "newName" | "new_name" => Ok(GeneratedField::NewName),
+                            "etag" => Ok(GeneratedField::Etag),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1160,6 +1186,7 @@ impl<'de> serde::Deserialize<'de> for UpdateCatalogRequest {
                 let mut comment__ = None;
                 let mut properties__ = None;
                 let mut new_name__ = None;
+                let mut etag__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Name => {
@@ -1192,6 +1219,12 @@ impl<'de> serde::Deserialize<'de> for UpdateCatalogRequest {
                             }
                             new_name__ = Some(map_.next_value()?);
                         }
+                        GeneratedField::Etag => {
+                            if etag__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("etag"));
+                            }
+                            etag__ = map_.next_value()?;
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -1203,6 +1236,7 @@ impl<'de> serde::Deserialize<'de> for UpdateCatalogRequest {
                     comment: comment__,
                     properties: properties__,
                     new_name: new_name__.unwrap_or_default(),
+                    etag: etag__,
                 })
             }
         }
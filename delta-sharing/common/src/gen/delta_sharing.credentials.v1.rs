@@ -61,6 +61,42 @@ pub struct AzureStorageKey {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AwsIamRole {
+    /// The Amazon Resource Name (ARN) of the AWS IAM role to assume.
+    #[prost(string, tag="1")]
+    pub role_arn: ::prost::alloc::string::String,
+    /// The external ID required by the trust policy of the role.
+    #[prost(string, optional, tag="2")]
+    pub external_id: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AwsAccessKey {
+    /// The AWS access key ID.
+    #[prost(string, tag="1")]
+    pub access_key_id: ::prost::alloc::string::String,
+    /// The AWS secret access key.
+    #[prost(string, tag="2")]
+    pub secret_access_key: ::prost::alloc::string::String,
+    /// The session token, if the key is temporary.
+    #[prost(string, optional, tag="3")]
+    pub session_token: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GcpServiceAccountKey {
+    /// The email of the service account.
+    #[prost(string, tag="1")]
+    pub email: ::prost::alloc::string::String,
+    /// The ID of the private key.
+    #[prost(string, tag="2")]
+    pub private_key_id: ::prost::alloc::string::String,
+    /// The PEM encoded private key of the service account.
+    #[prost(string, tag="3")]
+    pub private_key: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CredentialInfo {
     /// The unique identifier of the credential.
     #[prost(string, tag="1")]
@@ -104,13 +140,20 @@ pub struct CredentialInfo {
     /// The full name of the credential.
     #[prost(string, optional, tag="12")]
     pub full_name: ::core::option::Option<::prost::alloc::string::String>,
-    #[prost(oneof="credential_info::Credential", tags="100, 101, 102")]
+    /// Opaque identifier of the current revision of the credential.
+    ///
+    /// Pass it as precondition when updating the credential to detect concurrent modifications.
+    #[prost(string, optional, tag="13")]
+    pub etag: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(
+        oneof = "credential_info::Credential",
+        tags = "100, 101, 102, 103, 104, 105"
+    )]
     pub credential: ::core::option::Option<credential_info::Credential>,
 }
 /// Nested message and enum types in `CredentialInfo`.
 pub mod credential_info {
-    #[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Oneof)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
     pub enum Credential {
         #[prost(message, tag="100")]
         AzureServicePrincipal(super::AzureServicePrincipal),
@@ -118,8 +161,28 @@ pub mod credential_info {
         AzureManagedIdentity(super::AzureManagedIdentity),
         #[prost(message, tag="102")]
         AzureStorageKey(super::AzureStorageKey),
+        #[prost(message, tag="103")]
+        AwsIamRole(super::AwsIamRole),
+        #[prost(message, tag="104")]
+        AwsAccessKey(super::AwsAccessKey),
+        #[prost(message, tag="105")]
+        GcpServiceAccountKey(super::GcpServiceAccountKey),
     }
 }
+/// The outcome of a single validation probe.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValidationResult {
+    /// The operation that was probed.
+    #[prost(enumeration="ValidationOperation", tag="1")]
+    pub operation: i32,
+    /// Whether the operation succeeded.
+    #[prost(enumeration="ValidationResultStatus", tag="2")]
+    pub result: i32,
+    /// Error message or reason why the operation was skipped.
+    #[prost(string, optional, tag="3")]
+    pub message: ::core::option::Option<::prost::alloc::string::String>,
+}
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum Purpose {
@@ -149,6 +212,74 @@ impl Purpose {
         }
     }
 }
+/// An operation probed when validating a credential against a storage location.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ValidationOperation {
+    Unspecified = 0,
+    List = 1,
+    Read = 2,
+    Write = 3,
+    Delete = 4,
+}
+impl ValidationOperation {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "VALIDATION_OPERATION_UNSPECIFIED",
+            Self::List => "LIST",
+            Self::Read => "READ",
+            Self::Write => "WRITE",
+            Self::Delete => "DELETE",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "VALIDATION_OPERATION_UNSPECIFIED" => Some(Self::Unspecified),
+            "LIST" => Some(Self::List),
+            "READ" => Some(Self::Read),
+            "WRITE" => Some(Self::Write),
+            "DELETE" => Some(Self::Delete),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ValidationResultStatus {
+    Unspecified = 0,
+    Pass = 1,
+    Fail = 2,
+    Skip = 3,
+}
+impl ValidationResultStatus {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "VALIDATION_RESULT_STATUS_UNSPECIFIED",
+            Self::Pass => "PASS",
+            Self::Fail => "FAIL",
+            Self::Skip => "SKIP",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "VALIDATION_RESULT_STATUS_UNSPECIFIED" => Some(Self::Unspecified),
+            "PASS" => Some(Self::Pass),
+            "FAIL" => Some(Self::Fail),
+            "SKIP" => Some(Self::Skip),
+            _ => None,
+        }
+    }
+}
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ListCredentialsRequest {
@@ -191,13 +322,15 @@ pub struct CreateCredentialRequest {
     /// Supplying true to this argument skips validation of the created set of credentials.
     #[prost(bool, tag="5")]
     pub skip_validation: bool,
-    #[prost(oneof="create_credential_request::Credential", tags="100, 101, 102")]
+    #[prost(
+        oneof = "create_credential_request::Credential",
+        tags = "100, 101, 102, 103, 104, 105"
+    )]
     pub credential: ::core::option::Option<create_credential_request::Credential>,
 }
 /// Nested message and enum types in `CreateCredentialRequest`.
 pub mod create_credential_request {
-    #[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Oneof)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
     pub enum Credential {
         #[prost(message, tag="100")]
         AzureServicePrincipal(super::AzureServicePrincipal),
@@ -205,6 +338,12 @@ pub mod create_credential_request {
         AzureManagedIdentity(super::AzureManagedIdentity),
         #[prost(message, tag="102")]
         AzureStorageKey(super::AzureStorageKey),
+        #[prost(message, tag="103")]
+        AwsIamRole(super::AwsIamRole),
+        #[prost(message, tag="104")]
+        AwsAccessKey(super::AwsAccessKey),
+        #[prost(message, tag="105")]
+        GcpServiceAccountKey(super::GcpServiceAccountKey),
     }
 }
 /// Get a credential
@@ -241,13 +380,21 @@ pub struct UpdateCredentialRequest {
     /// or dependent external locations and external tables (when purpose is STORAGE).
     #[prost(bool, optional, tag="7")]
     pub force: ::core::option::Option<bool>,
-    #[prost(oneof="update_credential_request::Credential", tags="100, 101, 102")]
+    /// Revision of the credential the update is based on.
+    ///
+    /// If set, the update fails with a conflict if the credential was modified since.
+    /// May also be provided via the If-Match header.
+    #[prost(string, optional, tag="8")]
+    pub etag: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(
+        oneof = "update_credential_request::Credential",
+        tags = "100, 101, 102, 103, 104, 105"
+    )]
     pub credential: ::core::option::Option<update_credential_request::Credential>,
 }
 /// Nested message and enum types in `UpdateCredentialRequest`.
 pub mod update_credential_request {
-    #[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Oneof)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
     pub enum Credential {
         #[prost(message, tag="100")]
         AzureServicePrincipal(super::AzureServicePrincipal),
@@ -255,6 +402,12 @@ pub mod update_credential_request {
         AzureManagedIdentity(super::AzureManagedIdentity),
         #[prost(message, tag="102")]
         AzureStorageKey(super::AzureStorageKey),
+        #[prost(message, tag="103")]
+        AwsIamRole(super::AwsIamRole),
+        #[prost(message, tag="104")]
+        AwsAccessKey(super::AwsAccessKey),
+        #[prost(message, tag="105")]
+        GcpServiceAccountKey(super::GcpServiceAccountKey),
     }
 }
 /// Delete a credential
@@ -265,5 +418,28 @@ pub struct DeleteCredentialRequest {
     #[prost(string, tag="1")]
     pub name: ::prost::alloc::string::String,
 }
+/// Validate a credential against a storage location
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValidateCredentialRequest {
+    /// The credential to validate.
+    ///
+    /// If no secret material is given, the registered credential with the same name is used.
+    #[prost(message, optional, tag="1")]
+    pub credential: ::core::option::Option<CredentialInfo>,
+    /// The storage location to validate the credential against.
+    #[prost(string, tag="2")]
+    pub url: ::prost::alloc::string::String,
+    /// Whether only read operations should be probed.
+    #[prost(bool, optional, tag="3")]
+    pub read_only: ::core::option::Option<bool>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValidateCredentialResponse {
+    /// The results of the probed operations.
+    #[prost(message, repeated, tag="1")]
+    pub results: ::prost::alloc::vec::Vec<ValidationResult>,
+}
 include!("delta_sharing.credentials.v1.serde.rs");
 // @@protoc_insertion_point(module)
\ No newline at end of file
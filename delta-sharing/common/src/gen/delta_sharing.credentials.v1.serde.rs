@@ -1,4 +1,250 @@
 // @generated
+impl serde::Serialize for AwsAccessKey {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.access_key_id.is_empty() {
+            len += 1;
+        }
+        if !self.secret_access_key.is_empty() {
+            len += 1;
+        }
+        if self.session_token.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("delta_sharing.credentials.v1.AwsAccessKey", len)?;
+        if !self.access_key_id.is_empty() {
+            struct_ser.serialize_field("accessKeyId", &self.access_key_id)?;
+        }
+        if !self.secret_access_key.is_empty() {
+            struct_ser.serialize_field("secretAccessKey", &self.secret_access_key)?;
+        }
+        if let Some(v) = self.session_token.as_ref() {
+            struct_ser.serialize_field("sessionToken", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for AwsAccessKey {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "access_key_id",
+            "accessKeyId",
+            "secret_access_key",
+            "secretAccessKey",
+            "session_token",
+            "sessionToken",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            AccessKeyId,
+            SecretAccessKey,
+            SessionToken,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl serde::de::Visitor<'_> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "accessKeyId" | "access_key_id" => Ok(GeneratedField::AccessKeyId),
+                            "secretAccessKey" | "secret_access_key" => Ok(GeneratedField::SecretAccessKey),
+                            "sessionToken" | "session_token" => Ok(GeneratedField::SessionToken),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = AwsAccessKey;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct delta_sharing.credentials.v1.AwsAccessKey")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<AwsAccessKey, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut access_key_id__ = None;
+                let mut secret_access_key__ = None;
+                let mut session_token__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::AccessKeyId => {
+                            if access_key_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("accessKeyId"));
+                            }
+                            access_key_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::SecretAccessKey => {
+                            if secret_access_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("secretAccessKey"));
+                            }
+                            secret_access_key__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::SessionToken => {
+                            if session_token__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sessionToken"));
+                            }
+                            session_token__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(AwsAccessKey {
+                    access_key_id: access_key_id__.unwrap_or_default(),
+                    secret_access_key: secret_access_key__.unwrap_or_default(),
+                    session_token: session_token__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("delta_sharing.credentials.v1.AwsAccessKey", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for AwsIamRole {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.role_arn.is_empty() {
+            len += 1;
+        }
+        if self.external_id.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("delta_sharing.credentials.v1.AwsIamRole", len)?;
+        if !self.role_arn.is_empty() {
+            struct_ser.serialize_field("roleArn", &self.role_arn)?;
+        }
+        if let Some(v) = self.external_id.as_ref() {
+            struct_ser.serialize_field("externalId", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for AwsIamRole {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "role_arn",
+            "roleArn",
+            "external_id",
+            "externalId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            RoleArn,
+            ExternalId,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl serde::de::Visitor<'_> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "roleArn" | "role_arn" => Ok(GeneratedField::RoleArn),
+                            "externalId" | "external_id" => Ok(GeneratedField::ExternalId),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = AwsIamRole;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct delta_sharing.credentials.v1.AwsIamRole")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<AwsIamRole, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut role_arn__ = None;
+                let mut external_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::RoleArn => {
+                            if role_arn__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("roleArn"));
+                            }
+                            role_arn__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ExternalId => {
+                            if external_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("externalId"));
+                            }
+                            external_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(AwsIamRole {
+                    role_arn: role_arn__.unwrap_or_default(),
+                    external_id: external_id__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("delta_sharing.credentials.v1.AwsIamRole", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for AzureManagedIdentity {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -443,6 +689,15 @@ impl serde::Serialize for CreateCredentialRequest {
                 create_credential_request::Credential::AzureStorageKey(v) => {
                     struct_ser.serialize_field("azureStorageKey", v)?;
                 }
+                create_credential_request::Credential::AwsIamRole(v) => {
+                    struct_ser.serialize_field("awsIamRole", v)?;
+                }
+                create_credential_request::Credential::AwsAccessKey(v) => {
+                    struct_ser.serialize_field("awsAccessKey", v)?;
+                }
+                create_credential_request::Credential::GcpServiceAccountKey(v) => {
+                    struct_ser.serialize_field("gcpServiceAccountKey", v)?;
+                }
             }
         }
         struct_ser.end()
@@ -468,6 +723,12 @@ impl<'de> serde::Deserialize<'de> for CreateCredentialRequest {
             "azureManagedIdentity",
             "azure_storage_key",
             "azureStorageKey",
+            "aws_iam_role",
+            "awsIamRole",
+            "aws_access_key",
+            "awsAccessKey",
+            "gcp_service_account_key",
+            "gcpServiceAccountKey",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -480,6 +741,9 @@ impl<'de> serde::Deserialize<'de> for CreateCredentialRequest {
             AzureServicePrincipal,
             AzureManagedIdentity,
             AzureStorageKey,
+            AwsIamRole,
+            AwsAccessKey,
+            GcpServiceAccountKey,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -510,6 +774,9 @@ impl<'de> serde::Deserialize<'de> for CreateCredentialRequest {
                             "azureServicePrincipal" | "azure_service_principal" => Ok(GeneratedField::AzureServicePrincipal),
                             "azureManagedIdentity" | "azure_managed_identity" => Ok(GeneratedField::AzureManagedIdentity),
                             "azureStorageKey" | "azure_storage_key" => Ok(GeneratedField::AzureStorageKey),
+                            "awsIamRole" | "aws_iam_role" => Ok(GeneratedField::AwsIamRole),
+                            "awsAccessKey" | "aws_access_key" => Ok(GeneratedField::AwsAccessKey),
+                            "gcpServiceAccountKey" | "gcp_service_account_key" => Ok(GeneratedField::GcpServiceAccountKey),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -586,6 +853,27 @@ impl<'de> serde::Deserialize<'de> for CreateCredentialRequest {
                                 return Err(serde::de::Error::duplicate_field("azureStorageKey"));
                             }
                             credential__ = map_.next_value::<::std::option::Option<_>>()?.map(create_credential_request::Credential::AzureStorageKey)
+;
+                        }
+                        GeneratedField::AwsIamRole => {
+                            if credential__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("awsIamRole"));
+                            }
+                            credential__ = map_.next_value::<::std::option::Option<_>>()?.map(create_credential_request::Credential::AwsIamRole)
+;
+                        }
+                        GeneratedField::AwsAccessKey => {
+                            if credential__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("awsAccessKey"));
+                            }
+                            credential__ = map_.next_value::<::std::option::Option<_>>()?.map(create_credential_request::Credential::AwsAccessKey)
+;
+                        }
+                        GeneratedField::GcpServiceAccountKey => {
+                            if credential__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("gcpServiceAccountKey"));
+                            }
+                            credential__ = map_.next_value::<::std::option::Option<_>>()?.map(create_credential_request::Credential::GcpServiceAccountKey)
 ;
                         }
                         GeneratedField::__SkipField__ => {
@@ -650,6 +938,9 @@ impl serde::Serialize for CredentialInfo {
         if self.full_name.is_some() {
             len += 1;
         }
+        if self.etag.is_some() {
+            len += 1;
+        }
         if self.credential.is_some() {
             len += 1;
         }
@@ -696,6 +987,9 @@ impl serde::Serialize for CredentialInfo {
         if let Some(v) = self.full_name.as_ref() {
             struct_ser.serialize_field("fullName", v)?;
         }
+        if let Some(v) = self.etag.as_ref() {
+            struct_ser.serialize_field("etag", v)?;
+        }
         if let Some(v) = self.credential.as_ref() {
             match v {
                 credential_info::Credential::AzureServicePrincipal(v) => {
@@ -707,6 +1001,15 @@ impl serde::Serialize for CredentialInfo {
                 credential_info::Credential::AzureStorageKey(v) => {
                     struct_ser.serialize_field("azureStorageKey", v)?;
                 }
+                credential_info::Credential::AwsIamRole(v) => {
+                    struct_ser.serialize_field("awsIamRole", v)?;
+                }
+                credential_info::Credential::AwsAccessKey(v) => {
+                    struct_ser.serialize_field("awsAccessKey", v)?;
+                }
+                credential_info::Credential::GcpServiceAccountKey(v) => {
+                    struct_ser.serialize_field("gcpServiceAccountKey", v)?;
+                }
             }
         }
         struct_ser.end()
@@ -738,12 +1041,19 @@ impl<'de> serde::Deserialize<'de> for CredentialInfo {
             "usedForManagedStorage",
             "full_name",
             "fullName",
+            "etag",
             "azure_service_principal",
             "azureServicePrincipal",
             "azure_managed_identity",
             "azureManagedIdentity",
             "azure_storage_key",
             "azureStorageKey",
+            "aws_iam_role",
+            "awsIamRole",
+            "aws_access_key",
+            "awsAccessKey",
+            "gcp_service_account_key",
+            "gcpServiceAccountKey",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -760,9 +1070,13 @@ impl<'de> serde::Deserialize<'de> for CredentialInfo {
             UpdatedBy,
             UsedForManagedStorage,
             FullName,
+            Etag,
             AzureServicePrincipal,
             AzureManagedIdentity,
             AzureStorageKey,
+            AwsIamRole,
+            AwsAccessKey,
+            GcpServiceAccountKey,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -797,9 +1111,13 @@ impl<'de> serde::Deserialize<'de> for CredentialInfo {
                             "updatedBy" | "updated_by" => Ok(GeneratedField::UpdatedBy),
                             "usedForManagedStorage" | "used_for_managed_storage" => Ok(GeneratedField::UsedForManagedStorage),
                             "fullName" | "full_name" => Ok(GeneratedField::FullName),
+                            "etag" => Ok(GeneratedField::Etag),
                             "azureServicePrincipal" | "azure_service_principal" => Ok(GeneratedField::AzureServicePrincipal),
                             "azureManagedIdentity" | "azure_managed_identity" => Ok(GeneratedField::AzureManagedIdentity),
                             "azureStorageKey" | "azure_storage_key" => Ok(GeneratedField::AzureStorageKey),
+                            "awsIamRole" | "aws_iam_role" => Ok(GeneratedField::AwsIamRole),
+                            "awsAccessKey" | "aws_access_key" => Ok(GeneratedField::AwsAccessKey),
+                            "gcpServiceAccountKey" | "gcp_service_account_key" => Ok(GeneratedField::GcpServiceAccountKey),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -831,6 +1149,7 @@ impl<'de> serde::Deserialize<'de> for CredentialInfo {
                 let mut updated_by__ = None;
                 let mut used_for_managed_storage__ = None;
                 let mut full_name__ = None;
+                let mut etag__ = None;
                 let mut credential__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
@@ -910,6 +1229,12 @@ impl<'de> serde::Deserialize<'de> for CredentialInfo {
                             }
                             full_name__ = map_.next_value()?;
                         }
+                        GeneratedField::Etag => {
+                            if etag__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("etag"));
+                            }
+                            etag__ = map_.next_value()?;
+                        }
                         GeneratedField::AzureServicePrincipal => {
                             if credential__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("azureServicePrincipal"));
@@ -929,6 +1254,27 @@ impl<'de> serde::Deserialize<'de> for CredentialInfo {
                                 return Err(serde::de::Error::duplicate_field("azureStorageKey"));
                             }
                             credential__ = map_.next_value::<::std::option::Option<_>>()?.map(credential_info::Credential::AzureStorageKey)
+;
+                        }
+                        GeneratedField::AwsIamRole => {
+                            if credential__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("awsIamRole"));
+                            }
+                            credential__ = map_.next_value::<::std::option::Option<_>>()?.map(credential_info::Credential::AwsIamRole)
+;
+                        }
+                        GeneratedField::AwsAccessKey => {
+                            if credential__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("awsAccessKey"));
+                            }
+                            credential__ = map_.next_value::<::std::option::Option<_>>()?.map(credential_info::Credential::AwsAccessKey)
+;
+                        }
+                        GeneratedField::GcpServiceAccountKey => {
+                            if credential__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("gcpServiceAccountKey"));
+                            }
+                            credential__ = map_.next_value::<::std::option::Option<_>>()?.map(credential_info::Credential::GcpServiceAccountKey)
 ;
                         }
                         GeneratedField::__SkipField__ => {
@@ -949,6 +1295,7 @@ impl<'de> serde::Deserialize<'de> for CredentialInfo {
                     updated_by: updated_by__,
                     used_for_managed_storage: used_for_managed_storage__.unwrap_or_default(),
                     full_name: full_name__,
+                    etag: etag__,
                     credential: credential__,
                 })
             }
@@ -1051,7 +1398,7 @@ impl<'de> serde::Deserialize<'de> for DeleteCredentialRequest {
         deserializer.deserialize_struct("delta_sharing.credentials.v1.DeleteCredentialRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for GetCredentialRequest {
+impl serde::Serialize for GcpServiceAccountKey {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1059,29 +1406,47 @@ impl serde::Serialize for GetCredentialRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if !self.name.is_empty() {
+        if !self.email.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("delta_sharing.credentials.v1.GetCredentialRequest", len)?;
-        if !self.name.is_empty() {
-            struct_ser.serialize_field("name", &self.name)?;
+        if !self.private_key_id.is_empty() {
+            len += 1;
+        }
+        if !self.private_key.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("delta_sharing.credentials.v1.GcpServiceAccountKey", len)?;
+        if !self.email.is_empty() {
+            struct_ser.serialize_field("email", &self.email)?;
+        }
+        if !self.private_key_id.is_empty() {
+            struct_ser.serialize_field("privateKeyId", &self.private_key_id)?;
+        }
+        if !self.private_key.is_empty() {
+            struct_ser.serialize_field("privateKey", &self.private_key)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for GetCredentialRequest {
+impl<'de> serde::Deserialize<'de> for GcpServiceAccountKey {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "name",
+            "email",
+            "private_key_id",
+            "privateKeyId",
+            "private_key",
+            "privateKey",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Name,
+            Email,
+            PrivateKeyId,
+            PrivateKey,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1104,7 +1469,9 @@ impl<'de> serde::Deserialize<'de> for GetCredentialRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "name" => Ok(GeneratedField::Name),
+                            "email" => Ok(GeneratedField::Email),
+                            "privateKeyId" | "private_key_id" => Ok(GeneratedField::PrivateKeyId),
+                            "privateKey" | "private_key" => Ok(GeneratedField::PrivateKey),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1114,39 +1481,55 @@ impl<'de> serde::Deserialize<'de> for GetCredentialRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = GetCredentialRequest;
+            type Value = GcpServiceAccountKey;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct delta_sharing.credentials.v1.GetCredentialRequest")
+                formatter.write_str("struct delta_sharing.credentials.v1.GcpServiceAccountKey")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GetCredentialRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GcpServiceAccountKey, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut name__ = None;
+                let mut email__ = None;
+                let mut private_key_id__ = None;
+                let mut private_key__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Name => {
-                            if name__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("name"));
+                        GeneratedField::Email => {
+                            if email__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("email"));
                             }
-                            name__ = Some(map_.next_value()?);
+                            email__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::PrivateKeyId => {
+                            if private_key_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("privateKeyId"));
+                            }
+                            private_key_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::PrivateKey => {
+                            if private_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("privateKey"));
+                            }
+                            private_key__ = Some(map_.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(GetCredentialRequest {
-                    name: name__.unwrap_or_default(),
+                Ok(GcpServiceAccountKey {
+                    email: email__.unwrap_or_default(),
+                    private_key_id: private_key_id__.unwrap_or_default(),
+                    private_key: private_key__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("delta_sharing.credentials.v1.GetCredentialRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("delta_sharing.credentials.v1.GcpServiceAccountKey", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for ListCredentialsRequest {
+impl serde::Serialize for GetCredentialRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1154,31 +1537,126 @@ impl serde::Serialize for ListCredentialsRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.max_results.is_some() {
-            len += 1;
-        }
-        if self.page_token.is_some() {
-            len += 1;
-        }
-        if self.purpose.is_some() {
+        if !self.name.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("delta_sharing.credentials.v1.ListCredentialsRequest", len)?;
-        if let Some(v) = self.max_results.as_ref() {
-            struct_ser.serialize_field("maxResults", v)?;
-        }
-        if let Some(v) = self.page_token.as_ref() {
-            struct_ser.serialize_field("pageToken", v)?;
-        }
-        if let Some(v) = self.purpose.as_ref() {
-            let v = Purpose::try_from(*v)
-                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", *v)))?;
-            struct_ser.serialize_field("purpose", &v)?;
+        let mut struct_ser = serializer.serialize_struct("delta_sharing.credentials.v1.GetCredentialRequest", len)?;
+        if !self.name.is_empty() {
+            struct_ser.serialize_field("name", &self.name)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for ListCredentialsRequest {
+impl<'de> serde::Deserialize<'de> for GetCredentialRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "name",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Name,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl serde::de::Visitor<'_> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "name" => Ok(GeneratedField::Name),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = GetCredentialRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct delta_sharing.credentials.v1.GetCredentialRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GetCredentialRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut name__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Name => {
+                            if name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("name"));
+                            }
+                            name__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(GetCredentialRequest {
+                    name: name__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("delta_sharing.credentials.v1.GetCredentialRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ListCredentialsRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.max_results.is_some() {
+            len += 1;
+        }
+        if self.page_token.is_some() {
+            len += 1;
+        }
+        if self.purpose.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("delta_sharing.credentials.v1.ListCredentialsRequest", len)?;
+        if let Some(v) = self.max_results.as_ref() {
+            struct_ser.serialize_field("maxResults", v)?;
+        }
+        if let Some(v) = self.page_token.as_ref() {
+            struct_ser.serialize_field("pageToken", v)?;
+        }
+        if let Some(v) = self.purpose.as_ref() {
+            let v = Purpose::try_from(*v)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", *v)))?;
+            struct_ser.serialize_field("purpose", &v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ListCredentialsRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
@@ -1497,6 +1975,9 @@ impl serde::Serialize for UpdateCredentialRequest {
         if self.force.is_some() {
             len += 1;
         }
+        if self.etag.is_some() {
+            len += 1;
+        }
         if self.credential.is_some() {
             len += 1;
         }
@@ -1522,6 +2003,9 @@ impl serde::Serialize for UpdateCredentialRequest {
         if let Some(v) = self.force.as_ref() {
             struct_ser.serialize_field("force", v)?;
         }
+        if let Some(v) = self.etag.as_ref() {
+            struct_ser.serialize_field("etag", v)?;
+        }
         if let Some(v) = self.credential.as_ref() {
             match v {
                 update_credential_request::Credential::AzureServicePrincipal(v) => {
@@ -1533,6 +2017,15 @@ impl serde::Serialize for UpdateCredentialRequest {
                 update_credential_request::Credential::AzureStorageKey(v) => {
                     struct_ser.serialize_field("azureStorageKey", v)?;
                 }
+                update_credential_request::Credential::AwsIamRole(v) => {
+                    struct_ser.serialize_field("awsIamRole", v)?;
+                }
+                update_credential_request::Credential::AwsAccessKey(v) => {
+                    struct_ser.serialize_field("awsAccessKey", v)?;
+                }
+                update_credential_request::Credential::GcpServiceAccountKey(v) => {
+                    struct_ser.serialize_field("gcpServiceAccountKey", v)?;
+                }
             }
         }
         struct_ser.end()
@@ -1555,12 +2048,19 @@ impl<'de> serde::Deserialize<'de> for UpdateCredentialRequest {
             "skip_validation",
             "skipValidation",
             "force",
+            "etag",
             "azure_service_principal",
             "azureServicePrincipal",
             "azure_managed_identity",
             "azureManagedIdentity",
             "azure_storage_key",
             "azureStorageKey",
+            "aws_iam_role",
+            "awsIamRole",
+            "aws_access_key",
+            "awsAccessKey",
+            "gcp_service_account_key",
+            "gcpServiceAccountKey",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -1572,9 +2072,13 @@ impl<'de> serde::Deserialize<'de> for UpdateCredentialRequest {
             Owner,
             SkipValidation,
             Force,
+            Etag,
             AzureServicePrincipal,
             AzureManagedIdentity,
             AzureStorageKey,
+            AwsIamRole,
+            AwsAccessKey,
+            GcpServiceAccountKey,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1604,9 +2108,13 @@ impl<'de> serde::Deserialize<'de> for UpdateCredentialRequest {
                             "owner" => Ok(GeneratedField::Owner),
                             "skipValidation" | "skip_validation" => Ok(GeneratedField::SkipValidation),
                             "force" => Ok(GeneratedField::Force),
+                            "etag" => Ok(GeneratedField::Etag),
                             "azureServicePrincipal" | "azure_service_principal" => Ok(GeneratedField::AzureServicePrincipal),
                             "azureManagedIdentity" | "azure_managed_identity" => Ok(GeneratedField::AzureManagedIdentity),
                             "azureStorageKey" | "azure_storage_key" => Ok(GeneratedField::AzureStorageKey),
+                            "awsIamRole" | "aws_iam_role" => Ok(GeneratedField::AwsIamRole),
+                            "awsAccessKey" | "aws_access_key" => Ok(GeneratedField::AwsAccessKey),
+                            "gcpServiceAccountKey" | "gcp_service_account_key" => Ok(GeneratedField::GcpServiceAccountKey),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1633,6 +2141,7 @@ impl<'de> serde::Deserialize<'de> for UpdateCredentialRequest {
                 let mut owner__ = None;
                 let mut skip_validation__ = None;
                 let mut force__ = None;
+                let mut etag__ = None;
                 let mut credential__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
@@ -1678,6 +2187,12 @@ impl<'de> serde::Deserialize<'de> for UpdateCredentialRequest {
                             }
                             force__ = map_.next_value()?;
                         }
+                        GeneratedField::Etag => {
+                            if etag__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("etag"));
+                            }
+                            etag__ = map_.next_value()?;
+                        }
                         GeneratedField::AzureServicePrincipal => {
                             if credential__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("azureServicePrincipal"));
@@ -1697,6 +2212,27 @@ impl<'de> serde::Deserialize<'de> for UpdateCredentialRequest {
                                 return Err(serde::de::Error::duplicate_field("azureStorageKey"));
                             }
                             credential__ = map_.next_value::<::std::option::Option<_>>()?.map(update_credential_request::Credential::AzureStorageKey)
+;
+                        }
+                        GeneratedField::AwsIamRole => {
+                            if credential__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("awsIamRole"));
+                            }
+                            credential__ = map_.next_value::<::std::option::Option<_>>()?.map(update_credential_request::Credential::AwsIamRole)
+;
+                        }
+                        GeneratedField::AwsAccessKey => {
+                            if credential__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("awsAccessKey"));
+                            }
+                            credential__ = map_.next_value::<::std::option::Option<_>>()?.map(update_credential_request::Credential::AwsAccessKey)
+;
+                        }
+                        GeneratedField::GcpServiceAccountKey => {
+                            if credential__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("gcpServiceAccountKey"));
+                            }
+                            credential__ = map_.next_value::<::std::option::Option<_>>()?.map(update_credential_request::Credential::GcpServiceAccountKey)
 ;
                         }
                         GeneratedField::__SkipField__ => {
@@ -1712,6 +2248,7 @@ impl<'de> serde::Deserialize<'de> for UpdateCredentialRequest {
                     owner: owner__,
                     skip_validation: skip_validation__,
                     force: force__,
+                    etag: etag__,
                     credential: credential__,
                 })
             }
@@ -1719,3 +2256,518 @@ impl<'de> serde::Deserialize<'de> for UpdateCredentialRequest {
         deserializer.deserialize_struct("delta_sharing.credentials.v1.UpdateCredentialRequest", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for ValidateCredentialRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.credential.is_some() {
+            len += 1;
+        }
+        if !self.url.is_empty() {
+            len += 1;
+        }
+        if self.read_only.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("delta_sharing.credentials.v1.ValidateCredentialRequest", len)?;
+        if let Some(v) = self.credential.as_ref() {
+            struct_ser.serialize_field("credential", v)?;
+        }
+        if !self.url.is_empty() {
+            struct_ser.serialize_field("url", &self.url)?;
+        }
+        if let Some(v) = self.read_only.as_ref() {
+            struct_ser.serialize_field("readOnly", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ValidateCredentialRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "credential",
+            "url",
+            "read_only",
+            "readOnly",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Credential,
+            Url,
+            ReadOnly,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl serde::de::Visitor<'_> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "credential" => Ok(GeneratedField::Credential),
+                            "url" => Ok(GeneratedField::Url),
+                            "readOnly" | "read_only" => Ok(GeneratedField::ReadOnly),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ValidateCredentialRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct delta_sharing.credentials.v1.ValidateCredentialRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValidateCredentialRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut credential__ = None;
+                let mut url__ = None;
+                let mut read_only__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Credential => {
+                            if credential__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("credential"));
+                            }
+                            credential__ = map_.next_value()?;
+                        }
+                        GeneratedField::Url => {
+                            if url__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("url"));
+                            }
+                            url__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ReadOnly => {
+                            if read_only__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("readOnly"));
+                            }
+                            read_only__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ValidateCredentialRequest {
+                    credential: credential__,
+                    url: url__.unwrap_or_default(),
+                    read_only: read_only__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("delta_sharing.credentials.v1.ValidateCredentialRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ValidateCredentialResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.results.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("delta_sharing.credentials.v1.ValidateCredentialResponse", len)?;
+        if !self.results.is_empty() {
+            struct_ser.serialize_field("results", &self.results)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ValidateCredentialResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "results",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Results,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl serde::de::Visitor<'_> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "results" => Ok(GeneratedField::Results),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ValidateCredentialResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct delta_sharing.credentials.v1.ValidateCredentialResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValidateCredentialResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut results__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Results => {
+                            if results__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("results"));
+                            }
+                            results__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ValidateCredentialResponse {
+                    results: results__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("delta_sharing.credentials.v1.ValidateCredentialResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ValidationOperation {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => "VALIDATION_OPERATION_UNSPECIFIED",
+            Self::List => "LIST",
+            Self::Read => "READ",
+            Self::Write => "WRITE",
+            Self::Delete => "DELETE",
+        };
+        serializer.serialize_str(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for ValidationOperation {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "VALIDATION_OPERATION_UNSPECIFIED",
+            "LIST",
+            "READ",
+            "WRITE",
+            "DELETE",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl serde::de::Visitor<'_> for GeneratedVisitor {
+            type Value = ValidationOperation;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "VALIDATION_OPERATION_UNSPECIFIED" => Ok(ValidationOperation::Unspecified),
+                    "LIST" => Ok(ValidationOperation::List),
+                    "READ" => Ok(ValidationOperation::Read),
+                    "WRITE" => Ok(ValidationOperation::Write),
+                    "DELETE" => Ok(ValidationOperation::Delete),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ValidationResult {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.operation != 0 {
+            len += 1;
+        }
+        if self.result != 0 {
+            len += 1;
+        }
+        if self.message.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("delta_sharing.credentials.v1.ValidationResult", len)?;
+        if self.operation != 0 {
+            let v = ValidationOperation::try_from(self.operation)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.operation)))?;
+            struct_ser.serialize_field("operation", &v)?;
+        }
+        if self.result != 0 {
+            let v = ValidationResultStatus::try_from(self.result)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.result)))?;
+            struct_ser.serialize_field("result", &v)?;
+        }
+        if let Some(v) = self.message.as_ref() {
+            struct_ser.serialize_field("message", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ValidationResult {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "operation",
+            "result",
+            "message",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Operation,
+            Result,
+            Message,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl serde::de::Visitor<'_> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "operation" => Ok(GeneratedField::Operation),
+                            "result" => Ok(GeneratedField::Result),
+                            "message" => Ok(GeneratedField::Message),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ValidationResult;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct delta_sharing.credentials.v1.ValidationResult")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValidationResult, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut operation__ = None;
+                let mut result__ = None;
+                let mut message__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Operation => {
+                            if operation__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("operation"));
+                            }
+                            operation__ = Some(map_.next_value::<ValidationOperation>()? as i32);
+                        }
+                        GeneratedField::Result => {
+                            if result__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("result"));
+                            }
+                            result__ = Some(map_.next_value::<ValidationResultStatus>()? as i32);
+                        }
+                        GeneratedField::Message => {
+                            if message__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("message"));
+                            }
+                            message__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ValidationResult {
+                    operation: operation__.unwrap_or_default(),
+                    result: result__.unwrap_or_default(),
+                    message: message__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("delta_sharing.credentials.v1.ValidationResult", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ValidationResultStatus {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => "VALIDATION_RESULT_STATUS_UNSPECIFIED",
+            Self::Pass => "PASS",
+            Self::Fail => "FAIL",
+            Self::Skip => "SKIP",
+        };
+        serializer.serialize_str(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for ValidationResultStatus {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "VALIDATION_RESULT_STATUS_UNSPECIFIED",
+            "PASS",
+            "FAIL",
+            "SKIP",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl serde::de::Visitor<'_> for GeneratedVisitor {
+            type Value = ValidationResultStatus;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "VALIDATION_RESULT_STATUS_UNSPECIFIED" => Ok(ValidationResultStatus::Unspecified),
+                    "PASS" => Ok(ValidationResultStatus::Pass),
+                    "FAIL" => Ok(ValidationResultStatus::Fail),
+                    "SKIP" => Ok(ValidationResultStatus::Skip),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
@@ -34,6 +34,14 @@ pub mod credentials_service_server {
             &self,
             request: tonic::Request<super::DeleteCredentialRequest>,
         ) -> std::result::Result<tonic::Response<()>, tonic::Status>;
+        ///
+        async fn validate_credential(
+            &self,
+            request: tonic::Request<super::ValidateCredentialRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ValidateCredentialResponse>,
+            tonic::Status,
+        >;
     }
     /** Manage credentials to access external data sources and services
  as well as generate signed urls for the Delta Sharing service.
@@ -353,6 +361,55 @@ pub mod credentials_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/delta_sharing.credentials.v1.CredentialsService/ValidateCredential" => {
+                    #[allow(non_camel_case_types)]
+                    struct ValidateCredentialSvc<T: CredentialsService>(pub Arc<T>);
+                    impl<
+                        T: CredentialsService,
+                    > tonic::server::UnaryService<super::ValidateCredentialRequest>
+                    for ValidateCredentialSvc<T> {
+                        type Response = super::ValidateCredentialResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ValidateCredentialRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as CredentialsService>::validate_credential(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ValidateCredentialSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         Ok(
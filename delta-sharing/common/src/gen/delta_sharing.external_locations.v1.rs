@@ -45,6 +45,11 @@ pub struct ExternalLocationInfo {
     pub browse_only: ::core::option::Option<bool>,
     #[prost(string, optional, tag="100")]
     pub external_location_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Opaque identifier of the current revision of the external location.
+    ///
+    /// Pass it as precondition when updating the external location to detect concurrent modifications.
+    #[prost(string, optional, tag="14")]
+    pub etag: ::core::option::Option<::prost::alloc::string::String>,
 }
 /// List external locations
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -91,6 +96,8 @@ pub struct CreateExternalLocationRequest {
     #[prost(string, optional, tag="5")]
     pub comment: ::core::option::Option<::prost::alloc::string::String>,
     /// Skips validation of the storage credential associated with the external location.
+    ///
+    /// The credential is only validated against the url when this is explicitly set to false.
     #[prost(bool, optional, tag="6")]
     pub skip_validation: ::core::option::Option<bool>,
 }
@@ -133,6 +140,12 @@ pub struct UpdateExternalLocationRequest {
     /// Skips validation of the storage credential associated with the external location.
     #[prost(bool, optional, tag="9")]
     pub skip_validation: ::core::option::Option<bool>,
+    /// Revision of the external location the update is based on.
+    ///
+    /// If set, the update fails with a conflict if the external location was modified since.
+    /// May also be provided via the If-Match header.
+    #[prost(string, optional, tag="10")]
+    pub etag: ::core::option::Option<::prost::alloc::string::String>,
 }
 /// Delete an external location
 #[allow(clippy::derive_partial_eq_without_eq)]
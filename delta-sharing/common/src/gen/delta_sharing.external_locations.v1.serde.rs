@@ -341,6 +341,9 @@ impl serde::Serialize for ExternalLocationInfo {
         if self.external_location_id.is_some() {
             len += 1;
         }
+        if self.etag.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("delta_sharing.external_locations.v1.ExternalLocationInfo", len)?;
         if !self.name.is_empty() {
             struct_ser.serialize_field("name", &self.name)?;
@@ -385,6 +388,9 @@ impl serde::Serialize for ExternalLocationInfo {
         if let Some(v) = self.external_location_id.as_ref() {
             struct_ser.serialize_field("externalLocationId", v)?;
         }
+        if let Some(v) = self.etag.as_ref() {
+            struct_ser.serialize_field("etag", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -417,6 +423,7 @@ impl<'de> serde::Deserialize<'de> for ExternalLocationInfo {
             "browseOnly",
             "external_location_id",
             "externalLocationId",
+            "etag",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -434,6 +441,7 @@ impl<'de> serde::Deserialize<'de> for ExternalLocationInfo {
             UpdatedBy,
             BrowseOnly,
             ExternalLocationId,
+            Etag,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -469,6 +477,7 @@ impl<'de> serde::Deserialize<'de> for ExternalLocationInfo {
                             "updatedBy" | "updated_by" => Ok(GeneratedField::UpdatedBy),
                             "browseOnly" | "browse_only" => Ok(GeneratedField::BrowseOnly),
                             "externalLocationId" | "external_location_id" => Ok(GeneratedField::ExternalLocationId),
+                            "etag" => Ok(GeneratedField::Etag),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -501,6 +510,7 @@ impl<'de> serde::Deserialize<'de> for ExternalLocationInfo {
                 let mut updated_by__ = None;
                 let mut browse_only__ = None;
                 let mut external_location_id__ = None;
+                let mut etag__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Name => {
@@ -585,6 +595,12 @@ impl<'de> serde::Deserialize<'de> for ExternalLocationInfo {
                             }
                             external_location_id__ = map_.next_value()?;
                         }
+                        GeneratedField::Etag => {
+                            if etag__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("etag"));
+                            }
+                            etag__ = map_.next_value()?;
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -604,6 +620,7 @@ impl<'de> serde::Deserialize<'de> for ExternalLocationInfo {
                     updated_by: updated_by__,
                     browse_only: browse_only__,
                     external_location_id: external_location_id__,
+                    etag: etag__,
                 })
             }
         }
@@ -988,6 +1005,9 @@ impl serde::Serialize for UpdateExternalLocationRequest {
         if self.skip_validation.is_some() {
             len += 1;
         }
+        if self.etag.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("delta_sharing.external_locations.v1.UpdateExternalLocationRequest", len)?;
         if !self.name.is_empty() {
             struct_ser.serialize_field("name", &self.name)?;
@@ -1016,6 +1036,9 @@ impl serde::Serialize for UpdateExternalLocationRequest {
         if let Some(v) = self.skip_validation.as_ref() {
             struct_ser.serialize_field("skipValidation", v)?;
         }
+        if let Some(v) = self.etag.as_ref() {
+            struct_ser.serialize_field("etag", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -1039,6 +1062,7 @@ impl<'de> serde::Deserialize<'de> for UpdateExternalLocationRequest {
             "force",
             "skip_validation",
             "skipValidation",
+            "etag",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -1052,6 +1076,7 @@ impl<'de> serde::Deserialize<'de> for UpdateExternalLocationRequest {
             NewName,
             Force,
             SkipValidation,
+            Etag,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1083,6 +1108,7 @@ impl<'de> serde::Deserialize<'de> for UpdateExternalLocationRequest {
                             "newName" | "new_name" => Ok(GeneratedField::NewName),
                             "force" => Ok(GeneratedField::Force),
                             "skipValidation" | "skip_validation" => Ok(GeneratedField::SkipValidation),
+                            "etag" => Ok(GeneratedField::Etag),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1111,6 +1137,7 @@ impl<'de> serde::Deserialize<'de> for UpdateExternalLocationRequest {
                 let mut new_name__ = None;
                 let mut force__ = None;
                 let mut skip_validation__ = None;
+                let mut etag__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Name => {
@@ -1167,6 +1194,12 @@ impl<'de> serde::Deserialize<'de> for UpdateExternalLocationRequest {
                             }
                             skip_validation__ = map_.next_value()?;
                         }
+                        GeneratedField::Etag => {
+                            if etag__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("etag"));
+                            }
+                            etag__ = map_.next_value()?;
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -1182,6 +1215,7 @@ impl<'de> serde::Deserialize<'de> for UpdateExternalLocationRequest {
                     new_name: new_name__,
                     force: force__,
                     skip_validation: skip_validation__,
+                    etag: etag__,
                 })
             }
         }
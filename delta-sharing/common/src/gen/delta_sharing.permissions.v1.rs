@@ -0,0 +1,231 @@
+// @generated
+// This file is @generated by prost-build.
+/// The privileges assigned to a principal.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PrivilegeAssignment {
+    /// The principal (user email address or group name).
+    #[prost(string, tag="1")]
+    pub principal: ::prost::alloc::string::String,
+    /// The privileges assigned to the principal.
+    #[prost(enumeration = "Privilege", repeated, tag="2")]
+    pub privileges: ::prost::alloc::vec::Vec<i32>,
+}
+/// A change to the privileges of a principal.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PermissionsChange {
+    /// The principal whose privileges are changed.
+    #[prost(string, tag="1")]
+    pub principal: ::prost::alloc::string::String,
+    /// The set of privileges to add.
+    #[prost(enumeration = "Privilege", repeated, tag="2")]
+    pub add: ::prost::alloc::vec::Vec<i32>,
+    /// The set of privileges to remove.
+    #[prost(enumeration = "Privilege", repeated, tag="3")]
+    pub remove: ::prost::alloc::vec::Vec<i32>,
+}
+/// A privilege held by a principal, either directly or via an ancestor.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EffectivePrivilege {
+    /// The privilege held by the principal.
+    #[prost(enumeration = "Privilege", tag="1")]
+    pub privilege: i32,
+    /// The type of the securable the privilege is inherited from.
+    ///
+    /// Not set if the privilege is granted directly on the securable.
+    #[prost(string, optional, tag="2")]
+    pub inherited_from_type: ::core::option::Option<::prost::alloc::string::String>,
+    /// The full name of the securable the privilege is inherited from.
+    ///
+    /// Not set if the privilege is granted directly on the securable.
+    #[prost(string, optional, tag="3")]
+    pub inherited_from_name: ::core::option::Option<::prost::alloc::string::String>,
+}
+/// The effective privileges of a principal.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EffectivePrivilegeAssignment {
+    /// The principal (user email address or group name).
+    #[prost(string, tag="1")]
+    pub principal: ::prost::alloc::string::String,
+    /// The privileges held by the principal.
+    #[prost(message, repeated, tag="2")]
+    pub privileges: ::prost::alloc::vec::Vec<EffectivePrivilege>,
+}
+/// The type of a securable object permissions can be granted on.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum SecurableType {
+    /// Unknown securable type.
+    Unspecified = 0,
+    Catalog = 1,
+    Schema = 2,
+    Table = 3,
+    Credential = 4,
+    ExternalLocation = 5,
+    Share = 6,
+}
+impl SecurableType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "SECURABLE_TYPE_UNSPECIFIED",
+            Self::Catalog => "CATALOG",
+            Self::Schema => "SCHEMA",
+            Self::Table => "TABLE",
+            Self::Credential => "CREDENTIAL",
+            Self::ExternalLocation => "EXTERNAL_LOCATION",
+            Self::Share => "SHARE",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "SECURABLE_TYPE_UNSPECIFIED" => Some(Self::Unspecified),
+            "CATALOG" => Some(Self::Catalog),
+            "SCHEMA" => Some(Self::Schema),
+            "TABLE" => Some(Self::Table),
+            "CREDENTIAL" => Some(Self::Credential),
+            "EXTERNAL_LOCATION" => Some(Self::ExternalLocation),
+            "SHARE" => Some(Self::Share),
+            _ => None,
+        }
+    }
+}
+/// A privilege that can be granted on a securable.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum Privilege {
+    /// Unknown privilege.
+    Unspecified = 0,
+    Select = 1,
+    Modify = 2,
+    UseCatalog = 3,
+    UseSchema = 4,
+    CreateCatalog = 5,
+    CreateSchema = 6,
+    CreateTable = 7,
+    CreateExternalLocation = 8,
+    ReadFiles = 9,
+    WriteFiles = 10,
+    Browse = 11,
+    Manage = 12,
+    AllPrivileges = 13,
+}
+impl Privilege {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "PRIVILEGE_UNSPECIFIED",
+            Self::Select => "SELECT",
+            Self::Modify => "MODIFY",
+            Self::UseCatalog => "USE_CATALOG",
+            Self::UseSchema => "USE_SCHEMA",
+            Self::CreateCatalog => "CREATE_CATALOG",
+            Self::CreateSchema => "CREATE_SCHEMA",
+            Self::CreateTable => "CREATE_TABLE",
+            Self::CreateExternalLocation => "CREATE_EXTERNAL_LOCATION",
+            Self::ReadFiles => "READ_FILES",
+            Self::WriteFiles => "WRITE_FILES",
+            Self::Browse => "BROWSE",
+            Self::Manage => "MANAGE",
+            Self::AllPrivileges => "ALL_PRIVILEGES",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "PRIVILEGE_UNSPECIFIED" => Some(Self::Unspecified),
+            "SELECT" => Some(Self::Select),
+            "MODIFY" => Some(Self::Modify),
+            "USE_CATALOG" => Some(Self::UseCatalog),
+            "USE_SCHEMA" => Some(Self::UseSchema),
+            "CREATE_CATALOG" => Some(Self::CreateCatalog),
+            "CREATE_SCHEMA" => Some(Self::CreateSchema),
+            "CREATE_TABLE" => Some(Self::CreateTable),
+            "CREATE_EXTERNAL_LOCATION" => Some(Self::CreateExternalLocation),
+            "READ_FILES" => Some(Self::ReadFiles),
+            "WRITE_FILES" => Some(Self::WriteFiles),
+            "BROWSE" => Some(Self::Browse),
+            "MANAGE" => Some(Self::Manage),
+            "ALL_PRIVILEGES" => Some(Self::AllPrivileges),
+            _ => None,
+        }
+    }
+}
+/// Get the permissions on a securable
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetPermissionsRequest {
+    /// Type of the securable.
+    #[prost(string, tag="1")]
+    pub securable_type: ::prost::alloc::string::String,
+    /// Full name of the securable.
+    #[prost(string, tag="2")]
+    pub full_name: ::prost::alloc::string::String,
+    /// If provided, only the permissions for the specified principal are returned.
+    #[prost(string, optional, tag="3")]
+    pub principal: ::core::option::Option<::prost::alloc::string::String>,
+}
+/// Get permissions response.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetPermissionsResponse {
+    /// The privileges assigned to each principal.
+    #[prost(message, repeated, tag="1")]
+    pub privilege_assignments: ::prost::alloc::vec::Vec<PrivilegeAssignment>,
+}
+/// Update the permissions on a securable
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdatePermissionsRequest {
+    /// Type of the securable.
+    #[prost(string, tag="1")]
+    pub securable_type: ::prost::alloc::string::String,
+    /// Full name of the securable.
+    #[prost(string, tag="2")]
+    pub full_name: ::prost::alloc::string::String,
+    /// Array of permissions change objects.
+    #[prost(message, repeated, tag="3")]
+    pub changes: ::prost::alloc::vec::Vec<PermissionsChange>,
+}
+/// Update permissions response.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdatePermissionsResponse {
+    /// The privileges assigned to each principal after the update.
+    #[prost(message, repeated, tag="1")]
+    pub privilege_assignments: ::prost::alloc::vec::Vec<PrivilegeAssignment>,
+}
+/// Get the effective permissions on a securable
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetEffectivePermissionsRequest {
+    /// Type of the securable.
+    #[prost(string, tag="1")]
+    pub securable_type: ::prost::alloc::string::String,
+    /// Full name of the securable.
+    #[prost(string, tag="2")]
+    pub full_name: ::prost::alloc::string::String,
+    /// If provided, only the effective permissions for the specified principal are returned.
+    #[prost(string, optional, tag="3")]
+    pub principal: ::core::option::Option<::prost::alloc::string::String>,
+}
+/// Get effective permissions response.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetEffectivePermissionsResponse {
+    /// The privileges held by each principal, including inherited privileges.
+    #[prost(message, repeated, tag="1")]
+    pub privilege_assignments: ::prost::alloc::vec::Vec<EffectivePrivilegeAssignment>,
+}
+include!("delta_sharing.permissions.v1.serde.rs");
+// @@protoc_insertion_point(module)
\ No newline at end of file
@@ -25,6 +25,14 @@ pub struct RecipientToken {
     #[prost(string, tag="7")]
     pub updated_by: ::prost::alloc::string::String,
 }
+/// Network locations recipients may connect from.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct IpAccessList {
+    /// Allowed IP addresses in CIDR notation, e.g. `192.168.0.0/16`, or single addresses.
+    #[prost(string, repeated, tag="1")]
+    pub allowed_ip_addresses: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RecipientInfo {
@@ -61,6 +69,14 @@ pub struct RecipientInfo {
     /// Username of share updater.
     #[prost(string, optional, tag="11")]
     pub updated_by: ::core::option::Option<::prost::alloc::string::String>,
+    /// IP addresses the recipient may connect from, if not set all addresses are allowed.
+    #[prost(message, optional, tag="12")]
+    pub ip_access_list: ::core::option::Option<IpAccessList>,
+    /// Opaque identifier of the current revision of the recipient.
+    ///
+    /// Pass it as precondition when updating the recipient to detect concurrent modifications.
+    #[prost(string, optional, tag="13")]
+    pub etag: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
@@ -141,6 +157,9 @@ pub struct CreateRecipientRequest {
     /// Expiration timestamp of the token, in epoch milliseconds.
     #[prost(int64, optional, tag="6")]
     pub expiration_time: ::core::option::Option<i64>,
+    /// IP addresses the recipient may connect from.
+    #[prost(message, optional, tag="7")]
+    pub ip_access_list: ::core::option::Option<IpAccessList>,
 }
 /// Get a recipient by name.
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -175,6 +194,15 @@ pub struct UpdateRecipientRequest {
     /// Expiration timestamp of the token, in epoch milliseconds.
     #[prost(int64, optional, tag="6")]
     pub expiration_time: ::core::option::Option<i64>,
+    /// IP addresses the recipient may connect from.
+    #[prost(message, optional, tag="7")]
+    pub ip_access_list: ::core::option::Option<IpAccessList>,
+    /// Revision of the recipient the update is based on.
+    ///
+    /// If set, the update fails with a conflict if the recipient was modified since.
+    /// May also be provided via the If-Match header.
+    #[prost(string, optional, tag="8")]
+    pub etag: ::core::option::Option<::prost::alloc::string::String>,
 }
 /// Delete a recipient
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -99,6 +99,9 @@ impl serde::Serialize for CreateRecipientRequest {
         if self.expiration_time.is_some() {
             len += 1;
         }
+        if self.ip_access_list.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("delta_sharing.recipients.v1.CreateRecipientRequest", len)?;
         if !self.name.is_empty() {
             struct_ser.serialize_field("name", &self.name)?;
@@ -122,6 +125,9 @@ impl serde::Serialize for CreateRecipientRequest {
             #[allow(clippy::needless_borrows_for_generic_args)]
             struct_ser.serialize_field("expirationTime", ToString::to_string(&v).as_str())?;
         }
+        if let Some(v) = self.ip_access_list.as_ref() {
+            struct_ser.serialize_field("ipAccessList", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -140,6 +146,8 @@ impl<'de> serde::Deserialize<'de> for CreateRecipientRequest {
             "properties",
             "expiration_time",
             "expirationTime",
+            "ip_access_list",
+            "ipAccessList",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -150,6 +158,7 @@ impl<'de> serde::Deserialize<'de> for CreateRecipientRequest {
             Comment,
             Properties,
             ExpirationTime,
+            IpAccessList,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -178,6 +187,7 @@ impl<'de> serde::Deserialize<'de> for CreateRecipientRequest {
                             "comment" => Ok(GeneratedField::Comment),
                             "properties" => Ok(GeneratedField::Properties),
                             "expirationTime" | "expiration_time" => Ok(GeneratedField::ExpirationTime),
+                            "ipAccessList" | "ip_access_list" => Ok(GeneratedField::IpAccessList),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -203,6 +213,7 @@ impl<'de> serde::Deserialize<'de> for CreateRecipientRequest {
                 let mut comment__ = None;
                 let mut properties__ = None;
                 let mut expiration_time__ = None;
+                let mut ip_access_list__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Name => {
@@ -243,6 +254,12 @@ impl<'de> serde::Deserialize<'de> for CreateRecipientRequest {
                                 map_.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| x.0)
                             ;
                         }
+                        GeneratedField::IpAccessList => {
+                            if ip_access_list__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ipAccessList"));
+                            }
+                            ip_access_list__ = map_.next_value()?;
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -255,6 +272,7 @@ impl<'de> serde::Deserialize<'de> for CreateRecipientRequest {
                     comment: comment__,
                     properties: properties__,
                     expiration_time: expiration_time__,
+                    ip_access_list: ip_access_list__,
                 })
             }
         }
@@ -451,6 +469,102 @@ impl<'de> serde::Deserialize<'de> for GetRecipientRequest {
         deserializer.deserialize_struct("delta_sharing.recipients.v1.GetRecipientRequest", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for IpAccessList {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.allowed_ip_addresses.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("delta_sharing.recipients.v1.IpAccessList", len)?;
+        if !self.allowed_ip_addresses.is_empty() {
+            struct_ser.serialize_field("allowedIpAddresses", &self.allowed_ip_addresses)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for IpAccessList {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "allowed_ip_addresses",
+            "allowedIpAddresses",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            AllowedIpAddresses,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl serde::de::Visitor<'_> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "allowedIpAddresses" | "allowed_ip_addresses" => Ok(GeneratedField::AllowedIpAddresses),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = IpAccessList;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct delta_sharing.recipients.v1.IpAccessList")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<IpAccessList, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut allowed_ip_addresses__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::AllowedIpAddresses => {
+                            if allowed_ip_addresses__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("allowedIpAddresses"));
+                            }
+                            allowed_ip_addresses__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(IpAccessList {
+                    allowed_ip_addresses: allowed_ip_addresses__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("delta_sharing.recipients.v1.IpAccessList", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for ListRecipientsRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -721,6 +835,12 @@ impl serde::Serialize for RecipientInfo {
         if self.updated_by.is_some() {
             len += 1;
         }
+        if self.ip_access_list.is_some() {
+            len += 1;
+        }
+        if self.etag.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("delta_sharing.recipients.v1.RecipientInfo", len)?;
         if let Some(v) = self.id.as_ref() {
             struct_ser.serialize_field("id", v)?;
@@ -761,6 +881,12 @@ impl serde::Serialize for RecipientInfo {
         if let Some(v) = self.updated_by.as_ref() {
             struct_ser.serialize_field("updatedBy", v)?;
         }
+        if let Some(v) = self.ip_access_list.as_ref() {
+            struct_ser.serialize_field("ipAccessList", v)?;
+        }
+        if let Some(v) = self.etag.as_ref() {
+            struct_ser.serialize_field("etag", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -787,6 +913,9 @@ impl<'de> serde::Deserialize<'de> for RecipientInfo {
             "updatedAt",
             "updated_by",
             "updatedBy",
+            "ip_access_list",
+            "ipAccessList",
+            "etag",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -802,6 +931,8 @@ impl<'de> serde::Deserialize<'de> for RecipientInfo {
             Tokens,
             UpdatedAt,
             UpdatedBy,
+            IpAccessList,
+            Etag,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -835,6 +966,8 @@ impl<'de> serde::Deserialize<'de> for RecipientInfo {
                             "tokens" => Ok(GeneratedField::Tokens),
                             "updatedAt" | "updated_at" => Ok(GeneratedField::UpdatedAt),
                             "updatedBy" | "updated_by" => Ok(GeneratedField::UpdatedBy),
+                            "ipAccessList" | "ip_access_list" => Ok(GeneratedField::IpAccessList),
+                            "etag" => Ok(GeneratedField::Etag),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -865,6 +998,8 @@ impl<'de> serde::Deserialize<'de> for RecipientInfo {
                 let mut tokens__ = None;
                 let mut updated_at__ = None;
                 let mut updated_by__ = None;
+                let mut ip_access_list__ = None;
+                let mut etag__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Id => {
@@ -937,6 +1072,18 @@ impl<'de> serde::Deserialize<'de> for RecipientInfo {
                             }
                             updated_by__ = map_.next_value()?;
                         }
+                        GeneratedField::IpAccessList => {
+                            if ip_access_list__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ipAccessList"));
+                            }
+                            ip_access_list__ = map_.next_value()?;
+                        }
+                        GeneratedField::Etag => {
+                            if etag__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("etag"));
+                            }
+                            etag__ = map_.next_value()?;
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -954,6 +1101,8 @@ impl<'de> serde::Deserialize<'de> for RecipientInfo {
                     tokens: tokens__.unwrap_or_default(),
                     updated_at: updated_at__,
                     updated_by: updated_by__,
+                    ip_access_list: ip_access_list__,
+                    etag: etag__,
                 })
             }
         }
@@ -1201,6 +1350,12 @@ impl serde::Serialize for UpdateRecipientRequest {
         if self.expiration_time.is_some() {
             len += 1;
         }
+        if self.ip_access_list.is_some() {
+            len += 1;
+        }
+        if self.etag.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("delta_sharing.recipients.v1.UpdateRecipientRequest", len)?;
         if !self.name.is_empty() {
             struct_ser.serialize_field("name", &self.name)?;
@@ -1222,6 +1377,12 @@ impl serde::Serialize for UpdateRecipientRequest {
             #[allow(clippy::needless_borrows_for_generic_args)]
             struct_ser.serialize_field("expirationTime", ToString::to_string(&v).as_str())?;
         }
+        if let Some(v) = self.ip_access_list.as_ref() {
+            struct_ser.serialize_field("ipAccessList", v)?;
+        }
+        if let Some(v) = self.etag.as_ref() {
+            struct_ser.serialize_field("etag", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -1240,6 +1401,9 @@ impl<'de> serde::Deserialize<'de> for UpdateRecipientRequest {
             "properties",
             "expiration_time",
             "expirationTime",
+            "ip_access_list",
+            "ipAccessList",
+            "etag",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -1250,6 +1414,8 @@ impl<'de> serde::Deserialize<'de> for UpdateRecipientRequest {
             Comment,
             Properties,
             ExpirationTime,
+            IpAccessList,
+            Etag,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1278,6 +1444,8 @@ impl<'de> serde::Deserialize<'de> for UpdateRecipientRequest {
                             "comment" => Ok(GeneratedField::Comment),
                             "properties" => Ok(GeneratedField::Properties),
                             "expirationTime" | "expiration_time" => Ok(GeneratedField::ExpirationTime),
+                            "ipAccessList" | "ip_access_list" => Ok(GeneratedField::IpAccessList),
+                            "etag" => Ok(GeneratedField::Etag),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1303,6 +1471,8 @@ impl<'de> serde::Deserialize<'de> for UpdateRecipientRequest {
                 let mut comment__ = None;
                 let mut properties__ = None;
                 let mut expiration_time__ = None;
+                let mut ip_access_list__ = None;
+                let mut etag__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Name => {
@@ -1343,6 +1513,18 @@ impl<'de> serde::Deserialize<'de> for UpdateRecipientRequest {
                                 map_.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| x.0)
                             ;
                         }
+                        GeneratedField::IpAccessList => {
+                            if ip_access_list__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ipAccessList"));
+                            }
+                            ip_access_list__ = map_.next_value()?;
+                        }
+                        GeneratedField::Etag => {
+                            if etag__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("etag"));
+                            }
+                            etag__ = map_.next_value()?;
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -1355,6 +1537,8 @@ impl<'de> serde::Deserialize<'de> for UpdateRecipientRequest {
                     comment: comment__,
                     properties: properties__,
                     expiration_time: expiration_time__,
+                    ip_access_list: ip_access_list__,
+                    etag: etag__,
                 })
             }
         }
@@ -37,6 +37,11 @@ pub struct SchemaInfo {
     /// Unique identifier for the schema.
     #[prost(string, optional, tag="11")]
     pub schema_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Opaque identifier of the current revision of the schema.
+    ///
+    /// Pass it as precondition when updating the schema to detect concurrent modifications.
+    #[prost(string, optional, tag="12")]
+    pub etag: ::core::option::Option<::prost::alloc::string::String>,
 }
 /// List Schemas in a catalog
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -112,6 +117,12 @@ pub struct UpdateSchemaRequest {
     /// Name of schema.
     #[prost(string, tag="4")]
     pub new_name: ::prost::alloc::string::String,
+    /// Revision of the schema the update is based on.
+    ///
+    /// If set, the update fails with a conflict if the schema was modified since.
+    /// May also be provided via the If-Match header.
+    #[prost(string, optional, tag="5")]
+    pub etag: ::core::option::Option<::prost::alloc::string::String>,
 }
 /// Delete a Schema
 #[allow(clippy::derive_partial_eq_without_eq)]
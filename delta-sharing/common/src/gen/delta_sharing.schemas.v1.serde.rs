@@ -661,6 +661,9 @@ impl serde::Serialize for SchemaInfo {
         if self.schema_id.is_some() {
             len += 1;
         }
+        if self.etag.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("delta_sharing.schemas.v1.SchemaInfo", len)?;
         if !self.name.is_empty() {
             struct_ser.serialize_field("name", &self.name)?;
@@ -699,6 +702,9 @@ impl serde::Serialize for SchemaInfo {
         if let Some(v) = self.schema_id.as_ref() {
             struct_ser.serialize_field("schemaId", v)?;
         }
+        if let Some(v) = self.etag.as_ref() {
+            struct_ser.serialize_field("etag", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -727,6 +733,7 @@ impl<'de> serde::Deserialize<'de> for SchemaInfo {
             "updatedBy",
             "schema_id",
             "schemaId",
+            "etag",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -742,6 +749,7 @@ impl<'de> serde::Deserialize<'de> for SchemaInfo {
             UpdatedAt,
             UpdatedBy,
             SchemaId,
+            Etag,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -775,6 +783,7 @@ impl<'de> serde::Deserialize<'de> for SchemaInfo {
                             "updatedAt" | "updated_at" => Ok(GeneratedField::UpdatedAt),
                             "updatedBy" | "updated_by" => Ok(GeneratedField::UpdatedBy),
                             "schemaId" | "schema_id" => Ok(GeneratedField::SchemaId),
+                            "etag" => Ok(GeneratedField::Etag),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -805,6 +814,7 @@ impl<'de> serde::Deserialize<'de> for SchemaInfo {
                 let mut updated_at__ = None;
                 let mut updated_by__ = None;
                 let mut schema_id__ = None;
+                let mut etag__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Name => {
@@ -877,6 +887,12 @@ impl<'de> serde::Deserialize<'de> for SchemaInfo {
                             }
                             schema_id__ = map_.next_value()?;
                         }
+                        GeneratedField::Etag => {
+                            if etag__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("etag"));
+                            }
+                            etag__ = map_.next_value()?;
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -894,6 +910,7 @@ impl<'de> serde::Deserialize<'de> for SchemaInfo {
                     updated_at: updated_at__,
                     updated_by: updated_by__,
                     schema_id: schema_id__,
+                    etag: etag__,
                 })
             }
         }
@@ -920,6 +937,9 @@ impl serde::Serialize for UpdateSchemaRequest {
         if !self.new_name.is_empty() {
             len += 1;
         }
+        if self.etag.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("delta_sharing.schemas.v1.UpdateSchemaRequest", len)?;
         if !self.full_name.is_empty() {
             struct_ser.serialize_field("fullName", &self.full_name)?;
@@ -933,6 +953,9 @@ impl serde::Serialize for UpdateSchemaRequest {
         if !self.new_name.is_empty() {
             struct_ser.serialize_field("newName", &self.new_name)?;
         }
+        if let Some(v) = self.etag.as_ref() {
+            struct_ser.serialize_field("etag", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -949,6 +972,7 @@ impl<'de> serde::Deserialize<'de> for UpdateSchemaRequest {
             "properties",
             "new_name",
             "newName",
+            "etag",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -957,6 +981,7 @@ impl<'de> serde::Deserialize<'de> for UpdateSchemaRequest {
             Comment,
             Properties,
             NewName,
+            Etag,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -983,6 +1008,7 @@ impl<'de> serde::Deserialize<'de> for UpdateSchemaRequest {
                             "comment" => Ok(GeneratedField::Comment),
                             "properties" => Ok(GeneratedField::Properties),
                             "newName" | "new_name" => Ok(GeneratedField::NewName),
+                            "etag" => Ok(GeneratedField::Etag),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1006,6 +1032,7 @@ impl<'de> serde::Deserialize<'de> for UpdateSchemaRequest {
                 let mut comment__ = None;
                 let mut properties__ = None;
                 let mut new_name__ = None;
+                let mut etag__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::FullName => {
@@ -1032,6 +1059,12 @@ impl<'de> serde::Deserialize<'de> for UpdateSchemaRequest {
                             }
                             new_name__ = Some(map_.next_value()?);
                         }
+                        GeneratedField::Etag => {
+                            if etag__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("etag"));
+                            }
+                            etag__ = map_.next_value()?;
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -1042,6 +1075,7 @@ impl<'de> serde::Deserialize<'de> for UpdateSchemaRequest {
                     comment: comment__,
                     properties: properties__,
                     new_name: new_name__.unwrap_or_default(),
+                    etag: etag__,
                 })
             }
         }
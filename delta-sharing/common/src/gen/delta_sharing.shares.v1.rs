@@ -1,5 +1,16 @@
 // @generated
 // This file is @generated by prost-build.
+/// A column of a shared table that is visible to recipients.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SharedColumn {
+    /// Name of the column.
+    #[prost(string, tag="1")]
+    pub name: ::prost::alloc::string::String,
+    /// Masking applied to the values of the column.
+    #[prost(enumeration="ColumnMask", tag="2")]
+    pub mask: i32,
+}
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DataObject {
@@ -45,6 +56,18 @@ pub struct DataObject {
     /// NOTE: The start_version should be <= the current version of the object.
     #[prost(int64, optional, tag="10")]
     pub start_version: ::core::option::Option<i64>,
+    /// Predicate restricting the rows of the table that are visible to recipients.
+    ///
+    /// The predicate is a JSON predicate as defined by the Delta Sharing protocol. Literal values
+    /// of the form `${recipient.<property>}` are replaced with the properties of the requesting
+    /// recipient, `${recipient.name}` with its name.
+    #[prost(string, optional, tag="11")]
+    pub row_filter: ::core::option::Option<::prost::alloc::string::String>,
+    /// Columns of the table that are visible to recipients.
+    ///
+    /// If empty, all columns are visible and no masking is applied.
+    #[prost(message, repeated, tag="12")]
+    pub columns: ::prost::alloc::vec::Vec<SharedColumn>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -79,6 +102,11 @@ pub struct ShareInfo {
     /// Username of share updater.
     #[prost(string, optional, tag="9")]
     pub updated_by: ::core::option::Option<::prost::alloc::string::String>,
+    /// Opaque identifier of the current revision of the share.
+    ///
+    /// Pass it as precondition when updating the share to detect concurrent modifications.
+    #[prost(string, optional, tag="11")]
+    pub etag: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
@@ -138,6 +166,43 @@ impl HistoryStatus {
         }
     }
 }
+/// Masking applied to the values of a shared column.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ColumnMask {
+    /// Values are shared as is.
+    Unspecified = 0,
+    /// Values are replaced with null.
+    NullOut = 1,
+    /// Values are replaced with the hex encoded SHA-256 hash of their string representation.
+    Hash = 2,
+    /// Values are replaced with a constant placeholder.
+    Redact = 3,
+}
+impl ColumnMask {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "COLUMN_MASK_UNSPECIFIED",
+            Self::NullOut => "NULL_OUT",
+            Self::Hash => "HASH",
+            Self::Redact => "REDACT",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "COLUMN_MASK_UNSPECIFIED" => Some(Self::Unspecified),
+            "NULL_OUT" => Some(Self::NullOut),
+            "HASH" => Some(Self::Hash),
+            "REDACT" => Some(Self::Redact),
+            _ => None,
+        }
+    }
+}
 /// Request to list shares.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -217,6 +282,12 @@ pub struct UpdateShareRequest {
     /// User-provided free-form text description.
     #[prost(string, optional, tag="5")]
     pub comment: ::core::option::Option<::prost::alloc::string::String>,
+    /// Revision of the share the update is based on.
+    ///
+    /// If set, the update fails with a conflict if the share was modified since.
+    /// May also be provided via the If-Match header.
+    #[prost(string, optional, tag="6")]
+    pub etag: ::core::option::Option<::prost::alloc::string::String>,
 }
 /// Delete a share.
 ///
@@ -76,6 +76,83 @@ impl<'de> serde::Deserialize<'de> for Action {
         deserializer.deserialize_any(GeneratedVisitor)
     }
 }
+impl serde::Serialize for ColumnMask {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => "COLUMN_MASK_UNSPECIFIED",
+            Self::NullOut => "NULL_OUT",
+            Self::Hash => "HASH",
+            Self::Redact => "REDACT",
+        };
+        serializer.serialize_str(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for ColumnMask {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "COLUMN_MASK_UNSPECIFIED",
+            "NULL_OUT",
+            "HASH",
+            "REDACT",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl serde::de::Visitor<'_> for GeneratedVisitor {
+            type Value = ColumnMask;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "COLUMN_MASK_UNSPECIFIED" => Ok(ColumnMask::Unspecified),
+                    "NULL_OUT" => Ok(ColumnMask::NullOut),
+                    "HASH" => Ok(ColumnMask::Hash),
+                    "REDACT" => Ok(ColumnMask::Redact),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
 impl serde::Serialize for CreateShareRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -226,6 +303,12 @@ impl serde::Serialize for DataObject {
         if self.start_version.is_some() {
             len += 1;
         }
+        if self.row_filter.is_some() {
+            len += 1;
+        }
+        if !self.columns.is_empty() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("delta_sharing.shares.v1.DataObject", len)?;
         if !self.name.is_empty() {
             struct_ser.serialize_field("name", &self.name)?;
@@ -265,6 +348,12 @@ impl serde::Serialize for DataObject {
             #[allow(clippy::needless_borrows_for_generic_args)]
             struct_ser.serialize_field("startVersion", ToString::to_string(&v).as_str())?;
         }
+        if let Some(v) = self.row_filter.as_ref() {
+            struct_ser.serialize_field("rowFilter", v)?;
+        }
+        if !self.columns.is_empty() {
+            struct_ser.serialize_field("columns", &self.columns)?;
+        }
         struct_ser.end()
     }
 }
@@ -292,6 +381,9 @@ impl<'de> serde::Deserialize<'de> for DataObject {
             "historyDataSharingStatus",
             "start_version",
             "startVersion",
+            "row_filter",
+            "rowFilter",
+            "columns",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -306,6 +398,8 @@ impl<'de> serde::Deserialize<'de> for DataObject {
             EnableCdf,
             HistoryDataSharingStatus,
             StartVersion,
+            RowFilter,
+            Columns,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -338,6 +432,8 @@ impl<'de> serde::Deserialize<'de> for DataObject {
                             "enableCdf" | "enable_cdf" => Ok(GeneratedField::EnableCdf),
                             "historyDataSharingStatus" | "history_data_sharing_status" => Ok(GeneratedField::HistoryDataSharingStatus),
                             "startVersion" | "start_version" => Ok(GeneratedField::StartVersion),
+                            "rowFilter" | "row_filter" => Ok(GeneratedField::RowFilter),
+                            "columns" => Ok(GeneratedField::Columns),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -367,6 +463,8 @@ impl<'de> serde::Deserialize<'de> for DataObject {
                 let mut enable_cdf__ = None;
                 let mut history_data_sharing_status__ = None;
                 let mut start_version__ = None;
+                let mut row_filter__ = None;
+                let mut columns__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Name => {
@@ -433,6 +531,18 @@ impl<'de> serde::Deserialize<'de> for DataObject {
                                 map_.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| x.0)
                             ;
                         }
+                        GeneratedField::RowFilter => {
+                            if row_filter__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rowFilter"));
+                            }
+                            row_filter__ = map_.next_value()?;
+                        }
+                        GeneratedField::Columns => {
+                            if columns__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("columns"));
+                            }
+                            columns__ = Some(map_.next_value()?);
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -449,6 +559,8 @@ impl<'de> serde::Deserialize<'de> for DataObject {
                     enable_cdf: enable_cdf__,
                     history_data_sharing_status: history_data_sharing_status__,
                     start_version: start_version__,
+                    row_filter: row_filter__,
+                    columns: columns__.unwrap_or_default(),
                 })
             }
         }
@@ -1187,6 +1299,9 @@ impl serde::Serialize for ShareInfo {
         if self.updated_by.is_some() {
             len += 1;
         }
+        if self.etag.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("delta_sharing.shares.v1.ShareInfo", len)?;
         if let Some(v) = self.id.as_ref() {
             struct_ser.serialize_field("id", v)?;
@@ -1219,6 +1334,9 @@ impl serde::Serialize for ShareInfo {
         if let Some(v) = self.updated_by.as_ref() {
             struct_ser.serialize_field("updatedBy", v)?;
         }
+        if let Some(v) = self.etag.as_ref() {
+            struct_ser.serialize_field("etag", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -1243,6 +1361,7 @@ impl<'de> serde::Deserialize<'de> for ShareInfo {
             "updatedAt",
             "updated_by",
             "updatedBy",
+            "etag",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -1256,6 +1375,7 @@ impl<'de> serde::Deserialize<'de> for ShareInfo {
             CreatedBy,
             UpdatedAt,
             UpdatedBy,
+            Etag,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1287,6 +1407,7 @@ impl<'de> serde::Deserialize<'de> for ShareInfo {
                             "createdBy" | "created_by" => Ok(GeneratedField::CreatedBy),
                             "updatedAt" | "updated_at" => Ok(GeneratedField::UpdatedAt),
                             "updatedBy" | "updated_by" => Ok(GeneratedField::UpdatedBy),
+                            "etag" => Ok(GeneratedField::Etag),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1315,6 +1436,7 @@ impl<'de> serde::Deserialize<'de> for ShareInfo {
                 let mut created_by__ = None;
                 let mut updated_at__ = None;
                 let mut updated_by__ = None;
+                let mut etag__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Id => {
@@ -1375,6 +1497,12 @@ impl<'de> serde::Deserialize<'de> for ShareInfo {
                             }
                             updated_by__ = map_.next_value()?;
                         }
+                        GeneratedField::Etag => {
+                            if etag__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("etag"));
+                            }
+                            etag__ = map_.next_value()?;
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -1390,12 +1518,127 @@ impl<'de> serde::Deserialize<'de> for ShareInfo {
                     created_by: created_by__,
                     updated_at: updated_at__,
                     updated_by: updated_by__,
+                    etag: etag__,
                 })
             }
         }
         deserializer.deserialize_struct("delta_sharing.shares.v1.ShareInfo", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for SharedColumn {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.name.is_empty() {
+            len += 1;
+        }
+        if self.mask != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("delta_sharing.shares.v1.SharedColumn", len)?;
+        if !self.name.is_empty() {
+            struct_ser.serialize_field("name", &self.name)?;
+        }
+        if self.mask != 0 {
+            let v = ColumnMask::try_from(self.mask)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.mask)))?;
+            struct_ser.serialize_field("mask", &v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SharedColumn {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "name",
+            "mask",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Name,
+            Mask,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl serde::de::Visitor<'_> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "name" => Ok(GeneratedField::Name),
+                            "mask" => Ok(GeneratedField::Mask),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SharedColumn;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct delta_sharing.shares.v1.SharedColumn")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SharedColumn, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut name__ = None;
+                let mut mask__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Name => {
+                            if name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("name"));
+                            }
+                            name__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Mask => {
+                            if mask__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("mask"));
+                            }
+                            mask__ = Some(map_.next_value::<ColumnMask>()? as i32);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(SharedColumn {
+                    name: name__.unwrap_or_default(),
+                    mask: mask__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("delta_sharing.shares.v1.SharedColumn", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for UpdateShareRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -1419,6 +1662,9 @@ impl serde::Serialize for UpdateShareRequest {
         if self.comment.is_some() {
             len += 1;
         }
+        if self.etag.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("delta_sharing.shares.v1.UpdateShareRequest", len)?;
         if !self.name.is_empty() {
             struct_ser.serialize_field("name", &self.name)?;
@@ -1435,6 +1681,9 @@ impl serde::Serialize for UpdateShareRequest {
         if let Some(v) = self.comment.as_ref() {
             struct_ser.serialize_field("comment", v)?;
         }
+        if let Some(v) = self.etag.as_ref() {
+            struct_ser.serialize_field("etag", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -1451,6 +1700,7 @@ impl<'de> serde::Deserialize<'de> for UpdateShareRequest {
             "newName",
             "owner",
             "comment",
+            "etag",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -1460,6 +1710,7 @@ impl<'de> serde::Deserialize<'de> for UpdateShareRequest {
             NewName,
             Owner,
             Comment,
+            Etag,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1487,6 +1738,7 @@ impl<'de> serde::Deserialize<'de> for UpdateShareRequest {
                             "newName" | "new_name" => Ok(GeneratedField::NewName),
                             "owner" => Ok(GeneratedField::Owner),
                             "comment" => Ok(GeneratedField::Comment),
+                            "etag" => Ok(GeneratedField::Etag),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1511,6 +1763,7 @@ impl<'de> serde::Deserialize<'de> for UpdateShareRequest {
                 let mut new_name__ = None;
                 let mut owner__ = None;
                 let mut comment__ = None;
+                let mut etag__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Name => {
@@ -1543,6 +1796,12 @@ impl<'de> serde::Deserialize<'de> for UpdateShareRequest {
                             }
                             comment__ = map_.next_value()?;
                         }
+                        GeneratedField::Etag => {
+                            if etag__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("etag"));
+                            }
+                            etag__ = map_.next_value()?;
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -1554,6 +1813,7 @@ impl<'de> serde::Deserialize<'de> for UpdateShareRequest {
                     new_name: new_name__,
                     owner: owner__,
                     comment: comment__,
+                    etag: etag__,
                 })
             }
         }
@@ -154,6 +154,48 @@ pub struct GetTableMetadataRequest {
     #[prost(string, tag="3")]
     pub schema: ::prost::alloc::string::String,
 }
+/// Query the data files of a table.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryTableRequest {
+    /// The table name to query. It's case-insensitive.
+    #[prost(string, tag="1")]
+    pub name: ::prost::alloc::string::String,
+    /// The share name to query. It's case-insensitive.
+    #[prost(string, tag="2")]
+    pub share: ::prost::alloc::string::String,
+    /// The schema name to query. It's case-insensitive.
+    #[prost(string, tag="3")]
+    pub schema: ::prost::alloc::string::String,
+    /// A JSON encoded predicate used to skip files that cannot contain matching rows.
+    ///
+    /// The predicate is a hint, rows of returned files are not filtered by it.
+    #[prost(string, optional, tag="4")]
+    pub json_predicate_hints: ::core::option::Option<::prost::alloc::string::String>,
+    /// A hint for the number of rows the client intends to read.
+    #[prost(int64, optional, tag="5")]
+    pub limit_hint: ::core::option::Option<i64>,
+}
+/// Read a data file of a table through the server.
+///
+/// Files that may contain data hidden from the recipient are served by the server
+/// with that data removed, the data is returned as parquet.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetTableFileRequest {
+    /// The table name to query. It's case-insensitive.
+    #[prost(string, tag="1")]
+    pub name: ::prost::alloc::string::String,
+    /// The share name to query. It's case-insensitive.
+    #[prost(string, tag="2")]
+    pub share: ::prost::alloc::string::String,
+    /// The schema name to query. It's case-insensitive.
+    #[prost(string, tag="3")]
+    pub schema: ::prost::alloc::string::String,
+    /// The id of the file, as returned by a query against the table.
+    #[prost(string, tag="4")]
+    pub id: ::prost::alloc::string::String,
+}
 /// Response for a query against a table.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -185,7 +227,7 @@ pub struct ParquetResponse {
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ParquetLogMessage {
-    #[prost(oneof="parquet_log_message::Entry", tags="1, 2")]
+    #[prost(oneof="parquet_log_message::Entry", tags="1, 2, 3")]
     pub entry: ::core::option::Option<parquet_log_message::Entry>,
 }
 /// Nested message and enum types in `ParquetLogMessage`.
@@ -197,6 +239,8 @@ pub mod parquet_log_message {
         Protocol(super::ProtocolParquet),
         #[prost(message, tag="2")]
         Metadata(super::MetadataParquet),
+        #[prost(message, tag="3")]
+        File(super::FileParquet),
     }
 }
 /// Protocol for Parquet response.
@@ -230,6 +274,32 @@ pub struct MetadataParquet {
     /// An array containing the names of columns by which the data should be partitioned
     #[prost(string, repeated, tag="6")]
     pub partition_columns: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// A map containing configuration options for the table
+    #[prost(map="string, string", tag="7")]
+    pub configuration: ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
+}
+/// Data file for Parquet response.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FileParquet {
+    /// A URL the file can be read from.
+    #[prost(string, tag="1")]
+    pub url: ::prost::alloc::string::String,
+    /// Unique identifier for the file within the table.
+    #[prost(string, tag="2")]
+    pub id: ::prost::alloc::string::String,
+    /// The partition values of the file.
+    #[prost(map="string, string", tag="3")]
+    pub partition_values: ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
+    /// The size of the file in bytes.
+    #[prost(int64, tag="4")]
+    pub size: i64,
+    /// Statistics of the file, e.g. the number of records.
+    #[prost(string, optional, tag="5")]
+    pub stats: ::core::option::Option<::prost::alloc::string::String>,
+    /// Time at which the URL expires, in milliseconds since the epoch.
+    #[prost(int64, optional, tag="6")]
+    pub expiration_timestamp: ::core::option::Option<i64>,
 }
 /// Response for a query against a table in Delta format.
 #[allow(clippy::derive_partial_eq_without_eq)]
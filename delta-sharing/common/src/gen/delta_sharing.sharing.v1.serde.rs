@@ -207,6 +207,198 @@ impl<'de> serde::Deserialize<'de> for DeltaResponse {
         deserializer.deserialize_struct("delta_sharing.sharing.v1.DeltaResponse", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for FileParquet {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.url.is_empty() {
+            len += 1;
+        }
+        if !self.id.is_empty() {
+            len += 1;
+        }
+        if !self.partition_values.is_empty() {
+            len += 1;
+        }
+        if self.size != 0 {
+            len += 1;
+        }
+        if self.stats.is_some() {
+            len += 1;
+        }
+        if self.expiration_timestamp.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("delta_sharing.sharing.v1.FileParquet", len)?;
+        if !self.url.is_empty() {
+            struct_ser.serialize_field("url", &self.url)?;
+        }
+        if !self.id.is_empty() {
+            struct_ser.serialize_field("id", &self.id)?;
+        }
+        if !self.partition_values.is_empty() {
+            struct_ser.serialize_field("partitionValues", &self.partition_values)?;
+        }
+        if self.size != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("size", ToString::to_string(&self.size).as_str())?;
+        }
+        if let Some(v) = self.stats.as_ref() {
+            struct_ser.serialize_field("stats", v)?;
+        }
+        if let Some(v) = self.expiration_timestamp.as_ref() {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("expirationTimestamp", ToString::to_string(&v).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for FileParquet {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "url",
+            "id",
+            "partition_values",
+            "partitionValues",
+            "size",
+            "stats",
+            "expiration_timestamp",
+            "expirationTimestamp",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Url,
+            Id,
+            PartitionValues,
+            Size,
+            Stats,
+            ExpirationTimestamp,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl serde::de::Visitor<'_> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "url" => Ok(GeneratedField::Url),
+                            "id" => Ok(GeneratedField::Id),
+                            "partitionValues" | "partition_values" => Ok(GeneratedField::PartitionValues),
+                            "size" => Ok(GeneratedField::Size),
+                            "stats" => Ok(GeneratedField::Stats),
+                            "expirationTimestamp" | "expiration_timestamp" => Ok(GeneratedField::ExpirationTimestamp),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = FileParquet;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct delta_sharing.sharing.v1.FileParquet")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<FileParquet, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut url__ = None;
+                let mut id__ = None;
+                let mut partition_values__ = None;
+                let mut size__ = None;
+                let mut stats__ = None;
+                let mut expiration_timestamp__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Url => {
+                            if url__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("url"));
+                            }
+                            url__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Id => {
+                            if id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("id"));
+                            }
+                            id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::PartitionValues => {
+                            if partition_values__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("partitionValues"));
+                            }
+                            partition_values__ = Some(
+                                map_.next_value::<std::collections::HashMap<_, _>>()?
+                            );
+                        }
+                        GeneratedField::Size => {
+                            if size__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("size"));
+                            }
+                            size__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Stats => {
+                            if stats__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("stats"));
+                            }
+                            stats__ = map_.next_value()?;
+                        }
+                        GeneratedField::ExpirationTimestamp => {
+                            if expiration_timestamp__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("expirationTimestamp"));
+                            }
+                            expiration_timestamp__ = 
+                                map_.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| x.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(FileParquet {
+                    url: url__.unwrap_or_default(),
+                    id: id__.unwrap_or_default(),
+                    partition_values: partition_values__.unwrap_or_default(),
+                    size: size__.unwrap_or_default(),
+                    stats: stats__,
+                    expiration_timestamp: expiration_timestamp__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("delta_sharing.sharing.v1.FileParquet", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for Format {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -416,6 +608,152 @@ impl<'de> serde::Deserialize<'de> for GetShareRequest {
         deserializer.deserialize_struct("delta_sharing.sharing.v1.GetShareRequest", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for GetTableFileRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.name.is_empty() {
+            len += 1;
+        }
+        if !self.share.is_empty() {
+            len += 1;
+        }
+        if !self.schema.is_empty() {
+            len += 1;
+        }
+        if !self.id.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("delta_sharing.sharing.v1.GetTableFileRequest", len)?;
+        if !self.name.is_empty() {
+            struct_ser.serialize_field("name", &self.name)?;
+        }
+        if !self.share.is_empty() {
+            struct_ser.serialize_field("share", &self.share)?;
+        }
+        if !self.schema.is_empty() {
+            struct_ser.serialize_field("schema", &self.schema)?;
+        }
+        if !self.id.is_empty() {
+            struct_ser.serialize_field("id", &self.id)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for GetTableFileRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "name",
+            "share",
+            "schema",
+            "id",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Name,
+            Share,
+            Schema,
+            Id,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl serde::de::Visitor<'_> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "name" => Ok(GeneratedField::Name),
+                            "share" => Ok(GeneratedField::Share),
+                            "schema" => Ok(GeneratedField::Schema),
+                            "id" => Ok(GeneratedField::Id),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = GetTableFileRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct delta_sharing.sharing.v1.GetTableFileRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GetTableFileRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut name__ = None;
+                let mut share__ = None;
+                let mut schema__ = None;
+                let mut id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Name => {
+                            if name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("name"));
+                            }
+                            name__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Share => {
+                            if share__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("share"));
+                            }
+                            share__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Schema => {
+                            if schema__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("schema"));
+                            }
+                            schema__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Id => {
+                            if id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("id"));
+                            }
+                            id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(GetTableFileRequest {
+                    name: name__.unwrap_or_default(),
+                    share: share__.unwrap_or_default(),
+                    schema: schema__.unwrap_or_default(),
+                    id: id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("delta_sharing.sharing.v1.GetTableFileRequest", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for GetTableMetadataRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -2348,6 +2686,9 @@ impl serde::Serialize for MetadataParquet {
         if !self.partition_columns.is_empty() {
             len += 1;
         }
+        if !self.configuration.is_empty() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("delta_sharing.sharing.v1.MetadataParquet", len)?;
         if !self.id.is_empty() {
             struct_ser.serialize_field("id", &self.id)?;
@@ -2367,6 +2708,9 @@ impl serde::Serialize for MetadataParquet {
         if !self.partition_columns.is_empty() {
             struct_ser.serialize_field("partitionColumns", &self.partition_columns)?;
         }
+        if !self.configuration.is_empty() {
+            struct_ser.serialize_field("configuration", &self.configuration)?;
+        }
         struct_ser.end()
     }
 }
@@ -2385,6 +2729,7 @@ impl<'de> serde::Deserialize<'de> for MetadataParquet {
             "schemaString",
             "partition_columns",
             "partitionColumns",
+            "configuration",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -2395,6 +2740,7 @@ impl<'de> serde::Deserialize<'de> for MetadataParquet {
             Format,
             SchemaString,
             PartitionColumns,
+            Configuration,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2423,6 +2769,7 @@ impl<'de> serde::Deserialize<'de> for MetadataParquet {
                             "format" => Ok(GeneratedField::Format),
                             "schemaString" | "schema_string" => Ok(GeneratedField::SchemaString),
                             "partitionColumns" | "partition_columns" => Ok(GeneratedField::PartitionColumns),
+                            "configuration" => Ok(GeneratedField::Configuration),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -2448,6 +2795,7 @@ impl<'de> serde::Deserialize<'de> for MetadataParquet {
                 let mut format__ = None;
                 let mut schema_string__ = None;
                 let mut partition_columns__ = None;
+                let mut configuration__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Id => {
@@ -2486,6 +2834,14 @@ impl<'de> serde::Deserialize<'de> for MetadataParquet {
                             }
                             partition_columns__ = Some(map_.next_value()?);
                         }
+                        GeneratedField::Configuration => {
+                            if configuration__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("configuration"));
+                            }
+                            configuration__ = Some(
+                                map_.next_value::<std::collections::HashMap<_, _>>()?
+                            );
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -2498,6 +2854,7 @@ impl<'de> serde::Deserialize<'de> for MetadataParquet {
                     format: format__,
                     schema_string: schema_string__.unwrap_or_default(),
                     partition_columns: partition_columns__.unwrap_or_default(),
+                    configuration: configuration__.unwrap_or_default(),
                 })
             }
         }
@@ -2524,6 +2881,9 @@ impl serde::Serialize for ParquetLogMessage {
                 parquet_log_message::Entry::Metadata(v) => {
                     struct_ser.serialize_field("metadata", v)?;
                 }
+                parquet_log_message::Entry::File(v) => {
+                    struct_ser.serialize_field("file", v)?;
+                }
             }
         }
         struct_ser.end()
@@ -2538,12 +2898,14 @@ impl<'de> serde::Deserialize<'de> for ParquetLogMessage {
         const FIELDS: &[&str] = &[
             "protocol",
             "metadata",
+            "file",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             Protocol,
             Metadata,
+            File,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2568,6 +2930,7 @@ impl<'de> serde::Deserialize<'de> for ParquetLogMessage {
                         match value {
                             "protocol" => Ok(GeneratedField::Protocol),
                             "metadata" => Ok(GeneratedField::Metadata),
+                            "file" => Ok(GeneratedField::File),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -2602,6 +2965,13 @@ impl<'de> serde::Deserialize<'de> for ParquetLogMessage {
                                 return Err(serde::de::Error::duplicate_field("metadata"));
                             }
                             entry__ = map_.next_value::<::std::option::Option<_>>()?.map(parquet_log_message::Entry::Metadata)
+;
+                        }
+                        GeneratedField::File => {
+                            if entry__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("file"));
+                            }
+                            entry__ = map_.next_value::<::std::option::Option<_>>()?.map(parquet_log_message::Entry::File)
 ;
                         }
                         GeneratedField::__SkipField__ => {
@@ -3041,6 +3411,175 @@ impl<'de> serde::Deserialize<'de> for QueryResponse {
         deserializer.deserialize_struct("delta_sharing.sharing.v1.QueryResponse", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for QueryTableRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.name.is_empty() {
+            len += 1;
+        }
+        if !self.share.is_empty() {
+            len += 1;
+        }
+        if !self.schema.is_empty() {
+            len += 1;
+        }
+        if self.json_predicate_hints.is_some() {
+            len += 1;
+        }
+        if self.limit_hint.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("delta_sharing.sharing.v1.QueryTableRequest", len)?;
+        if !self.name.is_empty() {
+            struct_ser.serialize_field("name", &self.name)?;
+        }
+        if !self.share.is_empty() {
+            struct_ser.serialize_field("share", &self.share)?;
+        }
+        if !self.schema.is_empty() {
+            struct_ser.serialize_field("schema", &self.schema)?;
+        }
+        if let Some(v) = self.json_predicate_hints.as_ref() {
+            struct_ser.serialize_field("jsonPredicateHints", v)?;
+        }
+        if let Some(v) = self.limit_hint.as_ref() {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("limitHint", ToString::to_string(&v).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for QueryTableRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "name",
+            "share",
+            "schema",
+            "json_predicate_hints",
+            "jsonPredicateHints",
+            "limit_hint",
+            "limitHint",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Name,
+            Share,
+            Schema,
+            JsonPredicateHints,
+            LimitHint,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl serde::de::Visitor<'_> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "name" => Ok(GeneratedField::Name),
+                            "share" => Ok(GeneratedField::Share),
+                            "schema" => Ok(GeneratedField::Schema),
+                            "jsonPredicateHints" | "json_predicate_hints" => Ok(GeneratedField::JsonPredicateHints),
+                            "limitHint" | "limit_hint" => Ok(GeneratedField::LimitHint),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = QueryTableRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct delta_sharing.sharing.v1.QueryTableRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<QueryTableRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut name__ = None;
+                let mut share__ = None;
+                let mut schema__ = None;
+                let mut json_predicate_hints__ = None;
+                let mut limit_hint__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Name => {
+                            if name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("name"));
+                            }
+                            name__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Share => {
+                            if share__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("share"));
+                            }
+                            share__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Schema => {
+                            if schema__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("schema"));
+                            }
+                            schema__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::JsonPredicateHints => {
+                            if json_predicate_hints__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("jsonPredicateHints"));
+                            }
+                            json_predicate_hints__ = map_.next_value()?;
+                        }
+                        GeneratedField::LimitHint => {
+                            if limit_hint__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("limitHint"));
+                            }
+                            limit_hint__ = 
+                                map_.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| x.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(QueryTableRequest {
+                    name: name__.unwrap_or_default(),
+                    share: share__.unwrap_or_default(),
+                    schema: schema__.unwrap_or_default(),
+                    json_predicate_hints: json_predicate_hints__,
+                    limit_hint: limit_hint__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("delta_sharing.sharing.v1.QueryTableRequest", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for Share {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
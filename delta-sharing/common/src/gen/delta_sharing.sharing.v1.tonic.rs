@@ -62,6 +62,12 @@ pub mod delta_sharing_service_server {
             &self,
             request: tonic::Request<super::GetTableMetadataRequest>,
         ) -> std::result::Result<tonic::Response<super::QueryResponse>, tonic::Status>;
+        /** Query the data files of a table.
+*/
+        async fn query_table(
+            &self,
+            request: tonic::Request<super::QueryTableRequest>,
+        ) -> std::result::Result<tonic::Response<super::QueryResponse>, tonic::Status>;
     }
     /** Service exposing the official APIs for Delta Sharing.
 */
@@ -477,6 +483,55 @@ pub mod delta_sharing_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/delta_sharing.sharing.v1.DeltaSharingService/QueryTable" => {
+                    #[allow(non_camel_case_types)]
+                    struct QueryTableSvc<T: DeltaSharingService>(pub Arc<T>);
+                    impl<
+                        T: DeltaSharingService,
+                    > tonic::server::UnaryService<super::QueryTableRequest>
+                    for QueryTableSvc<T> {
+                        type Response = super::QueryResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryTableRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as DeltaSharingService>::query_table(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = QueryTableSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         Ok(
@@ -0,0 +1,144 @@
+// This file is @generated by prost-build.
+/// Temporary AWS credentials of an assumed role session.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AwsTemporaryCredentials {
+    /// The access key ID that identifies the temporary credentials.
+    #[prost(string, tag = "1")]
+    pub access_key_id: ::prost::alloc::string::String,
+    /// The secret access key that can be used to sign AWS API requests.
+    #[prost(string, tag = "2")]
+    pub secret_access_key: ::prost::alloc::string::String,
+    /// The token that users must pass to AWS API to use the temporary credentials.
+    #[prost(string, tag = "3")]
+    pub session_token: ::prost::alloc::string::String,
+}
+/// A user delegation SAS token for Azure storage.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AzureUserDelegationSas {
+    /// The signed URI query string of the shared access signature.
+    #[prost(string, tag = "1")]
+    pub sas_token: ::prost::alloc::string::String,
+}
+/// A downscoped OAuth token for Google Cloud Storage.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GcpOauthToken {
+    /// The OAuth token used to access Google Cloud Storage.
+    #[prost(string, tag = "1")]
+    pub oauth_token: ::prost::alloc::string::String,
+}
+/// Short-lived credentials scoped to a storage location.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TemporaryCredential {
+    /// Server time when the credential will expire, in epoch milliseconds.
+    #[prost(int64, tag = "1")]
+    pub expiration_time: i64,
+    /// The URL of the storage location the credential is scoped to.
+    #[prost(string, tag = "2")]
+    pub url: ::prost::alloc::string::String,
+    /// The cloud specific credential.
+    #[prost(oneof = "temporary_credential::Credentials", tags = "100, 101, 102")]
+    pub credentials: ::core::option::Option<temporary_credential::Credentials>,
+}
+/// Nested message and enum types in `TemporaryCredential`.
+pub mod temporary_credential {
+    /// The cloud specific credential.
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Credentials {
+        #[prost(message, tag = "100")]
+        AwsTempCredentials(super::AwsTemporaryCredentials),
+        #[prost(message, tag = "101")]
+        AzureUserDelegationSas(super::AzureUserDelegationSas),
+        #[prost(message, tag = "102")]
+        GcpOauthToken(super::GcpOauthToken),
+    }
+}
+/// Generate temporary credentials for the storage location of a table.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GenerateTemporaryTableCredentialsRequest {
+    /// UUID of the table to read or write.
+    #[prost(string, tag = "1")]
+    pub table_id: ::prost::alloc::string::String,
+    /// The operation performed against the table data.
+    #[prost(enumeration = "TableOperation", tag = "2")]
+    pub operation: i32,
+}
+/// Generate temporary credentials for a path within an external location.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GenerateTemporaryPathCredentialsRequest {
+    /// URL for path-based access.
+    #[prost(string, tag = "1")]
+    pub url: ::prost::alloc::string::String,
+    /// The operation performed against the path.
+    #[prost(enumeration = "PathOperation", tag = "2")]
+    pub operation: i32,
+    /// Only check permissions and the location, without issuing credentials.
+    #[prost(bool, optional, tag = "3")]
+    pub dry_run: ::core::option::Option<bool>,
+}
+/// The operation a temporary table credential is requested for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum TableOperation {
+    /// Unknown table operation.
+    Unspecified = 0,
+    Read = 1,
+    ReadWrite = 2,
+}
+impl TableOperation {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "TABLE_OPERATION_UNSPECIFIED",
+            Self::Read => "READ",
+            Self::ReadWrite => "READ_WRITE",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "TABLE_OPERATION_UNSPECIFIED" => Some(Self::Unspecified),
+            "READ" => Some(Self::Read),
+            "READ_WRITE" => Some(Self::ReadWrite),
+            _ => None,
+        }
+    }
+}
+/// The operation a temporary path credential is requested for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum PathOperation {
+    /// Unknown path operation.
+    Unspecified = 0,
+    PathRead = 1,
+    PathReadWrite = 2,
+    PathCreateTable = 3,
+}
+impl PathOperation {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "PATH_OPERATION_UNSPECIFIED",
+            Self::PathRead => "PATH_READ",
+            Self::PathReadWrite => "PATH_READ_WRITE",
+            Self::PathCreateTable => "PATH_CREATE_TABLE",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "PATH_OPERATION_UNSPECIFIED" => Some(Self::Unspecified),
+            "PATH_READ" => Some(Self::PathRead),
+            "PATH_READ_WRITE" => Some(Self::PathReadWrite),
+            "PATH_CREATE_TABLE" => Some(Self::PathCreateTable),
+            _ => None,
+        }
+    }
+}
+include!("delta_sharing.temporary_credentials.v1.serde.rs");
+// @@protoc_insertion_point(module)
\ No newline at end of file
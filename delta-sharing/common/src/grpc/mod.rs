@@ -80,4 +80,13 @@ where
         let result = T::get_table_metadata(self, request.into_inner(), ctx).await?;
         Ok(Response::new(result))
     }
+
+    async fn query_table(
+        &self,
+        request: Request<QueryTableRequest>,
+    ) -> Result<Response<QueryResponse>, Status> {
+        let ctx = extract_context(&request)?;
+        let result = T::query_table(self, request.into_inner(), ctx).await?;
+        Ok(Response::new(result))
+    }
 }
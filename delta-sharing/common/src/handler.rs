@@ -1,5 +1,7 @@
 use std::sync::Arc;
 
+use bytes::Bytes;
+
 use crate::policy::Policy;
 use crate::resources::ResourceStore;
 use crate::{
@@ -78,4 +80,22 @@ impl SharingQueryHandler for ServerHandler {
         self.check_required(&request, context.recipient()).await?;
         self.query.get_table_metadata(request, context).await
     }
+
+    async fn query_table(
+        &self,
+        request: QueryTableRequest,
+        context: RequestContext,
+    ) -> Result<QueryResponse> {
+        self.check_required(&request, context.recipient()).await?;
+        self.query.query_table(request, context).await
+    }
+
+    async fn get_table_file(
+        &self,
+        request: GetTableFileRequest,
+        context: RequestContext,
+    ) -> Result<Bytes> {
+        self.check_required(&request, context.recipient()).await?;
+        self.query.get_table_file(request, context).await
+    }
 }
@@ -3,8 +3,8 @@ use delta_kernel::actions::{Metadata, Protocol};
 use crate::error::Result;
 use crate::models::sharing::v1::{
     parquet_log_message::Entry as ParquetEntry, query_response::Response as QueryResponseType,
-    Format as FormatMessage, MetadataParquet, ParquetLogMessage, ParquetResponse, ProtocolParquet,
-    QueryResponse,
+    FileParquet, Format as FormatMessage, MetadataParquet, ParquetLogMessage, ParquetResponse,
+    ProtocolParquet, QueryResponse,
 };
 
 impl From<&Metadata> for MetadataParquet {
@@ -19,6 +19,7 @@ impl From<&Metadata> for MetadataParquet {
             }),
             schema_string: value.schema_string.clone(),
             partition_columns: value.partition_columns.clone(),
+            configuration: value.configuration.clone(),
         }
     }
 }
@@ -63,6 +64,14 @@ impl From<MetadataParquet> for ParquetLogMessage {
     }
 }
 
+impl From<FileParquet> for ParquetLogMessage {
+    fn from(value: FileParquet) -> Self {
+        ParquetLogMessage {
+            entry: Some(ParquetEntry::File(value)),
+        }
+    }
+}
+
 impl<T: IntoIterator<Item = ParquetLogMessage>> From<T> for QueryResponse {
     fn from(value: T) -> Self {
         QueryResponse {
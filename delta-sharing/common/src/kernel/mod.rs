@@ -1,43 +1,61 @@
-use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Duration;
 
+use bytes::Bytes;
 use delta_kernel::engine::default::executor::tokio::{
     TokioBackgroundExecutor, TokioMultiThreadExecutor,
 };
 use delta_kernel::engine::default::{executor::TaskExecutor, DefaultEngine};
+use delta_kernel::expressions::Expression;
 use delta_kernel::snapshot::Snapshot;
 use delta_kernel::{Engine, Table};
+use ring::hmac;
+use ring::rand::SystemRandom;
+use url::Url;
 
+use crate::api::external_locations::ExternalLocationResolver;
 use crate::api::sharing::SharingQueryHandler;
 use crate::api::{RequestContext, SecuredAction};
+use crate::audit::AuditLog;
 use crate::models::sharing::v1::{
-    GetTableMetadataRequest, GetTableVersionRequest, GetTableVersionResponse, QueryResponse,
+    FileParquet, GetTableFileRequest, GetTableMetadataRequest, GetTableVersionRequest,
+    GetTableVersionResponse, JsonPredicate, ParquetLogMessage, QueryResponse, QueryTableRequest,
 };
-use crate::{Policy, ResourceRef, Result, TableLocationResolver};
+use crate::{Error, Policy, ResourceRef, Result, TableLocationResolver};
 
+pub use partitions::PartitionSpec;
 pub use predicate::json_predicate_to_expression;
+pub use restrictions::{TableRestrictionResolver, TableRestrictions, PARTITIONS_CONFIGURATION_KEY};
+pub use scan::{FileAccess, RowFilterMode, SharedFile};
+pub use signer::{FileSigner, StorageFileSigner};
 
 mod conversion;
+mod partitions;
 mod predicate;
+mod restrictions;
+mod scan;
+mod signer;
+
+/// Time for which urls of files returned by a query are valid.
+const FILE_URL_EXPIRATION: Duration = Duration::from_secs(3600);
 
 #[async_trait::async_trait]
 pub trait KernelEngineFactroy: Send + Sync {
     async fn create(&self, table: &Table) -> Result<Arc<dyn Engine>>;
 }
 
+/// Creates engines that access tables with the credential of the external location
+/// containing them.
 pub struct DefaultKernelEngineFactroy<E: TaskExecutor> {
     task_executor: Arc<E>,
-    storage_configs: HashMap<(String, String), HashMap<String, String>>,
+    locations: Arc<dyn ExternalLocationResolver>,
 }
 
 impl<E: TaskExecutor> DefaultKernelEngineFactroy<E> {
-    pub fn new(
-        task_executor: Arc<E>,
-        storage_configs: HashMap<(String, String), HashMap<String, String>>,
-    ) -> Self {
+    pub fn new(task_executor: Arc<E>, locations: Arc<dyn ExternalLocationResolver>) -> Self {
         Self {
             task_executor,
-            storage_configs,
+            locations,
         }
     }
 }
@@ -46,23 +64,31 @@ impl<E: TaskExecutor> DefaultKernelEngineFactroy<E> {
 impl<E: TaskExecutor> KernelEngineFactroy for DefaultKernelEngineFactroy<E> {
     async fn create(&self, table: &Table) -> Result<Arc<dyn delta_kernel::Engine>> {
         let storage_config = self
-            .storage_configs
-            .get(&(
-                table.location().scheme().to_string(),
-                table.location().host_str().unwrap_or_default().to_string(),
-            ))
-            .cloned()
-            .unwrap_or_default();
+            .locations
+            .resolve_storage_options(table.location().as_str())
+            .await?;
         let engine =
             DefaultEngine::try_new(table.location(), storage_config, self.task_executor.clone())?;
         Ok(Arc::new(engine))
     }
 }
 
+/// Serves queries against shared tables with delta kernel.
+///
+/// Queries return the files of a table visible to the recipient. Files without hidden data
+/// are returned with a url signed by the [`FileSigner`], all other files with a url on the
+/// data proxy configured with [`with_data_proxy`](Self::with_data_proxy), which serves them
+/// through [`read_file`](Self::read_file).
 pub struct KernelQueryHandler {
     engine_factory: Arc<dyn KernelEngineFactroy>,
     location_resolver: Arc<dyn TableLocationResolver>,
     policy: Arc<dyn Policy>,
+    restrictions: Option<Arc<dyn TableRestrictionResolver>>,
+    row_filter_mode: RowFilterMode,
+    audit_log: Option<AuditLog>,
+    signer: Option<Arc<dyn FileSigner>>,
+    data_proxy: Option<Url>,
+    hash_key: hmac::Key,
 }
 
 impl KernelQueryHandler {
@@ -76,35 +102,84 @@ impl KernelQueryHandler {
             engine_factory,
             location_resolver,
             policy,
+            restrictions: None,
+            row_filter_mode: RowFilterMode::default(),
+            audit_log: None,
+            signer: None,
+            data_proxy: None,
+            hash_key: hmac::Key::generate(hmac::HMAC_SHA256, &SystemRandom::new())
+                .expect("system random number generator should be available"),
         }
     }
 
+    /// Enforce the restrictions shares place on their tables.
+    pub fn with_restrictions(mut self, resolver: Arc<dyn TableRestrictionResolver>) -> Self {
+        self.restrictions = Some(resolver);
+        self
+    }
+
+    /// Record the files handed out to recipients in the given [`AuditLog`].
+    pub fn with_audit_log(mut self, log: AuditLog) -> Self {
+        self.audit_log = Some(log);
+        self
+    }
+
+    /// Set how files that may contain rows hidden by a row filter are handled.
+    pub fn with_row_filter_mode(mut self, mode: RowFilterMode) -> Self {
+        self.row_filter_mode = mode;
+        self
+    }
+
+    /// Sign the urls of files recipients read from storage directly.
+    pub fn with_file_signer(mut self, signer: Arc<dyn FileSigner>) -> Self {
+        self.signer = Some(signer);
+        self
+    }
+
+    /// Serve files that may contain hidden data through the sharing server at `endpoint`.
+    ///
+    /// `endpoint` is the url the sharing router is reachable at by recipients.
+    pub fn with_data_proxy(mut self, endpoint: Url) -> Self {
+        self.data_proxy = Some(endpoint);
+        self
+    }
+
+    /// Key the hashes of masked values and the ids of proxied files with `secret`.
+    ///
+    /// Without a secret a random key is used, so hashes change when the server restarts.
+    pub fn with_hash_key(mut self, secret: &[u8]) -> Self {
+        self.hash_key = hmac::Key::new(hmac::HMAC_SHA256, secret);
+        self
+    }
+
     /// Create a new instance of [`KernelQueryHandler`] with a background executor.
     pub fn new_background(
         location_resolver: Arc<dyn TableLocationResolver>,
-        storage_configs: HashMap<(String, String), HashMap<String, String>>,
+        locations: Arc<dyn ExternalLocationResolver>,
         policy: Arc<dyn Policy>,
-    ) -> Arc<Self> {
+    ) -> Self {
         let engine_factory = Arc::new(DefaultKernelEngineFactroy::new(
             Arc::new(TokioBackgroundExecutor::new()),
-            storage_configs,
+            locations.clone(),
         ));
-        Arc::new(Self::new(engine_factory, location_resolver, policy))
+        Self::new(engine_factory, location_resolver, policy)
+            .with_file_signer(Arc::new(StorageFileSigner::new(locations)))
     }
 
     /// Create a new instance of [`KernelQueryHandler`] with a multi-threaded executor.
     pub fn new_multi_thread(
         location_resolver: Arc<dyn TableLocationResolver>,
-        storage_configs: HashMap<(String, String), HashMap<String, String>>,
+        locations: Arc<dyn ExternalLocationResolver>,
         policy: Arc<dyn Policy>,
-    ) -> Arc<Self> {
+    ) -> Self {
         let engine_factory = Arc::new(DefaultKernelEngineFactroy::new(
             Arc::new(TokioMultiThreadExecutor::new(
                 tokio::runtime::Handle::current(),
             )),
-            storage_configs,
+            locations.clone(),
         ));
-        Arc::new(Self::new(engine_factory, location_resolver, policy))
+        Self::new(engine_factory, location_resolver, policy)
+            .with_file_signer(Arc::new(StorageFileSigner::new(locations)))
     }
 
     async fn get_snapshot(&self, table_ref: &ResourceRef) -> Result<Snapshot> {
@@ -114,6 +189,55 @@ impl KernelQueryHandler {
         let snapshot = table.snapshot(engine.as_ref(), None)?;
         Ok(snapshot)
     }
+
+    async fn load_table(
+        &self,
+        table_ref: &ResourceRef,
+    ) -> Result<(Arc<Snapshot>, Arc<dyn Engine>)> {
+        let location = self.location_resolver.resolve(table_ref).await?;
+        let table = Table::new(location);
+        let engine = self.engine_factory.create(&table).await?;
+        let snapshot = table.snapshot(engine.as_ref(), None)?;
+        Ok((Arc::new(snapshot), engine))
+    }
+
+    /// Url of a file on the data proxy.
+    fn proxy_url(&self, request: &QueryTableRequest, id: &str) -> Result<Url> {
+        let mut url = self
+            .data_proxy
+            .clone()
+            .ok_or_else(|| Error::generic("no data proxy configured"))?;
+        url.path_segments_mut()
+            .map_err(|_| Error::generic("invalid data proxy url"))?
+            .pop_if_empty()
+            .extend([
+                "shares",
+                &request.share,
+                "schemas",
+                &request.schema,
+                "tables",
+                &request.name,
+                "files",
+                id,
+            ]);
+        Ok(url)
+    }
+}
+
+/// Parse the predicate hints of a query.
+///
+/// Hints only skip files, so invalid hints are ignored rather than failing the query.
+fn parse_predicate_hints(hints: &str) -> Option<Expression> {
+    let predicate = serde_json::from_str::<JsonPredicate>(hints)
+        .map_err(Error::from)
+        .and_then(|predicate| json_predicate_to_expression(&predicate));
+    match predicate {
+        Ok(predicate) => Some(predicate),
+        Err(err) => {
+            tracing::debug!("ignoring invalid predicate hints: {}", err);
+            None
+        }
+    }
 }
 
 #[async_trait::async_trait]
@@ -144,7 +268,82 @@ impl SharingQueryHandler for KernelQueryHandler {
             .check_required(&request, context.as_ref())
             .await?;
         let res = request.resource();
+        let restrictions = self
+            .resolve_restrictions(res.as_ref(), context.recipient())
+            .await?;
         let snapshot = self.get_snapshot(res.as_ref()).await?;
-        Ok([snapshot.metadata().into(), snapshot.protocol().into()].into())
+        let metadata = restrictions.restrict_metadata(snapshot.metadata(), snapshot.schema())?;
+        Ok([metadata.into(), snapshot.protocol().into()].into())
+    }
+
+    async fn query_table(
+        &self,
+        request: QueryTableRequest,
+        context: RequestContext,
+    ) -> Result<QueryResponse> {
+        self.policy
+            .check_required(&request, context.as_ref())
+            .await?;
+        let res = request.resource();
+        let predicate = request
+            .json_predicate_hints
+            .as_deref()
+            .and_then(parse_predicate_hints);
+        let (snapshot, restrictions, files) = self
+            .scan_table(res.as_ref(), context.recipient(), predicate)
+            .await?;
+        let metadata = restrictions.restrict_metadata(snapshot.metadata(), snapshot.schema())?;
+
+        let expiration_timestamp =
+            chrono::Utc::now().timestamp_millis() + FILE_URL_EXPIRATION.as_millis() as i64;
+        let mut entries: Vec<ParquetLogMessage> = vec![metadata.into(), snapshot.protocol().into()];
+        for file in &files {
+            let (url, expiration_timestamp) = match file.access {
+                FileAccess::Direct => {
+                    let signer = self
+                        .signer
+                        .as_ref()
+                        .ok_or_else(|| Error::generic("no file signer configured"))?;
+                    let location = snapshot.table_root().join(&file.path)?;
+                    let url = signer.sign(&location, FILE_URL_EXPIRATION).await?;
+                    (url, Some(expiration_timestamp))
+                }
+                FileAccess::Proxy => (self.proxy_url(&request, &file.id)?, None),
+            };
+            let file = FileParquet {
+                url: url.to_string(),
+                id: file.id.clone(),
+                partition_values: file.partition_values.clone(),
+                size: file.size,
+                stats: file
+                    .num_records
+                    .map(|n| serde_json::json!({ "numRecords": n }).to_string()),
+                expiration_timestamp,
+            };
+            entries.push(file.into());
+        }
+
+        self.record_access(
+            res.as_ref(),
+            context.recipient(),
+            snapshot.version(),
+            files.len(),
+            files.iter().map(|f| f.size).sum(),
+        )
+        .await;
+        Ok(entries.into())
+    }
+
+    async fn get_table_file(
+        &self,
+        request: GetTableFileRequest,
+        context: RequestContext,
+    ) -> Result<Bytes> {
+        self.policy
+            .check_required(&request, context.as_ref())
+            .await?;
+        let res = request.resource();
+        self.read_file(res.as_ref(), context.recipient(), &request.id)
+            .await
     }
 }
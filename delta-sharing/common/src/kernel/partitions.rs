@@ -0,0 +1,281 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::iter::Peekable;
+use std::str::Chars;
+
+use delta_kernel::expressions::{BinaryOperator, Expression};
+use delta_kernel::schema::{DataType, StructType};
+
+use crate::{Error, Result};
+
+/// Specification of the partitions of a table that are shared.
+///
+/// A specification is a conjunction of comparisons between partition columns and literal
+/// values, e.g. `date >= '2024-01-01' AND country = 'DE'`. Values of the form
+/// `${recipient.<property>}` are replaced with the properties of the requesting recipient.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartitionSpec {
+    predicates: Vec<PartitionPredicate>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct PartitionPredicate {
+    column: String,
+    op: BinaryOperator,
+    value: String,
+}
+
+impl PartitionSpec {
+    /// Parse a partition specification, replacing placeholders with `parameters`.
+    pub fn parse(spec: &str, parameters: &HashMap<String, String>) -> Result<Self> {
+        let mut tokens = Tokenizer::new(spec);
+        let mut predicates = Vec::new();
+        loop {
+            let column = match tokens.next_token()? {
+                Some(Token::Word(column)) => column,
+                token => return Err(unexpected(spec, "column name", token)),
+            };
+            let op = match tokens.next_token()? {
+                Some(Token::Op(op)) => op,
+                token => return Err(unexpected(spec, "comparison operator", token)),
+            };
+            let value = match tokens.next_token()? {
+                Some(Token::Word(value)) | Some(Token::Quoted(value)) => value,
+                Some(Token::Placeholder(key)) => {
+                    let Some(value) = parameters.get(key.trim()) else {
+                        tracing::warn!("no value for placeholder '{}' in partition spec", key);
+                        return Err(Error::NotAllowed);
+                    };
+                    value.clone()
+                }
+                token => return Err(unexpected(spec, "value", token)),
+            };
+            predicates.push(PartitionPredicate { column, op, value });
+            match tokens.next_token()? {
+                None => break,
+                Some(Token::Word(word)) if word.eq_ignore_ascii_case("and") => (),
+                token => return Err(unexpected(spec, "AND", token)),
+            }
+        }
+        Ok(Self { predicates })
+    }
+
+    /// Convert the specification into a predicate on the partition columns of a table.
+    pub fn to_expression(
+        &self,
+        schema: &StructType,
+        partition_columns: &[String],
+    ) -> Result<Expression> {
+        let exprs = self
+            .predicates
+            .iter()
+            .map(|predicate| {
+                let column = &predicate.column;
+                let field = schema
+                    .field(column)
+                    .filter(|_| partition_columns.contains(column))
+                    .ok_or_else(|| {
+                        Error::invalid_argument(format!("{} is not a partition column", column))
+                    })?;
+                let DataType::Primitive(data_type) = field.data_type() else {
+                    return Err(Error::invalid_argument(format!(
+                        "partition column {} is not of primitive type",
+                        column
+                    )));
+                };
+                let value = data_type.parse_scalar(&predicate.value).map_err(|_| {
+                    Error::invalid_argument(format!(
+                        "invalid value for partition column {}: {}",
+                        column, predicate.value
+                    ))
+                })?;
+                Ok(Expression::binary(
+                    predicate.op,
+                    Expression::column([column]),
+                    value,
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Expression::and_from(exprs))
+    }
+}
+
+impl fmt::Display for PartitionSpec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, predicate) in self.predicates.iter().enumerate() {
+            if i > 0 {
+                write!(f, " AND ")?;
+            }
+            let op = match predicate.op {
+                BinaryOperator::Equal => "=",
+                BinaryOperator::NotEqual => "!=",
+                BinaryOperator::LessThan => "<",
+                BinaryOperator::LessThanOrEqual => "<=",
+                BinaryOperator::GreaterThan => ">",
+                BinaryOperator::GreaterThanOrEqual => ">=",
+                _ => unreachable!("only comparisons are parsed"),
+            };
+            let value = predicate.value.replace('\'', "''");
+            write!(f, "{} {} '{}'", predicate.column, op, value)?;
+        }
+        Ok(())
+    }
+}
+
+fn unexpected(spec: &str, expected: &str, token: Option<Token>) -> Error {
+    let found = match token {
+        Some(token) => format!("{:?}", token),
+        None => "end of input".to_string(),
+    };
+    Error::invalid_argument(format!(
+        "invalid partition spec '{}': expected {}, found {}",
+        spec, expected, found
+    ))
+}
+
+#[derive(Debug, PartialEq)]
+enum Token {
+    /// A column name, keyword or unquoted value.
+    Word(String),
+    /// A value in single quotes.
+    Quoted(String),
+    /// A `${...}` placeholder.
+    Placeholder(String),
+    Op(BinaryOperator),
+}
+
+struct Tokenizer<'a> {
+    chars: Peekable<Chars<'a>>,
+}
+
+impl<'a> Tokenizer<'a> {
+    fn new(spec: &'a str) -> Self {
+        Self {
+            chars: spec.chars().peekable(),
+        }
+    }
+
+    fn next_token(&mut self) -> Result<Option<Token>> {
+        while self.chars.next_if(|c| c.is_whitespace()).is_some() {}
+        let Some(c) = self.chars.next() else {
+            return Ok(None);
+        };
+        let token = match c {
+            '\'' => {
+                let mut value = String::new();
+                loop {
+                    match self.chars.next() {
+                        // quotes are escaped by doubling them.
+                        Some('\'') if self.chars.next_if_eq(&'\'').is_some() => value.push('\''),
+                        Some('\'') => break,
+                        Some(c) => value.push(c),
+                        None => return Err(invalid_token("unterminated string")),
+                    }
+                }
+                Token::Quoted(value)
+            }
+            '$' if self.chars.next_if_eq(&'{').is_some() => {
+                let mut key = String::new();
+                loop {
+                    match self.chars.next() {
+                        Some('}') => break,
+                        Some(c) => key.push(c),
+                        None => return Err(invalid_token("unterminated placeholder")),
+                    }
+                }
+                Token::Placeholder(key)
+            }
+            '=' => Token::Op(BinaryOperator::Equal),
+            '!' if self.chars.next_if_eq(&'=').is_some() => Token::Op(BinaryOperator::NotEqual),
+            '<' if self.chars.next_if_eq(&'=').is_some() => {
+                Token::Op(BinaryOperator::LessThanOrEqual)
+            }
+            '<' if self.chars.next_if_eq(&'>').is_some() => Token::Op(BinaryOperator::NotEqual),
+            '<' => Token::Op(BinaryOperator::LessThan),
+            '>' if self.chars.next_if_eq(&'=').is_some() => {
+                Token::Op(BinaryOperator::GreaterThanOrEqual)
+            }
+            '>' => Token::Op(BinaryOperator::GreaterThan),
+            c if is_word_char(c) => {
+                let mut word = c.to_string();
+                while let Some(c) = self.chars.next_if(|c| is_word_char(*c)) {
+                    word.push(c);
+                }
+                Token::Word(word)
+            }
+            c => return Err(invalid_token(&format!("unexpected character '{}'", c))),
+        };
+        Ok(Some(token))
+    }
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '_' | '-' | '.' | ':' | '+')
+}
+
+fn invalid_token(message: &str) -> Error {
+    Error::invalid_argument(format!("invalid partition spec: {}", message))
+}
+
+#[cfg(test)]
+mod tests {
+    use delta_kernel::expressions::column_expr;
+    use delta_kernel::schema::{PrimitiveType, StructField};
+
+    use super::*;
+
+    fn schema() -> StructType {
+        StructType::new([
+            StructField::nullable("date", DataType::DATE),
+            StructField::nullable("country", DataType::STRING),
+            StructField::nullable("value", DataType::LONG),
+        ])
+    }
+
+    #[test]
+    fn test_parse_partition_spec() {
+        let parameters = HashMap::from([("recipient.country".to_string(), "DE".to_string())]);
+        let spec = PartitionSpec::parse(
+            "date >= '2024-01-01' and country = ${recipient.country}",
+            &parameters,
+        )
+        .unwrap();
+        assert_eq!(spec.to_string(), "date >= '2024-01-01' AND country = 'DE'");
+
+        let partition_columns = ["date".to_string(), "country".to_string()];
+        let expr = spec.to_expression(&schema(), &partition_columns).unwrap();
+        let date = PrimitiveType::Date.parse_scalar("2024-01-01").unwrap();
+        assert_eq!(
+            expr,
+            Expression::and(
+                column_expr!("date").gt_eq(date),
+                column_expr!("country").eq(Expression::literal("DE")),
+            )
+        );
+
+        let spec = PartitionSpec::parse("country <> 'O''Brien'", &parameters).unwrap();
+        assert_eq!(spec.to_string(), "country != 'O''Brien'");
+
+        let spec = PartitionSpec::parse("value = 1", &parameters).unwrap();
+        let result = spec.to_expression(&schema(), &partition_columns);
+        assert!(matches!(result, Err(Error::InvalidArgument(_))));
+
+        for invalid in [
+            "",
+            "country",
+            "country = ",
+            "country = 'DE' OR",
+            "country = 'DE",
+        ] {
+            let result = PartitionSpec::parse(invalid, &parameters);
+            assert!(
+                matches!(result, Err(Error::InvalidArgument(_))),
+                "{}",
+                invalid
+            );
+        }
+
+        let result = PartitionSpec::parse("country = ${recipient.region}", &parameters);
+        assert!(matches!(result, Err(Error::NotAllowed)));
+    }
+}
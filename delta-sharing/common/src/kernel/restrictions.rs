@@ -0,0 +1,635 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use delta_kernel::actions::Metadata;
+use delta_kernel::expressions::{
+    BinaryOperator, Expression, ExpressionRef, Scalar, UnaryOperator, VariadicOperator,
+};
+use delta_kernel::schema::{DataType, StructField, StructType};
+use ring::hmac;
+
+use super::json_predicate_to_expression;
+use super::partitions::PartitionSpec;
+use crate::models::shares::v1::{ColumnMask, DataObject};
+use crate::models::sharing::v1::{JsonPredicate, MetadataParquet};
+use crate::models::{RecipientInfo, ShareInfo};
+use crate::{
+    Error, Recipient, ResourceExt, ResourceIdent, ResourceName, ResourceRef, ResourceStore, Result,
+};
+
+/// Restrictions a share places on the data of a shared table.
+///
+/// Restrictions are derived for a specific recipient, so any parameters in the
+/// definition of the data object are already replaced with the recipient's values.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TableRestrictions {
+    /// Predicate rows have to satisfy to be visible to the recipient.
+    pub row_filter: Option<ExpressionRef>,
+    /// Columns visible to the recipient, with the masking applied to their values.
+    ///
+    /// `None` if all columns are visible without masking.
+    pub columns: Option<HashMap<String, ColumnMask>>,
+    /// Partitions visible to the recipient, a partition is visible if it matches any of the
+    /// specifications.
+    ///
+    /// Empty if all partitions are visible.
+    pub partitions: Vec<PartitionSpec>,
+}
+
+/// Placeholder for redacted values.
+pub(crate) const REDACTED: &str = "REDACTED";
+
+/// Key in the advertised table configuration describing the shared partitions.
+pub const PARTITIONS_CONFIGURATION_KEY: &str = "sharing.partitions";
+
+impl TableRestrictions {
+    /// Derive the restrictions of a shared data object.
+    ///
+    /// `parameters` holds the values for `${...}` placeholders, keyed by the placeholder name,
+    /// e.g. `recipient.region`.
+    pub fn try_new(object: &DataObject, parameters: &HashMap<String, String>) -> Result<Self> {
+        let row_filter = object
+            .row_filter
+            .as_deref()
+            .map(|filter| parse_row_filter(filter, parameters))
+            .transpose()?;
+        let columns = (!object.columns.is_empty())
+            .then(|| {
+                object
+                    .columns
+                    .iter()
+                    .map(|column| {
+                        let mask = ColumnMask::try_from(column.mask).map_err(|_| {
+                            Error::invalid_argument(format!(
+                                "invalid mask for column {}: {}",
+                                column.name, column.mask
+                            ))
+                        })?;
+                        Ok((column.name.clone(), mask))
+                    })
+                    .collect::<Result<_>>()
+            })
+            .transpose()?;
+        let partitions = object
+            .partitions
+            .iter()
+            .map(|spec| PartitionSpec::parse(spec, parameters))
+            .collect::<Result<_>>()?;
+        Ok(Self {
+            row_filter,
+            columns,
+            partitions,
+        })
+    }
+
+    /// Returns `true` if no restrictions apply.
+    pub fn is_empty(&self) -> bool {
+        self.row_filter.is_none() && self.columns.is_none() && self.partitions.is_empty()
+    }
+
+    /// Predicate rows have to satisfy to be visible to the recipient.
+    ///
+    /// Combines the partition specifications with the row filter.
+    pub fn filter(
+        &self,
+        schema: &StructType,
+        partition_columns: &[String],
+    ) -> Result<Option<Expression>> {
+        let partitions = match self.partitions.as_slice() {
+            [] => None,
+            [spec] => Some(spec.to_expression(schema, partition_columns)?),
+            specs => Some(Expression::or_from(
+                specs
+                    .iter()
+                    .map(|spec| spec.to_expression(schema, partition_columns))
+                    .collect::<Result<Vec<_>>>()?,
+            )),
+        };
+        let row_filter = self.row_filter.as_deref().cloned();
+        Ok(match (partitions, row_filter) {
+            (Some(partitions), Some(filter)) => Some(Expression::and(partitions, filter)),
+            (partitions, filter) => partitions.or(filter),
+        })
+    }
+
+    /// Masking applied to a column, `None` if the column is not visible.
+    pub fn column_mask(&self, name: &str) -> Option<ColumnMask> {
+        match &self.columns {
+            Some(columns) => columns.get(name).copied(),
+            None => Some(ColumnMask::Unspecified),
+        }
+    }
+
+    /// The schema of the table as seen by the recipient.
+    ///
+    /// Hidden columns are removed. Masked columns become nullable, and hashed or redacted
+    /// columns are strings.
+    pub fn restrict_schema(&self, schema: &StructType) -> StructType {
+        StructType::new(schema.fields().filter_map(|field| {
+            let data_type = match self.column_mask(field.name())? {
+                ColumnMask::Unspecified => return Some(field.clone()),
+                ColumnMask::NullOut => field.data_type.clone(),
+                ColumnMask::Hash | ColumnMask::Redact => DataType::STRING,
+            };
+            Some(StructField {
+                data_type,
+                nullable: true,
+                ..field.clone()
+            })
+        }))
+    }
+
+    /// The partition values of a file as seen by the recipient.
+    ///
+    /// Hashed values are keyed with `hash_key`.
+    pub fn restrict_partition_values(
+        &self,
+        values: HashMap<String, String>,
+        hash_key: &hmac::Key,
+    ) -> HashMap<String, String> {
+        if self.columns.is_none() {
+            return values;
+        }
+        values
+            .into_iter()
+            .filter_map(|(name, value)| {
+                let value = match self.column_mask(&name)? {
+                    ColumnMask::Unspecified => value,
+                    ColumnMask::NullOut => return None,
+                    ColumnMask::Hash => hash_value(hash_key, value.as_bytes()),
+                    ColumnMask::Redact => REDACTED.to_string(),
+                };
+                Some((name, value))
+            })
+            .collect()
+    }
+
+    /// The metadata of the table as advertised to the recipient.
+    pub(crate) fn restrict_metadata(
+        &self,
+        metadata: &Metadata,
+        schema: &StructType,
+    ) -> Result<MetadataParquet> {
+        let mut restricted = MetadataParquet::from(metadata);
+        if self.columns.is_some() {
+            restricted.schema_string = serde_json::to_string(&self.restrict_schema(schema))?;
+            restricted
+                .partition_columns
+                .retain(|name| self.column_mask(name).is_some());
+        }
+        if !self.partitions.is_empty() {
+            let specs = self.partitions.iter().map(|spec| spec.to_string());
+            let specs = match self.partitions.len() {
+                1 => specs.collect::<String>(),
+                _ => specs
+                    .map(|spec| format!("({})", spec))
+                    .collect::<Vec<_>>()
+                    .join(" OR "),
+            };
+            restricted
+                .configuration
+                .insert(PARTITIONS_CONFIGURATION_KEY.to_string(), specs);
+        }
+        Ok(restricted)
+    }
+}
+
+/// Hex encoded HMAC-SHA256 of a value.
+///
+/// Keying the hash with a secret of the server keeps recipients from recovering masked
+/// values by hashing candidates.
+pub(crate) fn hash_value(key: &hmac::Key, value: &[u8]) -> String {
+    hmac::sign(key, value)
+        .as_ref()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+/// Resolver for the restrictions that apply when a recipient reads a shared table.
+#[async_trait::async_trait]
+pub trait TableRestrictionResolver: Send + Sync {
+    async fn resolve_restrictions(
+        &self,
+        table: &ResourceRef,
+        recipient: &Recipient,
+    ) -> Result<TableRestrictions>;
+}
+
+/// Restrictions are read from the data objects of the share, tables that are not
+/// listed as data objects of a share are not restricted.
+#[async_trait::async_trait]
+impl<T: ResourceStore> TableRestrictionResolver for T {
+    async fn resolve_restrictions(
+        &self,
+        table: &ResourceRef,
+        recipient: &Recipient,
+    ) -> Result<TableRestrictions> {
+        let name = match table {
+            ResourceRef::Name(name) => name.clone(),
+            _ => {
+                let ident = ResourceIdent::sharing_table(table.clone());
+                self.get(&ident).await?.0.resource_name()
+            }
+        };
+        let [share, schema, table] = &name[..] else {
+            return Err(Error::invalid_argument(format!(
+                "invalid shared table name: {}",
+                name
+            )));
+        };
+
+        let ident = ResourceIdent::share(ResourceName::new([share]));
+        let share = match self.get(&ident).await {
+            Ok((resource, _)) => ShareInfo::try_from(resource)?,
+            Err(Error::NotFound) => return Ok(TableRestrictions::default()),
+            Err(e) => return Err(e),
+        };
+        let shared_as = format!("{}.{}", schema, table);
+        let Some(object) = share
+            .data_objects
+            .iter()
+            .find(|o| shared_name(o) == shared_as)
+        else {
+            return Ok(TableRestrictions::default());
+        };
+        if object.row_filter.is_none() && object.columns.is_empty() && object.partitions.is_empty()
+        {
+            return Ok(TableRestrictions::default());
+        }
+
+        let parameters = recipient_parameters(self, recipient).await?;
+        TableRestrictions::try_new(object, &parameters)
+    }
+}
+
+/// Name of the data object within the share, i.e. `<schema>.<table>`.
+fn shared_name(object: &DataObject) -> &str {
+    match object.shared_as.as_deref() {
+        Some(shared_as) => shared_as,
+        None => object
+            .name
+            .split_once('.')
+            .map_or(object.name.as_str(), |(_, name)| name),
+    }
+}
+
+/// Placeholder values for a recipient.
+///
+/// Users are looked up as recipients by name, their properties are available as
+/// `recipient.<property>` and the name as `recipient.name`.
+async fn recipient_parameters<T: ResourceStore + ?Sized>(
+    store: &T,
+    recipient: &Recipient,
+) -> Result<HashMap<String, String>> {
+    let Recipient::User(name) = recipient else {
+        return Ok(HashMap::new());
+    };
+    let ident = ResourceIdent::recipient(ResourceName::new([name]));
+    let properties = match store.get(&ident).await {
+        Ok((resource, _)) => RecipientInfo::try_from(resource)?.properties,
+        Err(Error::NotFound) => None,
+        Err(e) => return Err(e),
+    };
+    let mut parameters: HashMap<_, _> = properties
+        .into_iter()
+        .flat_map(|p| p.fields)
+        .filter_map(|(key, value)| {
+            let value = match value.kind? {
+                pbjson_types::value::Kind::StringValue(s) => s,
+                pbjson_types::value::Kind::NumberValue(n) => n.to_string(),
+                pbjson_types::value::Kind::BoolValue(b) => b.to_string(),
+                _ => return None,
+            };
+            Some((format!("recipient.{}", key), value))
+        })
+        .collect();
+    parameters.insert("recipient.name".to_string(), name.clone());
+    Ok(parameters)
+}
+
+fn parse_row_filter(filter: &str, parameters: &HashMap<String, String>) -> Result<ExpressionRef> {
+    let mut predicate: JsonPredicate = serde_json::from_str(filter)
+        .map_err(|e| Error::invalid_predicate(format!("invalid row filter: {}", e)))?;
+    substitute(&mut predicate, parameters)?;
+    Ok(Arc::new(json_predicate_to_expression(&predicate)?))
+}
+
+/// Replace `${...}` placeholders in literal values.
+///
+/// A placeholder without a value denies access, rather than silently dropping the filter.
+fn substitute(predicate: &mut JsonPredicate, parameters: &HashMap<String, String>) -> Result<()> {
+    if let Some(value) = predicate.value.as_mut() {
+        if let Some(key) = value.strip_prefix("${").and_then(|v| v.strip_suffix('}')) {
+            let Some(replacement) = parameters.get(key.trim()) else {
+                tracing::warn!("no value for placeholder '{}' in row filter", key);
+                return Err(Error::NotAllowed);
+            };
+            *value = replacement.clone();
+        }
+    }
+    predicate
+        .children
+        .iter_mut()
+        .try_for_each(|child| substitute(child, parameters))
+}
+
+/// Evaluate a predicate against the partition values of a file.
+///
+/// Returns `None` if the predicate references columns other than partition columns, or uses
+/// expressions that cannot be evaluated on partition values alone.
+pub(crate) fn eval_partition_predicate(
+    predicate: &Expression,
+    partition_values: &HashMap<String, String>,
+    partition_columns: &[String],
+    schema: &StructType,
+) -> Option<bool> {
+    let eval = PartitionEvaluator {
+        partition_values,
+        partition_columns,
+        schema,
+    };
+    match eval.eval(predicate)? {
+        Scalar::Boolean(value) => Some(value),
+        // rows for which a filter evaluates to null are not visible.
+        Scalar::Null(_) => Some(false),
+        _ => None,
+    }
+}
+
+struct PartitionEvaluator<'a> {
+    partition_values: &'a HashMap<String, String>,
+    partition_columns: &'a [String],
+    schema: &'a StructType,
+}
+
+impl PartitionEvaluator<'_> {
+    fn eval(&self, expr: &Expression) -> Option<Scalar> {
+        match expr {
+            Expression::Literal(scalar) => Some(scalar.clone()),
+            Expression::Column(name) => {
+                let [name] = name.path() else {
+                    return None;
+                };
+                if !self.partition_columns.contains(name) {
+                    return None;
+                }
+                let DataType::Primitive(data_type) = self.schema.field(name)?.data_type() else {
+                    return None;
+                };
+                match self.partition_values.get(name) {
+                    Some(value) => data_type.parse_scalar(value).ok(),
+                    None => Some(Scalar::Null(data_type.clone().into())),
+                }
+            }
+            Expression::Unary(unary) => {
+                let value = self.eval(&unary.expr)?;
+                match (unary.op, value) {
+                    (UnaryOperator::IsNull, value) => Some(Scalar::Boolean(value.is_null())),
+                    (UnaryOperator::Not, Scalar::Boolean(value)) => Some(Scalar::Boolean(!value)),
+                    (UnaryOperator::Not, Scalar::Null(_)) => Some(null_bool()),
+                    _ => None,
+                }
+            }
+            Expression::Binary(binary) => {
+                let left = self.eval(&binary.left)?;
+                let right = self.eval(&binary.right)?;
+                if left.is_null() || right.is_null() {
+                    return Some(null_bool());
+                }
+                let ordering = left.partial_cmp(&right)?;
+                let result = match binary.op {
+                    BinaryOperator::Equal => ordering == Ordering::Equal,
+                    BinaryOperator::NotEqual => ordering != Ordering::Equal,
+                    BinaryOperator::LessThan => ordering == Ordering::Less,
+                    BinaryOperator::LessThanOrEqual => ordering != Ordering::Greater,
+                    BinaryOperator::GreaterThan => ordering == Ordering::Greater,
+                    BinaryOperator::GreaterThanOrEqual => ordering != Ordering::Less,
+                    _ => return None,
+                };
+                Some(Scalar::Boolean(result))
+            }
+            Expression::Variadic(variadic) => {
+                // three-valued logic, the neutral element decides and the other value short circuits.
+                let (neutral, decisive) = match variadic.op {
+                    VariadicOperator::And => (true, false),
+                    VariadicOperator::Or => (false, true),
+                };
+                let mut has_null = false;
+                for expr in &variadic.exprs {
+                    match self.eval(expr)? {
+                        Scalar::Boolean(value) if value == decisive => {
+                            return Some(Scalar::Boolean(decisive))
+                        }
+                        Scalar::Boolean(_) => (),
+                        Scalar::Null(_) => has_null = true,
+                        _ => return None,
+                    }
+                }
+                Some(if has_null {
+                    null_bool()
+                } else {
+                    Scalar::Boolean(neutral)
+                })
+            }
+            Expression::Struct(_) => None,
+        }
+    }
+}
+
+fn null_bool() -> Scalar {
+    Scalar::Null(DataType::BOOLEAN)
+}
+
+#[cfg(test)]
+mod tests {
+    use delta_kernel::expressions::column_expr;
+    use delta_kernel::schema::StructField;
+
+    use super::*;
+    use crate::memory::InMemoryResourceStore;
+    use crate::models::shares::v1::SharedColumn;
+
+    fn schema() -> StructType {
+        StructType::new([
+            StructField::nullable("region", DataType::STRING),
+            StructField::nullable("year", DataType::INTEGER),
+            StructField::nullable("value", DataType::LONG),
+        ])
+    }
+
+    fn eval(predicate: &Expression, values: &[(&str, &str)]) -> Option<bool> {
+        let values = values
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        let partition_columns = ["region".to_string(), "year".to_string()];
+        eval_partition_predicate(predicate, &values, &partition_columns, &schema())
+    }
+
+    #[test]
+    fn test_eval_partition_predicate() {
+        let predicate = column_expr!("region").eq(Expression::literal("EU"));
+        assert_eq!(eval(&predicate, &[("region", "EU")]), Some(true));
+        assert_eq!(eval(&predicate, &[("region", "US")]), Some(false));
+        // null partition values never match.
+        assert_eq!(eval(&predicate, &[]), Some(false));
+
+        let predicate = Expression::and(
+            column_expr!("region").eq(Expression::literal("EU")),
+            column_expr!("year").gt_eq(Expression::literal(2024)),
+        );
+        assert_eq!(
+            eval(&predicate, &[("region", "EU"), ("year", "2024")]),
+            Some(true)
+        );
+        assert_eq!(
+            eval(&predicate, &[("region", "EU"), ("year", "2023")]),
+            Some(false)
+        );
+        // false short circuits a conjunction, even with unknown values.
+        assert_eq!(eval(&predicate, &[("region", "US")]), Some(false));
+
+        let predicate = Expression::or(
+            column_expr!("region").eq(Expression::literal("EU")),
+            column_expr!("value").gt(Expression::literal(10i64)),
+        );
+        assert_eq!(eval(&predicate, &[("region", "EU")]), Some(true));
+        // data columns can not be evaluated on partition values.
+        assert_eq!(eval(&predicate, &[("region", "US")]), None);
+    }
+
+    #[test]
+    fn test_parse_row_filter() {
+        let filter = r#"{"op": "equal", "children": [
+            {"op": "column", "name": "region", "valueType": "string"},
+            {"op": "literal", "value": "${recipient.region}", "valueType": "string"}
+        ]}"#;
+        let parameters = HashMap::from([("recipient.region".to_string(), "EU".to_string())]);
+        let expr = parse_row_filter(filter, &parameters).unwrap();
+        assert_eq!(
+            expr.as_ref(),
+            &column_expr!("region").eq(Expression::literal("EU"))
+        );
+
+        let result = parse_row_filter(filter, &HashMap::new());
+        assert!(matches!(result, Err(Error::NotAllowed)));
+
+        let result = parse_row_filter("region = 'EU'", &parameters);
+        assert!(matches!(result, Err(Error::InvalidPredicate(_))));
+    }
+
+    #[test]
+    fn test_restrict_columns() {
+        let object = DataObject {
+            name: "catalog.schema.table".to_string(),
+            columns: vec![
+                SharedColumn {
+                    name: "region".to_string(),
+                    mask: ColumnMask::NullOut as i32,
+                },
+                SharedColumn {
+                    name: "value".to_string(),
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        };
+        let restrictions = TableRestrictions::try_new(&object, &HashMap::new()).unwrap();
+        assert_eq!(restrictions.column_mask("year"), None);
+        assert_eq!(
+            restrictions.restrict_schema(&schema()),
+            StructType::new([
+                StructField::nullable("region", DataType::STRING),
+                StructField::nullable("value", DataType::LONG),
+            ])
+        );
+
+        let values = HashMap::from([
+            ("region".to_string(), "EU".to_string()),
+            ("year".to_string(), "2024".to_string()),
+        ]);
+        let key = hmac::Key::new(hmac::HMAC_SHA256, b"secret");
+        assert!(restrictions
+            .restrict_partition_values(values, &key)
+            .is_empty());
+
+        let object = DataObject {
+            columns: vec![SharedColumn {
+                name: "value".to_string(),
+                mask: 42,
+            }],
+            ..object
+        };
+        let result = TableRestrictions::try_new(&object, &HashMap::new());
+        assert!(matches!(result, Err(Error::InvalidArgument(_))));
+    }
+
+    #[test]
+    fn test_hash_value() {
+        let key = hmac::Key::new(hmac::HMAC_SHA256, b"secret");
+        assert_eq!(hash_value(&key, b"EU"), hash_value(&key, b"EU"));
+        assert_ne!(hash_value(&key, b"EU"), hash_value(&key, b"US"));
+
+        // hashes depend on the key, so they can not be computed without it.
+        let other = hmac::Key::new(hmac::HMAC_SHA256, b"other");
+        assert_ne!(hash_value(&key, b"EU"), hash_value(&other, b"EU"));
+    }
+
+    #[tokio::test]
+    async fn test_resolve_restrictions() {
+        let store = InMemoryResourceStore::new();
+        let filter = r#"{"op": "equal", "children": [
+            {"op": "column", "name": "region", "valueType": "string"},
+            {"op": "literal", "value": "${recipient.region}", "valueType": "string"}
+        ]}"#;
+        let share = ShareInfo {
+            name: "share".to_string(),
+            data_objects: vec![
+                DataObject {
+                    name: "catalog.schema.filtered".to_string(),
+                    row_filter: Some(filter.to_string()),
+                    ..Default::default()
+                },
+                DataObject {
+                    name: "catalog.schema.table".to_string(),
+                    shared_as: Some("other.open".to_string()),
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        };
+        store.create(share.into()).await.unwrap();
+        let properties = serde_json::from_value(serde_json::json!({ "region": "EU" })).unwrap();
+        let recipient = RecipientInfo {
+            name: "alice".to_string(),
+            properties: Some(properties),
+            ..Default::default()
+        };
+        store.create(recipient.into()).await.unwrap();
+
+        let table = ResourceRef::Name(ResourceName::new(["share", "schema", "filtered"]));
+        let restrictions = store
+            .resolve_restrictions(&table, &Recipient::user("alice"))
+            .await
+            .unwrap();
+        assert_eq!(
+            restrictions.row_filter.as_deref(),
+            Some(&column_expr!("region").eq(Expression::literal("EU")))
+        );
+
+        // recipients without the property are denied.
+        let result = store
+            .resolve_restrictions(&table, &Recipient::anonymous())
+            .await;
+        assert!(matches!(result, Err(Error::NotAllowed)));
+
+        let table = ResourceRef::Name(ResourceName::new(["share", "other", "open"]));
+        let restrictions = store
+            .resolve_restrictions(&table, &Recipient::anonymous())
+            .await
+            .unwrap();
+        assert!(restrictions.is_empty());
+    }
+}
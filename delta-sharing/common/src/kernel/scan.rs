@@ -0,0 +1,1134 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use bytes::Bytes;
+use delta_kernel::arrow::array::{
+    new_null_array, Array, ArrayRef, AsArray, BooleanArray, RecordBatch, RecordBatchOptions,
+    StringArray,
+};
+use delta_kernel::arrow::compute::{cast, filter_record_batch};
+use delta_kernel::arrow::datatypes::{DataType as ArrowDataType, Schema as ArrowSchema};
+use delta_kernel::engine::arrow_data::ArrowEngineData;
+use delta_kernel::expressions::{Expression, ExpressionRef};
+use delta_kernel::parquet::arrow::ArrowWriter;
+use delta_kernel::scan::state::{transform_to_logical, visit_scan_files, DvInfo, Stats};
+use delta_kernel::scan::ScanBuilder;
+use delta_kernel::schema::{DataType, SchemaRef};
+use delta_kernel::snapshot::Snapshot;
+use delta_kernel::{Engine, FileMeta};
+use ring::hmac;
+
+use super::restrictions::{eval_partition_predicate, hash_value, TableRestrictions, REDACTED};
+use super::KernelQueryHandler;
+use crate::audit::AuditEvent;
+use crate::models::shares::v1::ColumnMask;
+use crate::{Error, Permission, Recipient, ResourceIdent, ResourceRef, Result};
+
+/// How files that may contain data hidden from the recipient are handled.
+///
+/// Files need to be filtered if a row filter can not be decided on partition values
+/// alone, or if any column of the table is hidden or masked. This includes partition
+/// columns, whose values are part of the paths of files in storage.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, strum::EnumString)]
+#[strum(serialize_all = "snake_case", ascii_case_insensitive)]
+pub enum RowFilterMode {
+    /// Fail the request.
+    #[default]
+    Reject,
+    /// Serve the file through the server, which removes hidden rows and columns.
+    Proxy,
+}
+
+/// How a recipient may access a file of a shared table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileAccess {
+    /// All data in the file is visible, so the file can be read from storage directly.
+    Direct,
+    /// The file must be read through [`KernelQueryHandler::read_file`].
+    Proxy,
+}
+
+/// A data file of a shared table.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SharedFile {
+    /// Opaque id of the file, used to read it through the data proxy.
+    pub id: String,
+    /// Path of the file, relative to the table root.
+    pub path: String,
+    /// Size of the file in bytes.
+    pub size: i64,
+    pub partition_values: HashMap<String, String>,
+    /// Number of records in the file, if known.
+    pub num_records: Option<u64>,
+    pub access: FileAccess,
+}
+
+struct ScanFile {
+    path: String,
+    size: i64,
+    num_records: Option<u64>,
+    dv_info: DvInfo,
+    transform: Option<ExpressionRef>,
+    partition_values: HashMap<String, String>,
+}
+
+fn scan_file_callback(
+    files: &mut Vec<ScanFile>,
+    path: &str,
+    size: i64,
+    stats: Option<Stats>,
+    dv_info: DvInfo,
+    transform: Option<ExpressionRef>,
+    partition_values: HashMap<String, String>,
+) {
+    files.push(ScanFile {
+        path: path.to_string(),
+        size,
+        num_records: stats.map(|s| s.num_records),
+        dv_info,
+        transform,
+        partition_values,
+    });
+}
+
+impl KernelQueryHandler {
+    /// List the files of a shared table a recipient may read.
+    ///
+    /// The shared partitions and row filter of the table are combined with the optional
+    /// `predicate`, so files that cannot contain visible rows are skipped. Files that may contain hidden data are either
+    /// rejected or marked for proxied access, depending on the [`RowFilterMode`].
+    ///
+    /// The listed files are recorded as data access of the recipient in the audit log.
+    pub async fn scan_files(
+        &self,
+        table: &ResourceRef,
+        recipient: &Recipient,
+        predicate: Option<Expression>,
+    ) -> Result<Vec<SharedFile>> {
+        let (snapshot, _, files) = self.scan_table(table, recipient, predicate).await?;
+        self.record_access(
+            table,
+            recipient,
+            snapshot.version(),
+            files.len(),
+            files.iter().map(|f| f.size).sum(),
+        )
+        .await;
+        Ok(files)
+    }
+
+    /// List the files of a shared table a recipient may read, without recording the access.
+    pub(super) async fn scan_table(
+        &self,
+        table: &ResourceRef,
+        recipient: &Recipient,
+        predicate: Option<Expression>,
+    ) -> Result<(Arc<Snapshot>, TableRestrictions, Vec<SharedFile>)> {
+        let restrictions = self.authorize_table(table, recipient).await?;
+        let (snapshot, engine) = self.load_table(table).await?;
+        let files = restricted_scan(&snapshot, engine.as_ref(), &restrictions, predicate)?
+            .into_iter()
+            .map(|(file, access)| SharedFile {
+                id: hash_value(&self.hash_key, file.path.as_bytes()),
+                path: file.path,
+                size: file.size,
+                partition_values: restrictions
+                    .restrict_partition_values(file.partition_values, &self.hash_key),
+                num_records: file.num_records,
+                access,
+            })
+            .collect::<Vec<_>>();
+        if self.row_filter_mode == RowFilterMode::Reject
+            && files.iter().any(|f| f.access == FileAccess::Proxy)
+        {
+            tracing::debug!(
+                "restrictions can not be enforced on files of table {}",
+                table
+            );
+            return Err(Error::NotAllowed);
+        }
+        Ok((snapshot, restrictions, files))
+    }
+
+    /// Read a file of a shared table as parquet, with all data hidden from the recipient removed.
+    ///
+    /// This is the data proxy for files with [`FileAccess::Proxy`], `id` is the [`SharedFile::id`]
+    /// of the file. The returned data contains the columns visible to the recipient with their
+    /// masks applied, except partition columns.
+    /// The returned data is recorded as data access of the recipient in the audit log.
+    pub async fn read_file(
+        &self,
+        table: &ResourceRef,
+        recipient: &Recipient,
+        id: &str,
+    ) -> Result<Bytes> {
+        let restrictions = self.authorize_table(table, recipient).await?;
+        let (snapshot, engine) = self.load_table(table).await?;
+        let file = restricted_scan(&snapshot, engine.as_ref(), &restrictions, None)?
+            .into_iter()
+            .map(|(file, _)| file)
+            .find(|file| hash_value(&self.hash_key, file.path.as_bytes()) == id)
+            .ok_or(Error::NotFound)?;
+
+        let table_root = snapshot.table_root().clone();
+        let scan = ScanBuilder::new(snapshot.clone()).build()?;
+        let state = scan.global_scan_state();
+        let meta = FileMeta {
+            location: table_root.join(&file.path)?,
+            last_modified: 0,
+            size: file.size as usize,
+        };
+        let mut selection = file
+            .dv_info
+            .get_selection_vector(engine.as_ref(), &table_root)?
+            .unwrap_or_default()
+            .into_iter();
+        let results = engine.get_parquet_handler().read_parquet_files(
+            &[meta],
+            state.physical_schema.clone(),
+            None,
+        )?;
+
+        let mut writer: Option<ArrowWriter<Vec<u8>>> = None;
+        for data in results {
+            let data = transform_to_logical(
+                engine.as_ref(),
+                data?,
+                &state.physical_schema,
+                &state.logical_schema,
+                &file.transform,
+            )?;
+            let batch: RecordBatch = (*ArrowEngineData::try_from_engine_data(data)?).into();
+            let visible = match &restrictions.row_filter {
+                Some(filter) => Some(evaluate_filter(
+                    engine.as_ref(),
+                    &batch,
+                    &state.logical_schema,
+                    filter,
+                )?),
+                None => None,
+            };
+            // rows without an entry in the deletion vector selection are not deleted.
+            let mask = (0..batch.num_rows())
+                .map(|i| {
+                    let selected = selection.next().unwrap_or(true);
+                    selected
+                        && visible
+                            .as_ref()
+                            .map_or(true, |v| v.is_valid(i) && v.value(i))
+                })
+                .collect::<Vec<_>>();
+            let mask = BooleanArray::from(mask);
+            let batch = filter_record_batch(&batch, &mask).map_err(arrow_error)?;
+            let indices = (0..batch.num_columns())
+                .filter(|i| {
+                    !state
+                        .partition_columns
+                        .contains(batch.schema().field(*i).name())
+                })
+                .collect::<Vec<_>>();
+            let batch = batch.project(&indices).map_err(arrow_error)?;
+            let batch = restrict_columns(&batch, &restrictions, &self.hash_key)?;
+            if writer.is_none() {
+                writer = Some(
+                    ArrowWriter::try_new(Vec::new(), batch.schema(), None).map_err(arrow_error)?,
+                );
+            }
+            if let Some(writer) = writer.as_mut() {
+                writer.write(&batch).map_err(arrow_error)?;
+            }
+        }
+        let Some(writer) = writer else {
+            return Err(Error::generic(format!("no data in file {}", file.path)));
+        };
+        let data: Bytes = writer.into_inner().map_err(arrow_error)?.into();
+        self.record_access(table, recipient, snapshot.version(), 1, data.len() as i64)
+            .await;
+        Ok(data)
+    }
+
+    /// Record a data access event, if an audit log is configured.
+    pub(super) async fn record_access(
+        &self,
+        table: &ResourceRef,
+        recipient: &Recipient,
+        version: u64,
+        num_files: usize,
+        bytes: i64,
+    ) {
+        if let Some(log) = &self.audit_log {
+            let ident = ResourceIdent::sharing_table(table.clone());
+            let event =
+                AuditEvent::data_access(recipient, &ident, version as i64, num_files as i64, bytes);
+            log.record(event).await;
+        }
+    }
+
+    /// Check the recipient may read the table and resolve its restrictions.
+    async fn authorize_table(
+        &self,
+        table: &ResourceRef,
+        recipient: &Recipient,
+    ) -> Result<TableRestrictions> {
+        let ident = ResourceIdent::sharing_table(table.clone());
+        self.policy
+            .authorize_checked(&ident, &Permission::Read, recipient)
+            .await?;
+        self.resolve_restrictions(table, recipient).await
+    }
+
+    pub(super) async fn resolve_restrictions(
+        &self,
+        table: &ResourceRef,
+        recipient: &Recipient,
+    ) -> Result<TableRestrictions> {
+        match &self.restrictions {
+            Some(resolver) => resolver.resolve_restrictions(table, recipient).await,
+            None => Ok(TableRestrictions::default()),
+        }
+    }
+}
+
+/// Scan the files of a table visible under the given restrictions.
+fn restricted_scan(
+    snapshot: &Arc<Snapshot>,
+    engine: &dyn Engine,
+    restrictions: &TableRestrictions,
+    predicate: Option<Expression>,
+) -> Result<Vec<(ScanFile, FileAccess)>> {
+    let partition_columns = &snapshot.metadata().partition_columns;
+    let schema = snapshot.schema();
+    let filter = restrictions.filter(schema, partition_columns)?;
+    let scan_predicate = match (predicate.clone(), filter.clone()) {
+        (Some(predicate), Some(filter)) => Some(Expression::and(predicate, filter)),
+        (predicate, filter) => predicate.or(filter),
+    };
+    let scan = ScanBuilder::new(snapshot.clone())
+        .with_predicate(scan_predicate.map(Arc::new))
+        .build()?;
+
+    let mut files = Vec::new();
+    for data in scan.scan_data(engine)? {
+        let (data, selection, transforms) = data?;
+        files = visit_scan_files(
+            data.as_ref(),
+            &selection,
+            &transforms,
+            files,
+            scan_file_callback,
+        )?;
+    }
+
+    // data files contain all columns and their paths the partition values, so restricting
+    // any column requires serving files through the proxy.
+    let restricts_columns = schema
+        .fields()
+        .any(|field| restrictions.column_mask(field.name()) != Some(ColumnMask::Unspecified));
+    let direct = if restricts_columns {
+        FileAccess::Proxy
+    } else {
+        FileAccess::Direct
+    };
+    Ok(files
+        .into_iter()
+        .filter_map(|file| {
+            let eval = |expr: &Expression| {
+                eval_partition_predicate(expr, &file.partition_values, partition_columns, schema)
+            };
+            // the requested predicate only prunes files, rows are not filtered by it.
+            if predicate.as_ref().and_then(eval) == Some(false) {
+                return None;
+            }
+            match filter.as_ref().map(eval) {
+                None | Some(Some(true)) => Some((file, direct)),
+                Some(Some(false)) => None,
+                Some(None) => Some((file, FileAccess::Proxy)),
+            }
+        })
+        .collect())
+}
+
+fn evaluate_filter(
+    engine: &dyn Engine,
+    batch: &RecordBatch,
+    schema: &SchemaRef,
+    filter: &Expression,
+) -> Result<BooleanArray> {
+    let result = engine
+        .get_expression_handler()
+        .get_evaluator(schema.clone(), filter.clone(), DataType::BOOLEAN)
+        .evaluate(&ArrowEngineData::new(batch.clone()))?;
+    let result: RecordBatch = (*ArrowEngineData::try_from_engine_data(result)?).into();
+    Ok(result.column(0).as_boolean().clone())
+}
+
+/// Project a batch to the columns visible to the recipient and mask their values.
+fn restrict_columns(
+    batch: &RecordBatch,
+    restrictions: &TableRestrictions,
+    hash_key: &hmac::Key,
+) -> Result<RecordBatch> {
+    if restrictions.columns.is_none() {
+        return Ok(batch.clone());
+    }
+    let mut fields = Vec::new();
+    let mut columns = Vec::new();
+    for (field, column) in batch.schema().fields().iter().zip(batch.columns()) {
+        let Some(mask) = restrictions.column_mask(field.name()) else {
+            continue;
+        };
+        let column = match mask {
+            ColumnMask::Unspecified => {
+                fields.push(field.clone());
+                columns.push(column.clone());
+                continue;
+            }
+            ColumnMask::NullOut => new_null_array(field.data_type(), column.len()),
+            ColumnMask::Hash => {
+                let values = cast(column, &ArrowDataType::Utf8).map_err(arrow_error)?;
+                let hashes = values
+                    .as_string::<i32>()
+                    .iter()
+                    .map(|value| value.map(|v| hash_value(hash_key, v.as_bytes())))
+                    .collect::<StringArray>();
+                Arc::new(hashes) as ArrayRef
+            }
+            ColumnMask::Redact => {
+                let values = (0..column.len())
+                    .map(|i| column.is_valid(i).then_some(REDACTED))
+                    .collect::<StringArray>();
+                Arc::new(values) as ArrayRef
+            }
+        };
+        let field = field
+            .as_ref()
+            .clone()
+            .with_data_type(column.data_type().clone())
+            .with_nullable(true);
+        fields.push(Arc::new(field));
+        columns.push(column);
+    }
+    let options = RecordBatchOptions::new().with_row_count(Some(batch.num_rows()));
+    RecordBatch::try_new_with_options(Arc::new(ArrowSchema::new(fields)), columns, &options)
+        .map_err(arrow_error)
+}
+
+fn arrow_error(err: impl std::fmt::Display) -> Error {
+    Error::generic(format!("failed to process table data: {}", err))
+}
+
+#[cfg(test)]
+mod tests {
+    use delta_kernel::arrow::array::Int64Array;
+    use delta_kernel::arrow::datatypes::{Field, Int64Type};
+    use delta_kernel::engine::default::executor::tokio::TokioBackgroundExecutor;
+    use delta_kernel::expressions::column_expr;
+    use delta_kernel::parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+    use delta_kernel::schema::{StructField, StructType};
+    use url::Url;
+
+    use super::*;
+    use crate::api::sharing::SharingQueryHandler;
+    use crate::api::RequestContext;
+    use crate::kernel::PARTITIONS_CONFIGURATION_KEY;
+    use crate::memory::InMemoryResourceStore;
+    use crate::models::shares::v1::{DataObject, SharedColumn};
+    use crate::models::sharing::v1::{
+        parquet_log_message::Entry, query_response::Response, GetTableMetadataRequest,
+        QueryTableRequest,
+    };
+    use crate::models::{RecipientInfo, ShareInfo};
+    use crate::{ConstantPolicy, ResourceName, ResourceStore, TableLocationResolver};
+
+    struct StaticLocation(Url);
+
+    #[async_trait::async_trait]
+    impl TableLocationResolver for StaticLocation {
+        async fn resolve(&self, _table: &ResourceRef) -> Result<Url> {
+            Ok(self.0.clone())
+        }
+    }
+
+    /// Create a table partitioned by `region`, with one file per region.
+    fn create_table(location: &Url) {
+        let root = location.to_file_path().unwrap();
+        let schema = StructType::new([
+            StructField::nullable("region", DataType::STRING),
+            StructField::nullable("value", DataType::LONG),
+        ]);
+        let mut actions = vec![
+            serde_json::json!({ "protocol": { "minReaderVersion": 1, "minWriterVersion": 1 } }),
+            serde_json::json!({ "metaData": {
+                "id": "test",
+                "format": { "provider": "parquet", "options": {} },
+                "schemaString": serde_json::to_string(&schema).unwrap(),
+                "partitionColumns": ["region"],
+                "configuration": {},
+                "createdTime": 0,
+            }}),
+        ];
+
+        // partition values are not stored in the data files.
+        let arrow_schema = Arc::new(ArrowSchema::new(vec![Field::new(
+            "value",
+            ArrowDataType::Int64,
+            true,
+        )]));
+        for (region, values) in [("EU", vec![1, 2, 3]), ("US", vec![4])] {
+            let num_records = values.len();
+            let columns: Vec<ArrayRef> = vec![Arc::new(Int64Array::from(values))];
+            let batch = RecordBatch::try_new(arrow_schema.clone(), columns).unwrap();
+            let mut writer = ArrowWriter::try_new(Vec::new(), arrow_schema.clone(), None).unwrap();
+            writer.write(&batch).unwrap();
+            let data = writer.into_inner().unwrap();
+
+            let path = format!("region={}/part-00000.parquet", region);
+            std::fs::create_dir_all(root.join(format!("region={}", region))).unwrap();
+            std::fs::write(root.join(&path), &data).unwrap();
+            actions.push(serde_json::json!({ "add": {
+                "path": path,
+                "partitionValues": { "region": region },
+                "size": data.len(),
+                "modificationTime": 0,
+                "dataChange": true,
+                "stats": serde_json::json!({ "numRecords": num_records }).to_string(),
+            }}));
+        }
+
+        let commit = actions
+            .iter()
+            .map(|action| action.to_string())
+            .collect::<Vec<_>>()
+            .join("\n");
+        std::fs::create_dir_all(root.join("_delta_log")).unwrap();
+        std::fs::write(root.join("_delta_log/00000000000000000000.json"), commit).unwrap();
+    }
+
+    fn equal(column: &str, value: &str, value_type: &str) -> String {
+        serde_json::json!({ "op": "equal", "children": [
+            { "op": "column", "name": column, "valueType": value_type },
+            { "op": "literal", "value": value, "valueType": value_type },
+        ]})
+        .to_string()
+    }
+
+    async fn store() -> Arc<InMemoryResourceStore> {
+        let store = InMemoryResourceStore::new();
+        let share = ShareInfo {
+            name: "share".to_string(),
+            data_objects: vec![
+                DataObject {
+                    name: "catalog.schema.regional".to_string(),
+                    row_filter: Some(equal("region", "${recipient.region}", "string")),
+                    ..Default::default()
+                },
+                DataObject {
+                    name: "catalog.schema.values".to_string(),
+                    row_filter: Some(equal("value", "2", "long")),
+                    ..Default::default()
+                },
+                DataObject {
+                    name: "catalog.schema.partitioned".to_string(),
+                    partitions: vec!["region = ${recipient.region}".to_string()],
+                    ..Default::default()
+                },
+                DataObject {
+                    name: "catalog.schema.masked".to_string(),
+                    columns: vec![
+                        SharedColumn {
+                            name: "region".to_string(),
+                            mask: ColumnMask::Redact as i32,
+                        },
+                        SharedColumn {
+                            name: "value".to_string(),
+                            mask: ColumnMask::Hash as i32,
+                        },
+                    ],
+                    ..Default::default()
+                },
+                DataObject {
+                    name: "catalog.schema.partition_masked".to_string(),
+                    columns: vec![
+                        SharedColumn {
+                            name: "region".to_string(),
+                            mask: ColumnMask::Hash as i32,
+                        },
+                        SharedColumn {
+                            name: "value".to_string(),
+                            ..Default::default()
+                        },
+                    ],
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        };
+        store.create(share.into()).await.unwrap();
+        let properties = serde_json::from_value(serde_json::json!({ "region": "EU" })).unwrap();
+        let recipient = RecipientInfo {
+            name: "alice".to_string(),
+            properties: Some(properties),
+            ..Default::default()
+        };
+        store.create(recipient.into()).await.unwrap();
+        Arc::new(store)
+    }
+
+    fn query_handler(location: &Url, store: Arc<InMemoryResourceStore>) -> KernelQueryHandler {
+        let engine_factory = Arc::new(super::super::DefaultKernelEngineFactroy::new(
+            Arc::new(TokioBackgroundExecutor::new()),
+            store.clone(),
+        ));
+        KernelQueryHandler::new(
+            engine_factory,
+            Arc::new(StaticLocation(location.clone())),
+            Arc::new(ConstantPolicy::default()),
+        )
+        .with_restrictions(store)
+    }
+
+    async fn handler(location: &Url) -> KernelQueryHandler {
+        query_handler(location, store().await)
+    }
+
+    /// A [`ServerHandler`](crate::ServerHandler) serving the fixture tables with restrictions applied.
+    async fn server_handler(location: &Url) -> crate::ServerHandler {
+        let store = store().await;
+        let query =
+            query_handler(location, store.clone()).with_row_filter_mode(RowFilterMode::Proxy);
+        crate::ServerHandler::new(
+            Arc::new(ConstantPolicy::default()),
+            store.clone(),
+            Arc::new(query),
+            store,
+        )
+    }
+
+    fn table(name: &str) -> ResourceRef {
+        ResourceRef::Name(ResourceName::new(["share", "schema", name]))
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_row_filter_on_partitions() {
+        let dir = tempfile::tempdir().unwrap();
+        let location = Url::from_directory_path(dir.path()).unwrap();
+        create_table(&location);
+        let handler = handler(&location).await;
+
+        let files = handler
+            .scan_files(&table("regional"), &Recipient::user("alice"), None)
+            .await
+            .unwrap();
+        assert_eq!(files.len(), 1);
+        assert_eq!(files[0].partition_values["region"], "EU");
+        assert_eq!(files[0].access, FileAccess::Direct);
+
+        // recipients without a region property can not read the table.
+        let result = handler
+            .scan_files(&table("regional"), &Recipient::user("bob"), None)
+            .await;
+        assert!(matches!(result, Err(Error::NotAllowed)));
+
+        // tables without a row filter are not restricted.
+        let files = handler
+            .scan_files(&table("other"), &Recipient::user("bob"), None)
+            .await
+            .unwrap();
+        assert_eq!(files.len(), 2);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_row_filter_proxy() {
+        let dir = tempfile::tempdir().unwrap();
+        let location = Url::from_directory_path(dir.path()).unwrap();
+        create_table(&location);
+        let handler = handler(&location).await;
+        let alice = Recipient::user("alice");
+
+        let result = handler.scan_files(&table("values"), &alice, None).await;
+        assert!(matches!(result, Err(Error::NotAllowed)));
+
+        let handler = handler.with_row_filter_mode(RowFilterMode::Proxy);
+        let files = handler
+            .scan_files(&table("values"), &alice, None)
+            .await
+            .unwrap();
+        assert!(files.iter().all(|f| f.access == FileAccess::Proxy));
+        let file = files
+            .iter()
+            .find(|f| f.partition_values["region"] == "EU")
+            .unwrap();
+
+        let data = handler
+            .read_file(&table("values"), &alice, &file.id)
+            .await
+            .unwrap();
+        let batches = ParquetRecordBatchReaderBuilder::try_new(data)
+            .unwrap()
+            .build()
+            .unwrap()
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(batches.len(), 1);
+        // partition columns are not part of the data.
+        assert_eq!(batches[0].num_columns(), 1);
+        let values = batches[0].column(0).as_primitive::<Int64Type>();
+        assert_eq!(values.values().to_vec(), vec![2]);
+
+        let result = handler.read_file(&table("values"), &alice, "unknown").await;
+        assert!(matches!(result, Err(Error::NotFound)));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_column_masking() {
+        let dir = tempfile::tempdir().unwrap();
+        let location = Url::from_directory_path(dir.path()).unwrap();
+        create_table(&location);
+        let handler = handler(&location)
+            .await
+            .with_row_filter_mode(RowFilterMode::Proxy)
+            .with_hash_key(b"secret");
+        let key = hmac::Key::new(hmac::HMAC_SHA256, b"secret");
+        let alice = Recipient::user("alice");
+
+        let request = GetTableMetadataRequest {
+            share: "share".to_string(),
+            schema: "schema".to_string(),
+            name: "masked".to_string(),
+        };
+        let context = RequestContext {
+            recipient: alice.clone(),
+        };
+        let response = handler.get_table_metadata(request, context).await.unwrap();
+        let Some(Response::Parquet(response)) = response.response else {
+            panic!("expected parquet response");
+        };
+        let Some(Entry::Metadata(metadata)) = &response.entries[0].entry else {
+            panic!("expected metadata");
+        };
+        let schema: StructType = serde_json::from_str(&metadata.schema_string).unwrap();
+        assert_eq!(
+            schema,
+            StructType::new([
+                StructField::nullable("region", DataType::STRING),
+                StructField::nullable("value", DataType::STRING),
+            ])
+        );
+        assert_eq!(metadata.partition_columns, vec!["region".to_string()]);
+
+        let files = handler
+            .scan_files(&table("masked"), &alice, None)
+            .await
+            .unwrap();
+        assert_eq!(files.len(), 2);
+        assert!(files.iter().all(|f| f.access == FileAccess::Proxy));
+        assert!(files
+            .iter()
+            .all(|f| f.partition_values["region"] == REDACTED));
+
+        let file = files.iter().find(|f| f.num_records == Some(1)).unwrap();
+        let data = handler
+            .read_file(&table("masked"), &alice, &file.id)
+            .await
+            .unwrap();
+        let batches = ParquetRecordBatchReaderBuilder::try_new(data)
+            .unwrap()
+            .build()
+            .unwrap()
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .unwrap();
+        let values = batches[0].column(0).as_string::<i32>();
+        assert_eq!(values.value(0), hash_value(&key, b"4"));
+
+        // paths of files contain the values of partition columns, so masking only a partition
+        // column requires proxied access as well.
+        let files = handler
+            .scan_files(&table("partition_masked"), &alice, None)
+            .await
+            .unwrap();
+        assert_eq!(files.len(), 2);
+        for file in &files {
+            assert_eq!(file.access, FileAccess::Proxy);
+            assert!(!file.id.contains("region"));
+            let region = file.path.split(['=', '/']).nth(1).unwrap();
+            assert_eq!(
+                file.partition_values["region"],
+                hash_value(&key, region.as_bytes())
+            );
+        }
+
+        // masked columns can not be served from storage directly.
+        let handler = handler.with_row_filter_mode(RowFilterMode::Reject);
+        let result = handler.scan_files(&table("masked"), &alice, None).await;
+        assert!(matches!(result, Err(Error::NotAllowed)));
+        let result = handler
+            .scan_files(&table("partition_masked"), &alice, None)
+            .await;
+        assert!(matches!(result, Err(Error::NotAllowed)));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_partition_restrictions() {
+        let dir = tempfile::tempdir().unwrap();
+        let location = Url::from_directory_path(dir.path()).unwrap();
+        create_table(&location);
+        let handler = handler(&location).await;
+        let alice = Recipient::user("alice");
+
+        let files = handler
+            .scan_files(&table("partitioned"), &alice, None)
+            .await
+            .unwrap();
+        assert_eq!(files.len(), 1);
+        assert_eq!(files[0].partition_values["region"], "EU");
+        assert_eq!(files[0].access, FileAccess::Direct);
+
+        // the restriction is intersected with the predicate of the query.
+        let predicate = column_expr!("region").eq(Expression::literal("US"));
+        let files = handler
+            .scan_files(&table("partitioned"), &alice, Some(predicate))
+            .await
+            .unwrap();
+        assert!(files.is_empty());
+
+        let result = handler
+            .scan_files(&table("partitioned"), &Recipient::user("bob"), None)
+            .await;
+        assert!(matches!(result, Err(Error::NotAllowed)));
+
+        let request = GetTableMetadataRequest {
+            share: "share".to_string(),
+            schema: "schema".to_string(),
+            name: "partitioned".to_string(),
+        };
+        let context = RequestContext { recipient: alice };
+        let response = handler.get_table_metadata(request, context).await.unwrap();
+        let Some(Response::Parquet(response)) = response.response else {
+            panic!("expected parquet response");
+        };
+        let Some(Entry::Metadata(metadata)) = &response.entries[0].entry else {
+            panic!("expected metadata");
+        };
+        assert_eq!(
+            metadata.configuration[PARTITIONS_CONFIGURATION_KEY],
+            "region = 'EU'"
+        );
+    }
+
+    /// Signer recording the urls it signed.
+    #[derive(Default)]
+    struct RecordingSigner(std::sync::Mutex<Vec<Url>>);
+
+    #[async_trait::async_trait]
+    impl super::super::FileSigner for RecordingSigner {
+        async fn sign(&self, url: &Url, _: std::time::Duration) -> Result<Url> {
+            self.0.lock().unwrap().push(url.clone());
+            Ok(url.clone())
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_partition_restrictions_signing() {
+        let dir = tempfile::tempdir().unwrap();
+        let location = Url::from_directory_path(dir.path()).unwrap();
+        create_table(&location);
+        let signer = Arc::new(RecordingSigner::default());
+        let handler = handler(&location).await.with_file_signer(signer.clone());
+        let request = |hints: Option<String>| QueryTableRequest {
+            share: "share".to_string(),
+            schema: "schema".to_string(),
+            name: "partitioned".to_string(),
+            json_predicate_hints: hints,
+            limit_hint: None,
+        };
+        let context = |name: &str| RequestContext {
+            recipient: Recipient::user(name),
+        };
+
+        let response = handler
+            .query_table(request(None), context("alice"))
+            .await
+            .unwrap();
+        let Some(Response::Parquet(response)) = response.response else {
+            panic!("expected parquet response");
+        };
+        let files = response
+            .entries
+            .iter()
+            .filter_map(|e| match &e.entry {
+                Some(Entry::File(file)) => Some(file),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(files.len(), 1);
+        assert_eq!(files[0].partition_values["region"], "EU");
+        let signed = signer.0.lock().unwrap().clone();
+        assert_eq!(
+            signed,
+            vec![location.join("region=EU/part-00000.parquet").unwrap()]
+        );
+
+        // files outside the shared partitions are not signed, even if the query asks for them.
+        let hints = equal("region", "US", "string");
+        handler
+            .query_table(request(Some(hints)), context("alice"))
+            .await
+            .unwrap();
+        let result = handler.query_table(request(None), context("bob")).await;
+        assert!(matches!(result, Err(Error::NotAllowed)));
+        assert_eq!(signer.0.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_server_handler_column_masking() {
+        let dir = tempfile::tempdir().unwrap();
+        let location = Url::from_directory_path(dir.path()).unwrap();
+        create_table(&location);
+        let handler = server_handler(&location).await;
+
+        let request = GetTableMetadataRequest {
+            share: "share".to_string(),
+            schema: "schema".to_string(),
+            name: "masked".to_string(),
+        };
+        let context = RequestContext {
+            recipient: Recipient::user("alice"),
+        };
+        let response = handler.get_table_metadata(request, context).await.unwrap();
+        let Some(Response::Parquet(response)) = response.response else {
+            panic!("expected parquet response");
+        };
+        let Some(Entry::Metadata(metadata)) = &response.entries[0].entry else {
+            panic!("expected metadata");
+        };
+        let schema: StructType = serde_json::from_str(&metadata.schema_string).unwrap();
+        assert_eq!(
+            schema,
+            StructType::new([
+                StructField::nullable("region", DataType::STRING),
+                StructField::nullable("value", DataType::STRING),
+            ])
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_server_handler_partitions() {
+        let dir = tempfile::tempdir().unwrap();
+        let location = Url::from_directory_path(dir.path()).unwrap();
+        create_table(&location);
+        let handler = server_handler(&location).await;
+
+        let request = GetTableMetadataRequest {
+            share: "share".to_string(),
+            schema: "schema".to_string(),
+            name: "partitioned".to_string(),
+        };
+        let context = RequestContext {
+            recipient: Recipient::user("alice"),
+        };
+        let response = handler.get_table_metadata(request, context).await.unwrap();
+        let Some(Response::Parquet(response)) = response.response else {
+            panic!("expected parquet response");
+        };
+        let Some(Entry::Metadata(metadata)) = &response.entries[0].entry else {
+            panic!("expected metadata");
+        };
+        assert_eq!(
+            metadata.configuration[PARTITIONS_CONFIGURATION_KEY],
+            "region = 'EU'"
+        );
+
+        // recipients the partition spec can not be resolved for are rejected.
+        let request = GetTableMetadataRequest {
+            share: "share".to_string(),
+            schema: "schema".to_string(),
+            name: "partitioned".to_string(),
+        };
+        let context = RequestContext {
+            recipient: Recipient::user("bob"),
+        };
+        let result = handler.get_table_metadata(request, context).await;
+        assert!(matches!(result, Err(Error::NotAllowed)));
+    }
+
+    /// A router serving the fixture tables to alice, recording events in the returned sink.
+    #[cfg(feature = "axum")]
+    async fn router(
+        location: &Url,
+    ) -> (
+        axum::Router,
+        crate::audit::AuditLog,
+        Arc<crate::audit::tests::MemorySink>,
+    ) {
+        use axum::extract::Request;
+
+        use crate::audit::tests::MemorySink;
+        use crate::audit::AuditLog;
+        use crate::rest::{get_sharing_router, AuditLayer, AuthenticationLayer, Authenticator};
+
+        #[derive(Clone)]
+        struct Alice;
+
+        impl Authenticator for Alice {
+            fn authenticate(&self, _: &Request) -> Result<Recipient> {
+                Ok(Recipient::user("alice"))
+            }
+        }
+
+        let sink = Arc::new(MemorySink::default());
+        let log = AuditLog::new(sink.clone());
+        let store = store().await;
+        let query = query_handler(location, store.clone())
+            .with_row_filter_mode(RowFilterMode::Proxy)
+            .with_file_signer(Arc::new(super::super::StorageFileSigner::new(
+                store.clone(),
+            )))
+            .with_data_proxy(Url::parse("http://localhost/delta-sharing").unwrap())
+            .with_audit_log(log.clone());
+        let handler = crate::ServerHandler::new(
+            Arc::new(ConstantPolicy::default()),
+            store.clone(),
+            Arc::new(query),
+            store,
+        );
+        let app = get_sharing_router(handler)
+            .layer(AuditLayer::new(log.clone()))
+            .layer(AuthenticationLayer::new(Alice));
+        (app, log, sink)
+    }
+
+    /// Query a table through the router, returning the file entries of the response.
+    #[cfg(feature = "axum")]
+    async fn query_files(app: &axum::Router, name: &str) -> Vec<serde_json::Value> {
+        use axum::extract::Request;
+        use http_body_util::BodyExt;
+        use tower::ServiceExt;
+
+        let request = Request::post(format!(
+            "/shares/share/schemas/schema/tables/{}/query",
+            name
+        ))
+        .header("content-type", "application/json")
+        .body(axum::body::Body::from("{}"))
+        .unwrap();
+        let response = app.clone().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), 200);
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        std::str::from_utf8(&body)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+            .filter_map(|entry| entry.get("file").cloned())
+            .collect()
+    }
+
+    #[cfg(feature = "axum")]
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_data_access_audit() {
+        use crate::audit::AuditEventKind;
+
+        let dir = tempfile::tempdir().unwrap();
+        let location = Url::from_directory_path(dir.path()).unwrap();
+        create_table(&location);
+        let (app, log, sink) = router(&location).await;
+
+        let files = query_files(&app, "regional").await;
+        assert_eq!(files.len(), 1);
+        log.flush().await;
+
+        let events = sink.events.lock().unwrap();
+        assert_eq!(events.len(), 2);
+        let access = &events[0];
+        assert_eq!(access.kind, AuditEventKind::DataAccess);
+        assert_eq!(access.recipient.as_deref(), Some("alice"));
+        assert_eq!(
+            access.resource_name.as_deref(),
+            Some("share.schema.regional")
+        );
+        assert_eq!(access.table_version, Some(0));
+        assert_eq!(access.num_files, Some(1));
+        let size = std::fs::metadata(dir.path().join("region=EU/part-00000.parquet"))
+            .unwrap()
+            .len();
+        assert_eq!(access.bytes_signed, Some(size as i64));
+        assert_eq!(events[1].kind, AuditEventKind::Request);
+        assert_eq!(events[1].status, Some(200));
+    }
+
+    #[cfg(feature = "axum")]
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_query_table_router() {
+        use axum::extract::Request;
+        use http_body_util::BodyExt;
+        use tower::ServiceExt;
+
+        use crate::audit::AuditEventKind;
+
+        let dir = tempfile::tempdir().unwrap();
+        let location = Url::from_directory_path(dir.path()).unwrap();
+        create_table(&location);
+        let (app, log, sink) = router(&location).await;
+
+        // files without hidden data are read from storage directly.
+        let files = query_files(&app, "regional").await;
+        assert_eq!(files.len(), 1);
+        assert_eq!(
+            files[0]["url"],
+            location
+                .join("region=EU/part-00000.parquet")
+                .unwrap()
+                .as_str()
+        );
+        assert_eq!(files[0]["partitionValues"]["region"], "EU");
+        assert!(files[0]["expirationTimestamp"].is_string());
+
+        // files filtered by the server are read through the data proxy.
+        let files = query_files(&app, "values").await;
+        assert_eq!(files.len(), 2);
+        let file = files
+            .iter()
+            .find(|f| f["partitionValues"]["region"] == "EU")
+            .unwrap();
+        let url = file["url"].as_str().unwrap();
+        let path = url.strip_prefix("http://localhost/delta-sharing").unwrap();
+        assert_eq!(
+            path,
+            format!(
+                "/shares/share/schemas/schema/tables/values/files/{}",
+                file["id"].as_str().unwrap()
+            )
+        );
+
+        let request = Request::get(path).body(axum::body::Body::empty()).unwrap();
+        let response = app.clone().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), 200);
+        let data = response.into_body().collect().await.unwrap().to_bytes();
+        let batches = ParquetRecordBatchReaderBuilder::try_new(data)
+            .unwrap()
+            .build()
+            .unwrap()
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .unwrap();
+        let values = batches[0].column(0).as_primitive::<Int64Type>();
+        assert_eq!(values.values().to_vec(), vec![2]);
+
+        let request = Request::get("/shares/share/schemas/schema/tables/values/files/unknown")
+            .body(axum::body::Body::empty())
+            .unwrap();
+        let response = app.oneshot(request).await.unwrap();
+        assert_eq!(response.status(), 404);
+
+        log.flush().await;
+        let events = sink.events.lock().unwrap();
+        let access = events
+            .iter()
+            .filter(|e| e.kind == AuditEventKind::DataAccess)
+            .collect::<Vec<_>>();
+        assert_eq!(access.len(), 3);
+        assert_eq!(
+            access[2].resource_name.as_deref(),
+            Some("share.schema.values")
+        );
+        assert_eq!(access[2].num_files, Some(1));
+    }
+}
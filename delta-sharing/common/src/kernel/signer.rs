@@ -0,0 +1,99 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use object_store::aws::AmazonS3Builder;
+use object_store::azure::MicrosoftAzureBuilder;
+use object_store::gcp::GoogleCloudStorageBuilder;
+use object_store::path::Path;
+use object_store::signer::Signer;
+use object_store::ObjectStoreScheme;
+use url::Url;
+
+use crate::api::external_locations::ExternalLocationResolver;
+use crate::{Error, Result};
+
+/// Creates urls recipients can read data files from without further credentials.
+#[async_trait::async_trait]
+pub trait FileSigner: Send + Sync {
+    /// Sign a url to read the file at `url` for the given duration.
+    async fn sign(&self, url: &Url, expires_in: Duration) -> Result<Url>;
+}
+
+/// Signs urls with the credential of the external location containing the file.
+///
+/// Local files are not signed, their urls are only readable by recipients with access
+/// to the same file system.
+pub struct StorageFileSigner {
+    locations: Arc<dyn ExternalLocationResolver>,
+}
+
+impl StorageFileSigner {
+    pub fn new(locations: Arc<dyn ExternalLocationResolver>) -> Self {
+        Self { locations }
+    }
+}
+
+#[async_trait::async_trait]
+impl FileSigner for StorageFileSigner {
+    async fn sign(&self, url: &Url, expires_in: Duration) -> Result<Url> {
+        let (scheme, path) = ObjectStoreScheme::parse(url).map_err(signer_error)?;
+        let path = Path::from_url_path(path).map_err(signer_error)?;
+        let options = self.locations.resolve_storage_options(url.as_str()).await?;
+        let signer: Box<dyn Signer> = match scheme {
+            ObjectStoreScheme::Local => return Ok(url.clone()),
+            ObjectStoreScheme::AmazonS3 => Box::new(
+                options
+                    .iter()
+                    .fold(
+                        AmazonS3Builder::new().with_url(url.as_str()),
+                        |b, (k, v)| match k.parse() {
+                            Ok(key) => b.with_config(key, v),
+                            Err(_) => b,
+                        },
+                    )
+                    .build()
+                    .map_err(signer_error)?,
+            ),
+            ObjectStoreScheme::GoogleCloudStorage => Box::new(
+                options
+                    .iter()
+                    .fold(
+                        GoogleCloudStorageBuilder::new().with_url(url.as_str()),
+                        |b, (k, v)| match k.parse() {
+                            Ok(key) => b.with_config(key, v),
+                            Err(_) => b,
+                        },
+                    )
+                    .build()
+                    .map_err(signer_error)?,
+            ),
+            ObjectStoreScheme::MicrosoftAzure => Box::new(
+                options
+                    .iter()
+                    .fold(
+                        MicrosoftAzureBuilder::new().with_url(url.as_str()),
+                        |b, (k, v)| match k.parse() {
+                            Ok(key) => b.with_config(key, v),
+                            Err(_) => b,
+                        },
+                    )
+                    .build()
+                    .map_err(signer_error)?,
+            ),
+            _ => {
+                return Err(Error::invalid_argument(format!(
+                    "files can not be signed for url: {}",
+                    url
+                )))
+            }
+        };
+        signer
+            .signed_url(reqwest::Method::GET, &path, expires_in)
+            .await
+            .map_err(signer_error)
+    }
+}
+
+fn signer_error(err: impl std::fmt::Display) -> Error {
+    Error::generic(format!("failed to sign file url: {}", err))
+}
@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 pub mod api;
+pub mod audit;
 pub mod error;
 #[cfg(feature = "grpc")]
 mod grpc;
@@ -14,6 +15,8 @@ pub mod resources;
 #[cfg(feature = "axum")]
 pub mod rest;
 pub mod secrets;
+#[cfg(any(test, feature = "integration"))]
+pub mod testing;
 
 pub use self::resources::*;
 pub use delta_sharing_derive;
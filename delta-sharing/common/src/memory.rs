@@ -1,14 +1,25 @@
+use chrono::{TimeDelta, Utc};
+use dashmap::mapref::entry::Entry;
 use dashmap::DashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use uuid::Uuid;
 
-use crate::{AssociationLabel, Error, PropertyMap, ResourceExt, Result, TableLocationResolver};
+use crate::api::permissions::GRANT_PERMISSIONS_PROPERTY;
 use crate::{
-    ObjectLabel, Resource, ResourceIdent, ResourceName, ResourceRef, ResourceStore, SecretManager,
+    link_parent, AssociationLabel, Error, Object, PropertyMap, ResourceExt, Result,
+    TableLocationResolver,
+};
+use crate::{
+    ObjectLabel, Permission, Resource, ResourceIdent, ResourceName, ResourceRef, ResourceStore,
+    SecretManager, VisibilityFilter,
 };
 
 const MAX_PAGE_SIZE: usize = 10000;
 
+/// Targets of the associations of a resource with a given label.
+type Associations = HashMap<Uuid, Option<PropertyMap>>;
+
 /// An in-memory implementation of a resource store.
 ///
 /// This store is not intended for production use, but is useful for testing and development.
@@ -16,7 +27,7 @@ const MAX_PAGE_SIZE: usize = 10000;
 pub struct InMemoryResourceStore {
     resources: Arc<DashMap<Uuid, Resource>>,
     id_map: Arc<DashMap<ObjectLabel, DashMap<ResourceName, Uuid>>>,
-    associations: Arc<DashMap<AssociationLabel, DashMap<Uuid, (Uuid, Option<PropertyMap>)>>>,
+    associations: Arc<DashMap<AssociationLabel, DashMap<Uuid, Associations>>>,
     secrets: Arc<DashMap<String, DashMap<Uuid, bytes::Bytes>>>,
 }
 
@@ -48,6 +59,92 @@ impl InMemoryResourceStore {
             .and_then(|map| map.value().remove(name).map(|(_, uuid)| uuid))
     }
 
+    fn resolve_uuid(&self, ident: &ResourceIdent) -> Result<Uuid> {
+        match ident.as_ref() {
+            ResourceRef::Uuid(uuid) => Ok(*uuid),
+            ResourceRef::Name(name) => self.get_uuid(ident.label(), name).ok_or(Error::NotFound),
+            ResourceRef::Undefined => Err(Error::NotFound),
+        }
+    }
+
+    fn association(
+        &self,
+        label: &AssociationLabel,
+        from: &Uuid,
+        to: &Uuid,
+    ) -> Option<Option<PropertyMap>> {
+        self.associations
+            .get(label)
+            .and_then(|map| map.get(from).and_then(|targets| targets.get(to).cloned()))
+    }
+
+    fn association_targets(&self, label: &AssociationLabel, from: &Uuid) -> Vec<Uuid> {
+        self.associations
+            .get(label)
+            .and_then(|map| {
+                map.get(from)
+                    .map(|targets| targets.keys().copied().collect())
+            })
+            .unwrap_or_default()
+    }
+
+    fn holds_permission(&self, principal: &Uuid, resource: &Uuid, permission: &Permission) -> bool {
+        if self
+            .association(&AssociationLabel::OwnerOf, principal, resource)
+            .is_some()
+        {
+            return true;
+        }
+        let Some(Some(properties)) =
+            self.association(&AssociationLabel::HasGrant, principal, resource)
+        else {
+            return false;
+        };
+        properties
+            .get(GRANT_PERMISSIONS_PROPERTY)
+            .and_then(|value| value.as_array())
+            .is_some_and(|granted| {
+                granted.iter().any(|p| {
+                    p.as_str() == Some(permission.as_ref())
+                        || p.as_str() == Some(Permission::Manage.as_ref())
+                })
+            })
+    }
+
+    /// Retain the resources passing the filter.
+    fn apply_filter(&self, uuids: &mut Vec<Uuid>, filter: &VisibilityFilter) {
+        match filter {
+            VisibilityFilter::Ids(ids) => uuids.retain(|uuid| ids.contains(uuid)),
+            VisibilityFilter::Grants {
+                principal,
+                permission,
+            } => {
+                let Some(principal) =
+                    self.get_uuid(&ObjectLabel::RecipientInfo, &ResourceName::new([principal]))
+                else {
+                    uuids.clear();
+                    return;
+                };
+                uuids.retain(|uuid| {
+                    // permissions are inherited from all ancestors of the resource.
+                    let mut pending = vec![*uuid];
+                    let mut visited = HashSet::new();
+                    while let Some(current) = pending.pop() {
+                        if !visited.insert(current) {
+                            continue;
+                        }
+                        if self.holds_permission(&principal, &current, permission) {
+                            return true;
+                        }
+                        pending
+                            .extend(self.association_targets(&AssociationLabel::ChildOf, &current));
+                    }
+                    false
+                });
+            }
+        }
+    }
+
     fn new_uuid(&self, label: &ObjectLabel, name: &ResourceName) -> Result<Uuid> {
         if self.get_uuid(label, name).is_some() {
             return Err(Error::AlreadyExists);
@@ -59,6 +156,20 @@ impl InMemoryResourceStore {
     }
 }
 
+/// Etag for a new revision of a resource.
+///
+/// Revisions are identified by their modification time, which is moved past the
+/// previous revision in case the clock did not advance.
+fn next_etag(previous: Option<&str>) -> String {
+    let now = Utc::now();
+    let modified_at = previous
+        .and_then(|etag| Object::parse_etag(etag).ok())
+        .map_or(now, |previous| {
+            now.max(previous + TimeDelta::nanoseconds(1))
+        });
+    Object::format_etag(&modified_at)
+}
+
 #[async_trait::async_trait]
 impl TableLocationResolver for InMemoryResourceStore {
     async fn resolve(&self, table: &ResourceRef) -> Result<url::Url> {
@@ -85,7 +196,7 @@ impl ResourceStore for InMemoryResourceStore {
         }
     }
 
-    async fn create(&self, resource: Resource) -> Result<(Resource, ResourceRef)> {
+    async fn create(&self, mut resource: Resource) -> Result<(Resource, ResourceRef)> {
         if self
             .get_uuid(resource.resource_label(), &resource.resource_name())
             .is_some()
@@ -93,7 +204,16 @@ impl ResourceStore for InMemoryResourceStore {
             return Err(Error::AlreadyExists);
         }
         let uuid = self.new_uuid(resource.resource_label(), &resource.resource_name())?;
+        resource.set_etag(Some(next_etag(None)));
         self.resources.insert(uuid, resource.clone());
+        let ident = resource.resource_label().to_ident(uuid);
+        link_parent(
+            self,
+            resource.resource_label(),
+            &resource.resource_name(),
+            &ident,
+        )
+        .await?;
         Ok((resource, ResourceRef::Uuid(uuid)))
     }
 
@@ -107,26 +227,41 @@ impl ResourceStore for InMemoryResourceStore {
             Some((_, resource)) => self.remove_uuid(id.label(), &resource.resource_name()),
             None => None,
         };
+        for map in self.associations.iter() {
+            map.remove(&uuid);
+            map.iter_mut().for_each(|mut targets| {
+                targets.remove(&uuid);
+            });
+        }
         Ok(())
     }
 
     async fn update(
         &self,
         id: &ResourceIdent,
-        resource: Resource,
+        mut resource: Resource,
     ) -> Result<(Resource, ResourceRef)> {
         let uuid = match id.as_ref() {
             ResourceRef::Uuid(uuid) => *uuid,
             ResourceRef::Name(name) => self.get_uuid(id.label(), name).ok_or(Error::NotFound)?,
             ResourceRef::Undefined => return Err(Error::NotFound),
         };
-        // Need to clone to avoid locking the map while holding a reference to the value
-        let existing = self
-            .resources
-            .get(&uuid)
-            .ok_or(Error::NotFound)?
-            .value()
-            .clone();
+        // the entry stays locked until the update is applied, so concurrent
+        // updates based on the same revision can not both pass the etag check.
+        let mut entry = self.resources.get_mut(&uuid).ok_or(Error::NotFound)?;
+        let existing = entry.value().clone();
+        if resource
+            .etag()
+            .is_some_and(|etag| existing.etag() != Some(etag))
+        {
+            return Err(Error::Conflict);
+        }
+        if self
+            .get_uuid(resource.resource_label(), &resource.resource_name())
+            .is_some_and(|other| other != uuid)
+        {
+            return Err(Error::AlreadyExists);
+        }
         if existing.resource_label() != resource.resource_label() {
             self.id_map
                 .get(existing.resource_label())
@@ -143,7 +278,19 @@ impl ResourceStore for InMemoryResourceStore {
                 .get(existing.resource_label())
                 .and_then(|map| map.value().insert(resource.resource_name(), uuid));
         }
-        self.resources.insert(uuid, resource.clone());
+        resource.set_etag(Some(next_etag(existing.etag())));
+        *entry = resource.clone();
+        drop(entry);
+        if existing.resource_name() != resource.resource_name() {
+            let ident = resource.resource_label().to_ident(uuid);
+            link_parent(
+                self,
+                resource.resource_label(),
+                &resource.resource_name(),
+                &ident,
+            )
+            .await?;
+        }
         Ok((resource, ResourceRef::Uuid(uuid)))
     }
 
@@ -151,6 +298,7 @@ impl ResourceStore for InMemoryResourceStore {
         &self,
         label: &ObjectLabel,
         namespace: Option<&ResourceName>,
+        filter: Option<&VisibilityFilter>,
         max_results: Option<usize>,
         page_token: Option<String>,
     ) -> Result<(Vec<Resource>, Option<String>)> {
@@ -169,6 +317,9 @@ impl ResourceStore for InMemoryResourceStore {
                     .collect::<Vec<_>>()
             })
             .unwrap_or_default();
+        if let Some(filter) = filter {
+            self.apply_filter(&mut resource_ids, filter);
+        }
         if resource_ids.is_empty() {
             return Ok((Vec::new(), None));
         }
@@ -193,21 +344,24 @@ impl ResourceStore for InMemoryResourceStore {
         label: &AssociationLabel,
         properties: Option<PropertyMap>,
     ) -> Result<()> {
-        let from_uuid = match from.as_ref() {
-            ResourceRef::Uuid(uuid) => *uuid,
-            ResourceRef::Name(name) => self.get_uuid(from.label(), name).ok_or(Error::NotFound)?,
-            ResourceRef::Undefined => return Err(Error::NotFound),
-        };
-        let to_uuid = match to.as_ref() {
-            ResourceRef::Uuid(uuid) => *uuid,
-            ResourceRef::Name(name) => self.get_uuid(to.label(), name).ok_or(Error::NotFound)?,
-            ResourceRef::Undefined => return Err(Error::NotFound),
-        };
-        let map = self.associations.entry(label.clone()).or_default();
-        map.insert(from_uuid, (to_uuid, properties.clone()));
+        let from_uuid = self.resolve_uuid(from)?;
+        let to_uuid = self.resolve_uuid(to)?;
+        if self.association(label, &from_uuid, &to_uuid).is_some() {
+            return Err(Error::AlreadyExists);
+        }
+        // drop the entry guard first, the inverse entry may live in the same shard.
+        self.associations
+            .entry(label.clone())
+            .or_default()
+            .entry(from_uuid)
+            .or_default()
+            .insert(to_uuid, properties.clone());
         if let Some(inverse) = label.inverse() {
             let inverse_map = self.associations.entry(inverse).or_default();
-            inverse_map.insert(to_uuid, (from_uuid, properties.clone()));
+            inverse_map
+                .entry(to_uuid)
+                .or_default()
+                .insert(from_uuid, properties);
         }
         Ok(())
     }
@@ -218,30 +372,62 @@ impl ResourceStore for InMemoryResourceStore {
         to: &ResourceIdent,
         label: &AssociationLabel,
     ) -> Result<()> {
-        let from_uuid = match from.as_ref() {
-            ResourceRef::Uuid(uuid) => *uuid,
-            ResourceRef::Name(name) => self.get_uuid(from.label(), name).ok_or(Error::NotFound)?,
-            ResourceRef::Undefined => return Err(Error::NotFound),
+        let from_uuid = self.resolve_uuid(from)?;
+        let to_uuid = self.resolve_uuid(to)?;
+        let remove = |label: &AssociationLabel, from: &Uuid, to: &Uuid| {
+            self.associations
+                .get(label)
+                .and_then(|map| map.get_mut(from).and_then(|mut targets| targets.remove(to)))
         };
-        let to_uuid = match to.as_ref() {
-            ResourceRef::Uuid(uuid) => *uuid,
-            ResourceRef::Name(name) => self.get_uuid(to.label(), name).ok_or(Error::NotFound)?,
-            ResourceRef::Undefined => return Err(Error::NotFound),
+        remove(label, &from_uuid, &to_uuid).ok_or(Error::NotFound)?;
+        if let Some(inverse) = label.inverse() {
+            remove(&inverse, &to_uuid, &from_uuid);
+        }
+        Ok(())
+    }
+
+    async fn update_association(
+        &self,
+        from: &ResourceIdent,
+        to: &ResourceIdent,
+        label: &AssociationLabel,
+        properties: Option<PropertyMap>,
+    ) -> Result<()> {
+        let from_uuid = self.resolve_uuid(from)?;
+        let to_uuid = self.resolve_uuid(to)?;
+        // guards are released when the closure returns, the inverse entry may live in the same shard.
+        let update = |label: &AssociationLabel, from: &Uuid, to: &Uuid, properties| {
+            let map = self.associations.get(label)?;
+            let mut targets = map.get_mut(from)?;
+            let current = targets.get_mut(to)?;
+            *current = properties;
+            Some(())
         };
-        let map = self.associations.get(label).ok_or(Error::NotFound)?;
-        map.remove(&from_uuid);
+        update(label, &from_uuid, &to_uuid, properties.clone()).ok_or(Error::NotFound)?;
         if let Some(inverse) = label.inverse() {
-            let inverse_map = self.associations.get(&inverse).ok_or(Error::NotFound)?;
-            inverse_map.remove(&to_uuid);
+            update(&inverse, &to_uuid, &from_uuid, properties);
         }
         Ok(())
     }
 
+    async fn get_association(
+        &self,
+        from: &ResourceIdent,
+        to: &ResourceIdent,
+        label: &AssociationLabel,
+    ) -> Result<Option<PropertyMap>> {
+        let from_uuid = self.resolve_uuid(from)?;
+        let to_uuid = self.resolve_uuid(to)?;
+        self.association(label, &from_uuid, &to_uuid)
+            .ok_or(Error::NotFound)
+    }
+
     async fn list_associations(
         &self,
         resource: &ResourceIdent,
         label: &AssociationLabel,
         target_label: Option<&ResourceIdent>,
+        filter: Option<&VisibilityFilter>,
         max_results: Option<usize>,
         page_token: Option<String>,
     ) -> Result<(Vec<ResourceIdent>, Option<String>)> {
@@ -254,31 +440,30 @@ impl ResourceStore for InMemoryResourceStore {
                 return Err(Error::invalid_argument("resource must not be undefined"))
             }
         };
-        let target_uuid = target_label
-            .map(|tl| match tl.as_ref() {
-                ResourceRef::Uuid(uuid) => Ok(*uuid),
-                ResourceRef::Name(name) => self.get_uuid(tl.label(), name).ok_or(Error::NotFound),
-                ResourceRef::Undefined => Err(Error::invalid_argument(
-                    "target resource must not be undefined",
-                )),
-            })
-            .transpose()?;
         let page_token = page_token.map(|t| Uuid::parse_str(&t)).transpose()?;
         let mut association_ids = self
             .associations
             .get(label)
-            .map(|map| {
-                map.value()
-                    .get(&resource_uuid)
-                    .iter()
-                    .filter(|entry| {
-                        target_uuid.is_none_or(|uuid| entry.value().0 == uuid)
-                            && page_token.is_none_or(|t| &t > entry.key())
-                    })
-                    .map(|entry| entry.value().0)
-                    .collect::<Vec<_>>()
+            .and_then(|map| {
+                map.get(&resource_uuid).map(|targets| {
+                    targets
+                        .keys()
+                        .filter(|uuid| {
+                            page_token.map_or(true, |t| &t > *uuid)
+                                && target_label.map_or(true, |tl| {
+                                    self.resources
+                                        .get(*uuid)
+                                        .is_some_and(|r| r.resource_label() == tl.label())
+                                })
+                        })
+                        .copied()
+                        .collect::<Vec<_>>()
+                })
             })
             .unwrap_or_default();
+        if let Some(filter) = filter {
+            self.apply_filter(&mut association_ids, filter);
+        }
         if association_ids.is_empty() {
             return Ok((Vec::new(), None));
         }
@@ -343,11 +528,7 @@ impl SecretManager for InMemoryResourceStore {
     }
 
     async fn update_secret(&self, secret_name: &str, secret_value: bytes::Bytes) -> Result<Uuid> {
-        let map = self
-            .secrets
-            .get(secret_name)
-            .map(|entry| entry.value().clone())
-            .ok_or(Error::NotFound)?;
+        let map = self.secrets.get(secret_name).ok_or(Error::NotFound)?;
         let uuid = Uuid::now_v7();
         map.insert(uuid, secret_value);
         Ok(uuid)
@@ -357,11 +538,40 @@ impl SecretManager for InMemoryResourceStore {
         self.secrets.remove(secret_name).ok_or(Error::NotFound)?;
         Ok(())
     }
+
+    async fn put_secret_version(
+        &self,
+        secret_name: &str,
+        current: Option<Uuid>,
+        version: Uuid,
+        secret_value: bytes::Bytes,
+    ) -> Result<()> {
+        let Some(current) = current else {
+            let versions = DashMap::new();
+            versions.insert(version, secret_value);
+            return match self.secrets.entry(secret_name.to_string()) {
+                Entry::Occupied(_) => Err(Error::AlreadyExists),
+                Entry::Vacant(entry) => {
+                    entry.insert(versions);
+                    Ok(())
+                }
+            };
+        };
+        // holding the entry mutably excludes concurrent writes to the secret.
+        let versions = self.secrets.get_mut(secret_name).ok_or(Error::NotFound)?;
+        let latest = versions.iter().map(|entry| *entry.key()).max();
+        if latest != Some(current) || version <= current {
+            return Err(Error::Conflict);
+        }
+        versions.insert(version, secret_value);
+        Ok(())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::{RecipientInfo, SchemaInfo};
     use crate::{CatalogInfo, ObjectLabel};
 
     #[tokio::test]
@@ -396,7 +606,7 @@ mod tests {
         let (created, _) = store.create(resource.clone()).await.unwrap();
 
         let (resources, next) = store
-            .list(&ObjectLabel::CatalogInfo, None, None, None)
+            .list(&ObjectLabel::CatalogInfo, None, None, None, None)
             .await
             .unwrap();
         assert_eq!(resources.len(), 1);
@@ -418,17 +628,256 @@ mod tests {
         store.create(resource).await.unwrap();
 
         let (resources, next) = store
-            .list(&ObjectLabel::CatalogInfo, None, Some(2), None)
+            .list(&ObjectLabel::CatalogInfo, None, None, Some(2), None)
             .await
             .unwrap();
         assert_eq!(resources.len(), 2);
         assert!(next.is_some());
 
         let (resources, next) = store
-            .list(&ObjectLabel::CatalogInfo, None, Some(2), next)
+            .list(&ObjectLabel::CatalogInfo, None, None, Some(2), next)
             .await
             .unwrap();
         assert_eq!(resources.len(), 1);
         assert!(next.is_none());
     }
+
+    #[tokio::test]
+    async fn test_associations() {
+        let store = InMemoryResourceStore::new();
+        let mut idents = Vec::new();
+        for name in ["catalog", "other", "third"] {
+            let resource: Resource = CatalogInfo {
+                name: name.into(),
+                ..Default::default()
+            }
+            .into();
+            let (_, reference) = store.create(resource).await.unwrap();
+            idents.push(ObjectLabel::CatalogInfo.to_ident(reference));
+        }
+        let properties = PropertyMap::from([("key".to_string(), "value".into())]);
+        for (target, props) in [(&idents[1], Some(properties.clone())), (&idents[2], None)] {
+            store
+                .add_association(&idents[0], target, &AssociationLabel::HasGrant, props)
+                .await
+                .unwrap();
+        }
+
+        let (associated, _) = store
+            .list_associations(
+                &idents[0],
+                &AssociationLabel::HasGrant,
+                None,
+                None,
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+        assert_eq!(associated.len(), 2);
+        let (inverse, _) = store
+            .list_associations(
+                &idents[1],
+                &AssociationLabel::GrantedTo,
+                None,
+                None,
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            inverse,
+            vec![ObjectLabel::CatalogInfo.to_ident(ResourceName::new(["catalog"]))]
+        );
+
+        let props = store
+            .get_association(&idents[0], &idents[1], &AssociationLabel::HasGrant)
+            .await
+            .unwrap();
+        assert_eq!(props, Some(properties));
+
+        store
+            .remove_association(&idents[0], &idents[1], &AssociationLabel::HasGrant)
+            .await
+            .unwrap();
+        let result = store
+            .get_association(&idents[0], &idents[1], &AssociationLabel::HasGrant)
+            .await;
+        assert!(matches!(result.unwrap_err(), Error::NotFound));
+
+        store.delete(&idents[2]).await.unwrap();
+        let (associated, _) = store
+            .list_associations(
+                &idents[0],
+                &AssociationLabel::HasGrant,
+                None,
+                None,
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+        assert!(associated.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_associations_inverse_same_shard() {
+        // a label and its inverse may share a shard of the association map, writing both
+        // must not hold on to the first entry while the second is inserted. Shards are
+        // assigned by a randomly seeded hasher, so many fresh stores are tried.
+        let labels = [
+            AssociationLabel::OwnedBy,
+            AssociationLabel::DependsOn,
+            AssociationLabel::ParentOf,
+            AssociationLabel::HasPart,
+            AssociationLabel::References,
+            AssociationLabel::HasGrant,
+        ];
+        for _ in 0..100 {
+            let store = InMemoryResourceStore::new();
+            let mut idents = Vec::new();
+            for name in ["catalog", "other"] {
+                let resource: Resource = CatalogInfo {
+                    name: name.into(),
+                    ..Default::default()
+                }
+                .into();
+                let (_, reference) = store.create(resource).await.unwrap();
+                idents.push(ObjectLabel::CatalogInfo.to_ident(reference));
+            }
+            for label in &labels {
+                store
+                    .add_association(&idents[0], &idents[1], label, None)
+                    .await
+                    .unwrap();
+                let inverse = label.inverse().unwrap();
+                let props = store
+                    .get_association(&idents[1], &idents[0], &inverse)
+                    .await
+                    .unwrap();
+                assert_eq!(props, None);
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_conformance() {
+        crate::testing::resource_store_conformance(|| async { InMemoryResourceStore::new() }).await;
+    }
+
+    #[tokio::test]
+    async fn test_visibility_filter() {
+        let store = InMemoryResourceStore::new();
+        let recipient = RecipientInfo {
+            name: "alice".to_string(),
+            ..Default::default()
+        };
+        let (_, alice) = store.create(recipient.into()).await.unwrap();
+        let alice = ObjectLabel::RecipientInfo.to_ident(alice);
+        let mut catalogs = Vec::new();
+        for name in ["granted", "hidden", "owned", "managed"] {
+            let resource: Resource = CatalogInfo {
+                name: name.into(),
+                ..Default::default()
+            }
+            .into();
+            let (_, reference) = store.create(resource).await.unwrap();
+            catalogs.push(ObjectLabel::CatalogInfo.to_ident(reference));
+        }
+        let schema: Resource = SchemaInfo {
+            name: "schema".into(),
+            catalog_name: "owned".into(),
+            ..Default::default()
+        }
+        .into();
+        store.create(schema).await.unwrap();
+
+        let grant = |permissions: &[&str]| {
+            Some(PropertyMap::from([(
+                GRANT_PERMISSIONS_PROPERTY.to_string(),
+                permissions.into(),
+            )]))
+        };
+        for (catalog, label, properties) in [
+            (&catalogs[0], AssociationLabel::HasGrant, grant(&["read"])),
+            (&catalogs[1], AssociationLabel::HasGrant, grant(&["write"])),
+            (&catalogs[2], AssociationLabel::OwnerOf, None),
+            (&catalogs[3], AssociationLabel::HasGrant, grant(&["manage"])),
+        ] {
+            store
+                .add_association(&alice, catalog, &label, properties)
+                .await
+                .unwrap();
+        }
+
+        let filter = VisibilityFilter::Grants {
+            principal: "alice".to_string(),
+            permission: Permission::Read,
+        };
+        // pages are filled with visible resources only.
+        let mut names = Vec::new();
+        let mut page_token = None;
+        loop {
+            let (resources, next) = store
+                .list(
+                    &ObjectLabel::CatalogInfo,
+                    None,
+                    Some(&filter),
+                    Some(2),
+                    page_token,
+                )
+                .await
+                .unwrap();
+            names.extend(resources.iter().map(|r| r.resource_name().to_string()));
+            match next {
+                Some(next) => {
+                    assert_eq!(resources.len(), 2);
+                    page_token = Some(next);
+                }
+                None => break,
+            }
+        }
+        names.sort();
+        assert_eq!(names, vec!["granted", "managed", "owned"]);
+
+        // permissions are inherited from the parent catalog.
+        let (schemas, _) = store
+            .list(&ObjectLabel::SchemaInfo, None, Some(&filter), None, None)
+            .await
+            .unwrap();
+        assert_eq!(schemas.len(), 1);
+
+        let filter = VisibilityFilter::Grants {
+            principal: "bob".to_string(),
+            permission: Permission::Read,
+        };
+        let (resources, _) = store
+            .list(&ObjectLabel::CatalogInfo, None, Some(&filter), None, None)
+            .await
+            .unwrap();
+        assert!(resources.is_empty());
+
+        let ResourceRef::Uuid(owned) = catalogs[2].reference() else {
+            panic!("expected uuid reference");
+        };
+        let filter = VisibilityFilter::Ids(HashSet::from([*owned]));
+        let (resources, _) = store
+            .list(&ObjectLabel::CatalogInfo, None, Some(&filter), None, None)
+            .await
+            .unwrap();
+        assert_eq!(resources.len(), 1);
+        let (associated, _) = store
+            .list_associations(
+                &alice,
+                &AssociationLabel::OwnerOf,
+                None,
+                Some(&filter),
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+        assert_eq!(associated.len(), 1);
+    }
 }
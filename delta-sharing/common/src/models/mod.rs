@@ -76,6 +76,14 @@ pub mod external_locations {
     }
 }
 
+pub mod permissions {
+    pub mod v1 {
+        include!("../gen/delta_sharing.permissions.v1.rs");
+        #[cfg(feature = "grpc")]
+        include!("../gen/delta_sharing.permissions.v1.tonic.rs");
+    }
+}
+
 pub mod credentials {
     pub mod v1 {
         include!("../gen/delta_sharing.credentials.v1.rs");
@@ -84,6 +92,14 @@ pub mod credentials {
     }
 }
 
+pub mod temporary_credentials {
+    pub mod v1 {
+        include!("../gen/delta_sharing.temporary_credentials.v1.rs");
+        #[cfg(feature = "grpc")]
+        include!("../gen/delta_sharing.temporary_credentials.v1.tonic.rs");
+    }
+}
+
 pub mod profiles {
     pub mod v1 {
         include!("../gen/delta_sharing.profiles.v1.rs");
@@ -97,10 +113,25 @@ pub(crate) mod internal {
 }
 
 impl ObjectLabel {
+    /// Label of the resources containing resources with this label.
+    ///
+    /// Containment is reflected in resource names, i.e. the name of a contained
+    /// resource is the name of its parent followed by its own name.
+    pub fn parent_label(&self) -> Option<ObjectLabel> {
+        match self {
+            ObjectLabel::SharingSchemaInfo => Some(ObjectLabel::ShareInfo),
+            ObjectLabel::SharingTable => Some(ObjectLabel::SharingSchemaInfo),
+            ObjectLabel::SchemaInfo => Some(ObjectLabel::CatalogInfo),
+            ObjectLabel::TableInfo => Some(ObjectLabel::SchemaInfo),
+            ObjectLabel::ColumnInfo => Some(ObjectLabel::TableInfo),
+            _ => None,
+        }
+    }
+
     pub fn to_ident(&self, id: impl Into<ResourceRef>) -> ResourceIdent {
         match self {
             ObjectLabel::ShareInfo => ResourceIdent::share(id),
-            ObjectLabel::SharingSchemaInfo => ResourceIdent::schema(id),
+            ObjectLabel::SharingSchemaInfo => ResourceIdent::sharing_schema(id),
             ObjectLabel::SharingTable => ResourceIdent::sharing_table(id),
             ObjectLabel::CredentialInfo => ResourceIdent::credential(id),
             ObjectLabel::CatalogInfo => ResourceIdent::catalog(id),
@@ -197,4 +228,12 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_to_ident_round_trip() {
+        for label in ObjectLabel::iter() {
+            let ident = label.to_ident(ResourceRef::Undefined);
+            assert_eq!(ident.label(), &label);
+        }
+    }
 }
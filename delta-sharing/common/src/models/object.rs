@@ -1,3 +1,4 @@
+use chrono::{DateTime, Utc};
 use delta_sharing_derive::object_conversions;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
@@ -32,6 +33,73 @@ pub struct Object {
     pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
 }
 
+impl Object {
+    /// Opaque identifier of the current revision of the object.
+    ///
+    /// The etag is derived from the time of the last modification,
+    /// so every update of the object yields a new etag.
+    pub fn etag(&self) -> String {
+        Self::format_etag(&self.updated_at.unwrap_or(self.created_at))
+    }
+
+    /// Format the etag of an object last modified at `modified_at`.
+    pub fn format_etag(modified_at: &DateTime<Utc>) -> String {
+        format!(
+            "{}.{:09}",
+            modified_at.timestamp(),
+            modified_at.timestamp_subsec_nanos()
+        )
+    }
+
+    /// Parse the modification time an etag was derived from.
+    ///
+    /// # Errors
+    /// - [Conflict](Error::Conflict) If the etag is malformed, since it can not match
+    ///   any revision of an object.
+    pub fn parse_etag(etag: &str) -> Result<DateTime<Utc>, Error> {
+        etag.split_once('.')
+            .filter(|(_, nanos)| nanos.len() == 9)
+            .and_then(|(secs, nanos)| {
+                DateTime::from_timestamp(secs.parse().ok()?, nanos.parse().ok()?)
+            })
+            .ok_or(Error::Conflict)
+    }
+}
+
+impl Resource {
+    /// The etag of the resource, if its type carries one.
+    pub fn etag(&self) -> Option<&str> {
+        match self {
+            Resource::ShareInfo(obj) => obj.etag.as_deref(),
+            Resource::CredentialInfo(obj) => obj.etag.as_deref(),
+            Resource::CatalogInfo(obj) => obj.etag.as_deref(),
+            Resource::SchemaInfo(obj) => obj.etag.as_deref(),
+            Resource::ExternalLocationInfo(obj) => obj.etag.as_deref(),
+            Resource::RecipientInfo(obj) => obj.etag.as_deref(),
+            Resource::SharingSchemaInfo(_)
+            | Resource::SharingTable(_)
+            | Resource::TableInfo(_)
+            | Resource::ColumnInfo(_) => None,
+        }
+    }
+
+    /// Set the etag of the resource, ignored if its type does not carry one.
+    pub fn set_etag(&mut self, etag: Option<String>) {
+        match self {
+            Resource::ShareInfo(obj) => obj.etag = etag,
+            Resource::CredentialInfo(obj) => obj.etag = etag,
+            Resource::CatalogInfo(obj) => obj.etag = etag,
+            Resource::SchemaInfo(obj) => obj.etag = etag,
+            Resource::ExternalLocationInfo(obj) => obj.etag = etag,
+            Resource::RecipientInfo(obj) => obj.etag = etag,
+            Resource::SharingSchemaInfo(_)
+            | Resource::SharingTable(_)
+            | Resource::TableInfo(_)
+            | Resource::ColumnInfo(_) => {}
+        }
+    }
+}
+
 impl ResourceExt for Object {
     fn resource_label(&self) -> &ObjectLabel {
         &self.label
@@ -95,7 +163,9 @@ impl ResourceExt for Resource {
 impl TryFrom<Resource> for Object {
     type Error = Error;
 
-    fn try_from(resource: Resource) -> Result<Self, Self::Error> {
+    fn try_from(mut resource: Resource) -> Result<Self, Self::Error> {
+        // etags are derived from the modification time and never persisted.
+        resource.set_etag(None);
         match resource {
             Resource::ShareInfo(obj) => obj.try_into(),
             Resource::SharingSchemaInfo(obj) => obj.try_into(),
@@ -115,20 +185,21 @@ impl TryFrom<Object> for Resource {
     type Error = Error;
 
     fn try_from(obj: Object) -> Result<Self, Self::Error> {
-        match obj.label {
-            ObjectLabel::ShareInfo => Ok(Resource::ShareInfo(obj.try_into()?)),
-            ObjectLabel::SharingSchemaInfo => Ok(Resource::SharingSchemaInfo(obj.try_into()?)),
-            ObjectLabel::SharingTable => Ok(Resource::SharingTable(obj.try_into()?)),
-            ObjectLabel::CredentialInfo => Ok(Resource::CredentialInfo(obj.try_into()?)),
-            ObjectLabel::CatalogInfo => Ok(Resource::CatalogInfo(obj.try_into()?)),
-            ObjectLabel::SchemaInfo => Ok(Resource::SchemaInfo(obj.try_into()?)),
-            ObjectLabel::TableInfo => Ok(Resource::TableInfo(obj.try_into()?)),
-            ObjectLabel::ExternalLocationInfo => {
-                Ok(Resource::ExternalLocationInfo(obj.try_into()?))
-            }
-            ObjectLabel::RecipientInfo => Ok(Resource::RecipientInfo(obj.try_into()?)),
-            ObjectLabel::ColumnInfo => Ok(Resource::ColumnInfo(obj.try_into()?)),
-        }
+        let etag = obj.etag();
+        let mut resource = match obj.label {
+            ObjectLabel::ShareInfo => Resource::ShareInfo(obj.try_into()?),
+            ObjectLabel::SharingSchemaInfo => Resource::SharingSchemaInfo(obj.try_into()?),
+            ObjectLabel::SharingTable => Resource::SharingTable(obj.try_into()?),
+            ObjectLabel::CredentialInfo => Resource::CredentialInfo(obj.try_into()?),
+            ObjectLabel::CatalogInfo => Resource::CatalogInfo(obj.try_into()?),
+            ObjectLabel::SchemaInfo => Resource::SchemaInfo(obj.try_into()?),
+            ObjectLabel::TableInfo => Resource::TableInfo(obj.try_into()?),
+            ObjectLabel::ExternalLocationInfo => Resource::ExternalLocationInfo(obj.try_into()?),
+            ObjectLabel::RecipientInfo => Resource::RecipientInfo(obj.try_into()?),
+            ObjectLabel::ColumnInfo => Resource::ColumnInfo(obj.try_into()?),
+        };
+        resource.set_etag(Some(etag));
+        Ok(resource)
     }
 }
 
@@ -0,0 +1,735 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+
+use hashlink::LruCache;
+
+use super::{Decision, Permission, Policy, Recipient};
+use crate::{
+    AssociationChange, AssociationLabel, ObjectLabel, PropertyMap, Resource, ResourceExt,
+    ResourceIdent, ResourceName, ResourceRef, ResourceStore, Result, VisibilityFilter,
+};
+
+const DEFAULT_ALLOW_TTL: Duration = Duration::from_secs(60);
+const DEFAULT_DENY_TTL: Duration = Duration::from_secs(10);
+const DEFAULT_MAX_ENTRIES: usize = 10_000;
+
+type CacheKey = (Recipient, ResourceIdent, Permission);
+type Cache = LruCache<CacheKey, (Decision, Instant)>;
+
+/// Policy caching the decisions of another policy.
+///
+/// Decisions are cached per recipient, resource and permission. Allow and deny decisions
+/// expire after separate TTLs, so e.g. newly granted permissions can take effect quicker
+/// than revocations are allowed to linger.
+///
+/// Once the cache is full, the least recently stored decision is evicted. Expired entries
+/// are dropped when they are looked up. Use the [`PolicyCacheInvalidator`] returned by
+/// [`invalidator`](CachedPolicy::invalidator) - e.g. via a [`CacheInvalidatingStore`] -
+/// to drop cached decisions when grants change. Decisions fetched while an invalidation
+/// runs are returned, but not cached, since they may predate the change.
+pub struct CachedPolicy<P: Policy> {
+    inner: P,
+    cache: Arc<RwLock<Cache>>,
+    generation: Arc<AtomicU64>,
+    allow_ttl: Duration,
+    deny_ttl: Duration,
+}
+
+impl<P: Policy> CachedPolicy<P> {
+    /// Create a new instance of [`CachedPolicy`] wrapping the given policy.
+    pub fn new(inner: P) -> Self {
+        Self {
+            inner,
+            cache: Arc::new(RwLock::new(LruCache::new(DEFAULT_MAX_ENTRIES))),
+            generation: Arc::new(AtomicU64::new(0)),
+            allow_ttl: DEFAULT_ALLOW_TTL,
+            deny_ttl: DEFAULT_DENY_TTL,
+        }
+    }
+
+    /// Set the time allow decisions are cached.
+    pub fn with_allow_ttl(mut self, ttl: Duration) -> Self {
+        self.allow_ttl = ttl;
+        self
+    }
+
+    /// Set the time deny decisions are cached.
+    pub fn with_deny_ttl(mut self, ttl: Duration) -> Self {
+        self.deny_ttl = ttl;
+        self
+    }
+
+    /// Set the maximum number of cached decisions.
+    pub fn with_max_entries(self, max_entries: usize) -> Self {
+        self.cache.write().unwrap().set_capacity(max_entries);
+        self
+    }
+
+    /// Get a handle to invalidate cached decisions.
+    pub fn invalidator(&self) -> PolicyCacheInvalidator {
+        PolicyCacheInvalidator {
+            cache: self.cache.clone(),
+            generation: self.generation.clone(),
+        }
+    }
+
+    /// Get a reference to the wrapped policy.
+    pub fn inner(&self) -> &P {
+        &self.inner
+    }
+
+    fn lookup(&self, key: &CacheKey) -> Option<Decision> {
+        let now = Instant::now();
+        // entries are only reordered on insert, so lookups can share the lock.
+        let (decision, expires_at) = *self.cache.read().unwrap().peek(key)?;
+        if expires_at > now {
+            return Some(decision);
+        }
+        let mut cache = self.cache.write().unwrap();
+        if cache
+            .peek(key)
+            .is_some_and(|(_, expires_at)| *expires_at <= now)
+        {
+            cache.remove(key);
+        }
+        None
+    }
+
+    /// Generation of the cache, advanced by every invalidation.
+    fn generation(&self) -> u64 {
+        self.generation.load(Ordering::Acquire)
+    }
+
+    /// Cache decisions fetched from the inner policy at the given generation.
+    fn store(&self, generation: u64, entries: impl IntoIterator<Item = (CacheKey, Decision)>) {
+        let now = Instant::now();
+        let mut cache = self.cache.write().unwrap();
+        // invalidations advance the generation while holding the lock, so decisions
+        // fetched before a concurrent invalidation can not be cached after it.
+        if cache.capacity() == 0 || self.generation() != generation {
+            return;
+        }
+        for (key, decision) in entries {
+            let ttl = match decision {
+                Decision::Allow => self.allow_ttl,
+                Decision::Deny => self.deny_ttl,
+            };
+            if ttl.is_zero() {
+                continue;
+            }
+            cache.insert(key, (decision, now + ttl));
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl<P: Policy> Policy for CachedPolicy<P> {
+    async fn authorize(
+        &self,
+        resource: &ResourceIdent,
+        permission: &Permission,
+        recipient: &Recipient,
+    ) -> Result<Decision> {
+        let key = (recipient.clone(), resource.clone(), permission.clone());
+        if let Some(decision) = self.lookup(&key) {
+            return Ok(decision);
+        }
+        let generation = self.generation();
+        let decision = self
+            .inner
+            .authorize(resource, permission, recipient)
+            .await?;
+        self.store(generation, [(key, decision)]);
+        Ok(decision)
+    }
+
+    async fn authorize_many(
+        &self,
+        resources: &[ResourceIdent],
+        permission: &Permission,
+        recipient: &Recipient,
+    ) -> Result<Vec<Decision>> {
+        let mut decisions = Vec::with_capacity(resources.len());
+        let mut missing = Vec::new();
+        for (idx, resource) in resources.iter().enumerate() {
+            let key = (recipient.clone(), resource.clone(), permission.clone());
+            let decision = self.lookup(&key);
+            if decision.is_none() {
+                missing.push(idx);
+            }
+            decisions.push(decision);
+        }
+
+        if !missing.is_empty() {
+            let uncached = missing
+                .iter()
+                .map(|idx| resources[*idx].clone())
+                .collect::<Vec<_>>();
+            let generation = self.generation();
+            let fetched = self
+                .inner
+                .authorize_many(&uncached, permission, recipient)
+                .await?;
+            for (idx, decision) in missing.iter().zip(fetched.iter()) {
+                decisions[*idx] = Some(*decision);
+            }
+            self.store(
+                generation,
+                uncached
+                    .into_iter()
+                    .zip(fetched)
+                    .map(|(resource, decision)| {
+                        ((recipient.clone(), resource, permission.clone()), decision)
+                    }),
+            );
+        }
+
+        Ok(decisions
+            .into_iter()
+            .map(|d| d.unwrap_or(Decision::Deny))
+            .collect())
+    }
+
+    // filters are evaluated by the store and not cached.
+    async fn visibility_filter(
+        &self,
+        label: &ObjectLabel,
+        permission: &Permission,
+        recipient: &Recipient,
+    ) -> Result<Option<VisibilityFilter>> {
+        self.inner
+            .visibility_filter(label, permission, recipient)
+            .await
+    }
+}
+
+/// Handle to invalidate decisions cached by a [`CachedPolicy`].
+///
+/// The handle can be cloned and handed to the components that modify grants,
+/// e.g. a resource store, so they can drop stale decisions.
+#[derive(Clone)]
+pub struct PolicyCacheInvalidator {
+    cache: Arc<RwLock<Cache>>,
+    generation: Arc<AtomicU64>,
+}
+
+impl PolicyCacheInvalidator {
+    /// Drop all cached decisions.
+    pub fn invalidate_all(&self) {
+        let mut cache = self.cache.write().unwrap();
+        self.generation.fetch_add(1, Ordering::AcqRel);
+        cache.clear();
+    }
+
+    /// Drop all cached decisions for a recipient.
+    pub fn invalidate_recipient(&self, recipient: &Recipient) {
+        self.invalidate_where(|(r, _, _)| r == recipient);
+    }
+
+    /// Drop all cached decisions for a resource.
+    ///
+    /// When the resource is referenced by name, decisions for all resources nested
+    /// within it (e.g. the tables of a schema) are dropped as well. Permissions
+    /// inherited via references by id cannot be tracked, so callers should use
+    /// [`invalidate_all`](Self::invalidate_all) when grants change on a parent
+    /// resource that may have been checked by id.
+    pub fn invalidate_resource(&self, resource: &ResourceIdent) {
+        let matches = |cached: &ResourceIdent| match (resource.reference(), cached.reference()) {
+            (ResourceRef::Name(name), ResourceRef::Name(other)) => {
+                other.path().starts_with(name.path())
+            }
+            _ => cached == resource,
+        };
+        self.invalidate_where(|(_, r, _)| matches(r));
+    }
+
+    fn invalidate_where(&self, predicate: impl Fn(&CacheKey) -> bool) {
+        let mut cache = self.cache.write().unwrap();
+        self.generation.fetch_add(1, Ordering::AcqRel);
+        let stale = cache
+            .iter()
+            .map(|(key, _)| key)
+            .filter(|key| predicate(key))
+            .cloned()
+            .collect::<Vec<_>>();
+        for key in stale {
+            cache.remove(&key);
+        }
+    }
+}
+
+/// [`ResourceStore`] decorator dropping decisions cached by a [`CachedPolicy`] when
+/// the resources or associations they were derived from change.
+///
+/// - Adding or removing grant, ownership or hierarchy associations drops all cached
+///   decisions, since permissions are inherited along the resource hierarchy.
+/// - Creating or deleting a resource drops the decisions cached for it, so e.g. a
+///   recreated resource does not inherit decisions made for its predecessor.
+/// - Renaming a resource drops the decisions cached under its previous and new name.
+///   Moving it to another parent drops all cached decisions, since it inherits
+///   permissions from its new ancestors.
+///
+/// Grants managed via the [permissions API](crate::api::permissions) are written through
+/// the store, so wrapping the store of a handler covers them as well.
+pub struct CacheInvalidatingStore<S> {
+    inner: S,
+    invalidator: PolicyCacheInvalidator,
+}
+
+impl<S: ResourceStore> CacheInvalidatingStore<S> {
+    /// Create a new [`CacheInvalidatingStore`] wrapping the given store.
+    pub fn new(inner: S, invalidator: PolicyCacheInvalidator) -> Self {
+        Self { inner, invalidator }
+    }
+
+    /// Get a reference to the wrapped store.
+    pub fn inner(&self) -> &S {
+        &self.inner
+    }
+
+    fn invalidate_association(&self, label: &AssociationLabel) {
+        if matches!(
+            label,
+            AssociationLabel::HasGrant
+                | AssociationLabel::GrantedTo
+                | AssociationLabel::OwnerOf
+                | AssociationLabel::OwnedBy
+                | AssociationLabel::ParentOf
+                | AssociationLabel::ChildOf
+        ) {
+            self.invalidator.invalidate_all();
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl<S: ResourceStore> ResourceStore for CacheInvalidatingStore<S> {
+    async fn get(&self, id: &ResourceIdent) -> Result<(Resource, ResourceRef)> {
+        self.inner.get(id).await
+    }
+
+    async fn get_many(&self, ids: &[ResourceIdent]) -> Result<Vec<(Resource, ResourceRef)>> {
+        self.inner.get_many(ids).await
+    }
+
+    async fn list(
+        &self,
+        label: &ObjectLabel,
+        namespace: Option<&ResourceName>,
+        filter: Option<&VisibilityFilter>,
+        max_results: Option<usize>,
+        page_token: Option<String>,
+    ) -> Result<(Vec<Resource>, Option<String>)> {
+        self.inner
+            .list(label, namespace, filter, max_results, page_token)
+            .await
+    }
+
+    async fn create(&self, resource: Resource) -> Result<(Resource, ResourceRef)> {
+        let (resource, reference) = self.inner.create(resource).await?;
+        let label = resource.resource_label();
+        self.invalidator
+            .invalidate_resource(&label.to_ident(resource.resource_name()));
+        self.invalidator
+            .invalidate_resource(&label.to_ident(reference.clone()));
+        Ok((resource, reference))
+    }
+
+    async fn delete(&self, id: &ResourceIdent) -> Result<()> {
+        self.inner.delete(id).await?;
+        self.invalidator.invalidate_resource(id);
+        Ok(())
+    }
+
+    async fn update(
+        &self,
+        id: &ResourceIdent,
+        resource: Resource,
+    ) -> Result<(Resource, ResourceRef)> {
+        let (previous, _) = self.inner.get(id).await?;
+        let (resource, reference) = self.inner.update(id, resource).await?;
+        let (name, previous_name) = (resource.resource_name(), previous.resource_name());
+        if name != previous_name {
+            if name.path().split_last().map(|(_, ns)| ns)
+                != previous_name.path().split_last().map(|(_, ns)| ns)
+            {
+                self.invalidator.invalidate_all();
+            } else {
+                let label = resource.resource_label();
+                self.invalidator
+                    .invalidate_resource(&previous.resource_label().to_ident(previous_name));
+                self.invalidator.invalidate_resource(&label.to_ident(name));
+            }
+        }
+        Ok((resource, reference))
+    }
+
+    async fn add_association(
+        &self,
+        from: &ResourceIdent,
+        to: &ResourceIdent,
+        label: &AssociationLabel,
+        properties: Option<PropertyMap>,
+    ) -> Result<()> {
+        self.inner
+            .add_association(from, to, label, properties)
+            .await?;
+        self.invalidate_association(label);
+        Ok(())
+    }
+
+    async fn remove_association(
+        &self,
+        from: &ResourceIdent,
+        to: &ResourceIdent,
+        label: &AssociationLabel,
+    ) -> Result<()> {
+        self.inner.remove_association(from, to, label).await?;
+        self.invalidate_association(label);
+        Ok(())
+    }
+
+    async fn update_association(
+        &self,
+        from: &ResourceIdent,
+        to: &ResourceIdent,
+        label: &AssociationLabel,
+        properties: Option<PropertyMap>,
+    ) -> Result<()> {
+        self.inner
+            .update_association(from, to, label, properties)
+            .await?;
+        self.invalidate_association(label);
+        Ok(())
+    }
+
+    async fn get_association(
+        &self,
+        from: &ResourceIdent,
+        to: &ResourceIdent,
+        label: &AssociationLabel,
+    ) -> Result<Option<PropertyMap>> {
+        self.inner.get_association(from, to, label).await
+    }
+
+    async fn get_associations(
+        &self,
+        from: &ResourceIdent,
+        to: &[ResourceIdent],
+        label: &AssociationLabel,
+    ) -> Result<Vec<Option<PropertyMap>>> {
+        self.inner.get_associations(from, to, label).await
+    }
+
+    async fn list_associations(
+        &self,
+        resource: &ResourceIdent,
+        label: &AssociationLabel,
+        target_label: Option<&ResourceIdent>,
+        filter: Option<&VisibilityFilter>,
+        max_results: Option<usize>,
+        page_token: Option<String>,
+    ) -> Result<(Vec<ResourceIdent>, Option<String>)> {
+        self.inner
+            .list_associations(
+                resource,
+                label,
+                target_label,
+                filter,
+                max_results,
+                page_token,
+            )
+            .await
+    }
+
+    async fn apply_association_changes(&self, changes: &[AssociationChange]) -> Result<()> {
+        self.inner.apply_association_changes(changes).await?;
+        for change in changes {
+            self.invalidate_association(change.label());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use crate::memory::InMemoryResourceStore;
+    use crate::models::{CatalogInfo, RecipientInfo};
+    use crate::{resource_name, ResourceName};
+
+    use super::*;
+
+    /// Policy allowing access to resources named `allowed`, counting the checked resources.
+    #[derive(Default)]
+    struct CountingPolicy {
+        checks: AtomicUsize,
+    }
+
+    #[async_trait::async_trait]
+    impl Policy for CountingPolicy {
+        async fn authorize(
+            &self,
+            resource: &ResourceIdent,
+            _: &Permission,
+            _: &Recipient,
+        ) -> Result<Decision> {
+            self.checks.fetch_add(1, Ordering::SeqCst);
+            Ok(match resource.reference() {
+                ResourceRef::Name(name)
+                    if name.path().last().map(|s| s.as_str()) == Some("allowed") =>
+                {
+                    Decision::Allow
+                }
+                _ => Decision::Deny,
+            })
+        }
+    }
+
+    /// Policy allowing everything, blocking each check until it is released.
+    #[derive(Default)]
+    struct GatedPolicy {
+        started: tokio::sync::Notify,
+        release: tokio::sync::Notify,
+        checks: AtomicUsize,
+    }
+
+    #[async_trait::async_trait]
+    impl Policy for GatedPolicy {
+        async fn authorize(
+            &self,
+            _: &ResourceIdent,
+            _: &Permission,
+            _: &Recipient,
+        ) -> Result<Decision> {
+            self.checks.fetch_add(1, Ordering::SeqCst);
+            self.started.notify_one();
+            self.release.notified().await;
+            Ok(Decision::Allow)
+        }
+    }
+
+    #[test]
+    fn assert_send_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<CachedPolicy<CountingPolicy>>();
+        assert_send_sync::<PolicyCacheInvalidator>();
+    }
+
+    #[tokio::test]
+    async fn caches_decisions() {
+        let policy = CachedPolicy::new(CountingPolicy::default()).with_deny_ttl(Duration::ZERO);
+        let allowed = ResourceIdent::share(resource_name!("allowed"));
+        let denied = ResourceIdent::share(resource_name!("denied"));
+        let recipient = Recipient::user("alice");
+
+        for _ in 0..2 {
+            let decisions = policy
+                .authorize_many(
+                    &[allowed.clone(), denied.clone()],
+                    &Permission::Read,
+                    &recipient,
+                )
+                .await
+                .unwrap();
+            assert_eq!(decisions, vec![Decision::Allow, Decision::Deny]);
+        }
+        // deny decisions are not cached with a zero TTL.
+        assert_eq!(policy.inner().checks.load(Ordering::SeqCst), 3);
+
+        // decisions are cached per permission and recipient.
+        policy
+            .authorize(&allowed, &Permission::Write, &recipient)
+            .await
+            .unwrap();
+        policy
+            .authorize(&allowed, &Permission::Read, &Recipient::user("bob"))
+            .await
+            .unwrap();
+        assert_eq!(policy.inner().checks.load(Ordering::SeqCst), 5);
+    }
+
+    #[tokio::test]
+    async fn invalidates_decisions() {
+        let policy = CachedPolicy::new(CountingPolicy::default());
+        let invalidator = policy.invalidator();
+        let schema = ResourceIdent::schema(ResourceName::new(["catalog", "allowed"]));
+        let table = ResourceIdent::table(ResourceName::new(["catalog", "allowed", "allowed"]));
+        let other = ResourceIdent::table(ResourceName::new(["other", "allowed", "allowed"]));
+        let recipient = Recipient::user("alice");
+        let checks = || policy.inner().checks.load(Ordering::SeqCst);
+
+        let resources = [schema.clone(), table, other];
+        policy
+            .authorize_many(&resources, &Permission::Read, &recipient)
+            .await
+            .unwrap();
+        assert_eq!(checks(), 3);
+
+        invalidator.invalidate_resource(&schema);
+        policy
+            .authorize_many(&resources, &Permission::Read, &recipient)
+            .await
+            .unwrap();
+        assert_eq!(checks(), 5);
+
+        invalidator.invalidate_recipient(&recipient);
+        policy
+            .authorize_many(&resources, &Permission::Read, &recipient)
+            .await
+            .unwrap();
+        assert_eq!(checks(), 8);
+    }
+
+    #[tokio::test]
+    async fn skips_decisions_fetched_during_invalidation() {
+        let policy = CachedPolicy::new(GatedPolicy::default());
+        let invalidator = policy.invalidator();
+        let share = ResourceIdent::share(resource_name!("share"));
+        let recipient = Recipient::user("alice");
+
+        // the grant is revoked while the decision is fetched from the inner policy.
+        let (decision, _) = tokio::join!(
+            policy.authorize(&share, &Permission::Read, &recipient),
+            async {
+                policy.inner().started.notified().await;
+                invalidator.invalidate_all();
+                policy.inner().release.notify_one();
+            }
+        );
+        assert_eq!(decision.unwrap(), Decision::Allow);
+        assert_eq!(policy.cache.read().unwrap().len(), 0);
+
+        let (decision, _) = tokio::join!(
+            policy.authorize(&share, &Permission::Read, &recipient),
+            async {
+                policy.inner().started.notified().await;
+                policy.inner().release.notify_one();
+            }
+        );
+        assert_eq!(decision.unwrap(), Decision::Allow);
+        assert_eq!(policy.inner().checks.load(Ordering::SeqCst), 2);
+        assert_eq!(policy.cache.read().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn evicts_entries() {
+        let policy = CachedPolicy::new(CountingPolicy::default()).with_max_entries(2);
+        let recipient = Recipient::user("alice");
+        for name in ["a", "b", "c"] {
+            policy
+                .authorize(
+                    &ResourceIdent::share(ResourceName::new([name])),
+                    &Permission::Read,
+                    &recipient,
+                )
+                .await
+                .unwrap();
+        }
+        assert_eq!(policy.cache.read().unwrap().len(), 2);
+
+        // the least recently stored decision was evicted.
+        let checks = policy.inner().checks.load(Ordering::SeqCst);
+        for name in ["c", "a"] {
+            policy
+                .authorize(
+                    &ResourceIdent::share(ResourceName::new([name])),
+                    &Permission::Read,
+                    &recipient,
+                )
+                .await
+                .unwrap();
+        }
+        assert_eq!(policy.inner().checks.load(Ordering::SeqCst), checks + 1);
+    }
+
+    #[tokio::test]
+    async fn store_invalidates_decisions() {
+        let policy = CachedPolicy::new(CountingPolicy::default());
+        let store = CacheInvalidatingStore::new(InMemoryResourceStore::new(), policy.invalidator());
+        let recipient = Recipient::user("alice");
+        let checks = || policy.inner().checks.load(Ordering::SeqCst);
+
+        let mut idents = Vec::new();
+        for resource in [
+            Resource::from(CatalogInfo {
+                name: "allowed".to_string(),
+                ..Default::default()
+            }),
+            Resource::from(RecipientInfo {
+                name: "alice".to_string(),
+                ..Default::default()
+            }),
+        ] {
+            let (resource, reference) = store.create(resource).await.unwrap();
+            idents.push(resource.resource_label().to_ident(reference));
+        }
+        let catalog = ResourceIdent::catalog(resource_name!("allowed"));
+        policy
+            .authorize(&catalog, &Permission::Read, &recipient)
+            .await
+            .unwrap();
+        policy
+            .authorize(&catalog, &Permission::Read, &recipient)
+            .await
+            .unwrap();
+        assert_eq!(checks(), 1);
+
+        store
+            .add_association(&idents[1], &idents[0], &AssociationLabel::HasGrant, None)
+            .await
+            .unwrap();
+        policy
+            .authorize(&catalog, &Permission::Read, &recipient)
+            .await
+            .unwrap();
+        assert_eq!(checks(), 2);
+
+        // associations that do not affect permissions keep cached decisions.
+        store
+            .add_association(&idents[0], &idents[1], &AssociationLabel::References, None)
+            .await
+            .unwrap();
+        policy
+            .authorize(&catalog, &Permission::Read, &recipient)
+            .await
+            .unwrap();
+        assert_eq!(checks(), 2);
+
+        // renaming drops decisions cached for the previous name ...
+        let renamed = ResourceIdent::catalog(resource_name!("renamed"));
+        policy
+            .authorize(&renamed, &Permission::Read, &recipient)
+            .await
+            .unwrap();
+        assert_eq!(checks(), 3);
+        let rename = |name: &str| {
+            Resource::from(CatalogInfo {
+                name: name.to_string(),
+                ..Default::default()
+            })
+        };
+        store.update(&idents[0], rename("renamed")).await.unwrap();
+        for ident in [&catalog, &renamed] {
+            policy
+                .authorize(ident, &Permission::Read, &recipient)
+                .await
+                .unwrap();
+        }
+        // ... as well as for the new name.
+        assert_eq!(checks(), 5);
+        store.update(&idents[0], rename("allowed")).await.unwrap();
+
+        store
+            .delete(&ResourceIdent::catalog(resource_name!("allowed")))
+            .await
+            .unwrap();
+        policy
+            .authorize(&catalog, &Permission::Read, &recipient)
+            .await
+            .unwrap();
+        assert_eq!(checks(), 6);
+    }
+}
@@ -10,13 +10,20 @@ use std::sync::Arc;
 use strum::AsRefStr;
 
 use crate::api::SecuredAction;
-use crate::{Error, ResourceExt, ResourceIdent, Result};
+use crate::{
+    Error, ObjectLabel, Resource, ResourceExt, ResourceIdent, ResourceName, ResourceStore, Result,
+    VisibilityFilter,
+};
 
+pub use cached::*;
 pub use constant::*;
+pub use rules::*;
 
+mod cached;
 mod constant;
+mod rules;
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub enum Recipient {
     Anonymous,
     User(String),
@@ -38,7 +45,7 @@ impl Recipient {
 }
 
 /// Permission that a policy can authorize.
-#[derive(Debug, Clone, AsRefStr, PartialEq, Eq, strum::EnumString)]
+#[derive(Debug, Clone, AsRefStr, PartialEq, Eq, Hash, strum::EnumString)]
 #[strum(serialize_all = "snake_case", ascii_case_insensitive)]
 pub enum Permission {
     Read,
@@ -57,7 +64,9 @@ impl From<Permission> for String {
 }
 
 /// Decision made by a policy.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, AsRefStr, serde::Serialize, serde::Deserialize)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
 pub enum Decision {
     /// Allow the action.
     Allow,
@@ -104,6 +113,22 @@ pub trait Policy: Send + Sync + 'static {
         Ok(decisions)
     }
 
+    /// Get a filter selecting the resources with the given label the recipient holds the
+    /// permission on.
+    ///
+    /// The filter is pushed down into list operations of the [`ResourceStore`] instead of
+    /// authorizing each listed resource, and must select exactly the resources
+    /// [`authorize`](Self::authorize) allows. Policies that cannot express their decisions
+    /// as a filter return `None`.
+    async fn visibility_filter(
+        &self,
+        _label: &ObjectLabel,
+        _permission: &Permission,
+        _recipient: &Recipient,
+    ) -> Result<Option<VisibilityFilter>> {
+        Ok(None)
+    }
+
     /// Check if the policy allows the action, and return an error if denied.
     async fn authorize_checked(
         &self,
@@ -123,7 +148,7 @@ pub trait ProvidesPolicy: Send + Sync + 'static {
 }
 
 #[async_trait::async_trait]
-impl<T: Policy> Policy for Arc<T> {
+impl<T: Policy + ?Sized> Policy for Arc<T> {
     async fn authorize(
         &self,
         resource: &ResourceIdent,
@@ -141,6 +166,15 @@ impl<T: Policy> Policy for Arc<T> {
     ) -> Result<Vec<Decision>> {
         T::authorize_many(self, resources, permission, recipient).await
     }
+
+    async fn visibility_filter(
+        &self,
+        label: &ObjectLabel,
+        permission: &Permission,
+        recipient: &Recipient,
+    ) -> Result<Option<VisibilityFilter>> {
+        T::visibility_filter(self, label, permission, recipient).await
+    }
 }
 
 #[async_trait::async_trait]
@@ -166,6 +200,17 @@ impl<T: ProvidesPolicy> Policy for T {
             .authorize_many(resources, permission, recipient)
             .await
     }
+
+    async fn visibility_filter(
+        &self,
+        label: &ObjectLabel,
+        permission: &Permission,
+        recipient: &Recipient,
+    ) -> Result<Option<VisibilityFilter>> {
+        self.policy()
+            .visibility_filter(label, permission, recipient)
+            .await
+    }
 }
 
 /// Checks if the recipient has the given permission for each resource,
@@ -177,7 +222,154 @@ pub async fn process_resources<T: Policy + Sized, R: ResourceExt + Send>(
     resources: &mut Vec<R>,
 ) -> Result<()> {
     let res = resources.iter().map(|r| r.into()).collect::<Vec<_>>();
-    let mut decisions = handler.authorize_many(&res, permission, recipient).await?;
-    resources.retain(|_| decisions.pop() == Some(Decision::Allow));
+    let decisions = handler.authorize_many(&res, permission, recipient).await?;
+    let mut decisions = decisions.into_iter();
+    resources.retain(|_| decisions.next() == Some(Decision::Allow));
     Ok(())
 }
+
+/// Lists resources the recipient holds the permission on.
+///
+/// The [`VisibilityFilter`] of the policy is pushed down into the store, so pages are filled
+/// with visible resources. If the policy does not provide a filter, listed pages are
+/// authorized instead, and further pages are fetched until `max_results` resources are
+/// visible or the listing is exhausted. Without `max_results`, a single page is authorized
+/// and may contain fewer resources than the store's default page size.
+pub async fn list_visible<T: ResourceStore + Policy + Sized>(
+    handler: &T,
+    recipient: &Recipient,
+    permission: &Permission,
+    label: &ObjectLabel,
+    namespace: Option<&ResourceName>,
+    max_results: Option<usize>,
+    page_token: Option<String>,
+) -> Result<(Vec<Resource>, Option<String>)> {
+    let filter = handler
+        .visibility_filter(label, permission, recipient)
+        .await?;
+    if filter.is_some() {
+        return handler
+            .list(label, namespace, filter.as_ref(), max_results, page_token)
+            .await;
+    }
+
+    let mut visible = Vec::new();
+    let mut page_token = page_token;
+    loop {
+        // never request more than is missing, so no visible resource is skipped.
+        let remaining = max_results.map(|max| max - visible.len());
+        let (mut resources, next_page_token) = handler
+            .list(label, namespace, None, remaining, page_token)
+            .await?;
+        process_resources(handler, recipient, permission, &mut resources).await?;
+        visible.extend(resources);
+        match (max_results, next_page_token) {
+            (Some(max), Some(next)) if visible.len() < max => page_token = Some(next),
+            (_, next_page_token) => return Ok((visible, next_page_token)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::memory::InMemoryResourceStore;
+    use crate::models::CatalogInfo;
+    use crate::ProvidesResourceStore;
+
+    struct RulesHandler {
+        store: InMemoryResourceStore,
+        policy: Arc<dyn Policy>,
+    }
+
+    impl ProvidesResourceStore for RulesHandler {
+        fn store(&self) -> &dyn ResourceStore {
+            &self.store
+        }
+    }
+
+    impl ProvidesPolicy for RulesHandler {
+        fn policy(&self) -> &Arc<dyn Policy> {
+            &self.policy
+        }
+    }
+
+    #[tokio::test]
+    async fn list_visible_fills_pages() {
+        let rules = r#"
+rules:
+  - principals: ["*"]
+    resources: [{ name: catalog_1 }, { name: catalog_4 }, { name: catalog_5 }]
+    permissions: [read]
+"#;
+        let handler = RulesHandler {
+            store: InMemoryResourceStore::new(),
+            policy: Arc::new(RulesPolicy::new(RuleSet::parse(rules).unwrap())),
+        };
+        for idx in 0..7 {
+            let catalog = CatalogInfo {
+                name: format!("catalog_{idx}"),
+                ..Default::default()
+            };
+            handler.create(catalog.into()).await.unwrap();
+        }
+
+        let mut names = Vec::new();
+        let mut page_token = None;
+        loop {
+            let (resources, next) = list_visible(
+                &handler,
+                &Recipient::anonymous(),
+                &Permission::Read,
+                &ObjectLabel::CatalogInfo,
+                None,
+                Some(2),
+                page_token,
+            )
+            .await
+            .unwrap();
+            // pages are only short at the end of the listing.
+            assert!(resources.len() == 2 || next.is_none(), "short page");
+            names.extend(resources.iter().map(|r| r.resource_name().to_string()));
+            match next {
+                Some(next) => page_token = Some(next),
+                None => break,
+            }
+        }
+        names.sort();
+        assert_eq!(names, vec!["catalog_1", "catalog_4", "catalog_5"]);
+    }
+
+    #[tokio::test]
+    async fn process_resources_in_order() {
+        let rules = r#"
+rules:
+  - principals: ["*"]
+    resources: [{ name: first }, { name: third }]
+    permissions: [read]
+"#;
+        let policy = RulesPolicy::new(RuleSet::parse(rules).unwrap());
+        let mut resources = ["first", "second", "third", "fourth"]
+            .into_iter()
+            .map(|name| {
+                Resource::from(CatalogInfo {
+                    name: name.to_string(),
+                    ..Default::default()
+                })
+            })
+            .collect::<Vec<_>>();
+        process_resources(
+            &policy,
+            &Recipient::anonymous(),
+            &Permission::Read,
+            &mut resources,
+        )
+        .await
+        .unwrap();
+        let names = resources
+            .iter()
+            .map(|r| r.resource_name().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["first", "third"]);
+    }
+}
@@ -0,0 +1,641 @@
+//! Declarative authorization rules loaded from a file.
+//!
+//! Rules are defined in YAML (or JSON, which is valid YAML) and matched against the recipient,
+//! the resource and the requested permission:
+//!
+//! ```yaml
+//! rules:
+//!   - description: analysts may read everything in the sales share
+//!     principals:
+//!       - claim: { name: shares, value: sales }
+//!       - user: alice
+//!     resources:
+//!       - prefix: sales
+//!     permissions: [read, use]
+//!   - description: nobody may touch the restricted schema
+//!     effect: deny
+//!     principals: ["*"]
+//!     resources:
+//!       - label: sharing_schema_info
+//!         name: "*.restricted"
+//!     permissions: ["*"]
+//! ```
+//!
+//! A request is allowed if at least one allow rule and no deny rule matches it.
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, SystemTime};
+
+use serde::Deserialize;
+
+use super::{Decision, Permission, Policy, Recipient};
+use crate::{
+    Error, ObjectLabel, ResourceExt, ResourceIdent, ResourceName, ResourceRef, ResourceStore,
+    Result,
+};
+
+/// A set of authorization rules.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct RuleSet {
+    #[serde(default)]
+    pub rules: Vec<Rule>,
+}
+
+impl RuleSet {
+    /// Parse a rule set from YAML or JSON.
+    pub fn parse(data: &str) -> Result<Self> {
+        serde_yml::from_str(data)
+            .map_err(|e| Error::invalid_argument(format!("invalid policy rules: {e}")))
+    }
+
+    /// Get all rules matching a request.
+    pub fn matching(
+        &self,
+        resource: &ResourceIdent,
+        permission: &Permission,
+        recipient: &Recipient,
+    ) -> Vec<&Rule> {
+        self.rules
+            .iter()
+            .filter(|rule| rule.matches(resource, permission, recipient))
+            .collect()
+    }
+
+    /// Whether any rule matches resources by name or prefix.
+    fn matches_names(&self) -> bool {
+        self.rules.iter().any(|rule| {
+            rule.resources
+                .iter()
+                .any(|r| r.name.is_some() || r.prefix.is_some())
+        })
+    }
+
+    /// Decide a request, explicit denies take precedence over allows.
+    pub fn decide(
+        &self,
+        resource: &ResourceIdent,
+        permission: &Permission,
+        recipient: &Recipient,
+    ) -> Decision {
+        let matching = self.matching(resource, permission, recipient);
+        if matching.is_empty() || matching.iter().any(|r| r.effect == Effect::Deny) {
+            Decision::Deny
+        } else {
+            Decision::Allow
+        }
+    }
+}
+
+/// The effect of a matching rule.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Effect {
+    #[default]
+    Allow,
+    Deny,
+}
+
+/// A single authorization rule.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Rule {
+    /// Human readable description of the rule.
+    #[serde(default)]
+    pub description: Option<String>,
+
+    /// Whether the rule allows or denies matching requests.
+    #[serde(default)]
+    pub effect: Effect,
+
+    /// The principals the rule applies to.
+    pub principals: Vec<PrincipalMatcher>,
+
+    /// The resources the rule applies to.
+    pub resources: Vec<ResourceMatcher>,
+
+    /// The permissions the rule applies to.
+    pub permissions: Vec<PermissionMatcher>,
+}
+
+impl Rule {
+    fn matches(
+        &self,
+        resource: &ResourceIdent,
+        permission: &Permission,
+        recipient: &Recipient,
+    ) -> bool {
+        self.permissions.iter().any(|p| p.matches(permission))
+            && self.principals.iter().any(|p| p.matches(recipient))
+            && self.resources.iter().any(|r| r.matches(resource))
+    }
+}
+
+/// Matches the recipient of a request.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(try_from = "RawPrincipalMatcher")]
+pub enum PrincipalMatcher {
+    /// Matches all recipients.
+    Any,
+
+    /// Matches anonymous recipients.
+    Anonymous,
+
+    /// Matches users by name, `*` matches all users.
+    User(String),
+
+    /// Matches custom recipients by the value of a claim.
+    ///
+    /// Custom recipients are expected to be JSON encoded, as done by the authenticators
+    /// in the profiles crate. Claims are looked up in the top-level object or, for
+    /// recipients encoded as a tagged enum, in the object of the variant. Array claims
+    /// match if any element equals the value.
+    Claim { name: String, value: String },
+}
+
+/// Serialized form of [`PrincipalMatcher`], i.e. `"*"`, `anonymous`, `{ user: <name> }`
+/// or `{ claim: { name: <name>, value: <value> } }`.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawPrincipalMatcher {
+    Keyword(String),
+    User { user: String },
+    Claim { claim: RawClaim },
+}
+
+#[derive(Deserialize)]
+struct RawClaim {
+    name: String,
+    value: String,
+}
+
+impl TryFrom<RawPrincipalMatcher> for PrincipalMatcher {
+    type Error = String;
+
+    fn try_from(value: RawPrincipalMatcher) -> std::result::Result<Self, Self::Error> {
+        match value {
+            RawPrincipalMatcher::Keyword(k) if k == "*" => Ok(PrincipalMatcher::Any),
+            RawPrincipalMatcher::Keyword(k) if k == "anonymous" => Ok(PrincipalMatcher::Anonymous),
+            RawPrincipalMatcher::Keyword(k) => Err(format!("unknown principal: {k}")),
+            RawPrincipalMatcher::User { user } => Ok(PrincipalMatcher::User(user)),
+            RawPrincipalMatcher::Claim { claim } => Ok(PrincipalMatcher::Claim {
+                name: claim.name,
+                value: claim.value,
+            }),
+        }
+    }
+}
+
+impl PrincipalMatcher {
+    fn matches(&self, recipient: &Recipient) -> bool {
+        match (self, recipient) {
+            (PrincipalMatcher::Any, _) => true,
+            (PrincipalMatcher::Anonymous, Recipient::Anonymous) => true,
+            (PrincipalMatcher::User(pattern), Recipient::User(name)) => {
+                pattern == "*" || pattern == name
+            }
+            (PrincipalMatcher::Claim { name, value }, Recipient::Custom(data)) => {
+                claim_matches(data, name, value)
+            }
+            _ => false,
+        }
+    }
+}
+
+fn claim_matches(data: &[u8], name: &str, expected: &str) -> bool {
+    use serde_json::Value;
+
+    let Ok(Value::Object(mut claims)) = serde_json::from_slice::<Value>(data) else {
+        return false;
+    };
+    if !claims.contains_key(name) && claims.len() == 1 {
+        match claims.into_iter().next() {
+            Some((_, Value::Object(inner))) => claims = inner,
+            _ => return false,
+        }
+    }
+    let value_matches = |value: &Value| match value {
+        Value::String(s) => s == expected,
+        Value::Bool(b) => expected.parse::<bool>().is_ok_and(|e| e == *b),
+        Value::Number(n) => expected
+            .parse::<serde_json::Number>()
+            .is_ok_and(|e| &e == n),
+        _ => false,
+    };
+    match claims.get(name) {
+        Some(Value::Array(values)) => values.iter().any(value_matches),
+        Some(value) => value_matches(value),
+        None => false,
+    }
+}
+
+/// Matches the resource of a request.
+///
+/// Names are matched against dot separated patterns, where `*` matches any single
+/// segment, or as a prefix, matching the resource and all resources nested within it.
+/// Matchers without a name or prefix match all resources with the given label, including
+/// undefined resources and resources referenced by id. Resources referenced by id only
+/// match names and prefixes if the [`RulesPolicy`] can resolve them in its store.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ResourceMatcher {
+    /// The label of the resource, matches all labels if not set.
+    #[serde(default)]
+    pub label: Option<ObjectLabel>,
+
+    /// Pattern for the name of the resource.
+    #[serde(default)]
+    pub name: Option<String>,
+
+    /// Prefix of the name of the resource.
+    #[serde(default)]
+    pub prefix: Option<String>,
+}
+
+impl ResourceMatcher {
+    fn matches(&self, resource: &ResourceIdent) -> bool {
+        if self.label.is_some_and(|label| &label != resource.label()) {
+            return false;
+        }
+        if self.name.is_none() && self.prefix.is_none() {
+            return true;
+        }
+        let ResourceRef::Name(name) = resource.reference() else {
+            return false;
+        };
+        let name_matches = self.name.as_ref().map_or(true, |pattern| {
+            let pattern = pattern.split('.').collect::<Vec<_>>();
+            pattern.len() == name.len()
+                && pattern
+                    .iter()
+                    .zip(name.iter())
+                    .all(|(p, s)| *p == "*" || p.eq_ignore_ascii_case(s))
+        });
+        let prefix_matches = self.prefix.as_ref().map_or(true, |prefix| {
+            name.prefix_matches(&ResourceName::from_naive_str_split(prefix))
+        });
+        name_matches && prefix_matches
+    }
+}
+
+/// Matches the permission of a request, `*` matches all permissions.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(try_from = "String")]
+pub enum PermissionMatcher {
+    Any,
+    Permission(Permission),
+}
+
+impl PermissionMatcher {
+    fn matches(&self, permission: &Permission) -> bool {
+        match self {
+            PermissionMatcher::Any => true,
+            PermissionMatcher::Permission(p) => p == permission,
+        }
+    }
+}
+
+impl TryFrom<String> for PermissionMatcher {
+    type Error = String;
+
+    fn try_from(value: String) -> std::result::Result<Self, Self::Error> {
+        if value == "*" {
+            return Ok(PermissionMatcher::Any);
+        }
+        Permission::from_str(&value)
+            .map(PermissionMatcher::Permission)
+            .map_err(|_| format!("unknown permission: {value}"))
+    }
+}
+
+/// [`Policy`] evaluating a [`RuleSet`] loaded from a file.
+///
+/// Use [`watch`](RulesPolicy::watch) to pick up changes to the file while the server is
+/// running. If the file cannot be loaded after a change, the previous rules stay in effect.
+///
+/// Listed resources are referenced by id, configure a store with
+/// [`with_store`](RulesPolicy::with_store) to match them against names and prefixes.
+#[derive(Clone)]
+pub struct RulesPolicy {
+    path: Option<PathBuf>,
+    rules: Arc<RwLock<(RuleSet, Option<SystemTime>)>>,
+    store: Option<Arc<dyn ResourceStore>>,
+}
+
+impl RulesPolicy {
+    /// Create a new [`RulesPolicy`] from a fixed set of rules.
+    pub fn new(rules: RuleSet) -> Self {
+        Self {
+            path: None,
+            rules: Arc::new(RwLock::new((rules, None))),
+            store: None,
+        }
+    }
+
+    /// Load the rules from a YAML or JSON file.
+    pub fn try_from_file(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let (rules, modified) = load(&path)?;
+        Ok(Self {
+            path: Some(path),
+            rules: Arc::new(RwLock::new((rules, modified))),
+            store: None,
+        })
+    }
+
+    /// Resolve resources referenced by id in the given store.
+    pub fn with_store(mut self, store: Arc<dyn ResourceStore>) -> Self {
+        self.store = Some(store);
+        self
+    }
+
+    /// Get a copy of the rules currently in effect.
+    pub fn rules(&self) -> RuleSet {
+        self.rules.read().unwrap().0.clone()
+    }
+
+    /// Reload the rules if the file was modified since it was last loaded.
+    ///
+    /// Returns `true` if the rules were reloaded.
+    pub fn reload(&self) -> Result<bool> {
+        let Some(path) = &self.path else {
+            return Ok(false);
+        };
+        let modified = std::fs::metadata(path).map_err(read_error)?.modified().ok();
+        if modified.is_some() && modified == self.rules.read().unwrap().1 {
+            return Ok(false);
+        }
+        *self.rules.write().unwrap() = load(path)?;
+        Ok(true)
+    }
+
+    /// Periodically check the rules file for changes and reload it.
+    pub fn watch(&self, interval: Duration) -> tokio::task::JoinHandle<()> {
+        let policy = self.clone();
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            loop {
+                ticker.tick().await;
+                match policy.reload() {
+                    Ok(true) => tracing::info!("reloaded policy rules from {:?}", policy.path),
+                    Ok(false) => {}
+                    Err(e) => tracing::warn!("failed to reload policy rules: {e}"),
+                }
+            }
+        })
+    }
+}
+
+fn load(path: &Path) -> Result<(RuleSet, Option<SystemTime>)> {
+    let modified = std::fs::metadata(path).map_err(read_error)?.modified().ok();
+    let rules = RuleSet::parse(&std::fs::read_to_string(path).map_err(read_error)?)?;
+    Ok((rules, modified))
+}
+
+fn read_error(err: std::io::Error) -> Error {
+    Error::generic(format!("failed to read policy rules: {err}"))
+}
+
+impl RulesPolicy {
+    /// Replace references by id with references by name, if any rule matches on names.
+    async fn resolve_names(&self, resources: &[ResourceIdent]) -> Result<Vec<ResourceIdent>> {
+        let mut resolved = resources.to_vec();
+        let Some(store) = &self.store else {
+            return Ok(resolved);
+        };
+        if !self.rules.read().unwrap().0.matches_names() {
+            return Ok(resolved);
+        }
+        let (positions, ids): (Vec<_>, Vec<_>) = resources
+            .iter()
+            .enumerate()
+            .filter(|(_, r)| matches!(r.reference(), ResourceRef::Uuid(_)))
+            .map(|(i, r)| (i, r.clone()))
+            .unzip();
+        if ids.is_empty() {
+            return Ok(resolved);
+        }
+        for (position, (resource, _)) in positions.into_iter().zip(store.get_many(&ids).await?) {
+            resolved[position] = resource.resource_label().to_ident(resource.resource_name());
+        }
+        Ok(resolved)
+    }
+}
+
+#[async_trait::async_trait]
+impl Policy for RulesPolicy {
+    async fn authorize(
+        &self,
+        resource: &ResourceIdent,
+        permission: &Permission,
+        recipient: &Recipient,
+    ) -> Result<Decision> {
+        Ok(self
+            .authorize_many(std::slice::from_ref(resource), permission, recipient)
+            .await?
+            .pop()
+            .unwrap_or(Decision::Deny))
+    }
+
+    async fn authorize_many(
+        &self,
+        resources: &[ResourceIdent],
+        permission: &Permission,
+        recipient: &Recipient,
+    ) -> Result<Vec<Decision>> {
+        let resources = self.resolve_names(resources).await?;
+        let rules = self.rules.read().unwrap();
+        Ok(resources
+            .iter()
+            .map(|resource| rules.0.decide(resource, permission, recipient))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use bytes::Bytes;
+
+    use super::*;
+    use crate::memory::InMemoryResourceStore;
+    use crate::models::ShareInfo;
+
+    const RULES: &str = r#"
+rules:
+  - description: sales
+    principals:
+      - claim: { name: shares, value: sales }
+      - user: alice
+    resources:
+      - prefix: sales
+    permissions: [read, use]
+  - description: restricted
+    effect: deny
+    principals: ["*"]
+    resources:
+      - label: sharing_schema_info
+        name: "*.restricted"
+    permissions: ["*"]
+  - description: listing
+    principals: [anonymous]
+    resources:
+      - label: share_info
+    permissions: [read]
+"#;
+
+    fn claims(shares: &[&str]) -> Recipient {
+        let data = serde_json::json!({ "Profile": { "sub": "bob", "shares": shares } });
+        Recipient::custom(Bytes::from(serde_json::to_vec(&data).unwrap()))
+    }
+
+    #[test]
+    fn assert_send_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<RulesPolicy>();
+    }
+
+    #[tokio::test]
+    async fn evaluates_rules() {
+        let policy = RulesPolicy::new(RuleSet::parse(RULES).unwrap());
+        let schema = ResourceIdent::sharing_schema(ResourceName::new(["sales", "public"]));
+        let restricted = ResourceIdent::sharing_schema(ResourceName::new(["sales", "restricted"]));
+        let other = ResourceIdent::share(ResourceName::new(["other"]));
+
+        let cases = [
+            (
+                &schema,
+                Permission::Read,
+                Recipient::user("alice"),
+                Decision::Allow,
+            ),
+            (
+                &schema,
+                Permission::Write,
+                Recipient::user("alice"),
+                Decision::Deny,
+            ),
+            (
+                &schema,
+                Permission::Read,
+                Recipient::user("bob"),
+                Decision::Deny,
+            ),
+            (
+                &schema,
+                Permission::Use,
+                claims(&["sales"]),
+                Decision::Allow,
+            ),
+            (&schema, Permission::Use, claims(&["other"]), Decision::Deny),
+            (
+                &restricted,
+                Permission::Read,
+                Recipient::user("alice"),
+                Decision::Deny,
+            ),
+            (
+                &other,
+                Permission::Read,
+                Recipient::anonymous(),
+                Decision::Allow,
+            ),
+            (
+                &other,
+                Permission::Read,
+                Recipient::user("alice"),
+                Decision::Deny,
+            ),
+        ];
+        for (resource, permission, recipient, expected) in cases {
+            let decision = policy
+                .authorize(resource, &permission, &recipient)
+                .await
+                .unwrap();
+            assert_eq!(
+                decision, expected,
+                "{resource:?} {permission:?} {recipient:?}"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn matches_resources_by_id() {
+        let store = Arc::new(InMemoryResourceStore::new());
+        let mut shares = Vec::new();
+        for name in ["sales", "marketing", "other"] {
+            let share = ShareInfo {
+                name: name.to_string(),
+                ..Default::default()
+            };
+            let (_, id) = store.create(share.into()).await.unwrap();
+            shares.push(ResourceIdent::share(id));
+        }
+        let rules = r#"
+rules:
+  - principals: ["*"]
+    resources: [{ prefix: sales }, { label: share_info, name: marketing }]
+    permissions: [read]
+"#;
+        let recipient = Recipient::anonymous();
+
+        let policy = RulesPolicy::new(RuleSet::parse(rules).unwrap()).with_store(store.clone());
+        let decisions = policy
+            .authorize_many(&shares, &Permission::Read, &recipient)
+            .await
+            .unwrap();
+        assert_eq!(
+            decisions,
+            vec![Decision::Allow, Decision::Allow, Decision::Deny]
+        );
+
+        // without a store, references by id do not match names.
+        let policy = RulesPolicy::new(RuleSet::parse(rules).unwrap());
+        let decisions = policy
+            .authorize_many(&shares, &Permission::Read, &recipient)
+            .await
+            .unwrap();
+        assert_eq!(decisions, vec![Decision::Deny; 3]);
+    }
+
+    #[test]
+    fn rejects_invalid_rules() {
+        let rules =
+            "rules:\n  - principals: [anonymous]\n    resources: [{}]\n    permissions: [fly]";
+        assert!(RuleSet::parse(rules).is_err());
+    }
+
+    #[tokio::test]
+    async fn reloads_rules() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("rules.yaml");
+        std::fs::write(&path, "rules: []").unwrap();
+        let policy = RulesPolicy::try_from_file(&path).unwrap();
+        let share = ResourceIdent::share(ResourceName::new(["sales"]));
+        let recipient = Recipient::user("alice");
+
+        let decision = policy
+            .authorize(&share, &Permission::Read, &recipient)
+            .await
+            .unwrap();
+        assert_eq!(decision, Decision::Deny);
+
+        // make sure the modification time changes on coarse grained file systems.
+        std::thread::sleep(Duration::from_millis(10));
+        std::fs::write(&path, RULES).unwrap();
+        let file = std::fs::File::options().write(true).open(&path).unwrap();
+        file.set_modified(SystemTime::now() + Duration::from_secs(1))
+            .unwrap();
+        assert!(policy.reload().unwrap());
+        let decision = policy
+            .authorize(&share, &Permission::Read, &recipient)
+            .await
+            .unwrap();
+        assert_eq!(decision, Decision::Allow);
+
+        // invalid rules keep the previous rules in effect.
+        std::fs::write(&path, "rules: [").unwrap();
+        file.set_modified(SystemTime::now() + Duration::from_secs(2))
+            .unwrap();
+        assert!(policy.reload().is_err());
+        assert_eq!(policy.rules().rules.len(), 3);
+    }
+}
@@ -231,6 +231,8 @@ pub enum AssociationLabel {
     PartOf,
     References,
     ReferencedBy,
+    HasGrant,
+    GrantedTo,
 }
 
 impl AssociationLabel {
@@ -250,6 +252,8 @@ impl AssociationLabel {
             AssociationLabel::ReferencedBy => Some(AssociationLabel::References),
             AssociationLabel::OwnedBy => Some(AssociationLabel::OwnerOf),
             AssociationLabel::OwnerOf => Some(AssociationLabel::OwnedBy),
+            AssociationLabel::HasGrant => Some(AssociationLabel::GrantedTo),
+            AssociationLabel::GrantedTo => Some(AssociationLabel::HasGrant),
         }
     }
 }
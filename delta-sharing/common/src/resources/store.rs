@@ -1,17 +1,134 @@
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use itertools::Itertools;
+use uuid::Uuid;
 
 use crate::{
-    AssociationLabel, ObjectLabel, PropertyMap, Resource, ResourceIdent, ResourceName, ResourceRef,
-    Result,
+    AssociationLabel, ObjectLabel, Permission, PropertyMap, Resource, ResourceIdent, ResourceName,
+    ResourceRef, Result,
 };
 
+/// Restriction of listed resources to those visible to a recipient.
+///
+/// Filters are pushed down into the store, so that pages only contain visible resources
+/// and their size does not depend on how many resources are hidden from the recipient.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VisibilityFilter {
+    /// Only resources with one of the given ids are visible.
+    Ids(HashSet<Uuid>),
+    /// Resources on which the principal holds the permission.
+    ///
+    /// The permission is held if the principal owns the resource or one of its ancestors,
+    /// or is granted the permission - or [`Manage`](Permission::Manage) - on any of them.
+    /// Grants are [`HasGrant`](AssociationLabel::HasGrant) associations from the
+    /// [`RecipientInfo`](ObjectLabel::RecipientInfo) named after the principal.
+    Grants {
+        principal: String,
+        permission: Permission,
+    },
+}
+
+/// Change to an association, applied with [`ResourceStore::apply_association_changes`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum AssociationChange {
+    /// Add an association, see [`ResourceStore::add_association`].
+    Add {
+        from: ResourceIdent,
+        to: ResourceIdent,
+        label: AssociationLabel,
+        properties: Option<PropertyMap>,
+    },
+    /// Replace the properties of an association, see [`ResourceStore::update_association`].
+    Update {
+        from: ResourceIdent,
+        to: ResourceIdent,
+        label: AssociationLabel,
+        properties: Option<PropertyMap>,
+    },
+    /// Remove an association, see [`ResourceStore::remove_association`].
+    Remove {
+        from: ResourceIdent,
+        to: ResourceIdent,
+        label: AssociationLabel,
+    },
+}
+
+impl AssociationChange {
+    /// The label of the changed association.
+    pub fn label(&self) -> &AssociationLabel {
+        match self {
+            Self::Add { label, .. } | Self::Update { label, .. } | Self::Remove { label, .. } => {
+                label
+            }
+        }
+    }
+
+    async fn apply<S: ResourceStore + ?Sized>(&self, store: &S) -> Result<()> {
+        match self {
+            Self::Add {
+                from,
+                to,
+                label,
+                properties,
+            } => {
+                store
+                    .add_association(from, to, label, properties.clone())
+                    .await
+            }
+            Self::Update {
+                from,
+                to,
+                label,
+                properties,
+            } => {
+                store
+                    .update_association(from, to, label, properties.clone())
+                    .await
+            }
+            Self::Remove { from, to, label } => store.remove_association(from, to, label).await,
+        }
+    }
+
+    /// The change restoring the current state of the association in the store.
+    async fn inverse<S: ResourceStore + ?Sized>(&self, store: &S) -> Result<Self> {
+        Ok(match self {
+            Self::Add {
+                from, to, label, ..
+            } => Self::Remove {
+                from: from.clone(),
+                to: to.clone(),
+                label: label.clone(),
+            },
+            Self::Update {
+                from, to, label, ..
+            } => Self::Update {
+                from: from.clone(),
+                to: to.clone(),
+                label: label.clone(),
+                properties: store.get_association(from, to, label).await?,
+            },
+            Self::Remove { from, to, label } => Self::Add {
+                from: from.clone(),
+                to: to.clone(),
+                label: label.clone(),
+                properties: store.get_association(from, to, label).await?,
+            },
+        })
+    }
+}
+
 /// Generic store that can be used to store and retrieve resources.
 ///
 /// Any implementation must conform to the following rules:
 /// - Id fields are managed by the store and must be globally unique.
 ///   If the id field is set on a resource, it can be ignored.
+/// - Names must be unique among resources with the same label.
+/// - Resources returned by the store carry an etag identifying their current revision,
+///   if their type has an etag field. The etag must change whenever a resource is updated.
+///
+/// Implementations can be checked against these rules with the suites in
+/// `testing`, available with the `integration` feature.
 #[async_trait::async_trait]
 pub trait ResourceStore: Send + Sync + 'static {
     /// Get a resource by its identifier.
@@ -36,12 +153,14 @@ pub trait ResourceStore: Send + Sync + 'static {
     ///
     /// # Arguments
     /// - `root`: The root resource to list children of.
+    /// - `filter`: Only list resources passing the filter.
     /// - `max_results`: The maximum number of results to return.
     /// - `page_token`: The token to use to get the next page of results.
     async fn list(
         &self,
         label: &ObjectLabel,
         namespace: Option<&ResourceName>,
+        filter: Option<&VisibilityFilter>,
         max_results: Option<usize>,
         page_token: Option<String>,
     ) -> Result<(Vec<Resource>, Option<String>)>;
@@ -53,6 +172,9 @@ pub trait ResourceStore: Send + Sync + 'static {
     ///
     /// # Returns
     /// The created resource.
+    ///
+    /// # Errors
+    /// - [AlreadyExists](crate::Error::AlreadyExists) If a resource with the same name exists.
     async fn create(&self, resource: Resource) -> Result<(Resource, ResourceRef)>;
 
     /// Delete a resource and all connected associations by its identifier.
@@ -62,16 +184,29 @@ pub trait ResourceStore: Send + Sync + 'static {
     ///
     /// # Arguments
     /// - `id`: The identifier of the resource to delete.
+    ///
+    /// # Errors
+    /// - [NotFound](crate::Error::NotFound) If no resource has the given identifier.
     async fn delete(&self, id: &ResourceIdent) -> Result<()>;
 
     /// Update a resource.
     ///
+    /// If the updated resource carries an [etag](Resource::etag), the update is only
+    /// applied if it matches the etag of the stored resource. Without an etag, the
+    /// update overwrites the stored resource unconditionally.
+    ///
     /// # Arguments
     /// - `id`: The identifier of the resource to update.
     /// - `resource`: The updated resource.
     ///
     /// # Returns
-    /// The updated resource.
+    /// The updated resource with its new etag.
+    ///
+    /// # Errors
+    /// - [NotFound](crate::Error::NotFound) If the resource does not exist.
+    /// - [AlreadyExists](crate::Error::AlreadyExists) If the resource is renamed to the name
+    ///   of another resource.
+    /// - [Conflict](crate::Error::Conflict) If the etag does not match the stored resource.
     async fn update(
         &self,
         id: &ResourceIdent,
@@ -120,6 +255,65 @@ pub trait ResourceStore: Send + Sync + 'static {
         label: &AssociationLabel,
     ) -> Result<()>;
 
+    /// Replace the properties of an association between two resources.
+    ///
+    /// Implementations must update the inverse association as well.
+    ///
+    /// # Arguments
+    /// - `from`: The source resource of the association.
+    /// - `to`: The target resource of the association.
+    /// - `label`: The label of the association.
+    /// - `properties`: The new properties of the association.
+    ///
+    /// # Errors
+    /// - [NotFound](crate::Error::NotFound) If the association does not exist.
+    async fn update_association(
+        &self,
+        from: &ResourceIdent,
+        to: &ResourceIdent,
+        label: &AssociationLabel,
+        properties: Option<PropertyMap>,
+    ) -> Result<()>;
+
+    /// Get the properties of an association between two resources.
+    ///
+    /// # Arguments
+    /// - `from`: The source resource of the association.
+    /// - `to`: The target resource of the association.
+    /// - `label`: The label of the association.
+    ///
+    /// # Returns
+    /// The properties of the association, if any were stored with it.
+    ///
+    /// # Errors
+    /// - [NotFound](crate::Error::NotFound) If the association does not exist.
+    async fn get_association(
+        &self,
+        from: &ResourceIdent,
+        to: &ResourceIdent,
+        label: &AssociationLabel,
+    ) -> Result<Option<PropertyMap>>;
+
+    /// Get the properties of the associations from a resource to several others.
+    ///
+    /// # Returns
+    /// The properties of each association, in the order of `to`.
+    ///
+    /// # Errors
+    /// - [NotFound](crate::Error::NotFound) If any of the associations does not exist.
+    async fn get_associations(
+        &self,
+        from: &ResourceIdent,
+        to: &[ResourceIdent],
+        label: &AssociationLabel,
+    ) -> Result<Vec<Option<PropertyMap>>> {
+        let futures = to
+            .iter()
+            .map(|to| self.get_association(from, to, label))
+            .collect_vec();
+        Ok(futures_util::future::try_join_all(futures).await?)
+    }
+
     /// List associations of a resource.
     ///
     /// List associations of a resource with the given label.
@@ -128,6 +322,7 @@ pub trait ResourceStore: Send + Sync + 'static {
     /// - `resource`: The resource to list associations of.
     /// - `label`: The label of the associations to list.
     /// - `target_label`: The label of the target resource of the associations to list.
+    /// - `filter`: Only list associations to targets passing the filter.
     /// - `max_results`: The maximum number of results to return.
     /// - `page_token`: The token to use to get the next page of results.
     ///
@@ -139,9 +334,89 @@ pub trait ResourceStore: Send + Sync + 'static {
         resource: &ResourceIdent,
         label: &AssociationLabel,
         target_label: Option<&ResourceIdent>,
+        filter: Option<&VisibilityFilter>,
         max_results: Option<usize>,
         page_token: Option<String>,
     ) -> Result<(Vec<ResourceIdent>, Option<String>)>;
+
+    /// Apply several changes to associations as a single operation.
+    ///
+    /// Either all changes are applied or none of them. The default implementation applies
+    /// the changes in order and reverts the applied changes if one fails. Stores supporting
+    /// transactions should apply all changes in one transaction instead.
+    ///
+    /// # Errors
+    /// The error of the first change that could not be applied.
+    async fn apply_association_changes(&self, changes: &[AssociationChange]) -> Result<()> {
+        let mut applied = Vec::with_capacity(changes.len());
+        for change in changes {
+            let result = match change.inverse(self).await {
+                Ok(inverse) => change.apply(self).await.map(|_| inverse),
+                Err(err) => Err(err),
+            };
+            match result {
+                Ok(inverse) => applied.push(inverse),
+                Err(err) => {
+                    for inverse in applied.iter().rev() {
+                        if let Err(revert) = inverse.apply(self).await {
+                            tracing::error!("failed to revert {:?}: {}", inverse, revert);
+                        }
+                    }
+                    return Err(err);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Link a resource to the resource containing it.
+///
+/// Grants are inherited along [`ParentOf`](AssociationLabel::ParentOf) associations,
+/// so stores call this whenever a resource is created or renamed. Existing links to a
+/// parent are replaced. Resources whose parent does not exist are left unlinked.
+pub async fn link_parent<S: ResourceStore + ?Sized>(
+    store: &S,
+    label: &ObjectLabel,
+    name: &ResourceName,
+    id: &ResourceIdent,
+) -> Result<()> {
+    let Some(parent_label) = label.parent_label() else {
+        return Ok(());
+    };
+    let (current, _) = store
+        .list_associations(
+            id,
+            &AssociationLabel::ChildOf,
+            Some(&parent_label.to_ident(ResourceRef::Undefined)),
+            None,
+            None,
+            None,
+        )
+        .await?;
+    for parent in current {
+        store
+            .remove_association(id, &parent, &AssociationLabel::ChildOf)
+            .await?;
+    }
+    let Some((_, namespace)) = name.path().split_last() else {
+        return Ok(());
+    };
+    let parent = parent_label.to_ident(ResourceName::new(namespace));
+    match store.get(&parent).await {
+        Ok((_, parent)) => {
+            store
+                .add_association(
+                    &parent_label.to_ident(parent),
+                    id,
+                    &AssociationLabel::ParentOf,
+                    None,
+                )
+                .await
+        }
+        Err(crate::Error::NotFound) => Ok(()),
+        Err(err) => Err(err),
+    }
 }
 
 pub trait ProvidesResourceStore: Send + Sync + 'static {
@@ -162,10 +437,11 @@ impl<T: ResourceStore> ResourceStore for Arc<T> {
         &self,
         label: &ObjectLabel,
         namespace: Option<&ResourceName>,
+        filter: Option<&VisibilityFilter>,
         max_results: Option<usize>,
         page_token: Option<String>,
     ) -> Result<(Vec<Resource>, Option<String>)> {
-        T::list(self, label, namespace, max_results, page_token).await
+        T::list(self, label, namespace, filter, max_results, page_token).await
     }
 
     async fn create(&self, resource: Resource) -> Result<(Resource, ResourceRef)> {
@@ -203,15 +479,57 @@ impl<T: ResourceStore> ResourceStore for Arc<T> {
         T::remove_association(self, from, to, label).await
     }
 
+    async fn update_association(
+        &self,
+        from: &ResourceIdent,
+        to: &ResourceIdent,
+        label: &AssociationLabel,
+        properties: Option<PropertyMap>,
+    ) -> Result<()> {
+        T::update_association(self, from, to, label, properties).await
+    }
+
+    async fn get_association(
+        &self,
+        from: &ResourceIdent,
+        to: &ResourceIdent,
+        label: &AssociationLabel,
+    ) -> Result<Option<PropertyMap>> {
+        T::get_association(self, from, to, label).await
+    }
+
+    async fn get_associations(
+        &self,
+        from: &ResourceIdent,
+        to: &[ResourceIdent],
+        label: &AssociationLabel,
+    ) -> Result<Vec<Option<PropertyMap>>> {
+        T::get_associations(self, from, to, label).await
+    }
+
     async fn list_associations(
         &self,
         resource: &ResourceIdent,
         label: &AssociationLabel,
         target_label: Option<&ResourceIdent>,
+        filter: Option<&VisibilityFilter>,
         max_results: Option<usize>,
         page_token: Option<String>,
     ) -> Result<(Vec<ResourceIdent>, Option<String>)> {
-        T::list_associations(self, resource, label, target_label, max_results, page_token).await
+        T::list_associations(
+            self,
+            resource,
+            label,
+            target_label,
+            filter,
+            max_results,
+            page_token,
+        )
+        .await
+    }
+
+    async fn apply_association_changes(&self, changes: &[AssociationChange]) -> Result<()> {
+        T::apply_association_changes(self, changes).await
     }
 }
 
@@ -229,11 +547,12 @@ impl<T: ProvidesResourceStore> ResourceStore for T {
         &self,
         label: &ObjectLabel,
         namespace: Option<&ResourceName>,
+        filter: Option<&VisibilityFilter>,
         max_results: Option<usize>,
         page_token: Option<String>,
     ) -> Result<(Vec<Resource>, Option<String>)> {
         self.store()
-            .list(label, namespace, max_results, page_token)
+            .list(label, namespace, filter, max_results, page_token)
             .await
     }
 
@@ -274,16 +593,58 @@ impl<T: ProvidesResourceStore> ResourceStore for T {
         self.store().remove_association(from, to, label).await
     }
 
+    async fn update_association(
+        &self,
+        from: &ResourceIdent,
+        to: &ResourceIdent,
+        label: &AssociationLabel,
+        properties: Option<PropertyMap>,
+    ) -> Result<()> {
+        self.store()
+            .update_association(from, to, label, properties)
+            .await
+    }
+
+    async fn get_association(
+        &self,
+        from: &ResourceIdent,
+        to: &ResourceIdent,
+        label: &AssociationLabel,
+    ) -> Result<Option<PropertyMap>> {
+        self.store().get_association(from, to, label).await
+    }
+
+    async fn get_associations(
+        &self,
+        from: &ResourceIdent,
+        to: &[ResourceIdent],
+        label: &AssociationLabel,
+    ) -> Result<Vec<Option<PropertyMap>>> {
+        self.store().get_associations(from, to, label).await
+    }
+
     async fn list_associations(
         &self,
         resource: &ResourceIdent,
         label: &AssociationLabel,
         target_label: Option<&ResourceIdent>,
+        filter: Option<&VisibilityFilter>,
         max_results: Option<usize>,
         page_token: Option<String>,
     ) -> Result<(Vec<ResourceIdent>, Option<String>)> {
         self.store()
-            .list_associations(resource, label, target_label, max_results, page_token)
+            .list_associations(
+                resource,
+                label,
+                target_label,
+                filter,
+                max_results,
+                page_token,
+            )
             .await
     }
+
+    async fn apply_association_changes(&self, changes: &[AssociationChange]) -> Result<()> {
+        self.store().apply_association_changes(changes).await
+    }
 }
@@ -0,0 +1,309 @@
+//! Network access restrictions for recipients.
+use std::net::{IpAddr, SocketAddr};
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use axum::extract::{ConnectInfo, Request};
+use axum::response::{IntoResponse, Response};
+use futures_util::{future::BoxFuture, FutureExt};
+use ipnet::IpNet;
+use tower::{Layer, Service};
+
+use crate::api::recipients::parse_ip_network;
+use crate::models::RecipientInfo;
+use crate::{Error, Recipient, ResourceIdent, ResourceName, ResourceStore, Result};
+
+const X_FORWARDED_FOR: &str = "x-forwarded-for";
+
+/// Resolver for the networks a recipient may connect from.
+#[async_trait::async_trait]
+pub trait IpAccessResolver: Send + Sync {
+    /// Networks the recipient may connect from, `None` if all addresses are allowed.
+    async fn allowed_networks(&self, recipient: &Recipient) -> Result<Option<Vec<IpNet>>>;
+}
+
+/// Users are looked up as recipients by name and restricted by their IP access list.
+#[async_trait::async_trait]
+impl<T: ResourceStore> IpAccessResolver for T {
+    async fn allowed_networks(&self, recipient: &Recipient) -> Result<Option<Vec<IpNet>>> {
+        let Recipient::User(name) = recipient else {
+            return Ok(None);
+        };
+        let ident = ResourceIdent::recipient(ResourceName::new([name]));
+        let info = match self.get(&ident).await {
+            Ok((resource, _)) => RecipientInfo::try_from(resource)?,
+            Err(Error::NotFound) => return Ok(None),
+            Err(e) => return Err(e),
+        };
+        Ok(info.ip_access_list.map(|list| {
+            list.allowed_ip_addresses
+                .iter()
+                // invalid entries never match, so they can only narrow access.
+                .filter_map(|value| match parse_ip_network(value) {
+                    Ok(network) => Some(network),
+                    Err(_) => {
+                        tracing::warn!("invalid IP access list entry for {}: {}", name, value);
+                        None
+                    }
+                })
+                .collect()
+        }))
+    }
+}
+
+/// Determine the address of the client that sent a request.
+///
+/// This is the peer address, unless the peer is a trusted proxy. Then the `X-Forwarded-For`
+/// header is followed from the right, until an address that is not a trusted proxy is found.
+/// Returns `None` if the address can not be determined.
+fn client_address(request: &Request, trusted_proxies: &[IpNet]) -> Option<IpAddr> {
+    let is_trusted = |addr: &IpAddr| trusted_proxies.iter().any(|net| net.contains(addr));
+    let mut client = request
+        .extensions()
+        .get::<ConnectInfo<SocketAddr>>()?
+        .0
+        .ip()
+        .to_canonical();
+    if !is_trusted(&client) {
+        return Some(client);
+    }
+    let forwarded = request
+        .headers()
+        .get_all(X_FORWARDED_FOR)
+        .iter()
+        .map(|value| value.to_str().ok())
+        .collect::<Option<Vec<_>>>()?;
+    for addr in forwarded.iter().flat_map(|v| v.split(',')).rev() {
+        client = addr.trim().parse::<IpAddr>().ok()?.to_canonical();
+        if !is_trusted(&client) {
+            break;
+        }
+    }
+    Some(client)
+}
+
+/// Middleware that rejects requests from addresses outside the IP access list of the recipient.
+///
+/// The recipient is taken from the request extensions, so the middleware has to be applied
+/// inside the [`AuthenticationLayer`](super::AuthenticationLayer). To record blocked requests,
+/// apply it inside the [`AuditLayer`](super::AuditLayer) as well. The peer address is read from
+/// the [`ConnectInfo`] of the connection, requests without it are rejected if the recipient
+/// is restricted.
+#[derive(Clone)]
+pub struct IpAccessMiddleware<S> {
+    inner: S,
+    resolver: Arc<dyn IpAccessResolver>,
+    trusted_proxies: Arc<Vec<IpNet>>,
+}
+
+impl<S> Service<Request> for IpAccessMiddleware<S>
+where
+    S: Service<Request, Response = Response> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: Request) -> Self::Future {
+        let recipient = req
+            .extensions()
+            .get::<Recipient>()
+            .cloned()
+            .unwrap_or(Recipient::Anonymous);
+        let client = client_address(&req, &self.trusted_proxies);
+        let resolver = self.resolver.clone();
+        // the service driven to readiness has to handle the request.
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+        async move {
+            let networks = match resolver.allowed_networks(&recipient).await {
+                Ok(networks) => networks,
+                Err(e) => return Ok(e.into_response()),
+            };
+            if let Some(networks) = networks {
+                if !client.is_some_and(|addr| networks.iter().any(|net| net.contains(&addr))) {
+                    tracing::warn!(
+                        "blocked request from {:?} for recipient {:?}",
+                        client,
+                        recipient
+                    );
+                    return Ok(Error::NotAllowed.into_response());
+                }
+            }
+            inner.call(req).await
+        }
+        .boxed()
+    }
+}
+
+/// Layer that applies the [`IpAccessMiddleware`].
+#[derive(Clone)]
+pub struct IpAccessLayer {
+    resolver: Arc<dyn IpAccessResolver>,
+    trusted_proxies: Arc<Vec<IpNet>>,
+}
+
+impl IpAccessLayer {
+    /// Create a new [`IpAccessLayer`] with the given [`IpAccessResolver`].
+    ///
+    /// By default no proxies are trusted, so `X-Forwarded-For` headers are ignored.
+    /// Access lists are looked up for the authenticated recipient, the layer has no
+    /// effect behind an authenticator that marks all requests as anonymous.
+    pub fn new(resolver: Arc<dyn IpAccessResolver>) -> Self {
+        Self {
+            resolver,
+            trusted_proxies: Arc::new(Vec::new()),
+        }
+    }
+
+    /// Trust `X-Forwarded-For` headers set by proxies in the given networks.
+    pub fn with_trusted_proxies(mut self, proxies: impl IntoIterator<Item = IpNet>) -> Self {
+        self.trusted_proxies = Arc::new(proxies.into_iter().collect());
+        self
+    }
+}
+
+impl<S> Layer<S> for IpAccessLayer {
+    type Service = IpAccessMiddleware<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        IpAccessMiddleware {
+            inner,
+            resolver: self.resolver.clone(),
+            trusted_proxies: self.trusted_proxies.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::Body;
+    use axum::http::StatusCode;
+    use http_body_util::BodyExt;
+    use tower::{ServiceBuilder, ServiceExt};
+
+    use super::*;
+    use crate::audit::tests::MemorySink;
+    use crate::audit::AuditLog;
+    use crate::memory::InMemoryResourceStore;
+    use crate::models::recipients::v1::IpAccessList;
+    use crate::rest::{AuditLayer, AuthenticationLayer, Authenticator};
+
+    #[derive(Clone)]
+    struct UserAuthenticator;
+
+    impl Authenticator for UserAuthenticator {
+        fn authenticate(&self, request: &Request) -> Result<Recipient> {
+            let user = request
+                .headers()
+                .get("x-user")
+                .ok_or(Error::Unauthenticated)?;
+            Ok(Recipient::user(user.to_str().unwrap()))
+        }
+    }
+
+    async fn ok(_: Request) -> Result<Response<Body>> {
+        Ok(Response::new(Body::empty()))
+    }
+
+    fn request(user: &str, peer: &str, forwarded: Option<&str>) -> Request {
+        let mut builder = Request::get("/shares").header("x-user", user);
+        if let Some(forwarded) = forwarded {
+            builder = builder.header(X_FORWARDED_FOR, forwarded);
+        }
+        let mut request = builder.body(Body::empty()).unwrap();
+        let peer = SocketAddr::new(peer.parse().unwrap(), 1234);
+        request.extensions_mut().insert(ConnectInfo(peer));
+        request
+    }
+
+    fn networks(values: &[&str]) -> Vec<IpNet> {
+        values
+            .iter()
+            .map(|v| parse_ip_network(v).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_client_address() {
+        let proxies = networks(&["10.0.0.0/8"]);
+        let addr = |req: &Request| client_address(req, &proxies).map(|a| a.to_string());
+
+        // forwarded headers from untrusted peers are ignored.
+        let req = request("alice", "192.168.1.1", Some("1.2.3.4"));
+        assert_eq!(addr(&req).as_deref(), Some("192.168.1.1"));
+
+        let req = request("alice", "10.0.0.1", Some("6.6.6.6, 1.2.3.4, 10.0.0.2"));
+        assert_eq!(addr(&req).as_deref(), Some("1.2.3.4"));
+
+        let req = request("alice", "10.0.0.1", Some("garbage"));
+        assert_eq!(addr(&req), None);
+
+        let req = request("alice", "::ffff:192.168.1.1", None);
+        assert_eq!(addr(&req).as_deref(), Some("192.168.1.1"));
+    }
+
+    #[tokio::test]
+    async fn test_ip_access_middleware() {
+        let store = InMemoryResourceStore::new();
+        let recipient = RecipientInfo {
+            name: "alice".to_string(),
+            ip_access_list: Some(IpAccessList {
+                allowed_ip_addresses: vec!["192.168.0.0/16".to_string(), "1.2.3.4".to_string()],
+            }),
+            ..Default::default()
+        };
+        store.create(recipient.into()).await.unwrap();
+
+        let sink = Arc::new(MemorySink::default());
+        let log = AuditLog::new(sink.clone());
+        let mut service = ServiceBuilder::new()
+            .layer(AuthenticationLayer::new(UserAuthenticator))
+            .layer(AuditLayer::new(log.clone()))
+            .layer(
+                IpAccessLayer::new(Arc::new(store)).with_trusted_proxies(networks(&["10.0.0.0/8"])),
+            )
+            .service_fn(ok);
+
+        let cases = [
+            ("alice", "192.168.1.1", None, StatusCode::OK),
+            ("alice", "1.2.3.4", None, StatusCode::OK),
+            ("alice", "1.2.3.5", None, StatusCode::FORBIDDEN),
+            ("alice", "10.0.0.1", Some("1.2.3.4"), StatusCode::OK),
+            ("alice", "10.0.0.1", Some("1.2.3.5"), StatusCode::FORBIDDEN),
+            // recipients without an access list are not restricted.
+            ("bob", "1.2.3.5", None, StatusCode::OK),
+        ];
+        for (user, peer, forwarded, status) in cases {
+            let response = service
+                .ready()
+                .await
+                .unwrap()
+                .call(request(user, peer, forwarded))
+                .await
+                .unwrap();
+            assert_eq!(response.status(), status, "{} from {}", user, peer);
+        }
+
+        let response = service
+            .ready()
+            .await
+            .unwrap()
+            .call(request("alice", "8.8.8.8", None))
+            .await
+            .unwrap();
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(body["errorCode"], "403 Forbidden");
+
+        log.flush().await;
+        let events = sink.events.lock().unwrap();
+        let blocked = events.iter().filter(|e| e.status == Some(403)).count();
+        assert_eq!(blocked, 3);
+    }
+}
@@ -0,0 +1,139 @@
+//! Audit middleware for Delta Sharing server.
+use std::task::{Context, Poll};
+
+use axum::extract::Request;
+use axum::response::Response;
+use futures_util::{future::BoxFuture, FutureExt};
+use tower::{Layer, Service};
+
+use crate::audit::{AuditEvent, AuditLog};
+use crate::Recipient;
+
+const TABLE_VERSION_HEADER: &str = "delta-table-version";
+
+/// Middleware that records an audit event for every request.
+///
+/// The recipient is taken from the request extensions, so the middleware has to be applied
+/// inside the [`AuthenticationLayer`](super::AuthenticationLayer).
+#[derive(Clone)]
+pub struct AuditMiddleware<S> {
+    inner: S,
+    log: AuditLog,
+}
+
+impl<S> Service<Request> for AuditMiddleware<S>
+where
+    S: Service<Request, Response = Response> + Send + 'static,
+    S::Future: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: Request) -> Self::Future {
+        let recipient = req
+            .extensions()
+            .get::<Recipient>()
+            .cloned()
+            .unwrap_or(Recipient::Anonymous);
+        let method = req.method().to_string();
+        let path = req.uri().path().to_string();
+        let log = self.log.clone();
+        let future = self.inner.call(req);
+        async move {
+            let response = future.await?;
+            let mut event =
+                AuditEvent::request(&recipient, method, path, response.status().as_u16());
+            if let Some(version) = response
+                .headers()
+                .get(TABLE_VERSION_HEADER)
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| v.parse().ok())
+            {
+                event = event.with_table_version(version);
+            }
+            log.record(event).await;
+            Ok(response)
+        }
+        .boxed()
+    }
+}
+
+/// Layer that applies the [`AuditMiddleware`].
+#[derive(Clone)]
+pub struct AuditLayer {
+    log: AuditLog,
+}
+
+impl AuditLayer {
+    /// Create a new [`AuditLayer`] recording events in the given [`AuditLog`].
+    pub fn new(log: AuditLog) -> Self {
+        Self { log }
+    }
+}
+
+impl<S> Layer<S> for AuditLayer {
+    type Service = AuditMiddleware<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        AuditMiddleware {
+            inner,
+            log: self.log.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::body::Body;
+    use axum::http::StatusCode;
+    use tower::{ServiceBuilder, ServiceExt};
+
+    use super::*;
+    use crate::audit::tests::MemorySink;
+    use crate::audit::AuditEventKind;
+    use crate::rest::{AnonymousAuthenticator, AuthenticationLayer};
+    use crate::Result;
+
+    async fn table_version(_: Request) -> Result<Response<Body>> {
+        Ok(Response::builder()
+            .header("Delta-Table-Version", 7)
+            .body(Body::empty())
+            .unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_audit_middleware() {
+        let sink = Arc::new(MemorySink::default());
+        let log = AuditLog::new(sink.clone());
+        let mut service = ServiceBuilder::new()
+            .layer(AuthenticationLayer::new(AnonymousAuthenticator))
+            .layer(AuditLayer::new(log.clone()))
+            .service_fn(table_version);
+
+        let request = Request::get("/shares/share/schemas/schema/tables/table/version")
+            .body(Body::empty())
+            .unwrap();
+        let response = service.ready().await.unwrap().call(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        log.flush().await;
+
+        let events = sink.events.lock().unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].kind, AuditEventKind::Request);
+        assert_eq!(events[0].recipient_type, "anonymous");
+        assert_eq!(events[0].method.as_deref(), Some("GET"));
+        assert_eq!(
+            events[0].path.as_deref(),
+            Some("/shares/share/schemas/schema/tables/table/version")
+        );
+        assert_eq!(events[0].status, Some(200));
+        assert_eq!(events[0].table_version, Some(7));
+    }
+}
@@ -1,12 +1,20 @@
 //! Authentication middleware for Delta Sharing server.
+use std::path::PathBuf;
+use std::sync::{Arc, RwLock, Weak};
 use std::task::{Context, Poll};
+use std::time::Duration;
 
 use axum::extract::Request;
+use axum::http::header::AUTHORIZATION;
 use axum::response::{IntoResponse, Response};
 use futures_util::{future::BoxFuture, FutureExt};
+use jsonwebtoken::jwk::JwkSet;
+use jsonwebtoken::{decode, decode_header, Algorithm, DecodingKey, Validation};
+use serde_json::{Map, Value};
 use tower::{Layer, Service};
+use url::Url;
 
-use crate::{Recipient, Result};
+use crate::{Error, Recipient, Result};
 
 /// Authenticator for authenticating requests to a sharing server.
 pub trait Authenticator: Send + Sync + 'static {
@@ -27,6 +35,206 @@ impl Authenticator for AnonymousAuthenticator {
     }
 }
 
+/// Location of a JSON Web Key Set (JWKS) document.
+#[derive(Debug, Clone)]
+pub enum JwksSource {
+    /// JWKS document stored in a local file.
+    File(PathBuf),
+    /// JWKS document served by an identity provider, e.g. the `jwks_uri` of an OIDC issuer.
+    Url(Url),
+}
+
+impl JwksSource {
+    async fn load(&self) -> Result<JwkSet> {
+        match self {
+            JwksSource::File(path) => {
+                let data = tokio::fs::read(path).await.map_err(|e| {
+                    Error::generic(format!(
+                        "failed to read JWKS from {}: {}",
+                        path.display(),
+                        e
+                    ))
+                })?;
+                Ok(serde_json::from_slice(&data)?)
+            }
+            JwksSource::Url(url) => Ok(reqwest::get(url.clone())
+                .await?
+                .error_for_status()?
+                .json()
+                .await?),
+        }
+    }
+}
+
+/// Configuration for a [`JwksAuthenticator`].
+#[derive(Debug, Clone)]
+pub struct JwksConfig {
+    source: JwksSource,
+    issuer: Option<String>,
+    audience: Vec<String>,
+    user_claim: String,
+    refresh_interval: Option<Duration>,
+}
+
+impl JwksConfig {
+    /// Create a new [`JwksConfig`] loading keys from the given source.
+    ///
+    /// By default the `sub` claim is used to identify the user, issuer and audience
+    /// are not validated and the key set is never refreshed.
+    pub fn new(source: JwksSource) -> Self {
+        Self {
+            source,
+            issuer: None,
+            audience: Vec::new(),
+            user_claim: "sub".to_string(),
+            refresh_interval: None,
+        }
+    }
+
+    /// Require tokens to be issued by the given issuer (`iss` claim).
+    pub fn with_issuer(mut self, issuer: impl Into<String>) -> Self {
+        self.issuer = Some(issuer.into());
+        self
+    }
+
+    /// Require tokens to be intended for one of the given audiences (`aud` claim).
+    pub fn with_audience(mut self, audience: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.audience = audience.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Name of the claim that is mapped to [`Recipient::User`], e.g. `email`.
+    pub fn with_user_claim(mut self, claim: impl Into<String>) -> Self {
+        self.user_claim = claim.into();
+        self
+    }
+
+    /// Periodically reload the key set so that rotated keys are picked up.
+    pub fn with_refresh_interval(mut self, interval: Duration) -> Self {
+        self.refresh_interval = Some(interval);
+        self
+    }
+}
+
+/// Authenticator validating RS256 / ES256 bearer tokens against a JSON Web Key Set.
+///
+/// The value of the configured user claim becomes the [`Recipient::User`] of the request.
+#[derive(Clone)]
+pub struct JwksAuthenticator {
+    config: Arc<JwksConfig>,
+    keys: Arc<RwLock<JwkSet>>,
+}
+
+impl JwksAuthenticator {
+    /// Create a new [`JwksAuthenticator`].
+    ///
+    /// The key set is loaded eagerly. If a refresh interval is configured, a background
+    /// task reloads the key set until the authenticator is dropped.
+    pub async fn try_new(config: JwksConfig) -> Result<Self> {
+        let keys = config.source.load().await?;
+        let authenticator = Self {
+            config: Arc::new(config),
+            keys: Arc::new(RwLock::new(keys)),
+        };
+        if let Some(interval) = authenticator.config.refresh_interval {
+            tokio::spawn(refresh_keys(
+                authenticator.config.clone(),
+                Arc::downgrade(&authenticator.keys),
+                interval,
+            ));
+        }
+        Ok(authenticator)
+    }
+
+    /// Reload the key set from the configured source.
+    pub async fn refresh(&self) -> Result<()> {
+        let keys = self.config.source.load().await?;
+        *self.keys.write().map_err(|_| poisoned())? = keys;
+        Ok(())
+    }
+
+    fn validate(&self, token: &str) -> Result<Recipient> {
+        let header = decode_header(token).map_err(unauthenticated)?;
+        if !matches!(header.alg, Algorithm::RS256 | Algorithm::ES256) {
+            tracing::debug!("unsupported token algorithm: {:?}", header.alg);
+            return Err(Error::Unauthenticated);
+        }
+
+        let key = {
+            let keys = self.keys.read().map_err(|_| poisoned())?;
+            let jwk = match (&header.kid, keys.keys.as_slice()) {
+                (Some(kid), _) => keys.find(kid),
+                (None, [jwk]) => Some(jwk),
+                (None, _) => None,
+            }
+            .ok_or(Error::Unauthenticated)?;
+            DecodingKey::from_jwk(jwk).map_err(unauthenticated)?
+        };
+
+        let mut validation = Validation::new(header.alg);
+        if let Some(issuer) = &self.config.issuer {
+            validation.set_issuer(&[issuer]);
+        }
+        if self.config.audience.is_empty() {
+            validation.validate_aud = false;
+        } else {
+            validation.set_audience(&self.config.audience);
+        }
+
+        let claims = decode::<Map<String, Value>>(token, &key, &validation)
+            .map_err(unauthenticated)?
+            .claims;
+        match claims.get(&self.config.user_claim) {
+            Some(Value::String(user)) => Ok(Recipient::User(user.clone())),
+            _ => {
+                tracing::debug!("token is missing claim `{}`", self.config.user_claim);
+                Err(Error::Unauthenticated)
+            }
+        }
+    }
+}
+
+impl Authenticator for JwksAuthenticator {
+    fn authenticate(&self, request: &Request) -> Result<Recipient> {
+        let token = request
+            .headers()
+            .get(AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "))
+            .ok_or(Error::Unauthenticated)?;
+        self.validate(token.trim())
+    }
+}
+
+async fn refresh_keys(config: Arc<JwksConfig>, keys: Weak<RwLock<JwkSet>>, interval: Duration) {
+    let mut ticker = tokio::time::interval(interval);
+    // the first tick completes immediately and keys were just loaded.
+    ticker.tick().await;
+    loop {
+        ticker.tick().await;
+        let Some(keys) = keys.upgrade() else {
+            break;
+        };
+        match config.source.load().await {
+            Ok(loaded) => match keys.write() {
+                Ok(mut guard) => *guard = loaded,
+                Err(_) => break,
+            },
+            // keep serving the previous keys, the identity provider may be temporarily unavailable.
+            Err(e) => tracing::warn!("failed to refresh JWKS: {}", e),
+        }
+    }
+}
+
+fn unauthenticated(err: jsonwebtoken::errors::Error) -> Error {
+    tracing::debug!("failed to validate token: {}", err);
+    Error::Unauthenticated
+}
+
+fn poisoned() -> Error {
+    Error::generic("JWKS lock poisoned")
+}
+
 /// Middleware that authenticates requests using the given [`Authenticator`].
 #[derive(Clone)]
 pub struct AuthenticationMiddleware<S, T> {
@@ -137,4 +345,177 @@ mod tests {
         let response = service.ready().await.unwrap().call(request).await.unwrap();
         assert_eq!(response.status(), StatusCode::OK);
     }
+
+    mod jwks {
+        use std::time::{SystemTime, UNIX_EPOCH};
+
+        use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+        use base64::Engine;
+        use jsonwebtoken::{encode, EncodingKey, Header};
+        use ring::rand::SystemRandom;
+        use ring::signature::{EcdsaKeyPair, KeyPair, ECDSA_P256_SHA256_FIXED_SIGNING};
+        use serde_json::json;
+        use tempfile::NamedTempFile;
+
+        use super::super::*;
+
+        const ISSUER: &str = "https://idp.example.com";
+        const AUDIENCE: &str = "delta-sharing";
+
+        struct TestKey {
+            kid: String,
+            encoding: EncodingKey,
+            jwk: Value,
+        }
+
+        fn test_key(kid: &str) -> TestKey {
+            let rng = SystemRandom::new();
+            let pkcs8 =
+                EcdsaKeyPair::generate_pkcs8(&ECDSA_P256_SHA256_FIXED_SIGNING, &rng).unwrap();
+            let pair =
+                EcdsaKeyPair::from_pkcs8(&ECDSA_P256_SHA256_FIXED_SIGNING, pkcs8.as_ref(), &rng)
+                    .unwrap();
+            // uncompressed point: 0x04 || x || y
+            let point = pair.public_key().as_ref();
+            let jwk = json!({
+                "kty": "EC",
+                "crv": "P-256",
+                "alg": "ES256",
+                "use": "sig",
+                "kid": kid,
+                "x": URL_SAFE_NO_PAD.encode(&point[1..33]),
+                "y": URL_SAFE_NO_PAD.encode(&point[33..]),
+            });
+            TestKey {
+                kid: kid.to_string(),
+                encoding: EncodingKey::from_ec_der(pkcs8.as_ref()),
+                jwk,
+            }
+        }
+
+        fn write_jwks(file: &NamedTempFile, keys: &[&TestKey]) {
+            let jwks = json!({ "keys": keys.iter().map(|k| k.jwk.clone()).collect::<Vec<_>>() });
+            std::fs::write(file.path(), serde_json::to_vec(&jwks).unwrap()).unwrap();
+        }
+
+        fn token(key: &TestKey, claims: Value) -> String {
+            let mut header = Header::new(Algorithm::ES256);
+            header.kid = Some(key.kid.clone());
+            encode(&header, &claims, &key.encoding).unwrap()
+        }
+
+        fn claims(sub: &str) -> Value {
+            let exp = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap()
+                .as_secs()
+                + 3600;
+            json!({
+                "sub": sub,
+                "email": format!("{sub}@example.com"),
+                "iss": ISSUER,
+                "aud": AUDIENCE,
+                "exp": exp,
+            })
+        }
+
+        fn request(token: &str) -> Request {
+            Request::get("/")
+                .header(AUTHORIZATION, format!("Bearer {token}"))
+                .body(axum::body::Body::empty())
+                .unwrap()
+        }
+
+        async fn authenticator(file: &NamedTempFile) -> JwksAuthenticator {
+            let config = JwksConfig::new(JwksSource::File(file.path().to_path_buf()))
+                .with_issuer(ISSUER)
+                .with_audience([AUDIENCE]);
+            JwksAuthenticator::try_new(config).await.unwrap()
+        }
+
+        #[tokio::test]
+        async fn test_jwks_authenticator() {
+            let key = test_key("key-1");
+            let file = NamedTempFile::new().unwrap();
+            write_jwks(&file, &[&key]);
+            let authenticator = authenticator(&file).await;
+
+            let recipient = authenticator
+                .authenticate(&request(&token(&key, claims("admin"))))
+                .unwrap();
+            assert!(matches!(recipient, Recipient::User(user) if user == "admin"));
+
+            // missing header
+            let req = Request::get("/").body(axum::body::Body::empty()).unwrap();
+            assert!(matches!(
+                authenticator.authenticate(&req),
+                Err(Error::Unauthenticated)
+            ));
+
+            // wrong issuer
+            let mut wrong_issuer = claims("admin");
+            wrong_issuer["iss"] = json!("https://other.example.com");
+            assert!(authenticator
+                .authenticate(&request(&token(&key, wrong_issuer)))
+                .is_err());
+
+            // wrong audience
+            let mut wrong_audience = claims("admin");
+            wrong_audience["aud"] = json!("other");
+            assert!(authenticator
+                .authenticate(&request(&token(&key, wrong_audience)))
+                .is_err());
+
+            // expired
+            let mut expired = claims("admin");
+            expired["exp"] = json!(1);
+            assert!(authenticator
+                .authenticate(&request(&token(&key, expired)))
+                .is_err());
+
+            // signed with a key not in the key set
+            let unknown = test_key("key-1");
+            assert!(authenticator
+                .authenticate(&request(&token(&unknown, claims("admin"))))
+                .is_err());
+        }
+
+        #[tokio::test]
+        async fn test_jwks_user_claim() {
+            let key = test_key("key-1");
+            let file = NamedTempFile::new().unwrap();
+            write_jwks(&file, &[&key]);
+            let config = JwksConfig::new(JwksSource::File(file.path().to_path_buf()))
+                .with_user_claim("email");
+            let authenticator = JwksAuthenticator::try_new(config).await.unwrap();
+
+            let recipient = authenticator
+                .authenticate(&request(&token(&key, claims("admin"))))
+                .unwrap();
+            assert!(matches!(recipient, Recipient::User(user) if user == "admin@example.com"));
+        }
+
+        #[tokio::test]
+        async fn test_jwks_refresh() {
+            let key1 = test_key("key-1");
+            let key2 = test_key("key-2");
+            let file = NamedTempFile::new().unwrap();
+            write_jwks(&file, &[&key1]);
+            let authenticator = authenticator(&file).await;
+
+            assert!(authenticator
+                .authenticate(&request(&token(&key2, claims("admin"))))
+                .is_err());
+
+            write_jwks(&file, &[&key1, &key2]);
+            authenticator.refresh().await.unwrap();
+
+            assert!(authenticator
+                .authenticate(&request(&token(&key1, claims("admin"))))
+                .is_ok());
+            assert!(authenticator
+                .authenticate(&request(&token(&key2, claims("admin"))))
+                .is_ok());
+        }
+    }
 }
@@ -375,6 +375,26 @@ impl CredentialsClient {
         let request = cred::DeleteCredentialRequest { name: name.into() };
         self.delete_credential(&request).await
     }
+
+    pub async fn validate(
+        &self,
+        name: impl Into<String>,
+        url: impl IntoUrl,
+        read_only: impl Into<Option<bool>>,
+    ) -> Result<Vec<cred::ValidationResult>> {
+        let request = cred::ValidateCredentialRequest {
+            credential: Some(cred::CredentialInfo {
+                name: name.into(),
+                ..Default::default()
+            }),
+            url: url
+                .into_url()
+                .map(|u| u.to_string())
+                .map_err(|e| Error::generic(e.to_string()))?,
+            read_only: read_only.into(),
+        };
+        Ok(self.validate_credential(&request).await?.results)
+    }
 }
 
 impl ExternalLocationsClient {
@@ -167,6 +167,7 @@ async fn test_catalog_router_crud(app: Router) {
     let body: CatalogInfo = collect_body(create_catalog_response).await;
     assert_eq!(body.name, catalog.name);
     assert_eq!(body.comment, catalog.comment);
+    let created_etag = body.etag.expect("created catalog must carry an etag");
 
     // list catalogs
     let list_catalogs = Request::builder()
@@ -217,6 +218,8 @@ async fn test_catalog_router_crud(app: Router) {
     let body: CatalogInfo = collect_body(update_catalog_response).await;
     assert_eq!(body.name, new_catalog.new_name);
     assert_eq!(body.comment, new_catalog.comment);
+    let updated_etag = body.etag.expect("updated catalog must carry an etag");
+    assert_ne!(updated_etag, created_etag);
 
     // get catalog again with new name
     let get_catalog = Request::builder()
@@ -232,6 +235,49 @@ async fn test_catalog_router_crud(app: Router) {
     );
     let body: CatalogInfo = collect_body(get_catalog_response).await;
     assert_eq!(body.name, new_catalog.new_name);
+    assert_eq!(body.etag.as_ref(), Some(&updated_etag));
+
+    // updates based on an outdated revision are rejected
+    let stale_update = UpdateCatalogRequest {
+        name: "new_test".to_string(),
+        new_name: "new_test".to_string(),
+        comment: Some("stale comment".to_string()),
+        ..Default::default()
+    };
+    let update_catalog = Request::builder()
+        .method(Method::PATCH)
+        .uri("/catalogs/new_test")
+        .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+        .header(http::header::IF_MATCH, format!("\"{}\"", created_etag))
+        .body(Body::from(serde_json::to_vec(&stale_update).unwrap()))
+        .unwrap();
+    let update_catalog_response = app.clone().oneshot(update_catalog).await.unwrap();
+    assert_eq!(
+        update_catalog_response.status(),
+        StatusCode::CONFLICT,
+        "update catalog with stale etag"
+    );
+
+    // updates based on the current revision are applied
+    let current_update = UpdateCatalogRequest {
+        comment: Some("current comment".to_string()),
+        ..stale_update
+    };
+    let update_catalog = Request::builder()
+        .method(Method::PATCH)
+        .uri("/catalogs/new_test")
+        .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+        .header(http::header::IF_MATCH, format!("\"{}\"", updated_etag))
+        .body(Body::from(serde_json::to_vec(&current_update).unwrap()))
+        .unwrap();
+    let update_catalog_response = app.clone().oneshot(update_catalog).await.unwrap();
+    assert_eq!(
+        update_catalog_response.status(),
+        StatusCode::OK,
+        "update catalog with current etag"
+    );
+    let body: CatalogInfo = collect_body(update_catalog_response).await;
+    assert_eq!(body.comment, current_update.comment);
 
     // create a schema
     let schema = SchemaInfo {
@@ -0,0 +1,90 @@
+use axum::http::{Method, StatusCode};
+use tower::ServiceExt;
+
+use super::*;
+use crate::models::catalogs::v1::*;
+use crate::models::permissions::v1::*;
+use crate::models::recipients::v1::*;
+
+pub async fn test_permissions_router(app: Router) {
+    let catalog = CatalogInfo {
+        name: "catalog".to_string(),
+        ..Default::default()
+    };
+    let request = create_request(Method::POST, "/catalogs", Some(catalog));
+    let response = app.clone().oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK, "create catalog");
+    for name in ["alice", "bob"] {
+        let recipient = CreateRecipientRequest {
+            name: name.to_string(),
+            authentication_type: AuthenticationType::Token as i32,
+            owner: "owner".to_string(),
+            ..Default::default()
+        };
+        let request = create_request(Method::POST, "/recipients", Some(recipient));
+        let response = app.clone().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK, "create recipient");
+    }
+
+    // path parameters are taken from the route, not the body.
+    let update = UpdatePermissionsRequest {
+        changes: vec![
+            PermissionsChange {
+                principal: "alice".to_string(),
+                add: vec![Privilege::UseCatalog as i32, Privilege::Select as i32],
+                remove: vec![],
+            },
+            PermissionsChange {
+                principal: "bob".to_string(),
+                add: vec![Privilege::Browse as i32],
+                remove: vec![],
+            },
+        ],
+        ..Default::default()
+    };
+    let request = create_request(Method::PATCH, "/permissions/catalog/catalog", Some(update));
+    let response = app.clone().oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK, "update permissions");
+    let body: UpdatePermissionsResponse = collect_body(response).await;
+    assert_eq!(body.privilege_assignments.len(), 2);
+
+    let request = create_request(
+        Method::GET,
+        "/permissions/catalog/catalog?principal=bob",
+        None::<()>,
+    );
+    let response = app.clone().oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK, "get permissions");
+    let body: GetPermissionsResponse = collect_body(response).await;
+    assert_eq!(
+        body.privilege_assignments,
+        vec![PrivilegeAssignment {
+            principal: "bob".to_string(),
+            privileges: vec![Privilege::Browse as i32],
+        }]
+    );
+
+    let request = create_request(
+        Method::GET,
+        "/effective-permissions/catalog/catalog?principal=alice",
+        None::<()>,
+    );
+    let response = app.clone().oneshot(request).await.unwrap();
+    assert_eq!(
+        response.status(),
+        StatusCode::OK,
+        "get effective permissions"
+    );
+    let body: GetEffectivePermissionsResponse = collect_body(response).await;
+    assert_eq!(body.privilege_assignments.len(), 1);
+    assert_eq!(body.privilege_assignments[0].principal, "alice");
+    assert_eq!(body.privilege_assignments[0].privileges.len(), 2);
+
+    let request = create_request(Method::GET, "/permissions/catalog/missing", None::<()>);
+    let response = app.clone().oneshot(request).await.unwrap();
+    assert_eq!(
+        response.status(),
+        StatusCode::NOT_FOUND,
+        "missing securable"
+    );
+}
@@ -1,13 +1,19 @@
+pub use access::*;
+pub use audit::*;
 pub use auth::*;
 pub use catalogs::get_router as get_catalog_router;
 pub use credentials::get_router as get_credentials_router;
 pub use external_locations::get_router as get_external_locations_router;
+pub use permissions::get_router as get_permissions_router;
 pub use recipients::get_router as get_recipients_router;
 pub use schemas::get_router as get_schemas_router;
 pub use shares::get_router as get_shares_router;
 pub use sharing::get_router as get_sharing_router;
 pub use tables::get_router as get_tables_router;
+pub use temporary_credentials::get_router as get_temporary_credentials_router;
 
+mod access;
+mod audit;
 mod auth;
 pub mod client;
 #[cfg(any(test, feature = "integration"))]
@@ -73,6 +79,7 @@ mod credentials {
             .route("/credentials/{name}", get(get_credential::<T>))
             //.route("/credentials/{name}", patch(update_credential::<T>))
             .route("/credentials/{name}", delete(delete_credential::<T>))
+            .route("/validate-credentials", post(validate_credential::<T>))
             .with_state(handler)
     }
 }
@@ -102,6 +109,29 @@ mod external_locations {
     }
 }
 
+mod permissions {
+    use crate::api::permissions::*;
+    use axum::routing::{get, patch, Router};
+
+    /// Create a new [Router] for the permissions REST API.
+    pub fn get_router<T: PermissionsHandler + Clone>(handler: T) -> Router {
+        Router::new()
+            .route(
+                "/permissions/{securable_type}/{full_name}",
+                get(get_permissions::<T>),
+            )
+            .route(
+                "/permissions/{securable_type}/{full_name}",
+                patch(update_permissions::<T>),
+            )
+            .route(
+                "/effective-permissions/{securable_type}/{full_name}",
+                get(get_effective_permissions::<T>),
+            )
+            .with_state(handler)
+    }
+}
+
 mod shares {
     use crate::api::shares::*;
     use axum::routing::{delete, get, post, Router};
@@ -133,6 +163,25 @@ mod tables {
     }
 }
 
+mod temporary_credentials {
+    use crate::api::temporary_credentials::*;
+    use axum::routing::{post, Router};
+
+    /// Create a new [Router] for the temporary credentials REST API.
+    pub fn get_router<T: TemporaryCredentialsHandler + Clone>(handler: T) -> Router {
+        Router::new()
+            .route(
+                "/temporary-table-credentials",
+                post(generate_temporary_table_credentials::<T>),
+            )
+            .route(
+                "/temporary-path-credentials",
+                post(generate_temporary_path_credentials::<T>),
+            )
+            .with_state(handler)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -194,4 +243,14 @@ mod tests {
             .layer(AuthenticationLayer::new(AnonymousAuthenticator));
         super::integration::test_credentials_router(app).await;
     }
+
+    #[tokio::test]
+    async fn test_permissions_router() {
+        let handler = Handler::default();
+        let app = get_permissions_router(handler.clone())
+            .merge(get_catalog_router(handler.clone()))
+            .merge(get_recipients_router(handler))
+            .layer(AuthenticationLayer::new(AnonymousAuthenticator));
+        super::integration::test_permissions_router(app).await;
+    }
 }
@@ -1,7 +1,7 @@
 use axum::body::Body;
-use axum::extract::{Extension, State};
+use axum::extract::{Extension, Path, State};
 use axum::response::Response;
-use axum::routing::{get, Router};
+use axum::routing::{get, post, Router};
 use http::header::CONTENT_TYPE;
 
 use crate::api::sharing::*;
@@ -28,6 +28,14 @@ pub fn get_router<T: SharingDiscoveryHandler + SharingQueryHandler + Clone>(stat
             "/shares/{share}/schemas/{schema}/tables/{name}/metadata",
             get(get_table_metadata_correct::<T>),
         )
+        .route(
+            "/shares/{share}/schemas/{schema}/tables/{name}/query",
+            post(query_table_correct::<T>),
+        )
+        .route(
+            "/shares/{share}/schemas/{schema}/tables/{name}/files/{id}",
+            get(get_table_file::<T>),
+        )
         .with_state(state)
 }
 
@@ -58,6 +66,39 @@ async fn get_table_metadata_correct<T: SharingQueryHandler>(
     Ok(response)
 }
 
+async fn query_table_correct<T: SharingQueryHandler>(
+    State(handler): State<T>,
+    Extension(recipient): Extension<Recipient>,
+    request: QueryTableRequest,
+) -> Result<Response> {
+    let ctx = RequestContext { recipient };
+    let result = handler.query_table(request, ctx).await?;
+    let response = Response::builder()
+        .header(CONTENT_TYPE, "application/x-ndjson; charset=utf-8")
+        .body(Body::from(query_response_to_ndjson(result)?))
+        .map_err(|e| Error::generic(e.to_string()))?;
+    Ok(response)
+}
+
+async fn get_table_file<T: SharingQueryHandler>(
+    State(handler): State<T>,
+    Extension(recipient): Extension<Recipient>,
+    Path((share, schema, name, id)): Path<(String, String, String, String)>,
+) -> Result<Response> {
+    let ctx = RequestContext { recipient };
+    let request = GetTableFileRequest {
+        share,
+        schema,
+        name,
+        id,
+    };
+    let data = handler.get_table_file(request, ctx).await?;
+    Response::builder()
+        .header(CONTENT_TYPE, "application/octet-stream")
+        .body(Body::from(data))
+        .map_err(|e| Error::generic(e.to_string()))
+}
+
 fn query_response_to_ndjson(response: impl IntoIterator<Item = Result<String>>) -> Result<String> {
     Ok(response
         .into_iter()
@@ -0,0 +1,475 @@
+//! Envelope encryption for secrets stored by another [`SecretManager`].
+//!
+//! Every secret value is encrypted with a fresh AES-256-GCM data key. The data key is
+//! wrapped by a key-encryption key (KEK) and stored next to the ciphertext, so the
+//! underlying store never sees plaintext values or unwrapped keys.
+//!
+//! Values are bound to the version the underlying store keeps them under, so the
+//! underlying store must support [`SecretManager::put_secret_version`].
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Arc;
+
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
+use bytes::Bytes;
+use ring::aead::{Aad, LessSafeKey, Nonce, UnboundKey, AES_256_GCM, NONCE_LEN};
+use ring::rand::{SecureRandom, SystemRandom};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::SecretManager;
+use crate::{Error, Result};
+
+/// Length of data keys and local key-encryption keys in bytes.
+const KEY_LEN: usize = 32;
+
+/// Number of times a write is attempted when the secret is updated concurrently.
+const MAX_WRITE_ATTEMPTS: usize = 3;
+
+/// A data key wrapped by a key-encryption key.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WrappedKey {
+    /// Id of the key-encryption key that wrapped the data key.
+    pub key_id: String,
+    /// The wrapped data key.
+    #[serde(with = "base64_bytes")]
+    pub ciphertext: Vec<u8>,
+}
+
+/// Provider of the key-encryption keys that wrap data keys.
+///
+/// Implementations may keep keys locally or delegate to a key management service.
+#[async_trait::async_trait]
+pub trait KeyEncryptionKeyProvider: Send + Sync + 'static {
+    /// Id of the key new data keys are wrapped with.
+    fn primary_key_id(&self) -> String;
+
+    /// Wrap a data key with the primary key.
+    async fn wrap_key(&self, data_key: &[u8]) -> Result<WrappedKey>;
+
+    /// Unwrap a data key with the key that wrapped it.
+    async fn unwrap_key(&self, wrapped: &WrappedKey) -> Result<Vec<u8>>;
+}
+
+/// Key-encryption keys held in memory, typically loaded from a keyfile.
+///
+/// Rotating the primary key keeps the previous keys for unwrapping existing data keys.
+/// [`EncryptedSecretManager::rewrap_secret`] moves the latest version of a secret to the
+/// primary key, superseded versions keep the key they were written with. Retired keys
+/// are needed until those versions are purged from the underlying store.
+pub struct LocalKeyProvider {
+    primary: String,
+    keys: HashMap<String, LessSafeKey>,
+    rng: SystemRandom,
+}
+
+/// On-disk format of a keyfile.
+#[derive(Deserialize)]
+struct KeyFile {
+    primary: String,
+    keys: HashMap<String, String>,
+}
+
+impl LocalKeyProvider {
+    /// Create a provider that wraps data keys with `key`.
+    pub fn new(key_id: impl Into<String>, key: &[u8]) -> Result<Self> {
+        let key_id = key_id.into();
+        let mut keys = HashMap::new();
+        keys.insert(key_id.clone(), aead_key(key)?);
+        Ok(Self {
+            primary: key_id,
+            keys,
+            rng: SystemRandom::new(),
+        })
+    }
+
+    /// Keep a retired key to unwrap data keys that were wrapped with it.
+    pub fn with_key(mut self, key_id: impl Into<String>, key: &[u8]) -> Result<Self> {
+        self.keys.insert(key_id.into(), aead_key(key)?);
+        Ok(self)
+    }
+
+    /// Load keys from a JSON keyfile.
+    ///
+    /// The file maps key ids to base64 encoded 256 bit keys and names the primary key:
+    /// `{"primary": "2025-01", "keys": {"2024-07": "...", "2025-01": "..."}}`.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self> {
+        let data = std::fs::read(path.as_ref())
+            .map_err(|e| Error::generic(format!("failed to read keyfile: {e}")))?;
+        let file: KeyFile = serde_json::from_slice(&data)?;
+        let mut keys = HashMap::new();
+        for (key_id, key) in file.keys {
+            let key = BASE64_STANDARD
+                .decode(key)
+                .map_err(|_| Error::invalid_argument(format!("key '{key_id}' is not base64")))?;
+            keys.insert(key_id, aead_key(&key)?);
+        }
+        if !keys.contains_key(&file.primary) {
+            return Err(Error::invalid_argument(format!(
+                "primary key '{}' is not in the keyfile",
+                file.primary
+            )));
+        }
+        Ok(Self {
+            primary: file.primary,
+            keys,
+            rng: SystemRandom::new(),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl KeyEncryptionKeyProvider for LocalKeyProvider {
+    fn primary_key_id(&self) -> String {
+        self.primary.clone()
+    }
+
+    async fn wrap_key(&self, data_key: &[u8]) -> Result<WrappedKey> {
+        let key = &self.keys[&self.primary];
+        Ok(WrappedKey {
+            key_id: self.primary.clone(),
+            ciphertext: seal(&self.rng, key, self.primary.as_bytes(), data_key)?,
+        })
+    }
+
+    async fn unwrap_key(&self, wrapped: &WrappedKey) -> Result<Vec<u8>> {
+        let key = self
+            .keys
+            .get(&wrapped.key_id)
+            .ok_or_else(|| Error::generic(format!("unknown key '{}'", wrapped.key_id)))?;
+        open(key, wrapped.key_id.as_bytes(), &wrapped.ciphertext)
+    }
+}
+
+/// An encrypted secret value as stored in the underlying secret manager.
+#[derive(Serialize, Deserialize)]
+struct Envelope {
+    data_key: WrappedKey,
+    /// Nonce followed by the ciphertext and tag of the value.
+    #[serde(with = "base64_bytes")]
+    ciphertext: Vec<u8>,
+}
+
+/// A [`SecretManager`] that encrypts values before passing them to another manager.
+///
+/// The ciphertext of each value is bound to the secret name and the version the underlying
+/// store keeps it under, so tampering with the underlying store can neither move values to
+/// another secret nor roll a secret back by copying an older value into a newer version.
+pub struct EncryptedSecretManager<S: SecretManager> {
+    inner: S,
+    keys: Arc<dyn KeyEncryptionKeyProvider>,
+    rng: SystemRandom,
+}
+
+impl<S: SecretManager> EncryptedSecretManager<S> {
+    pub fn new(inner: S, keys: Arc<dyn KeyEncryptionKeyProvider>) -> Self {
+        Self {
+            inner,
+            keys,
+            rng: SystemRandom::new(),
+        }
+    }
+
+    /// Re-encrypt the latest version of a secret under the current primary key-encryption key.
+    ///
+    /// The value is written as a new version if the secret was not updated concurrently,
+    /// superseded versions are left untouched. Returns the new version of the secret, or
+    /// `None` if its data key was already wrapped with the primary key.
+    pub async fn rewrap_secret(&self, secret_name: &str) -> Result<Option<Uuid>> {
+        let mut attempt = 1;
+        loop {
+            let (current, value) = self.inner.get_secret(secret_name).await?;
+            let envelope: Envelope = serde_json::from_slice(&value)?;
+            if envelope.data_key.key_id == self.keys.primary_key_id() {
+                return Ok(None);
+            }
+            let plaintext = self.open_envelope(secret_name, current, envelope).await?;
+            match self.replace(secret_name, current, &plaintext).await {
+                Err(Error::Conflict) if attempt < MAX_WRITE_ATTEMPTS => attempt += 1,
+                result => return result.map(Some),
+            }
+        }
+    }
+
+    /// Write `value` as a new version of a secret whose latest version is `current`.
+    async fn replace(&self, secret_name: &str, current: Uuid, value: &[u8]) -> Result<Uuid> {
+        let version = Uuid::now_v7();
+        let value = self.encrypt(secret_name, version, value).await?;
+        self.inner
+            .put_secret_version(secret_name, Some(current), version, value)
+            .await?;
+        Ok(version)
+    }
+
+    async fn encrypt(&self, secret_name: &str, version: Uuid, value: &[u8]) -> Result<Bytes> {
+        let mut data_key = [0; KEY_LEN];
+        self.rng
+            .fill(&mut data_key)
+            .map_err(|_| Error::generic("failed to generate data key"))?;
+        let ciphertext = seal(
+            &self.rng,
+            &aead_key(&data_key)?,
+            &associated_data(secret_name, &version),
+            value,
+        )?;
+        let envelope = Envelope {
+            data_key: self.keys.wrap_key(&data_key).await?,
+            ciphertext,
+        };
+        Ok(serde_json::to_vec(&envelope)?.into())
+    }
+
+    async fn decrypt(&self, secret_name: &str, version: Uuid, value: &[u8]) -> Result<Bytes> {
+        let envelope: Envelope = serde_json::from_slice(value)?;
+        Ok(self
+            .open_envelope(secret_name, version, envelope)
+            .await?
+            .into())
+    }
+
+    async fn open_envelope(
+        &self,
+        secret_name: &str,
+        version: Uuid,
+        envelope: Envelope,
+    ) -> Result<Vec<u8>> {
+        let data_key = self.keys.unwrap_key(&envelope.data_key).await?;
+        open(
+            &aead_key(&data_key)?,
+            &associated_data(secret_name, &version),
+            &envelope.ciphertext,
+        )
+    }
+}
+
+#[async_trait::async_trait]
+impl<S: SecretManager> SecretManager for EncryptedSecretManager<S> {
+    async fn get_secret(&self, secret_name: &str) -> Result<(Uuid, Bytes)> {
+        let (version, value) = self.inner.get_secret(secret_name).await?;
+        Ok((version, self.decrypt(secret_name, version, &value).await?))
+    }
+
+    async fn get_secret_version(&self, secret_name: &str, version: Uuid) -> Result<Bytes> {
+        let value = self.inner.get_secret_version(secret_name, version).await?;
+        self.decrypt(secret_name, version, &value).await
+    }
+
+    async fn create_secret(&self, secret_name: &str, secret_value: Bytes) -> Result<Uuid> {
+        let version = Uuid::now_v7();
+        self.put_secret_version(secret_name, None, version, secret_value)
+            .await?;
+        Ok(version)
+    }
+
+    async fn update_secret(&self, secret_name: &str, secret_value: Bytes) -> Result<Uuid> {
+        // values are bound to their version, which is written on the condition that
+        // the secret was not updated since it was read.
+        let mut attempt = 1;
+        loop {
+            let (current, _) = self.inner.get_secret(secret_name).await?;
+            match self.replace(secret_name, current, &secret_value).await {
+                Err(Error::Conflict) if attempt < MAX_WRITE_ATTEMPTS => attempt += 1,
+                result => return result,
+            }
+        }
+    }
+
+    async fn delete_secret(&self, secret_name: &str) -> Result<()> {
+        self.inner.delete_secret(secret_name).await
+    }
+
+    async fn put_secret_version(
+        &self,
+        secret_name: &str,
+        current: Option<Uuid>,
+        version: Uuid,
+        secret_value: Bytes,
+    ) -> Result<()> {
+        let value = self.encrypt(secret_name, version, &secret_value).await?;
+        self.inner
+            .put_secret_version(secret_name, current, version, value)
+            .await
+    }
+}
+
+fn associated_data(secret_name: &str, version: &Uuid) -> Vec<u8> {
+    let mut aad = secret_name.as_bytes().to_vec();
+    aad.push(0);
+    aad.extend_from_slice(version.as_bytes());
+    aad
+}
+
+fn aead_key(key: &[u8]) -> Result<LessSafeKey> {
+    if key.len() != KEY_LEN {
+        return Err(Error::invalid_argument("encryption keys must be 256 bits"));
+    }
+    let key = UnboundKey::new(&AES_256_GCM, key)
+        .map_err(|_| Error::invalid_argument("invalid encryption key"))?;
+    Ok(LessSafeKey::new(key))
+}
+
+/// Encrypt `plaintext` under a random nonce, which is prepended to the ciphertext.
+fn seal(rng: &SystemRandom, key: &LessSafeKey, aad: &[u8], plaintext: &[u8]) -> Result<Vec<u8>> {
+    let mut nonce = [0; NONCE_LEN];
+    rng.fill(&mut nonce)
+        .map_err(|_| Error::generic("failed to generate nonce"))?;
+    let mut in_out = plaintext.to_vec();
+    key.seal_in_place_append_tag(
+        Nonce::assume_unique_for_key(nonce),
+        Aad::from(aad),
+        &mut in_out,
+    )
+    .map_err(|_| Error::generic("failed to encrypt secret"))?;
+    let mut sealed = nonce.to_vec();
+    sealed.extend(in_out);
+    Ok(sealed)
+}
+
+fn open(key: &LessSafeKey, aad: &[u8], sealed: &[u8]) -> Result<Vec<u8>> {
+    if sealed.len() < NONCE_LEN {
+        return Err(Error::generic("failed to decrypt secret"));
+    }
+    let (nonce, ciphertext) = sealed.split_at(NONCE_LEN);
+    let nonce = Nonce::try_assume_unique_for_key(nonce)
+        .map_err(|_| Error::generic("failed to decrypt secret"))?;
+    let mut in_out = ciphertext.to_vec();
+    let plaintext = key
+        .open_in_place(nonce, Aad::from(aad), &mut in_out)
+        .map_err(|_| Error::generic("failed to decrypt secret"))?;
+    Ok(plaintext.to_vec())
+}
+
+mod base64_bytes {
+    use base64::prelude::BASE64_STANDARD;
+    use base64::Engine;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&BASE64_STANDARD.encode(bytes))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        BASE64_STANDARD
+            .decode(value)
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(all(test, feature = "memory"))]
+mod tests {
+    use super::*;
+    use crate::memory::InMemoryResourceStore;
+
+    fn provider(key_id: &str, key: u8) -> LocalKeyProvider {
+        LocalKeyProvider::new(key_id, &[key; KEY_LEN]).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_encrypted_roundtrip() {
+        let store = Arc::new(InMemoryResourceStore::new());
+        let secrets = EncryptedSecretManager::new(store.clone(), Arc::new(provider("k1", 1)));
+
+        let version = secrets
+            .create_secret("secret", Bytes::from_static(b"value"))
+            .await
+            .unwrap();
+        let (current, value) = secrets.get_secret("secret").await.unwrap();
+        assert_eq!(current, version);
+        assert_eq!(value, Bytes::from_static(b"value"));
+
+        // the underlying store only sees the envelope
+        let (_, stored) = store.get_secret("secret").await.unwrap();
+        assert!(!stored.windows(5).any(|w| w == b"value"));
+
+        let updated = secrets
+            .update_secret("secret", Bytes::from_static(b"other"))
+            .await
+            .unwrap();
+        assert_eq!(
+            secrets.get_secret_version("secret", version).await.unwrap(),
+            Bytes::from_static(b"value")
+        );
+        assert_eq!(
+            secrets.get_secret_version("secret", updated).await.unwrap(),
+            Bytes::from_static(b"other")
+        );
+
+        // ciphertexts are bound to the name of their secret
+        store.create_secret("copy", stored.clone()).await.unwrap();
+        assert!(secrets.get_secret("copy").await.is_err());
+
+        // and to their version, so older values can not be restored as the latest one
+        store.update_secret("secret", stored).await.unwrap();
+        assert!(secrets.get_secret("secret").await.is_err());
+
+        let other = EncryptedSecretManager::new(store.clone(), Arc::new(provider("k1", 2)));
+        assert!(other.get_secret("secret").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_rewrap_secret() {
+        let store = Arc::new(InMemoryResourceStore::new());
+        let secrets = EncryptedSecretManager::new(store.clone(), Arc::new(provider("k1", 1)));
+        secrets
+            .create_secret("secret", Bytes::from_static(b"value"))
+            .await
+            .unwrap();
+        assert!(secrets.rewrap_secret("secret").await.unwrap().is_none());
+
+        let rotated = provider("k2", 2).with_key("k1", &[1; KEY_LEN]).unwrap();
+        let secrets = EncryptedSecretManager::new(store.clone(), Arc::new(rotated));
+        let (_, value) = secrets.get_secret("secret").await.unwrap();
+        assert_eq!(value, Bytes::from_static(b"value"));
+        let (previous, _) = store.get_secret("secret").await.unwrap();
+        let version = secrets.rewrap_secret("secret").await.unwrap().unwrap();
+        assert_eq!(store.get_secret("secret").await.unwrap().0, version);
+
+        // once re-wrapped, the retired key is only needed for superseded versions
+        let secrets = EncryptedSecretManager::new(store.clone(), Arc::new(provider("k2", 2)));
+        let (_, value) = secrets.get_secret("secret").await.unwrap();
+        assert_eq!(value, Bytes::from_static(b"value"));
+        assert!(secrets
+            .get_secret_version("secret", previous)
+            .await
+            .is_err());
+
+        // writes based on a superseded version do not replace the latest one
+        let result = secrets
+            .put_secret_version(
+                "secret",
+                Some(previous),
+                Uuid::now_v7(),
+                Bytes::from_static(b"stale"),
+            )
+            .await;
+        assert!(matches!(result, Err(Error::Conflict)));
+    }
+
+    #[test]
+    fn test_keyfile() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("keys.json");
+        let keys = serde_json::json!({
+            "primary": "k2",
+            "keys": {
+                "k1": BASE64_STANDARD.encode([1; KEY_LEN]),
+                "k2": BASE64_STANDARD.encode([2; KEY_LEN]),
+            }
+        });
+        std::fs::write(&path, keys.to_string()).unwrap();
+        let provider = LocalKeyProvider::from_file(&path).unwrap();
+        assert_eq!(provider.primary_key_id(), "k2");
+        assert_eq!(provider.keys.len(), 2);
+
+        let keys = serde_json::json!({
+            "primary": "k3",
+            "keys": { "k1": BASE64_STANDARD.encode([1; KEY_LEN]) }
+        });
+        std::fs::write(&path, keys.to_string()).unwrap();
+        assert!(LocalKeyProvider::from_file(&path).is_err());
+
+        assert!(LocalKeyProvider::new("short", &[1; 16]).is_err());
+    }
+}
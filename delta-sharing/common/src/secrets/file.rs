@@ -0,0 +1,337 @@
+//! Secrets provided by the deployment environment.
+//!
+//! Values are read from a directory containing one file per secret, as mounted for
+//! Kubernetes secret volumes, or from environment variables sharing a common prefix.
+//! Versions are derived from the content of a secret, so the same value always has the
+//! same version and rotated values get a new one.
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+use bytes::Bytes;
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+use super::SecretManager;
+use crate::{Error, Result};
+
+/// Where a [`FileSecretManager`] reads secret values from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SecretSource {
+    /// A directory with one file per secret, named after the secret.
+    Directory(PathBuf),
+    /// Environment variables named after the secret and prefixed with `prefix`.
+    ///
+    /// Secret names are upper-cased and characters other than ASCII letters and
+    /// digits are replaced with `_`, i.e. `my-secret` is read from `{prefix}MY_SECRET`.
+    Env { prefix: String },
+}
+
+/// A [`SecretManager`] resolving secrets from files or environment variables.
+///
+/// Values are cached in memory. Use [`reload`](Self::reload) or [`watch`](Self::watch)
+/// to pick up values that were rotated externally. Only the current version of a
+/// secret is available.
+#[derive(Clone)]
+pub struct FileSecretManager {
+    source: SecretSource,
+    read_only: bool,
+    secrets: Arc<RwLock<HashMap<String, (Uuid, Bytes)>>>,
+}
+
+impl FileSecretManager {
+    /// Create a manager reading secrets from the files in a directory.
+    ///
+    /// Hidden files are ignored, which skips the bookkeeping entries Kubernetes
+    /// creates when mounting secret volumes.
+    pub fn try_from_dir(path: impl AsRef<Path>) -> Result<Self> {
+        Self::try_new(SecretSource::Directory(path.as_ref().to_path_buf()))
+    }
+
+    /// Create a manager reading secrets from environment variables.
+    ///
+    /// Environment variables can not be written, so the manager is read-only.
+    pub fn from_env(prefix: impl Into<String>) -> Self {
+        let source = SecretSource::Env {
+            prefix: prefix.into(),
+        };
+        let secrets = load(&source).unwrap_or_default();
+        Self {
+            source,
+            read_only: true,
+            secrets: Arc::new(RwLock::new(secrets)),
+        }
+    }
+
+    fn try_new(source: SecretSource) -> Result<Self> {
+        let secrets = load(&source)?;
+        Ok(Self {
+            source,
+            read_only: false,
+            secrets: Arc::new(RwLock::new(secrets)),
+        })
+    }
+
+    /// Reject all attempts to create, update, or delete secrets.
+    pub fn with_read_only(mut self, read_only: bool) -> Self {
+        self.read_only = read_only || matches!(self.source, SecretSource::Env { .. });
+        self
+    }
+
+    /// Re-read all secrets from the source.
+    ///
+    /// Returns `true` if any secret was added, changed, or removed.
+    pub fn reload(&self) -> Result<bool> {
+        let secrets = load(&self.source)?;
+        let mut current = self.secrets.write().unwrap();
+        if *current == secrets {
+            return Ok(false);
+        }
+        *current = secrets;
+        Ok(true)
+    }
+
+    /// Periodically re-read the secrets to pick up rotated values.
+    pub fn watch(&self, interval: Duration) -> tokio::task::JoinHandle<()> {
+        let manager = self.clone();
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            loop {
+                ticker.tick().await;
+                match manager.reload() {
+                    Ok(true) => tracing::info!("reloaded secrets from {:?}", manager.source),
+                    Ok(false) => {}
+                    Err(e) => tracing::warn!("failed to reload secrets: {e}"),
+                }
+            }
+        })
+    }
+
+    /// Key of a secret in the source.
+    fn key(&self, secret_name: &str) -> Result<String> {
+        match &self.source {
+            SecretSource::Directory(_) => {
+                if secret_name.is_empty()
+                    || secret_name.starts_with('.')
+                    || secret_name.contains(['/', '\\'])
+                {
+                    return Err(Error::invalid_argument(format!(
+                        "invalid secret name '{secret_name}'"
+                    )));
+                }
+                Ok(secret_name.to_string())
+            }
+            SecretSource::Env { .. } => Ok(secret_name
+                .chars()
+                .map(|c| match c.is_ascii_alphanumeric() {
+                    true => c.to_ascii_uppercase(),
+                    false => '_',
+                })
+                .collect()),
+        }
+    }
+
+    fn write(&self, secret_name: &str, value: Bytes, exists: bool) -> Result<Uuid> {
+        let SecretSource::Directory(dir) = &self.source else {
+            return Err(Error::NotAllowed);
+        };
+        if self.read_only {
+            return Err(Error::NotAllowed);
+        }
+        let key = self.key(secret_name)?;
+        let path = dir.join(&key);
+        match (path.exists(), exists) {
+            (true, false) => return Err(Error::AlreadyExists),
+            (false, true) => return Err(Error::NotFound),
+            _ => {}
+        }
+        // write to a temporary file first, so readers never observe partial values.
+        let tmp = dir.join(format!(".{key}.tmp"));
+        std::fs::write(&tmp, &value).map_err(io_error)?;
+        std::fs::rename(&tmp, &path).map_err(io_error)?;
+        let version = content_version(&value);
+        self.secrets.write().unwrap().insert(key, (version, value));
+        Ok(version)
+    }
+}
+
+#[async_trait::async_trait]
+impl SecretManager for FileSecretManager {
+    async fn get_secret(&self, secret_name: &str) -> Result<(Uuid, Bytes)> {
+        let key = self.key(secret_name)?;
+        self.secrets
+            .read()
+            .unwrap()
+            .get(&key)
+            .cloned()
+            .ok_or(Error::NotFound)
+    }
+
+    async fn get_secret_version(&self, secret_name: &str, version: Uuid) -> Result<Bytes> {
+        let (current, value) = self.get_secret(secret_name).await?;
+        if current != version {
+            return Err(Error::NotFound);
+        }
+        Ok(value)
+    }
+
+    async fn create_secret(&self, secret_name: &str, secret_value: Bytes) -> Result<Uuid> {
+        self.write(secret_name, secret_value, false)
+    }
+
+    async fn update_secret(&self, secret_name: &str, secret_value: Bytes) -> Result<Uuid> {
+        self.write(secret_name, secret_value, true)
+    }
+
+    async fn delete_secret(&self, secret_name: &str) -> Result<()> {
+        let SecretSource::Directory(dir) = &self.source else {
+            return Err(Error::NotAllowed);
+        };
+        if self.read_only {
+            return Err(Error::NotAllowed);
+        }
+        let key = self.key(secret_name)?;
+        match std::fs::remove_file(dir.join(&key)) {
+            Ok(()) => {}
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Err(Error::NotFound),
+            Err(e) => return Err(io_error(e)),
+        }
+        self.secrets.write().unwrap().remove(&key);
+        Ok(())
+    }
+}
+
+/// Version of a secret value, derived from a hash of its content.
+fn content_version(value: &[u8]) -> Uuid {
+    let digest = Sha256::digest(value);
+    Uuid::new_v8(digest[..16].try_into().unwrap())
+}
+
+fn load(source: &SecretSource) -> Result<HashMap<String, (Uuid, Bytes)>> {
+    let mut secrets = HashMap::new();
+    match source {
+        SecretSource::Directory(dir) => {
+            for entry in std::fs::read_dir(dir).map_err(io_error)? {
+                let entry = entry.map_err(io_error)?;
+                let name = entry.file_name().to_string_lossy().to_string();
+                // metadata follows symlinks, as used for the files of secret volumes.
+                if name.starts_with('.')
+                    || !std::fs::metadata(entry.path()).is_ok_and(|m| m.is_file())
+                {
+                    continue;
+                }
+                let value = Bytes::from(std::fs::read(entry.path()).map_err(io_error)?);
+                secrets.insert(name, (content_version(&value), value));
+            }
+        }
+        SecretSource::Env { prefix } => {
+            for (key, value) in std::env::vars() {
+                if let Some(name) = key.strip_prefix(prefix.as_str()) {
+                    let value = Bytes::from(value);
+                    secrets.insert(name.to_string(), (content_version(&value), value));
+                }
+            }
+        }
+    }
+    Ok(secrets)
+}
+
+fn io_error(err: std::io::Error) -> Error {
+    Error::generic(format!("failed to access secrets: {err}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_directory_secrets() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("secret"), "v1").unwrap();
+        std::fs::write(dir.path().join(".hidden"), "hidden").unwrap();
+        std::fs::create_dir(dir.path().join("..data")).unwrap();
+
+        let secrets = FileSecretManager::try_from_dir(dir.path()).unwrap();
+        let (v1, value) = secrets.get_secret("secret").await.unwrap();
+        assert_eq!(value, Bytes::from_static(b"v1"));
+        assert!(secrets.get_secret(".hidden").await.is_err());
+        assert!(secrets.get_secret("../secret").await.is_err());
+
+        // rotated values are picked up on reload, versions follow the content.
+        std::fs::write(dir.path().join("secret"), "v2").unwrap();
+        assert_eq!(secrets.get_secret("secret").await.unwrap().0, v1);
+        assert!(secrets.reload().unwrap());
+        assert!(!secrets.reload().unwrap());
+        let (v2, value) = secrets.get_secret("secret").await.unwrap();
+        assert_ne!(v1, v2);
+        assert_eq!(value, Bytes::from_static(b"v2"));
+        assert!(secrets.get_secret_version("secret", v1).await.is_err());
+        assert_eq!(
+            secrets.get_secret_version("secret", v2).await.unwrap(),
+            Bytes::from_static(b"v2")
+        );
+
+        std::fs::write(dir.path().join("secret"), "v1").unwrap();
+        secrets.reload().unwrap();
+        assert_eq!(secrets.get_secret("secret").await.unwrap().0, v1);
+    }
+
+    #[tokio::test]
+    async fn test_directory_writes() {
+        let dir = tempfile::tempdir().unwrap();
+        let secrets = FileSecretManager::try_from_dir(dir.path()).unwrap();
+
+        let version = secrets
+            .create_secret("secret", Bytes::from_static(b"value"))
+            .await
+            .unwrap();
+        assert_eq!(std::fs::read(dir.path().join("secret")).unwrap(), b"value");
+        assert_eq!(secrets.get_secret("secret").await.unwrap().0, version);
+        assert!(matches!(
+            secrets
+                .create_secret("secret", Bytes::from_static(b"value"))
+                .await,
+            Err(Error::AlreadyExists)
+        ));
+        assert!(matches!(
+            secrets
+                .update_secret("other", Bytes::from_static(b"value"))
+                .await,
+            Err(Error::NotFound)
+        ));
+        secrets
+            .update_secret("secret", Bytes::from_static(b"updated"))
+            .await
+            .unwrap();
+        assert_eq!(
+            std::fs::read(dir.path().join("secret")).unwrap(),
+            b"updated"
+        );
+
+        let read_only = secrets.clone().with_read_only(true);
+        assert!(matches!(
+            read_only.delete_secret("secret").await,
+            Err(Error::NotAllowed)
+        ));
+        secrets.delete_secret("secret").await.unwrap();
+        assert!(!dir.path().join("secret").exists());
+        assert!(secrets.get_secret("secret").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_env_secrets() {
+        std::env::set_var("TEST_FILE_SECRETS_MY_SECRET", "value");
+        let secrets = FileSecretManager::from_env("TEST_FILE_SECRETS_");
+        let (_, value) = secrets.get_secret("my-secret").await.unwrap();
+        assert_eq!(value, Bytes::from_static(b"value"));
+        assert!(matches!(
+            secrets
+                .create_secret("other", Bytes::from_static(b"value"))
+                .await,
+            Err(Error::NotAllowed)
+        ));
+        std::env::remove_var("TEST_FILE_SECRETS_MY_SECRET");
+    }
+}
@@ -3,7 +3,15 @@ use std::sync::Arc;
 use bytes::Bytes;
 use uuid::Uuid;
 
-use crate::Result;
+use crate::{Error, Result};
+
+pub use encrypted::{
+    EncryptedSecretManager, KeyEncryptionKeyProvider, LocalKeyProvider, WrappedKey,
+};
+pub use file::{FileSecretManager, SecretSource};
+
+mod encrypted;
+mod file;
 
 /// A trait for managing secrets.
 ///
@@ -40,6 +48,27 @@ pub trait SecretManager: Send + Sync + 'static {
     /// Delete the secret with the given name.
     /// The secret manager should return an error if the secret does not exist
     async fn delete_secret(&self, secret_name: &str) -> Result<()>;
+
+    /// Write a version of a secret with an id chosen by the caller.
+    ///
+    /// Without `current` the secret is created, failing like [`create_secret`](Self::create_secret)
+    /// if it already exists. Otherwise the version is added to the secret if `current` is still
+    /// its latest version, and the write fails with [`Error::Conflict`] if the secret was
+    /// updated in the meantime. `version` must sort after all existing versions of the secret,
+    /// e.g. by using [`Uuid::now_v7`].
+    ///
+    /// Secret managers that derive versions themselves do not support this.
+    async fn put_secret_version(
+        &self,
+        _secret_name: &str,
+        _current: Option<Uuid>,
+        _version: Uuid,
+        _secret_value: Bytes,
+    ) -> Result<()> {
+        Err(Error::generic(
+            "secret manager does not support versions chosen by the caller",
+        ))
+    }
 }
 
 pub trait ProvidesSecretManager: Send + Sync + 'static {
@@ -67,6 +96,16 @@ impl<T: SecretManager> SecretManager for Arc<T> {
     async fn delete_secret(&self, secret_name: &str) -> Result<()> {
         T::delete_secret(self, secret_name).await
     }
+
+    async fn put_secret_version(
+        &self,
+        secret_name: &str,
+        current: Option<Uuid>,
+        version: Uuid,
+        secret_value: Bytes,
+    ) -> Result<()> {
+        T::put_secret_version(self, secret_name, current, version, secret_value).await
+    }
 }
 
 #[async_trait::async_trait]
@@ -96,4 +135,16 @@ impl<T: ProvidesSecretManager> SecretManager for T {
     async fn delete_secret(&self, secret_name: &str) -> Result<()> {
         self.secret_manager().delete_secret(secret_name).await
     }
+
+    async fn put_secret_version(
+        &self,
+        secret_name: &str,
+        current: Option<Uuid>,
+        version: Uuid,
+        secret_value: Bytes,
+    ) -> Result<()> {
+        self.secret_manager()
+            .put_secret_version(secret_name, current, version, secret_value)
+            .await
+    }
 }
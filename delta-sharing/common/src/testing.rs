@@ -0,0 +1,923 @@
+//! Reusable test suites for implementations of the store traits.
+//!
+//! Backends implementing [`ResourceStore`] outside of this crate can run the same checks
+//! as the built-in stores by enabling the `integration` feature.
+use std::collections::HashSet;
+use std::future::Future;
+
+use proptest::prelude::*;
+use proptest::strategy::ValueTree;
+use proptest::test_runner::{Config, TestRunner};
+
+use crate::models::{RecipientInfo, SchemaInfo};
+use crate::{
+    AssociationChange, AssociationLabel, CatalogInfo, Error, ObjectLabel, PropertyMap, Resource,
+    ResourceExt, ResourceIdent, ResourceName, ResourceRef, ResourceStore,
+};
+
+/// Check that a [`ResourceStore`] implementation conforms to the documented contract.
+///
+/// `new_store` must return an empty store, it is called once for every check.
+pub async fn resource_store_conformance<S, F, Fut>(new_store: F)
+where
+    S: ResourceStore,
+    F: Fn() -> Fut,
+    Fut: Future<Output = S>,
+{
+    check_unique_ids(&new_store().await).await;
+    check_resource_errors(&new_store().await).await;
+    check_etags(&new_store().await).await;
+    check_associations(&new_store().await).await;
+    check_cascading_delete(&new_store().await).await;
+    check_parent_links(&new_store().await).await;
+    check_association_changes(&new_store().await).await;
+    check_pagination(&new_store().await).await;
+    check_pagination_with_writes(&new_store().await).await;
+}
+
+/// Check that two [`ResourceStore`] implementations behave the same.
+///
+/// Random sequences of operations are applied to a fresh pair of stores and the results
+/// of every operation are compared. Failing sequences are shrunk before panicking.
+pub async fn resource_store_equivalence<A, B, FA, FB, FutA, FutB>(
+    new_reference: FA,
+    new_store: FB,
+    cases: u32,
+) where
+    A: ResourceStore,
+    B: ResourceStore,
+    FA: Fn() -> FutA,
+    FB: Fn() -> FutB,
+    FutA: Future<Output = A>,
+    FutB: Future<Output = B>,
+{
+    let (new_reference, new_store) = (&new_reference, &new_store);
+    let run = move |ops: Vec<Op>| async move {
+        let reference = new_reference().await;
+        let store = new_store().await;
+        for (idx, op) in ops.iter().enumerate() {
+            let expected = op.apply(&reference).await;
+            let actual = op.apply(&store).await;
+            if expected != actual {
+                return Err(format!(
+                    "operation {idx} ({op:?}): expected {expected:?}, got {actual:?}"
+                ));
+            }
+        }
+        Ok(())
+    };
+
+    // the runner is driven by hand, since test cases need to be awaited.
+    let strategy = prop::collection::vec(op_strategy(), 1..40);
+    let mut runner = TestRunner::new(Config {
+        cases,
+        ..Config::default()
+    });
+    for _ in 0..cases {
+        let mut tree = strategy.new_tree(&mut runner).unwrap();
+        let Err(message) = run(tree.current()).await else {
+            continue;
+        };
+        let mut failure = (tree.current(), message);
+        while tree.simplify() {
+            match run(tree.current()).await {
+                Err(message) => failure = (tree.current(), message),
+                Ok(()) => {
+                    while tree.complicate() {
+                        if let Err(message) = run(tree.current()).await {
+                            failure = (tree.current(), message);
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+        panic!(
+            "stores diverged: {}\nminimal sequence: {:#?}",
+            failure.1, failure.0
+        );
+    }
+}
+
+const NAMES: [&str; 4] = ["alpha", "beta", "gamma", "delta"];
+
+const LABELS: [ObjectLabel; 2] = [ObjectLabel::CatalogInfo, ObjectLabel::RecipientInfo];
+
+const ASSOCIATION_LABELS: [AssociationLabel; 4] = [
+    AssociationLabel::ParentOf,
+    AssociationLabel::ChildOf,
+    AssociationLabel::HasGrant,
+    AssociationLabel::DependsOn,
+];
+
+fn resource(label: &ObjectLabel, name: &str, comment: Option<String>) -> Resource {
+    match label {
+        ObjectLabel::RecipientInfo => RecipientInfo {
+            name: name.to_string(),
+            comment,
+            ..Default::default()
+        }
+        .into(),
+        _ => CatalogInfo {
+            name: name.to_string(),
+            comment,
+            ..Default::default()
+        }
+        .into(),
+    }
+}
+
+fn comment(resource: &Resource) -> Option<String> {
+    match resource {
+        Resource::RecipientInfo(info) => info.comment.clone(),
+        Resource::CatalogInfo(info) => info.comment.clone(),
+        _ => None,
+    }
+}
+
+fn ident(label: &ObjectLabel, name: &str) -> ResourceIdent {
+    label.to_ident(ResourceName::new([name]))
+}
+
+fn properties(value: &str) -> PropertyMap {
+    PropertyMap::from([("key".to_string(), value.into())])
+}
+
+async fn create<S: ResourceStore>(store: &S, label: &ObjectLabel, name: &str) -> ResourceIdent {
+    let (_, reference) = store
+        .create(resource(label, name, None))
+        .await
+        .unwrap_or_else(|e| panic!("failed to create {name}: {e}"));
+    label.to_ident(reference)
+}
+
+/// Names of all resources with the label, collected from pages of the given size.
+async fn list_names<S: ResourceStore>(
+    store: &S,
+    label: &ObjectLabel,
+    page_size: usize,
+) -> crate::Result<Vec<String>> {
+    let mut names = Vec::new();
+    let mut page_token = None;
+    loop {
+        let (resources, next) = store
+            .list(label, None, None, Some(page_size), page_token)
+            .await?;
+        assert!(resources.len() <= page_size, "page exceeds max results");
+        names.extend(resources.iter().map(|r| r.resource_name().to_string()));
+        match next {
+            Some(next) => page_token = Some(next),
+            None => return Ok(names),
+        }
+    }
+}
+
+/// Label and name of all targets of a resource's associations, in sorted order.
+async fn list_targets<S: ResourceStore>(
+    store: &S,
+    resource: &ResourceIdent,
+    label: &AssociationLabel,
+    target_label: Option<&ObjectLabel>,
+    page_size: usize,
+) -> crate::Result<Vec<(ObjectLabel, String)>> {
+    let target_label = target_label.map(|l| l.to_ident(ResourceRef::Undefined));
+    let mut targets = Vec::new();
+    let mut page_token = None;
+    loop {
+        let (idents, next) = store
+            .list_associations(
+                resource,
+                label,
+                target_label.as_ref(),
+                None,
+                Some(page_size),
+                page_token,
+            )
+            .await?;
+        assert!(idents.len() <= page_size, "page exceeds max results");
+        for ident in idents {
+            let (target, _) = store.get(&ident).await?;
+            targets.push((*ident.label(), target.resource_name().to_string()));
+        }
+        match next {
+            Some(next) => page_token = Some(next),
+            None => break,
+        }
+    }
+    targets.sort_by(|a, b| (a.0.as_ref(), &a.1).cmp(&(b.0.as_ref(), &b.1)));
+    Ok(targets)
+}
+
+async fn check_unique_ids<S: ResourceStore>(store: &S) {
+    let mut ids = HashSet::new();
+    for label in LABELS.iter() {
+        for name in NAMES {
+            let ident = create(store, label, name).await;
+            let ResourceRef::Uuid(id) = ident.reference() else {
+                panic!("created resources must be referenced by id");
+            };
+            assert!(ids.insert(*id), "duplicate id {id}");
+
+            let (found, reference) = store.get(&ident).await.unwrap();
+            assert_eq!(found.resource_name().to_string(), name);
+            assert_eq!(found.resource_label(), label);
+            let (_, by_name) = store.get(&self::ident(label, name)).await.unwrap();
+            assert_eq!(
+                by_name, reference,
+                "name and id must resolve to the same resource"
+            );
+        }
+    }
+
+    // ids are never re-used, even for a resource with the same name.
+    let alpha = ident(&ObjectLabel::CatalogInfo, "alpha");
+    store.delete(&alpha).await.unwrap();
+    let recreated = create(store, &ObjectLabel::CatalogInfo, "alpha").await;
+    let ResourceRef::Uuid(id) = recreated.reference() else {
+        panic!("created resources must be referenced by id");
+    };
+    assert!(ids.insert(*id), "id {id} was re-used");
+}
+
+async fn check_resource_errors<S: ResourceStore>(store: &S) {
+    let label = ObjectLabel::CatalogInfo;
+    let alpha = create(store, &label, "alpha").await;
+    create(store, &label, "beta").await;
+
+    let result = store.create(resource(&label, "alpha", None)).await;
+    assert!(
+        matches!(result, Err(Error::AlreadyExists)),
+        "create duplicate: {result:?}"
+    );
+    // names only need to be unique per label.
+    create(store, &ObjectLabel::RecipientInfo, "alpha").await;
+
+    let missing = ident(&label, "missing");
+    let result = store.get(&missing).await;
+    assert!(matches!(result, Err(Error::NotFound)), "get: {result:?}");
+    let result = store
+        .update(&missing, resource(&label, "missing", None))
+        .await;
+    assert!(matches!(result, Err(Error::NotFound)), "update: {result:?}");
+    let result = store.delete(&missing).await;
+    assert!(matches!(result, Err(Error::NotFound)), "delete: {result:?}");
+
+    let result = store.update(&alpha, resource(&label, "beta", None)).await;
+    assert!(
+        matches!(result, Err(Error::AlreadyExists)),
+        "rename to existing name: {result:?}"
+    );
+
+    let comment = Some("renamed".to_string());
+    store
+        .update(&alpha, resource(&label, "gamma", comment.clone()))
+        .await
+        .unwrap();
+    let result = store.get(&ident(&label, "alpha")).await;
+    assert!(
+        matches!(result, Err(Error::NotFound)),
+        "get renamed: {result:?}"
+    );
+    let (renamed, reference) = store.get(&ident(&label, "gamma")).await.unwrap();
+    assert_eq!(&reference, alpha.reference());
+    assert_eq!(self::comment(&renamed), comment);
+}
+
+async fn check_etags<S: ResourceStore>(store: &S) {
+    let label = ObjectLabel::CatalogInfo;
+    let (created, _) = store.create(resource(&label, "alpha", None)).await.unwrap();
+    let alpha = ident(&label, "alpha");
+    let etag = created
+        .etag()
+        .expect("created resources must carry an etag");
+
+    let (found, _) = store.get(&alpha).await.unwrap();
+    assert_eq!(found.etag(), Some(etag), "get must return the current etag");
+    let (listed, _) = store.list(&label, None, None, None, None).await.unwrap();
+    assert_eq!(
+        listed[0].etag(),
+        Some(etag),
+        "list must return the current etag"
+    );
+
+    let with_etag = |comment: &str, etag: &str| {
+        let mut updated = resource(&label, "alpha", Some(comment.to_string()));
+        updated.set_etag(Some(etag.to_string()));
+        updated
+    };
+    let (updated, _) = store
+        .update(&alpha, with_etag("first", etag))
+        .await
+        .unwrap();
+    let current = updated
+        .etag()
+        .expect("updated resources must carry an etag");
+    assert_ne!(current, etag, "updates must change the etag");
+    let (found, _) = store.get(&alpha).await.unwrap();
+    assert_eq!(found.etag(), Some(current));
+
+    // writes based on an outdated or invalid revision must not be applied.
+    for stale in [etag, "invalid"] {
+        let result = store.update(&alpha, with_etag("second", stale)).await;
+        assert!(
+            matches!(result, Err(Error::Conflict)),
+            "update with etag {stale}: {result:?}"
+        );
+    }
+    let (found, _) = store.get(&alpha).await.unwrap();
+    assert_eq!(comment(&found).as_deref(), Some("first"));
+    assert_eq!(found.etag(), Some(current));
+
+    let result = store
+        .update(&ident(&label, "missing"), with_etag("second", current))
+        .await;
+    assert!(matches!(result, Err(Error::NotFound)), "update: {result:?}");
+
+    // updates without an etag are applied unconditionally.
+    let comment = Some("second".to_string());
+    let (updated, _) = store
+        .update(&alpha, resource(&label, "alpha", comment.clone()))
+        .await
+        .unwrap();
+    assert_eq!(self::comment(&updated), comment);
+    assert_ne!(updated.etag(), Some(current));
+}
+
+async fn check_associations<S: ResourceStore>(store: &S) {
+    let catalog = create(store, &ObjectLabel::CatalogInfo, "catalog").await;
+    let other = create(store, &ObjectLabel::CatalogInfo, "other").await;
+    let recipient = create(store, &ObjectLabel::RecipientInfo, "recipient").await;
+
+    let props = properties("value");
+    store
+        .add_association(
+            &catalog,
+            &other,
+            &AssociationLabel::ParentOf,
+            Some(props.clone()),
+        )
+        .await
+        .unwrap();
+    store
+        .add_association(&catalog, &recipient, &AssociationLabel::ParentOf, None)
+        .await
+        .unwrap();
+
+    // associations can be resolved by name and from both ends.
+    let by_name = ident(&ObjectLabel::CatalogInfo, "catalog");
+    let found = store
+        .get_association(&by_name, &other, &AssociationLabel::ParentOf)
+        .await
+        .unwrap();
+    assert_eq!(found, Some(props.clone()));
+    let inverse = store
+        .get_association(&other, &catalog, &AssociationLabel::ChildOf)
+        .await
+        .unwrap();
+    assert_eq!(inverse, Some(props));
+    let result = store
+        .get_association(&catalog, &other, &AssociationLabel::ChildOf)
+        .await;
+    assert!(
+        matches!(result, Err(Error::NotFound)),
+        "get association: {result:?}"
+    );
+
+    let children = list_targets(store, &catalog, &AssociationLabel::ParentOf, None, 1)
+        .await
+        .unwrap();
+    assert_eq!(
+        children,
+        vec![
+            (ObjectLabel::CatalogInfo, "other".to_string()),
+            (ObjectLabel::RecipientInfo, "recipient".to_string()),
+        ]
+    );
+    let recipients = list_targets(
+        store,
+        &catalog,
+        &AssociationLabel::ParentOf,
+        Some(&ObjectLabel::RecipientInfo),
+        10,
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        recipients,
+        vec![(ObjectLabel::RecipientInfo, "recipient".to_string())]
+    );
+    let parents = list_targets(store, &recipient, &AssociationLabel::ChildOf, None, 10)
+        .await
+        .unwrap();
+    assert_eq!(
+        parents,
+        vec![(ObjectLabel::CatalogInfo, "catalog".to_string())]
+    );
+
+    // updating an association replaces its properties on both ends.
+    let updated = properties("updated");
+    store
+        .update_association(
+            &other,
+            &catalog,
+            &AssociationLabel::ChildOf,
+            Some(updated.clone()),
+        )
+        .await
+        .unwrap();
+    let found = store
+        .get_associations(
+            &catalog,
+            &[other.clone(), recipient.clone()],
+            &AssociationLabel::ParentOf,
+        )
+        .await
+        .unwrap();
+    assert_eq!(found, vec![Some(updated), None]);
+    let result = store
+        .update_association(&recipient, &other, &AssociationLabel::ChildOf, None)
+        .await;
+    assert!(
+        matches!(result, Err(Error::NotFound)),
+        "update missing association: {result:?}"
+    );
+    let result = store
+        .get_associations(
+            &catalog,
+            &[other.clone(), catalog.clone()],
+            &AssociationLabel::ParentOf,
+        )
+        .await;
+    assert!(
+        matches!(result, Err(Error::NotFound)),
+        "get missing associations: {result:?}"
+    );
+
+    // there is at most one association with a label between two resources.
+    let result = store
+        .add_association(&catalog, &other, &AssociationLabel::ParentOf, None)
+        .await;
+    assert!(
+        matches!(result, Err(Error::AlreadyExists)),
+        "add duplicate association: {result:?}"
+    );
+    let result = store
+        .add_association(&other, &catalog, &AssociationLabel::ChildOf, None)
+        .await;
+    assert!(
+        matches!(result, Err(Error::AlreadyExists)),
+        "add duplicate inverse association: {result:?}"
+    );
+
+    // removing an association removes its inverse.
+    store
+        .remove_association(&other, &catalog, &AssociationLabel::ChildOf)
+        .await
+        .unwrap();
+    let result = store
+        .get_association(&catalog, &other, &AssociationLabel::ParentOf)
+        .await;
+    assert!(
+        matches!(result, Err(Error::NotFound)),
+        "get removed association: {result:?}"
+    );
+    let result = store
+        .remove_association(&catalog, &other, &AssociationLabel::ParentOf)
+        .await;
+    assert!(
+        matches!(result, Err(Error::NotFound)),
+        "remove missing association: {result:?}"
+    );
+    let children = list_targets(store, &catalog, &AssociationLabel::ParentOf, None, 10)
+        .await
+        .unwrap();
+    assert_eq!(
+        children,
+        vec![(ObjectLabel::RecipientInfo, "recipient".to_string())]
+    );
+}
+
+async fn check_cascading_delete<S: ResourceStore>(store: &S) {
+    let label = ObjectLabel::CatalogInfo;
+    let deleted = create(store, &label, "deleted").await;
+    let child = create(store, &label, "child").await;
+    let parent = create(store, &label, "parent").await;
+    let recipient = create(store, &ObjectLabel::RecipientInfo, "recipient").await;
+    for (from, to, label) in [
+        (&deleted, &child, AssociationLabel::ParentOf),
+        (&parent, &deleted, AssociationLabel::ParentOf),
+        (&recipient, &deleted, AssociationLabel::HasGrant),
+        (&recipient, &child, AssociationLabel::HasGrant),
+    ] {
+        store.add_association(from, to, &label, None).await.unwrap();
+    }
+
+    store.delete(&deleted).await.unwrap();
+    for (resource, label) in [
+        (&child, AssociationLabel::ChildOf),
+        (&parent, AssociationLabel::ParentOf),
+    ] {
+        let targets = list_targets(store, resource, &label, None, 10)
+            .await
+            .unwrap();
+        assert!(
+            targets.is_empty(),
+            "associations of deleted resource remain"
+        );
+    }
+    let grants = list_targets(store, &recipient, &AssociationLabel::HasGrant, None, 10)
+        .await
+        .unwrap();
+    assert_eq!(grants, vec![(label, "child".to_string())]);
+
+    // a new resource with the same name does not inherit any associations.
+    let recreated = create(store, &label, "deleted").await;
+    for association in [AssociationLabel::ParentOf, AssociationLabel::ChildOf] {
+        let targets = list_targets(store, &recreated, &association, None, 10)
+            .await
+            .unwrap();
+        assert!(targets.is_empty(), "recreated resource has associations");
+    }
+    let result = store
+        .get_association(&recipient, &recreated, &AssociationLabel::HasGrant)
+        .await;
+    assert!(
+        matches!(result, Err(Error::NotFound)),
+        "get association: {result:?}"
+    );
+}
+
+async fn check_parent_links<S: ResourceStore>(store: &S) {
+    create(store, &ObjectLabel::CatalogInfo, "catalog").await;
+    create(store, &ObjectLabel::CatalogInfo, "other").await;
+    let schema = |catalog: &str| -> Resource {
+        SchemaInfo {
+            name: "schema".to_string(),
+            catalog_name: catalog.to_string(),
+            ..Default::default()
+        }
+        .into()
+    };
+
+    // resources are linked to the resource containing them when created ...
+    let (_, reference) = store.create(schema("catalog")).await.unwrap();
+    let ident = ResourceIdent::schema(reference);
+    let parents = list_targets(store, &ident, &AssociationLabel::ChildOf, None, 10)
+        .await
+        .unwrap();
+    assert_eq!(parents, vec![(ObjectLabel::CatalogInfo, "catalog".into())]);
+
+    // ... and moved to their new parent when renamed.
+    store.update(&ident, schema("other")).await.unwrap();
+    let parents = list_targets(store, &ident, &AssociationLabel::ChildOf, None, 10)
+        .await
+        .unwrap();
+    assert_eq!(parents, vec![(ObjectLabel::CatalogInfo, "other".into())]);
+}
+
+async fn check_association_changes<S: ResourceStore>(store: &S) {
+    let catalog = create(store, &ObjectLabel::CatalogInfo, "catalog").await;
+    let other = create(store, &ObjectLabel::CatalogInfo, "other").await;
+    let recipient = create(store, &ObjectLabel::RecipientInfo, "recipient").await;
+    let label = AssociationLabel::HasGrant;
+    store
+        .add_association(&recipient, &catalog, &label, Some(properties("before")))
+        .await
+        .unwrap();
+    let grant = |to: &ResourceIdent, value: &str| AssociationChange::Add {
+        from: recipient.clone(),
+        to: to.clone(),
+        label: label.clone(),
+        properties: Some(properties(value)),
+    };
+    let update = AssociationChange::Update {
+        from: recipient.clone(),
+        to: catalog.clone(),
+        label: label.clone(),
+        properties: Some(properties("after")),
+    };
+
+    // a failing change discards all changes in the batch ...
+    let changes = [
+        update.clone(),
+        grant(&other, "other"),
+        grant(&catalog, "dup"),
+    ];
+    let result = store.apply_association_changes(&changes).await;
+    assert!(
+        matches!(result, Err(Error::AlreadyExists)),
+        "apply changes: {result:?}"
+    );
+    let found = store
+        .get_association(&recipient, &catalog, &label)
+        .await
+        .unwrap();
+    assert_eq!(found, Some(properties("before")));
+    let result = store.get_association(&recipient, &other, &label).await;
+    assert!(
+        !matches!(result, Ok(Some(_))),
+        "get association: {result:?}"
+    );
+
+    // ... while a valid batch is applied as a whole.
+    let remove = AssociationChange::Remove {
+        from: recipient.clone(),
+        to: catalog.clone(),
+        label: label.clone(),
+    };
+    store
+        .apply_association_changes(&[update, grant(&other, "other"), remove])
+        .await
+        .unwrap();
+    let targets = list_targets(store, &recipient, &label, None, 10)
+        .await
+        .unwrap();
+    assert_eq!(targets, vec![(ObjectLabel::CatalogInfo, "other".into())]);
+    let found = store
+        .get_association(&recipient, &other, &label)
+        .await
+        .unwrap();
+    assert_eq!(found, Some(properties("other")));
+}
+
+async fn check_pagination<S: ResourceStore>(store: &S) {
+    let label = ObjectLabel::CatalogInfo;
+    let (resources, next) = store.list(&label, None, None, None, None).await.unwrap();
+    assert!(resources.is_empty() && next.is_none());
+
+    let mut expected = Vec::new();
+    for idx in 0..7 {
+        let name = format!("catalog_{idx}");
+        create(store, &label, &name).await;
+        expected.push(name);
+    }
+    create(store, &ObjectLabel::RecipientInfo, "recipient").await;
+    expected.sort();
+
+    for page_size in [1, 3, 7, 10] {
+        let mut names = list_names(store, &label, page_size).await.unwrap();
+        names.sort();
+        assert_eq!(names, expected, "listing with page size {page_size}");
+    }
+}
+
+async fn check_pagination_with_writes<S: ResourceStore>(store: &S) {
+    let label = ObjectLabel::CatalogInfo;
+    let mut initial = HashSet::new();
+    for idx in 0..8 {
+        let name = format!("catalog_{idx}");
+        create(store, &label, &name).await;
+        initial.insert(name);
+    }
+
+    let mut seen = Vec::new();
+    let mut removed = HashSet::new();
+    let mut page_token = None;
+    let mut page = 0;
+    loop {
+        let (resources, next) = store
+            .list(&label, None, None, Some(2), page_token)
+            .await
+            .unwrap();
+        seen.extend(resources.iter().map(|r| r.resource_name().to_string()));
+
+        // modify the store between requesting pages.
+        create(store, &label, &format!("added_{page}")).await;
+        if let Some(name) = initial
+            .iter()
+            .find(|name| !seen.contains(name) && !removed.contains(*name))
+            .cloned()
+        {
+            store.delete(&ident(&label, &name)).await.unwrap();
+            removed.insert(name);
+        }
+        page += 1;
+
+        match next {
+            Some(next) => page_token = Some(next),
+            None => break,
+        }
+    }
+
+    let unique = seen.iter().collect::<HashSet<_>>();
+    assert_eq!(unique.len(), seen.len(), "resources listed twice: {seen:?}");
+    for name in initial.difference(&removed) {
+        assert!(seen.contains(name), "{name} was skipped while paging");
+    }
+    for name in removed.iter() {
+        assert!(!seen.contains(name), "deleted {name} was listed");
+    }
+}
+
+/// Operations applied to stores when comparing implementations.
+///
+/// Resources are referred to by name, since ids differ between stores.
+#[derive(Debug, Clone)]
+enum Op {
+    Create(ObjectLabel, &'static str, Option<String>),
+    Get(ObjectLabel, &'static str),
+    Update(ObjectLabel, &'static str, &'static str, Option<String>),
+    Delete(ObjectLabel, &'static str),
+    List(ObjectLabel, usize),
+    AddAssociation(
+        (ObjectLabel, &'static str),
+        (ObjectLabel, &'static str),
+        AssociationLabel,
+        Option<String>,
+    ),
+    RemoveAssociation(
+        (ObjectLabel, &'static str),
+        (ObjectLabel, &'static str),
+        AssociationLabel,
+    ),
+    GetAssociation(
+        (ObjectLabel, &'static str),
+        (ObjectLabel, &'static str),
+        AssociationLabel,
+    ),
+    ListAssociations(
+        (ObjectLabel, &'static str),
+        AssociationLabel,
+        Option<ObjectLabel>,
+        usize,
+    ),
+}
+
+/// Observable result of an operation.
+#[derive(Debug, PartialEq)]
+enum Outcome {
+    Done,
+    Resource(String, Option<String>),
+    Names(Vec<String>),
+    Targets(Vec<(ObjectLabel, String)>),
+    Properties(Option<PropertyMap>),
+    NotFound,
+    AlreadyExists,
+    Failed,
+}
+
+impl From<Error> for Outcome {
+    fn from(error: Error) -> Self {
+        match error {
+            Error::NotFound => Outcome::NotFound,
+            Error::AlreadyExists => Outcome::AlreadyExists,
+            _ => Outcome::Failed,
+        }
+    }
+}
+
+impl Op {
+    async fn apply<S: ResourceStore>(&self, store: &S) -> Outcome {
+        self.try_apply(store).await.unwrap_or_else(Outcome::from)
+    }
+
+    async fn try_apply<S: ResourceStore>(&self, store: &S) -> crate::Result<Outcome> {
+        let to_ident = |(label, name): &(ObjectLabel, &str)| ident(label, name);
+        Ok(match self {
+            Op::Create(label, name, comment) => {
+                store.create(resource(label, name, comment.clone())).await?;
+                Outcome::Done
+            }
+            Op::Get(label, name) => {
+                let (resource, _) = store.get(&ident(label, name)).await?;
+                Outcome::Resource(resource.resource_name().to_string(), comment(&resource))
+            }
+            Op::Update(label, name, new_name, comment) => {
+                let updated = resource(label, new_name, comment.clone());
+                store.update(&ident(label, name), updated).await?;
+                Outcome::Done
+            }
+            Op::Delete(label, name) => {
+                store.delete(&ident(label, name)).await?;
+                Outcome::Done
+            }
+            Op::List(label, page_size) => {
+                // ids created within the same millisecond may be ordered differently.
+                let mut names = list_names(store, label, *page_size).await?;
+                names.sort();
+                Outcome::Names(names)
+            }
+            Op::AddAssociation(from, to, label, value) => {
+                store
+                    .add_association(
+                        &to_ident(from),
+                        &to_ident(to),
+                        label,
+                        value.as_deref().map(properties),
+                    )
+                    .await?;
+                Outcome::Done
+            }
+            Op::RemoveAssociation(from, to, label) => {
+                store
+                    .remove_association(&to_ident(from), &to_ident(to), label)
+                    .await?;
+                Outcome::Done
+            }
+            Op::GetAssociation(from, to, label) => Outcome::Properties(
+                store
+                    .get_association(&to_ident(from), &to_ident(to), label)
+                    .await?,
+            ),
+            Op::ListAssociations(from, label, target_label, page_size) => Outcome::Targets(
+                list_targets(
+                    store,
+                    &to_ident(from),
+                    label,
+                    target_label.as_ref(),
+                    *page_size,
+                )
+                .await?,
+            ),
+        })
+    }
+}
+
+fn op_strategy() -> impl Strategy<Value = Op> {
+    let label = || prop::sample::select(LABELS.to_vec());
+    let name = || prop::sample::select(NAMES.to_vec());
+    let key = move || (label(), name());
+    let association_label = || prop::sample::select(ASSOCIATION_LABELS.to_vec());
+    let comment =
+        || prop::option::of(prop::sample::select(vec!["one", "two"]).prop_map(String::from));
+    prop_oneof![
+        3 => (label(), name(), comment()).prop_map(|(l, n, c)| Op::Create(l, n, c)),
+        1 => (label(), name()).prop_map(|(l, n)| Op::Get(l, n)),
+        1 => (label(), name(), name(), comment())
+            .prop_map(|(l, n, r, c)| Op::Update(l, n, r, c)),
+        1 => (label(), name()).prop_map(|(l, n)| Op::Delete(l, n)),
+        1 => (label(), 1..4usize).prop_map(|(l, s)| Op::List(l, s)),
+        3 => (key(), key(), association_label(), comment())
+            .prop_map(|(f, t, l, c)| Op::AddAssociation(f, t, l, c)),
+        1 => (key(), key(), association_label())
+            .prop_map(|(f, t, l)| Op::RemoveAssociation(f, t, l)),
+        1 => (key(), key(), association_label())
+            .prop_map(|(f, t, l)| Op::GetAssociation(f, t, l)),
+        1 => (key(), association_label(), prop::option::of(label()), 1..4usize)
+            .prop_map(|(f, l, t, s)| Op::ListAssociations(f, l, t, s)),
+    ]
+}
+
+#[cfg(test)]
+pub(crate) use self::handler::{context, TestHandler};
+
+/// Fixtures shared by the unit tests of the API handlers.
+#[cfg(test)]
+mod handler {
+    use std::sync::Arc;
+
+    use crate::api::RequestContext;
+    use crate::memory::InMemoryResourceStore;
+    use crate::policy::{ConstantPolicy, Decision, Policy, ProvidesPolicy};
+    use crate::secrets::{ProvidesSecretManager, SecretManager};
+    use crate::{ProvidesResourceStore, Recipient, ResourceStore};
+
+    /// Handler backed by an in-memory store, which also manages the secrets,
+    /// and a policy returning a constant decision.
+    pub(crate) struct TestHandler {
+        pub(crate) store: InMemoryResourceStore,
+        pub(crate) policy: Arc<dyn Policy>,
+    }
+
+    impl TestHandler {
+        pub(crate) fn new(decision: Decision) -> Self {
+            Self {
+                store: InMemoryResourceStore::new(),
+                policy: Arc::new(ConstantPolicy::new(decision)),
+            }
+        }
+
+        /// Replace the policy, keeping the stored resources.
+        pub(crate) fn with_decision(self, decision: Decision) -> Self {
+            Self {
+                policy: Arc::new(ConstantPolicy::new(decision)),
+                ..self
+            }
+        }
+    }
+
+    impl ProvidesResourceStore for TestHandler {
+        fn store(&self) -> &dyn ResourceStore {
+            &self.store
+        }
+    }
+
+    impl ProvidesPolicy for TestHandler {
+        fn policy(&self) -> &Arc<dyn Policy> {
+            &self.policy
+        }
+    }
+
+    impl ProvidesSecretManager for TestHandler {
+        fn secret_manager(&self) -> &dyn SecretManager {
+            &self.store
+        }
+    }
+
+    /// Context of an anonymous request.
+    pub(crate) fn context() -> RequestContext {
+        RequestContext {
+            recipient: Recipient::anonymous(),
+        }
+    }
+}
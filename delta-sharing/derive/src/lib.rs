@@ -55,6 +55,10 @@ pub fn parse_column_name(input: proc_macro::TokenStream) -> proc_macro::TokenStr
 /// Names extracted from path parameters must be specified in hierarchical order, e.g.:
 /// share -> schema -> table
 /// catalog -> schema -> table
+///
+/// Requests served at a different route than the rest of the block can override it with
+/// `at "route"` after the response type. Requests that do not target a single stored
+/// resource use `_` as resource, in which case the handler must authorize the request itself.
 #[proc_macro]
 pub fn rest_handlers(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse_macro_input!(input as HandlerParams);
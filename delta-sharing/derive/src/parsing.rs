@@ -11,6 +11,7 @@ mod kw {
     ::syn::custom_keyword!(with);
     ::syn::custom_keyword!(path);
     ::syn::custom_keyword!(query);
+    ::syn::custom_keyword!(at);
 }
 
 /// Source of a field, e.g. path or query parameters
@@ -55,6 +56,8 @@ pub struct HandlerDef {
     pub fields: Vec<FieldDef>,
     pub permission: Type,
     pub resource: Type,
+    /// Route segments overriding the route of the handler block.
+    pub segments: Option<Vec<String>>,
 }
 
 impl Parse for HandlerDef {
@@ -73,6 +76,14 @@ impl Parse for HandlerDef {
             response_type = Some(input.parse()?);
         }
 
+        // Parse optional route override
+        let mut segments = None;
+        if input.peek(kw::at) {
+            input.parse::<kw::at>()?;
+            let route: LitStr = input.parse()?;
+            segments = Some(route.value().split('/').map(|v| v.to_string()).collect());
+        }
+
         // Parse optional field definitions
         let mut fields = Vec::new();
         if input.peek(kw::with) {
@@ -89,6 +100,7 @@ impl Parse for HandlerDef {
             fields,
             permission,
             resource,
+            segments,
         })
     }
 }
@@ -40,6 +40,7 @@ pub fn to_handler(handler: &HandlerDef, handler_type: &Type) -> proc_macro2::Tok
 }
 
 pub fn to_client(handler: &HandlerDef, path_segments: &[String]) -> proc_macro2::TokenStream {
+    let path_segments = handler.segments.as_deref().unwrap_or(path_segments);
     let handler_name = generate_handler_name(&handler.request_type);
     let fn_name = Ident::new(&handler_name, Span::call_site());
     let request_type = &handler.request_type;
@@ -112,6 +113,11 @@ pub fn to_client(handler: &HandlerDef, path_segments: &[String]) -> proc_macro2:
     if !path_names.is_empty() {
         template.push_str("/{}");
     }
+    // path parameters beyond the named segments follow each other directly,
+    // e.g. `permissions/{securable_type}/{full_name}`.
+    for _ in used_segments.len()..path_names.len() {
+        template.push_str("/{}");
+    }
 
     let template = LitStr::new(&template, Span::call_site());
 
@@ -165,6 +171,9 @@ pub fn to_client(handler: &HandlerDef, path_segments: &[String]) -> proc_macro2:
         (RequestType::Update, Some(response_type)) => {
             quote! {}
         }
+        (RequestType::Query, Some(_)) => {
+            quote! {}
+        }
         (RequestType::Delete, None) => {
             quote! {
                 pub async fn #fn_name(
@@ -188,12 +197,86 @@ pub fn to_request_impl(handler: &HandlerDef) -> proc_macro2::TokenStream {
     let request_type = &handler.request_type;
     let type_name = get_type_name(request_type).unwrap();
 
-    match get_request_type(&type_name) {
+    let request_kind = get_request_type(&type_name);
+    match request_kind {
         RequestType::List => {
             // Generate paginated implementation
             generate_path_query_request_impl(request_type, &handler.fields, true)
         }
-        RequestType::Create | RequestType::Update => {
+        RequestType::Update | RequestType::Query => {
+            // Generate JSON body implementation, with the etag taken from an If-Match header
+            // and the fields identifying the resource taken from the path.
+            let path_fields: Vec<_> = handler
+                .fields
+                .iter()
+                .filter(|f| matches!(f.source, FieldSource::Path))
+                .collect();
+            let path_names: Vec<_> = path_fields.iter().map(|f| &f.name).collect();
+            let path_types: Vec<_> = path_fields.iter().map(|f| &f.ty).collect();
+            let path_ext = (!path_names.is_empty())
+                .then(|| {
+                    quote! {
+                        use ::axum::extract::Path;
+                        let Path((#(#path_names),*)) = req
+                            .extract_parts::<Path<(#(#path_types),*)>>()
+                            .await
+                            .map_err(::axum::response::IntoResponse::into_response)?;
+                    }
+                })
+                .unwrap_or_default();
+            // queries and requests not targeting a single stored resource have no etag.
+            let (if_match, set_etag) = if matches!(request_kind, RequestType::Query)
+                || matches!(handler.resource, Type::Infer(_))
+            {
+                Default::default()
+            } else {
+                (
+                    quote! {
+                        let if_match = match req.headers().get(::axum::http::header::IF_MATCH) {
+                            Some(value) => match value.to_str() {
+                                Ok(etag) => Some(etag.trim().trim_matches('"').to_string()),
+                                Err(_) => {
+                                    return Err(::axum::response::IntoResponse::into_response(
+                                        Error::invalid_argument("Invalid If-Match header"),
+                                    ))
+                                }
+                            },
+                            None => None,
+                        };
+                    },
+                    quote! {
+                        if let Some(etag) = if_match {
+                            // `*` matches any revision of an existing resource.
+                            request.etag = (etag != "*").then_some(etag);
+                        }
+                    },
+                )
+            };
+            let req_mut = (!path_names.is_empty()).then(|| quote! { mut });
+            let request_mut =
+                (!path_names.is_empty() || !set_etag.is_empty()).then(|| quote! { mut });
+            quote! {
+                impl<S: Send + Sync> ::axum::extract::FromRequest<S> for #request_type {
+                    type Rejection = ::axum::response::Response;
+
+                    async fn from_request(
+                        #req_mut req: ::axum::extract::Request<::axum::body::Body>,
+                        _state: &S
+                    ) -> Result<Self, Self::Rejection> {
+                        #path_ext
+                        #if_match
+                        let ::axum::extract::Json(#request_mut request) = req
+                            .extract::<::axum::extract::Json<Self>, _>()
+                            .await
+                            .map_err(::axum::response::IntoResponse::into_response)?;
+                        #set_etag
+                        #(request.#path_names = #path_names;)*
+                        Ok(request)
+                    }
+                }
+            }
+        }
+        RequestType::Create => {
             // Generate JSON body implementation
             quote! {
                 impl<S: Send + Sync> ::axum::extract::FromRequest<S> for #request_type {
@@ -221,6 +304,10 @@ pub fn to_request_impl(handler: &HandlerDef) -> proc_macro2::TokenStream {
 
 pub(crate) fn to_action(handler: &HandlerDef) -> proc_macro2::TokenStream {
     let resource = &handler.resource;
+    // `_` marks requests whose resource is resolved by the handler itself.
+    if matches!(resource, Type::Infer(_)) {
+        return quote! {};
+    }
     let request_type = &handler.request_type;
     let permission = &handler.permission;
     // HACK: we should probably annotate the query fields that should be extracted for
@@ -319,6 +406,7 @@ enum RequestType {
     List,
     Create,
     Update,
+    Query,
     Get,
     Delete,
 }
@@ -327,15 +415,19 @@ fn get_request_type(type_name: &str) -> RequestType {
     // Heuristics:
     //   “ListFooRequest” => List
     //   “CreateBarRequest” => Create
+    //   “ValidateQuxRequest” => Create, validations post a JSON body as well
     //   “UpdateBazRequest” => Update
+    //   “QueryQuuxRequest” => Query, queries post a JSON body with the resource in the path
     //   “GetSomethingRequest” => Get
     //   “DeleteSomethingRequest” => Delete
     if type_name.starts_with("List") {
         RequestType::List
-    } else if type_name.starts_with("Create") {
+    } else if type_name.starts_with("Create") || type_name.starts_with("Validate") {
         RequestType::Create
     } else if type_name.starts_with("Update") {
         RequestType::Update
+    } else if type_name.starts_with("Query") {
+        RequestType::Query
     } else if type_name.starts_with("Get") {
         RequestType::Get
     } else if type_name.starts_with("Delete") {
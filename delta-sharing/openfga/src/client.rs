@@ -1,9 +1,54 @@
+use std::collections::HashMap;
+
+use tonic::body::BoxBody;
+use tonic::client::GrpcService;
 use tonic::codegen::{Body, Bytes, StdError};
 use tonic::transport::{Channel, Endpoint};
 
 use crate::error::{Error, Result};
+use crate::gen::v1::batch_check_single_result::CheckResult;
 use crate::gen::v1::open_fga_service_client::OpenFgaServiceClient;
-use crate::gen::v1::{AuthorizationModel, ListStoresRequest, ReadAuthorizationModelsRequest};
+use crate::gen::v1::{
+    AuthorizationModel, BatchCheckItem, BatchCheckRequest, CheckRequest, CheckRequestTupleKey,
+    ListStoresRequest, ReadAuthorizationModelsRequest, ReadRequest, ReadRequestTupleKey, TupleKey,
+    TupleKeyWithoutCondition, WriteRequest, WriteRequestDeletes, WriteRequestWrites,
+};
+
+/// Maximum number of checks OpenFGA accepts in a single batch check request.
+const MAX_BATCH_CHECK_SIZE: usize = 50;
+
+/// Maximum number of tuples OpenFGA accepts in a single write request.
+pub(crate) const MAX_TUPLES_PER_WRITE: usize = 100;
+
+/// Transport that can be used by an [`OpenFgaClient`].
+///
+/// This is implemented for [`Channel`], but allows to plug in any other gRPC service,
+/// e.g. to run against a mock in tests.
+pub trait GrpcTransport:
+    GrpcService<
+        BoxBody,
+        Error: Into<StdError>,
+        ResponseBody: Body<Data = Bytes, Error: Into<StdError> + Send> + Send + 'static,
+        Future: Send,
+    > + Clone
+    + Send
+    + Sync
+    + 'static
+{
+}
+
+impl<T> GrpcTransport for T where
+    T: GrpcService<
+            BoxBody,
+            Error: Into<StdError>,
+            ResponseBody: Body<Data = Bytes, Error: Into<StdError> + Send> + Send + 'static,
+            Future: Send,
+        > + Clone
+        + Send
+        + Sync
+        + 'static
+{
+}
 
 impl<T> OpenFgaServiceClient<T>
 where
@@ -54,17 +99,16 @@ pub struct ClientConfig {
 }
 
 #[derive(Clone)]
-pub struct OpenFgaClient {
-    inner: OpenFgaServiceClient<Channel>,
+pub struct OpenFgaClient<T = Channel> {
+    inner: OpenFgaServiceClient<T>,
     store_id: String,
-    #[allow(unused)]
     authorization_model_id: String,
 }
 
-impl OpenFgaClient {
+impl<T: GrpcTransport> OpenFgaClient<T> {
     /// Create a new instance of [`OpenFgaClient`].
     pub fn new(
-        client: OpenFgaServiceClient<Channel>,
+        client: OpenFgaServiceClient<T>,
         store_id: impl Into<String>,
         authorization_model_id: impl Into<String>,
     ) -> Self {
@@ -75,6 +119,127 @@ impl OpenFgaClient {
         }
     }
 
+    /// Read the latest authorization model for the current store
+    pub async fn read_latest_authoriztation_model(&self) -> Result<Option<AuthorizationModel>> {
+        self.inner
+            .clone()
+            .read_latest_authoriztation_model(&self.store_id)
+            .await
+    }
+
+    /// Check if the user has the relation to the object.
+    pub async fn check(
+        &self,
+        user: impl Into<String>,
+        relation: impl Into<String>,
+        object: impl Into<String>,
+    ) -> Result<bool> {
+        let request = CheckRequest {
+            store_id: self.store_id.clone(),
+            authorization_model_id: self.authorization_model_id.clone(),
+            tuple_key: Some(CheckRequestTupleKey {
+                user: user.into(),
+                relation: relation.into(),
+                object: object.into(),
+            }),
+            ..Default::default()
+        };
+        Ok(self
+            .inner
+            .clone()
+            .check(request)
+            .await?
+            .into_inner()
+            .allowed)
+    }
+
+    /// Run multiple checks, returning the results in the order of the given tuples.
+    ///
+    /// Checks are sent in batches of at most 50 items, the limit enforced by OpenFGA.
+    pub async fn batch_check(&self, tuples: Vec<CheckRequestTupleKey>) -> Result<Vec<bool>> {
+        let mut results = Vec::with_capacity(tuples.len());
+        for chunk in tuples.chunks(MAX_BATCH_CHECK_SIZE) {
+            let request = BatchCheckRequest {
+                store_id: self.store_id.clone(),
+                authorization_model_id: self.authorization_model_id.clone(),
+                checks: chunk
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, tuple)| BatchCheckItem {
+                        tuple_key: Some(tuple.clone()),
+                        correlation_id: idx.to_string(),
+                        ..Default::default()
+                    })
+                    .collect(),
+                ..Default::default()
+            };
+            let mut response: HashMap<_, _> = self
+                .inner
+                .clone()
+                .batch_check(request)
+                .await?
+                .into_inner()
+                .result;
+            for idx in 0..chunk.len() {
+                let result = response
+                    .remove(&idx.to_string())
+                    .and_then(|r| r.check_result);
+                match result {
+                    Some(CheckResult::Allowed(allowed)) => results.push(allowed),
+                    Some(CheckResult::Error(err)) => return Err(Error::check_failed(err.message)),
+                    None => return Err(Error::check_failed("missing result in batch check")),
+                }
+            }
+        }
+        Ok(results)
+    }
+
+    /// Write and delete relationship tuples in a single transaction.
+    ///
+    /// OpenFGA rejects requests with more than [`MAX_TUPLES_PER_WRITE`] tuples in total.
+    pub async fn write(
+        &self,
+        writes: Vec<TupleKey>,
+        deletes: Vec<TupleKeyWithoutCondition>,
+    ) -> Result<()> {
+        if writes.is_empty() && deletes.is_empty() {
+            return Ok(());
+        }
+        let request = WriteRequest {
+            store_id: self.store_id.clone(),
+            authorization_model_id: self.authorization_model_id.clone(),
+            writes: (!writes.is_empty()).then_some(WriteRequestWrites { tuple_keys: writes }),
+            deletes: (!deletes.is_empty()).then_some(WriteRequestDeletes {
+                tuple_keys: deletes,
+            }),
+        };
+        self.inner.clone().write(request).await?;
+        Ok(())
+    }
+
+    /// Read all relationship tuples matching the (partial) tuple key.
+    pub async fn read(&self, tuple_key: ReadRequestTupleKey) -> Result<Vec<TupleKey>> {
+        let mut tuples = Vec::new();
+        let mut continuation_token = String::new();
+        loop {
+            let request = ReadRequest {
+                store_id: self.store_id.clone(),
+                tuple_key: Some(tuple_key.clone()),
+                continuation_token,
+                ..Default::default()
+            };
+            let response = self.inner.clone().read(request).await?.into_inner();
+            tuples.extend(response.tuples.into_iter().filter_map(|t| t.key));
+            if response.continuation_token.is_empty() {
+                break;
+            }
+            continuation_token = response.continuation_token;
+        }
+        Ok(tuples)
+    }
+}
+
+impl OpenFgaClient {
     pub async fn connect<D>(address: D, config: ClientConfig) -> Result<OpenFgaClient>
     where
         D: TryInto<Endpoint>,
@@ -119,14 +284,6 @@ impl OpenFgaClient {
             authorization_model_id,
         })
     }
-
-    /// Read the latest authorization model for the current store
-    pub async fn read_latest_authoriztation_model(&self) -> Result<Option<AuthorizationModel>> {
-        self.inner
-            .clone()
-            .read_latest_authoriztation_model(&self.store_id)
-            .await
-    }
 }
 
 #[cfg(test)]
@@ -1,3 +1,5 @@
+use delta_sharing_common::Error as CommonError;
+
 /// A convenience type for declaring Results in the Delta Sharing libraries.
 pub type Result<T, E = Error> = std::result::Result<T, E>;
 
@@ -17,6 +19,9 @@ pub enum Error {
 
     #[error("Either store id or name must be configured.")]
     MissingStoreConfig,
+
+    #[error("Check failed: {0}")]
+    CheckFailed(String),
 }
 
 impl Error {
@@ -27,4 +32,21 @@ impl Error {
     pub fn authorization_model_missing(msg: impl Into<String>) -> Self {
         Self::AuthorizationModelMissing(msg.into())
     }
+
+    pub fn check_failed(msg: impl Into<String>) -> Self {
+        Self::CheckFailed(msg.into())
+    }
+}
+
+impl From<Error> for CommonError {
+    fn from(e: Error) -> Self {
+        match e {
+            Error::Connection(e) => CommonError::generic(e.to_string()),
+            Error::OpenFGA(status) => CommonError::generic(status.to_string()),
+            Error::StoreNotFound(_)
+            | Error::AuthorizationModelMissing(_)
+            | Error::MissingStoreConfig => CommonError::generic(e.to_string()),
+            Error::CheckFailed(e) => CommonError::Generic(e),
+        }
+    }
 }
@@ -1,7 +1,11 @@
 mod client;
 mod error;
+mod policy;
+mod sync;
 
 #[allow(
+    dead_code,
+    clippy::doc_overindented_list_items,
     clippy::enum_variant_names,
     clippy::empty_docs,
     clippy::large_enum_variant
@@ -12,10 +16,15 @@ pub(crate) mod gen {
     }
 }
 
-pub use client::{ClientConfig, OpenFgaClient};
+pub use client::{ClientConfig, GrpcTransport, OpenFgaClient};
+pub use error::{Error, Result};
+pub use policy::*;
+pub use sync::*;
 
 #[cfg(test)]
 mod tests {
+    pub(crate) mod mock;
+
     macro_rules! maybe_skip_fga {
         () => {
             if std::env::var("FGA_STORE_ID").is_err() {
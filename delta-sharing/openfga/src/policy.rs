@@ -0,0 +1,307 @@
+//! [`Policy`] implementation backed by OpenFGA.
+//!
+//! Resources are modelled as OpenFGA objects of the type returned by [`object_type`], using
+//! the resource UUID as object id. Each [`Permission`] maps to a `can_<permission>` relation
+//! defined on every type in the shipped authorization model (`fga/model.fga`).
+use std::sync::Arc;
+
+use delta_sharing_common::{
+    Decision, Error as CommonError, ObjectLabel, Permission, Policy, Recipient, ResourceIdent,
+    ResourceRef, ResourceStore, Result,
+};
+use tonic::transport::Channel;
+
+use crate::client::{GrpcTransport, OpenFgaClient};
+use crate::gen::v1::CheckRequestTupleKey;
+
+/// Object representing the server itself.
+///
+/// Checks against resources without a specific reference (e.g. when creating or
+/// listing resources) are evaluated against this object.
+pub const SERVER_OBJECT: &str = "server:default";
+
+/// Relation linking an object to its parent in the authorization model.
+pub const PARENT_RELATION: &str = "parent";
+
+/// Function mapping a recipient to an OpenFGA user.
+pub type UserMapper = Arc<dyn Fn(&Recipient) -> Option<String> + Send + Sync>;
+
+/// OpenFGA type name for objects with the given label.
+pub fn object_type(label: &ObjectLabel) -> &'static str {
+    match label {
+        ObjectLabel::ShareInfo => "share",
+        ObjectLabel::SharingSchemaInfo => "sharing_schema",
+        ObjectLabel::SharingTable => "sharing_table",
+        ObjectLabel::CredentialInfo => "credential",
+        ObjectLabel::CatalogInfo => "catalog",
+        ObjectLabel::SchemaInfo => "schema",
+        ObjectLabel::TableInfo => "table",
+        ObjectLabel::ExternalLocationInfo => "external_location",
+        ObjectLabel::RecipientInfo => "recipient",
+        ObjectLabel::ColumnInfo => "column",
+    }
+}
+
+/// OpenFGA relation that grants the given permission.
+pub fn relation(permission: &Permission) -> String {
+    format!("can_{}", permission.as_ref())
+}
+
+/// Label of the parent of objects with the given label.
+///
+/// Objects without a parent label are children of the [`SERVER_OBJECT`].
+pub fn parent_label(label: &ObjectLabel) -> Option<ObjectLabel> {
+    label.parent_label()
+}
+
+/// Map a recipient to an OpenFGA user.
+///
+/// Anonymous recipients are mapped to `user:anonymous`, so they are only granted
+/// access via public (`user:*`) tuples. Custom recipients are not mapped.
+pub fn default_user(recipient: &Recipient) -> Option<String> {
+    match recipient {
+        Recipient::Anonymous => Some("user:anonymous".to_string()),
+        Recipient::User(name) => Some(format!("user:{}", name)),
+        Recipient::Custom(_) => None,
+    }
+}
+
+/// Resolve the OpenFGA object for a resource.
+///
+/// Named references are resolved to the resource UUID via the store.
+/// Returns `None` if the referenced resource does not exist.
+pub(crate) async fn resolve_object(
+    store: &dyn ResourceStore,
+    resource: &ResourceIdent,
+) -> Result<Option<String>> {
+    let id = match resource.reference() {
+        ResourceRef::Uuid(id) => *id,
+        ResourceRef::Undefined => return Ok(Some(SERVER_OBJECT.to_string())),
+        ResourceRef::Name(_) => match store.get(resource).await {
+            Ok((_, ResourceRef::Uuid(id))) => id,
+            Ok(_) | Err(CommonError::NotFound) => return Ok(None),
+            Err(e) => return Err(e),
+        },
+    };
+    Ok(Some(format!(
+        "{}:{}",
+        object_type(resource.label()),
+        id.hyphenated()
+    )))
+}
+
+/// Policy delegating authorization decisions to OpenFGA.
+pub struct OpenFgaPolicy<T = Channel> {
+    client: OpenFgaClient<T>,
+    store: Arc<dyn ResourceStore>,
+    user_mapper: UserMapper,
+}
+
+impl<T: GrpcTransport> OpenFgaPolicy<T> {
+    /// Create a new [`OpenFgaPolicy`].
+    ///
+    /// The store is used to resolve named resource references to their ids.
+    pub fn new(client: OpenFgaClient<T>, store: Arc<dyn ResourceStore>) -> Self {
+        Self {
+            client,
+            store,
+            user_mapper: Arc::new(default_user),
+        }
+    }
+
+    /// Use a custom mapping from recipients to OpenFGA users.
+    pub fn with_user_mapper(
+        mut self,
+        mapper: impl Fn(&Recipient) -> Option<String> + Send + Sync + 'static,
+    ) -> Self {
+        self.user_mapper = Arc::new(mapper);
+        self
+    }
+}
+
+#[async_trait::async_trait]
+impl<T: GrpcTransport> Policy for OpenFgaPolicy<T> {
+    async fn authorize(
+        &self,
+        resource: &ResourceIdent,
+        permission: &Permission,
+        recipient: &Recipient,
+    ) -> Result<Decision> {
+        let Some(user) = (self.user_mapper)(recipient) else {
+            return Ok(Decision::Deny);
+        };
+        let Some(object) = resolve_object(self.store.as_ref(), resource).await? else {
+            return Ok(Decision::Deny);
+        };
+        let allowed = self
+            .client
+            .check(user, relation(permission), object)
+            .await?;
+        Ok(if allowed {
+            Decision::Allow
+        } else {
+            Decision::Deny
+        })
+    }
+
+    async fn authorize_many(
+        &self,
+        resources: &[ResourceIdent],
+        permission: &Permission,
+        recipient: &Recipient,
+    ) -> Result<Vec<Decision>> {
+        let Some(user) = (self.user_mapper)(recipient) else {
+            return Ok(vec![Decision::Deny; resources.len()]);
+        };
+
+        let mut objects = Vec::with_capacity(resources.len());
+        for resource in resources {
+            objects.push(resolve_object(self.store.as_ref(), resource).await?);
+        }
+        let checks = objects
+            .iter()
+            .flatten()
+            .map(|object| CheckRequestTupleKey {
+                user: user.clone(),
+                relation: relation(permission),
+                object: object.clone(),
+            })
+            .collect();
+        let mut results = self.client.batch_check(checks).await?.into_iter();
+
+        Ok(objects
+            .iter()
+            .map(|object| match object.as_ref().map(|_| results.next()) {
+                Some(Some(true)) => Decision::Allow,
+                _ => Decision::Deny,
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use delta_sharing_common::memory::InMemoryResourceStore;
+    use delta_sharing_common::models::catalogs::v1::CatalogInfo;
+    use delta_sharing_common::models::schemas::v1::SchemaInfo;
+    use delta_sharing_common::ResourceName;
+
+    use super::*;
+    use crate::tests::mock::MockOpenFga;
+
+    async fn setup() -> (MockOpenFga, Arc<InMemoryResourceStore>, String, String) {
+        let store = Arc::new(InMemoryResourceStore::new());
+        let (_, catalog) = store
+            .create(
+                CatalogInfo {
+                    name: "catalog".to_string(),
+                    ..Default::default()
+                }
+                .into(),
+            )
+            .await
+            .unwrap();
+        let (_, schema) = store
+            .create(
+                SchemaInfo {
+                    name: "schema".to_string(),
+                    catalog_name: "catalog".to_string(),
+                    ..Default::default()
+                }
+                .into(),
+            )
+            .await
+            .unwrap();
+        (
+            MockOpenFga::default(),
+            store,
+            format!("catalog:{}", catalog),
+            format!("schema:{}", schema),
+        )
+    }
+
+    #[test]
+    fn test_mappings() {
+        assert_eq!(relation(&Permission::Read), "can_read");
+        assert_eq!(relation(&Permission::Select), "can_select");
+        assert_eq!(
+            object_type(&ObjectLabel::SharingSchemaInfo),
+            "sharing_schema"
+        );
+        assert_eq!(
+            parent_label(&ObjectLabel::TableInfo),
+            Some(ObjectLabel::SchemaInfo)
+        );
+        assert_eq!(parent_label(&ObjectLabel::ShareInfo), None);
+        assert_eq!(
+            default_user(&Recipient::user("alice")),
+            Some("user:alice".to_string())
+        );
+        assert_eq!(default_user(&Recipient::custom(Default::default())), None);
+    }
+
+    #[tokio::test]
+    async fn test_authorize() {
+        let (fga, store, catalog, _) = setup().await;
+        fga.allow("user:alice", "can_read", &catalog);
+        fga.allow("user:alice", "can_create", SERVER_OBJECT);
+        let policy = OpenFgaPolicy::new(fga.client(), store);
+
+        let alice = Recipient::user("alice");
+        let by_name = ResourceIdent::catalog(ResourceName::new(["catalog"]));
+        let decision = policy
+            .authorize(&by_name, &Permission::Read, &alice)
+            .await
+            .unwrap();
+        assert_eq!(decision, Decision::Allow);
+
+        let decision = policy
+            .authorize(&by_name, &Permission::Manage, &alice)
+            .await
+            .unwrap();
+        assert_eq!(decision, Decision::Deny);
+
+        let undefined = ResourceIdent::catalog(ResourceRef::Undefined);
+        let decision = policy
+            .authorize(&undefined, &Permission::Create, &alice)
+            .await
+            .unwrap();
+        assert_eq!(decision, Decision::Allow);
+
+        let missing = ResourceIdent::catalog(ResourceName::new(["missing"]));
+        let decision = policy
+            .authorize(&missing, &Permission::Read, &alice)
+            .await
+            .unwrap();
+        assert_eq!(decision, Decision::Deny);
+
+        let decision = policy
+            .authorize(&by_name, &Permission::Read, &Recipient::user("bob"))
+            .await
+            .unwrap();
+        assert_eq!(decision, Decision::Deny);
+    }
+
+    #[tokio::test]
+    async fn test_authorize_many() {
+        let (fga, store, catalog, schema) = setup().await;
+        fga.allow("user:alice", "can_read", &schema);
+        let policy = OpenFgaPolicy::new(fga.client(), store);
+
+        let resources = [
+            ResourceIdent::catalog(ResourceName::new(["catalog"])),
+            ResourceIdent::schema(ResourceName::new(["missing", "schema"])),
+            ResourceIdent::schema(ResourceName::new(["catalog", "schema"])),
+        ];
+        let decisions = policy
+            .authorize_many(&resources, &Permission::Read, &Recipient::user("alice"))
+            .await
+            .unwrap();
+        assert_eq!(
+            decisions,
+            vec![Decision::Deny, Decision::Deny, Decision::Allow]
+        );
+        // a single batch check covers all resolvable resources.
+        assert_eq!(fga.batch_checks(), vec![vec![catalog, schema]]);
+    }
+}
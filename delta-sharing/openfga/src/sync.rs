@@ -0,0 +1,454 @@
+//! Keep OpenFGA relationship tuples in sync with the resource store.
+use std::sync::Arc;
+
+use delta_sharing_common::{
+    AssociationLabel, ObjectLabel, PropertyMap, Resource, ResourceExt, ResourceIdent, ResourceName,
+    ResourceRef, ResourceStore, Result, VisibilityFilter,
+};
+use tonic::transport::Channel;
+
+use crate::client::{GrpcTransport, OpenFgaClient, MAX_TUPLES_PER_WRITE};
+use crate::gen::v1::{ReadRequestTupleKey, TupleKey, TupleKeyWithoutCondition};
+use crate::policy::{object_type, parent_label, resolve_object, PARENT_RELATION, SERVER_OBJECT};
+
+/// [`ResourceStore`] decorator that mirrors the resource hierarchy into OpenFGA.
+///
+/// - Creating a resource writes a `parent` tuple linking it to its parent resource,
+///   or to the [`SERVER_OBJECT`] for top level resources.
+/// - Deleting a resource removes all tuples on the resource as well as the `parent`
+///   tuples of its direct children. The tuples are removed before the resource and
+///   restored if the resource cannot be deleted, so no grants are left behind.
+/// - [`ParentOf`](AssociationLabel::ParentOf) and [`ChildOf`](AssociationLabel::ChildOf)
+///   associations write and remove `parent` tuples respectively.
+///
+/// All other operations are passed through to the wrapped store.
+pub struct OpenFgaSyncStore<S, T = Channel> {
+    inner: S,
+    client: OpenFgaClient<T>,
+}
+
+impl<S: ResourceStore, T: GrpcTransport> OpenFgaSyncStore<S, T> {
+    /// Create a new [`OpenFgaSyncStore`] wrapping the given store.
+    pub fn new(inner: S, client: OpenFgaClient<T>) -> Self {
+        Self { inner, client }
+    }
+
+    /// Get a reference to the wrapped store.
+    pub fn inner(&self) -> &S {
+        &self.inner
+    }
+
+    async fn object(&self, ident: &ResourceIdent) -> Result<Option<String>> {
+        resolve_object(&self.inner, ident).await
+    }
+
+    async fn parent_object(&self, resource: &Resource) -> Result<Option<String>> {
+        let label = resource.resource_label();
+        let Some(parent) = parent_label(label) else {
+            return Ok(Some(SERVER_OBJECT.to_string()));
+        };
+        let name = resource.resource_name();
+        let Some((_, namespace)) = name.path().split_last() else {
+            return Ok(None);
+        };
+        let parent_ident = parent.to_ident(ResourceRef::Name(ResourceName::new(namespace)));
+        self.object(&parent_ident).await
+    }
+
+    /// Write the `parent` tuple linking a newly created resource to its parent.
+    async fn write_parent(&self, parent: Option<String>, ident: &ResourceIdent) -> Result<()> {
+        if let (Some(user), Some(object)) = (parent, self.object(ident).await?) {
+            let tuple = TupleKey {
+                user,
+                relation: PARENT_RELATION.to_string(),
+                object,
+                condition: None,
+            };
+            self.client.write(vec![tuple], vec![]).await?;
+        }
+        Ok(())
+    }
+
+    /// Delete tuples in writes OpenFGA accepts, restoring the already deleted
+    /// tuples if a later write fails.
+    async fn delete_tuples(&self, tuples: &[TupleKey]) -> Result<()> {
+        for (idx, chunk) in tuples.chunks(MAX_TUPLES_PER_WRITE).enumerate() {
+            let deletes = chunk
+                .iter()
+                .map(|t| TupleKeyWithoutCondition {
+                    user: t.user.clone(),
+                    relation: t.relation.clone(),
+                    object: t.object.clone(),
+                })
+                .collect();
+            if let Err(err) = self.client.write(vec![], deletes).await {
+                self.restore_tuples(&tuples[..idx * MAX_TUPLES_PER_WRITE])
+                    .await;
+                return Err(err.into());
+            }
+        }
+        Ok(())
+    }
+
+    /// Write back tuples removed by an operation that could not be completed.
+    async fn restore_tuples(&self, tuples: &[TupleKey]) {
+        for chunk in tuples.chunks(MAX_TUPLES_PER_WRITE) {
+            if let Err(err) = self.client.write(chunk.to_vec(), vec![]).await {
+                tracing::error!("failed to restore {} tuples: {}", chunk.len(), err);
+            }
+        }
+    }
+
+    /// Resolve the (parent, child) objects for a hierarchy association.
+    async fn parent_tuple(
+        &self,
+        from: &ResourceIdent,
+        to: &ResourceIdent,
+        label: &AssociationLabel,
+    ) -> Result<Option<TupleKeyWithoutCondition>> {
+        let (parent, child) = match label {
+            AssociationLabel::ParentOf => (from, to),
+            AssociationLabel::ChildOf => (to, from),
+            _ => return Ok(None),
+        };
+        let (Some(user), Some(object)) = (self.object(parent).await?, self.object(child).await?)
+        else {
+            return Ok(None);
+        };
+        Ok(Some(TupleKeyWithoutCondition {
+            user,
+            relation: PARENT_RELATION.to_string(),
+            object,
+        }))
+    }
+}
+
+fn child_labels(label: &ObjectLabel) -> impl Iterator<Item = ObjectLabel> + '_ {
+    use strum::IntoEnumIterator;
+    ObjectLabel::iter().filter(move |l| parent_label(l).as_ref() == Some(label))
+}
+
+#[async_trait::async_trait]
+impl<S: ResourceStore, T: GrpcTransport> ResourceStore for OpenFgaSyncStore<S, T> {
+    async fn get(&self, id: &ResourceIdent) -> Result<(Resource, ResourceRef)> {
+        self.inner.get(id).await
+    }
+
+    async fn get_many(&self, ids: &[ResourceIdent]) -> Result<Vec<(Resource, ResourceRef)>> {
+        self.inner.get_many(ids).await
+    }
+
+    async fn list(
+        &self,
+        label: &ObjectLabel,
+        namespace: Option<&ResourceName>,
+        filter: Option<&VisibilityFilter>,
+        max_results: Option<usize>,
+        page_token: Option<String>,
+    ) -> Result<(Vec<Resource>, Option<String>)> {
+        self.inner
+            .list(label, namespace, filter, max_results, page_token)
+            .await
+    }
+
+    async fn create(&self, resource: Resource) -> Result<(Resource, ResourceRef)> {
+        let parent = self.parent_object(&resource).await?;
+        let (resource, reference) = self.inner.create(resource).await?;
+        let ident = resource.resource_label().to_ident(reference.clone());
+        if let Err(err) = self.write_parent(parent, &ident).await {
+            // without its parent tuple the resource would not inherit any grants,
+            // so the create is rolled back and can be retried by the caller.
+            if let Err(rollback) = self.inner.delete(&ident).await {
+                tracing::error!("failed to roll back {:?}: {}", ident, rollback);
+            }
+            return Err(err);
+        }
+        Ok((resource, reference))
+    }
+
+    async fn delete(&self, id: &ResourceIdent) -> Result<()> {
+        let Some(object) = self.object(id).await? else {
+            return self.inner.delete(id).await;
+        };
+
+        let mut tuples = self
+            .client
+            .read(ReadRequestTupleKey {
+                object: object.clone(),
+                ..Default::default()
+            })
+            .await?;
+        for label in child_labels(id.label()) {
+            tuples.extend(
+                self.client
+                    .read(ReadRequestTupleKey {
+                        user: object.clone(),
+                        relation: PARENT_RELATION.to_string(),
+                        object: format!("{}:", object_type(&label)),
+                    })
+                    .await?,
+            );
+        }
+        self.delete_tuples(&tuples).await?;
+        if let Err(err) = self.inner.delete(id).await {
+            self.restore_tuples(&tuples).await;
+            return Err(err);
+        }
+        Ok(())
+    }
+
+    async fn update(
+        &self,
+        id: &ResourceIdent,
+        resource: Resource,
+    ) -> Result<(Resource, ResourceRef)> {
+        self.inner.update(id, resource).await
+    }
+
+    async fn add_association(
+        &self,
+        from: &ResourceIdent,
+        to: &ResourceIdent,
+        label: &AssociationLabel,
+        properties: Option<PropertyMap>,
+    ) -> Result<()> {
+        self.inner
+            .add_association(from, to, label, properties)
+            .await?;
+        if let Some(tuple) = self.parent_tuple(from, to, label).await? {
+            let tuple = TupleKey {
+                user: tuple.user,
+                relation: tuple.relation,
+                object: tuple.object,
+                condition: None,
+            };
+            self.client.write(vec![tuple], vec![]).await?;
+        }
+        Ok(())
+    }
+
+    async fn remove_association(
+        &self,
+        from: &ResourceIdent,
+        to: &ResourceIdent,
+        label: &AssociationLabel,
+    ) -> Result<()> {
+        self.inner.remove_association(from, to, label).await?;
+        if let Some(tuple) = self.parent_tuple(from, to, label).await? {
+            self.client.write(vec![], vec![tuple]).await?;
+        }
+        Ok(())
+    }
+
+    async fn update_association(
+        &self,
+        from: &ResourceIdent,
+        to: &ResourceIdent,
+        label: &AssociationLabel,
+        properties: Option<PropertyMap>,
+    ) -> Result<()> {
+        // only the association itself is mirrored as a tuple, its properties are not.
+        self.inner
+            .update_association(from, to, label, properties)
+            .await
+    }
+
+    async fn get_association(
+        &self,
+        from: &ResourceIdent,
+        to: &ResourceIdent,
+        label: &AssociationLabel,
+    ) -> Result<Option<PropertyMap>> {
+        self.inner.get_association(from, to, label).await
+    }
+
+    async fn get_associations(
+        &self,
+        from: &ResourceIdent,
+        to: &[ResourceIdent],
+        label: &AssociationLabel,
+    ) -> Result<Vec<Option<PropertyMap>>> {
+        self.inner.get_associations(from, to, label).await
+    }
+
+    async fn list_associations(
+        &self,
+        resource: &ResourceIdent,
+        label: &AssociationLabel,
+        target_label: Option<&ResourceIdent>,
+        filter: Option<&VisibilityFilter>,
+        max_results: Option<usize>,
+        page_token: Option<String>,
+    ) -> Result<(Vec<ResourceIdent>, Option<String>)> {
+        self.inner
+            .list_associations(
+                resource,
+                label,
+                target_label,
+                filter,
+                max_results,
+                page_token,
+            )
+            .await
+    }
+}
+
+impl<S: ResourceStore, T: GrpcTransport> From<OpenFgaSyncStore<S, T>> for Arc<dyn ResourceStore> {
+    fn from(store: OpenFgaSyncStore<S, T>) -> Self {
+        Arc::new(store)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use delta_sharing_common::memory::InMemoryResourceStore;
+    use delta_sharing_common::models::catalogs::v1::CatalogInfo;
+    use delta_sharing_common::models::schemas::v1::SchemaInfo;
+
+    use super::*;
+    use crate::tests::mock::MockOpenFga;
+
+    fn tuple(user: &str, relation: &str, object: &str) -> (String, String, String) {
+        (user.to_string(), relation.to_string(), object.to_string())
+    }
+
+    fn catalog() -> Resource {
+        CatalogInfo {
+            name: "catalog".to_string(),
+            ..Default::default()
+        }
+        .into()
+    }
+
+    fn schema() -> Resource {
+        SchemaInfo {
+            name: "schema".to_string(),
+            catalog_name: "catalog".to_string(),
+            ..Default::default()
+        }
+        .into()
+    }
+
+    #[tokio::test]
+    async fn test_sync_create_delete() {
+        let fga = MockOpenFga::default();
+        let store = OpenFgaSyncStore::new(InMemoryResourceStore::new(), fga.client());
+
+        let (_, catalog) = store.create(catalog()).await.unwrap();
+        let (_, schema) = store.create(schema()).await.unwrap();
+        let catalog_object = format!("catalog:{}", catalog);
+        let schema_object = format!("schema:{}", schema);
+        fga.allow("user:alice", "owner", &catalog_object);
+
+        let mut expected = vec![
+            tuple(SERVER_OBJECT, PARENT_RELATION, &catalog_object),
+            tuple(&catalog_object, PARENT_RELATION, &schema_object),
+            tuple("user:alice", "owner", &catalog_object),
+        ];
+        expected.sort();
+        assert_eq!(fga.tuples(), expected);
+
+        // deleting the catalog removes its tuples and the links to its children.
+        store
+            .delete(&ResourceIdent::catalog(catalog))
+            .await
+            .unwrap();
+        assert_eq!(fga.tuples(), vec![]);
+    }
+
+    #[tokio::test]
+    async fn test_sync_create_rollback() {
+        let fga = MockOpenFga::default();
+        let store = OpenFgaSyncStore::new(InMemoryResourceStore::new(), fga.client());
+        let (_, catalog) = store.create(catalog()).await.unwrap();
+
+        // the schema is removed again if its parent tuple cannot be written.
+        fga.fail_writes(true);
+        assert!(store.create(schema()).await.is_err());
+        let schema_ident = ResourceIdent::schema(ResourceName::new(["catalog", "schema"]));
+        assert!(store.get(&schema_ident).await.is_err());
+
+        // once OpenFGA is available again, the create can be retried.
+        fga.fail_writes(false);
+        let (_, schema) = store.create(schema()).await.unwrap();
+        let mut expected = vec![
+            tuple(
+                SERVER_OBJECT,
+                PARENT_RELATION,
+                &format!("catalog:{}", catalog),
+            ),
+            tuple(
+                &format!("catalog:{}", catalog),
+                PARENT_RELATION,
+                &format!("schema:{}", schema),
+            ),
+        ];
+        expected.sort();
+        assert_eq!(fga.tuples(), expected);
+    }
+
+    #[tokio::test]
+    async fn test_sync_delete_many_grants() {
+        let fga = MockOpenFga::default();
+        let store = OpenFgaSyncStore::new(InMemoryResourceStore::new(), fga.client());
+        let (_, catalog) = store.create(catalog()).await.unwrap();
+        let catalog_object = format!("catalog:{}", catalog);
+        for idx in 0..250 {
+            fga.allow(&format!("user:{idx}"), "viewer", &catalog_object);
+        }
+
+        // grants exceeding the size of a single write are removed in several writes.
+        store
+            .delete(&ResourceIdent::catalog(catalog))
+            .await
+            .unwrap();
+        assert_eq!(fga.tuples(), vec![]);
+    }
+
+    #[tokio::test]
+    async fn test_sync_delete_failure() {
+        let fga = MockOpenFga::default();
+        let store = OpenFgaSyncStore::new(InMemoryResourceStore::new(), fga.client());
+        let (_, catalog) = store.create(catalog()).await.unwrap();
+        let catalog_object = format!("catalog:{}", catalog);
+        fga.allow("user:alice", "owner", &catalog_object);
+        let expected = fga.tuples();
+
+        // the resource is kept along with its tuples if they cannot be removed.
+        fga.fail_writes(true);
+        let ident = ResourceIdent::catalog(catalog);
+        assert!(store.delete(&ident).await.is_err());
+        assert!(store.get(&ident).await.is_ok());
+        assert_eq!(fga.tuples(), expected);
+    }
+
+    #[tokio::test]
+    async fn test_sync_associations() {
+        let fga = MockOpenFga::default();
+        let store = OpenFgaSyncStore::new(InMemoryResourceStore::new(), fga.client());
+
+        // create resources directly in the inner store to start without tuples. The
+        // schema is created first, so the inner store does not link it to the catalog.
+        let (_, schema) = store.inner().create(schema()).await.unwrap();
+        let (_, catalog) = store.inner().create(catalog()).await.unwrap();
+        let catalog_object = format!("catalog:{}", catalog);
+        let schema_object = format!("schema:{}", schema);
+        let catalog = ResourceIdent::catalog(catalog);
+        let schema = ResourceIdent::schema(schema);
+
+        store
+            .add_association(&catalog, &schema, &AssociationLabel::ParentOf, None)
+            .await
+            .unwrap();
+        assert_eq!(
+            fga.tuples(),
+            vec![tuple(&catalog_object, PARENT_RELATION, &schema_object)]
+        );
+
+        store
+            .remove_association(&schema, &catalog, &AssociationLabel::ChildOf)
+            .await
+            .unwrap();
+        assert_eq!(fga.tuples(), vec![]);
+    }
+}
@@ -0,0 +1,204 @@
+//! In-process mock of the OpenFGA gRPC service.
+use std::collections::BTreeSet;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+
+use bytes::{BufMut, Bytes, BytesMut};
+use http_body_util::{BodyExt, Full};
+use prost::Message;
+use tonic::body::BoxBody;
+use tonic::Status;
+
+use crate::client::MAX_TUPLES_PER_WRITE;
+use crate::gen::v1::batch_check_single_result::CheckResult;
+use crate::gen::v1::open_fga_service_client::OpenFgaServiceClient;
+use crate::gen::v1::{
+    BatchCheckRequest, BatchCheckResponse, BatchCheckSingleResult, CheckRequest, CheckResponse,
+    ReadRequest, ReadResponse, Tuple, TupleKey, WriteRequest, WriteResponse,
+};
+use crate::OpenFgaClient;
+
+type Tuples = BTreeSet<(String, String, String)>;
+
+#[derive(Default)]
+struct State {
+    tuples: Tuples,
+    batch_checks: Vec<Vec<String>>,
+    fail_writes: bool,
+}
+
+/// Mock OpenFGA service evaluating checks against directly stored tuples.
+///
+/// Relations are not rewritten according to any model, i.e. a check is
+/// only allowed if the exact tuple was written before.
+#[derive(Clone, Default)]
+pub(crate) struct MockOpenFga {
+    state: Arc<Mutex<State>>,
+}
+
+impl MockOpenFga {
+    pub(crate) fn client(&self) -> OpenFgaClient<Self> {
+        OpenFgaClient::new(OpenFgaServiceClient::new(self.clone()), "store", "model")
+    }
+
+    pub(crate) fn allow(&self, user: &str, relation: &str, object: &str) {
+        self.state.lock().unwrap().tuples.insert((
+            user.to_string(),
+            relation.to_string(),
+            object.to_string(),
+        ));
+    }
+
+    /// All tuples currently stored as (user, relation, object).
+    pub(crate) fn tuples(&self) -> Vec<(String, String, String)> {
+        self.state.lock().unwrap().tuples.iter().cloned().collect()
+    }
+
+    /// Objects included in each batch check request received so far.
+    pub(crate) fn batch_checks(&self) -> Vec<Vec<String>> {
+        self.state.lock().unwrap().batch_checks.clone()
+    }
+
+    /// Reject all subsequent write requests as if the service was unavailable.
+    pub(crate) fn fail_writes(&self, fail: bool) {
+        self.state.lock().unwrap().fail_writes = fail;
+    }
+
+    fn handle(&self, path: &str, body: Bytes) -> Result<Bytes, Box<Status>> {
+        let mut state = self.state.lock().unwrap();
+        let message = match path {
+            "/openfga.v1.OpenFGAService/Check" => {
+                let request = CheckRequest::decode(body).unwrap();
+                let key = request.tuple_key.unwrap();
+                let allowed = state.tuples.contains(&(key.user, key.relation, key.object));
+                CheckResponse {
+                    allowed,
+                    ..Default::default()
+                }
+                .encode_to_vec()
+            }
+            "/openfga.v1.OpenFGAService/BatchCheck" => {
+                let request = BatchCheckRequest::decode(body).unwrap();
+                let mut objects = Vec::new();
+                let mut response = BatchCheckResponse::default();
+                for item in request.checks {
+                    let key = item.tuple_key.unwrap();
+                    objects.push(key.object.clone());
+                    let allowed = state.tuples.contains(&(key.user, key.relation, key.object));
+                    response.result.insert(
+                        item.correlation_id,
+                        BatchCheckSingleResult {
+                            check_result: Some(CheckResult::Allowed(allowed)),
+                        },
+                    );
+                }
+                state.batch_checks.push(objects);
+                response.encode_to_vec()
+            }
+            "/openfga.v1.OpenFGAService/Write" => {
+                if state.fail_writes {
+                    return Err(Box::new(Status::unavailable("writes are failing")));
+                }
+                let request = WriteRequest::decode(body).unwrap();
+                let size = request
+                    .writes
+                    .iter()
+                    .map(|w| w.tuple_keys.len())
+                    .sum::<usize>()
+                    + request
+                        .deletes
+                        .iter()
+                        .map(|d| d.tuple_keys.len())
+                        .sum::<usize>();
+                if size > MAX_TUPLES_PER_WRITE {
+                    return Err(Box::new(Status::invalid_argument(format!(
+                        "write contains {size} tuples"
+                    ))));
+                }
+                for key in request.writes.into_iter().flat_map(|w| w.tuple_keys) {
+                    state.tuples.insert((key.user, key.relation, key.object));
+                }
+                for key in request.deletes.into_iter().flat_map(|d| d.tuple_keys) {
+                    state.tuples.remove(&(key.user, key.relation, key.object));
+                }
+                WriteResponse::default().encode_to_vec()
+            }
+            "/openfga.v1.OpenFGAService/Read" => {
+                let request = ReadRequest::decode(body).unwrap();
+                let filter = request.tuple_key.unwrap_or_default();
+                let matches = |(user, relation, object): &(String, String, String)| {
+                    (filter.user.is_empty() || &filter.user == user)
+                        && (filter.relation.is_empty() || &filter.relation == relation)
+                        && if filter.object.ends_with(':') {
+                            object.starts_with(&filter.object)
+                        } else {
+                            &filter.object == object
+                        }
+                };
+                let tuples = state
+                    .tuples
+                    .iter()
+                    .filter(|t| matches(t))
+                    .map(|(user, relation, object)| Tuple {
+                        key: Some(TupleKey {
+                            user: user.clone(),
+                            relation: relation.clone(),
+                            object: object.clone(),
+                            condition: None,
+                        }),
+                        timestamp: None,
+                    })
+                    .collect();
+                ReadResponse {
+                    tuples,
+                    continuation_token: String::new(),
+                }
+                .encode_to_vec()
+            }
+            _ => {
+                return Err(Box::new(Status::unimplemented(format!(
+                    "mock does not support {path}"
+                ))))
+            }
+        };
+        Ok(message.into())
+    }
+}
+
+impl tower::Service<http::Request<BoxBody>> for MockOpenFga {
+    type Response = http::Response<BoxBody>;
+    type Error = std::convert::Infallible;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: http::Request<BoxBody>) -> Self::Future {
+        let service = self.clone();
+        Box::pin(async move {
+            let path = request.uri().path().to_string();
+            let body = request.into_body().collect().await.unwrap().to_bytes();
+            // strip the gRPC message frame: 1 byte compression flag, 4 bytes length.
+            let message = match service.handle(&path, body.slice(5..)) {
+                Ok(message) => message,
+                Err(status) => return Ok((*status).into_http()),
+            };
+
+            let mut frame = BytesMut::with_capacity(message.len() + 5);
+            frame.put_u8(0);
+            frame.put_u32(message.len() as u32);
+            frame.put(message);
+
+            let mut trailers = http::HeaderMap::new();
+            trailers.insert("grpc-status", http::HeaderValue::from_static("0"));
+            let body = Full::new(frame.freeze()).with_trailers(async move { Some(Ok(trailers)) });
+            Ok(http::Response::builder()
+                .header("content-type", "application/grpc")
+                .body(tonic::body::boxed(body))
+                .unwrap())
+        })
+    }
+}
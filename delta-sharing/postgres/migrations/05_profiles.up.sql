@@ -0,0 +1,12 @@
+create table if not exists profiles (
+    fingerprint Text primary key,
+    subject Text,
+    shares Text [] collate case_insensitive not null default '{}',
+    expires_at timestamptz,
+    revoked_at timestamptz,
+    created_at timestamptz not null default now(),
+    updated_at timestamptz
+);
+select trigger_updated_at('profiles');
+create index profiles_subject_index on profiles (subject);
+create index profiles_shares_index on profiles using gin (shares);
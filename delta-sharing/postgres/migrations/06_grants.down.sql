@@ -0,0 +1,3 @@
+-- enum values cannot be removed from a type, only the grants themselves are dropped.
+delete from associations where label in ('has_grant', 'granted_to');
+drop index if exists associations_to_index;
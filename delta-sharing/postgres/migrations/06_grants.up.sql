@@ -0,0 +1,11 @@
+-- labels of sharing resources managed via the graph.
+ALTER TYPE object_label ADD VALUE IF NOT EXISTS 'sharing_schema_info';
+ALTER TYPE object_label ADD VALUE IF NOT EXISTS 'sharing_table';
+
+-- grants of permissions on a resource to a recipient.
+-- the granted permissions are stored in the association properties.
+ALTER TYPE association_label ADD VALUE IF NOT EXISTS 'has_grant';
+ALTER TYPE association_label ADD VALUE IF NOT EXISTS 'granted_to';
+
+-- grants are resolved by walking up the resource hierarchy.
+create index associations_to_index on associations (to_id, label);
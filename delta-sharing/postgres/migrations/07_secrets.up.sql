@@ -0,0 +1,9 @@
+-- versioned secret values, rows are never updated in place.
+-- deleting a secret inserts a tombstone version without a value.
+create table if not exists secrets (
+    name Text not null,
+    version uuid not null default uuidv7_sub_ms(),
+    value bytea,
+    created_at timestamptz not null default now(),
+    primary key (name, version)
+);
@@ -25,6 +25,9 @@ pub enum Error {
 
     #[error("Already exists: '{0}'")]
     AlreadyExists(String),
+
+    #[error("Conflict: '{0}'")]
+    Conflict(String),
 }
 
 impl Error {
@@ -68,7 +71,8 @@ impl From<Error> for CommonError {
             Error::DecodePageToken(e) => CommonError::InvalidArgument(e.to_string()),
             Error::Generic(e) => CommonError::Generic(e),
             Error::EntityNotFound(_) => CommonError::NotFound,
-            Error::AlreadyExists(_) => CommonError::NotAllowed,
+            Error::AlreadyExists(_) => CommonError::AlreadyExists,
+            Error::Conflict(_) => CommonError::Conflict,
         }
     }
 }
@@ -40,6 +40,17 @@ pub struct Association {
     pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
 }
 
+/// Change to an association between two objects, see [`GraphStore::apply_association_changes`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum AssociationWrite {
+    /// Add an association from the first to the second object with the given properties.
+    Add(Uuid, AssociationLabel, Uuid, Option<serde_json::Value>),
+    /// Replace the properties of the association from the first to the second object.
+    Update(Uuid, AssociationLabel, Uuid, Option<serde_json::Value>),
+    /// Remove the association from the first to the second object.
+    Remove(Uuid, AssociationLabel, Uuid),
+}
+
 impl Association {
     pub fn target_ident(&self) -> ResourceIdent {
         self.to_label.to_ident(ResourceRef::Uuid(self.to_id))
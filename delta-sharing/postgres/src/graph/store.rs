@@ -6,12 +6,14 @@
 
 use std::sync::Arc;
 
-use delta_sharing_common::{ResourceIdent, ResourceRef};
+use chrono::{DateTime, Utc};
+use delta_sharing_common::api::permissions::GRANT_PERMISSIONS_PROPERTY;
+use delta_sharing_common::{Permission, ResourceIdent, ResourceRef, VisibilityFilter};
 use sqlx::migrate::Migrator;
 use sqlx::PgPool;
 use uuid::Uuid;
 
-use super::{Association, AssociationLabel, Object, ObjectLabel};
+use super::{Association, AssociationLabel, AssociationWrite, Object, ObjectLabel};
 use crate::constants::MAX_PAGE_SIZE;
 use crate::pagination::V1PaginateToken;
 use crate::resources::IdentRefs as _;
@@ -19,6 +21,37 @@ use crate::{error::Result, pagination::PaginateToken};
 
 static MIGRATOR: Migrator = sqlx::migrate!();
 
+/// Query parameters pushing a [`VisibilityFilter`] down into list queries.
+#[derive(Default)]
+struct FilterParams {
+    ids: Option<Vec<Uuid>>,
+    principal: Option<String>,
+    permissions: Vec<String>,
+}
+
+impl From<Option<&VisibilityFilter>> for FilterParams {
+    fn from(filter: Option<&VisibilityFilter>) -> Self {
+        match filter {
+            None => Self::default(),
+            Some(VisibilityFilter::Ids(ids)) => Self {
+                ids: Some(ids.iter().copied().collect()),
+                ..Default::default()
+            },
+            Some(VisibilityFilter::Grants {
+                principal,
+                permission,
+            }) => Self {
+                ids: None,
+                principal: Some(principal.clone()),
+                permissions: vec![
+                    permission.as_ref().to_string(),
+                    Permission::Manage.as_ref().to_string(),
+                ],
+            },
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct Store {
     pool: Arc<PgPool>,
@@ -34,6 +67,11 @@ impl Store {
         Ok(Self::new(Arc::new(pool)))
     }
 
+    /// The connection pool used by the store.
+    pub fn pool(&self) -> Arc<PgPool> {
+        self.pool.clone()
+    }
+
     pub async fn migrate(&self) -> Result<()> {
         MIGRATOR.run(&*self.pool).await?;
         Ok(())
@@ -177,20 +215,23 @@ impl Store {
     /// # Parameters
     /// - `id`: The globally unique identifier of the object.
     /// - `properties`: The properties of the object.
+    /// - `revision`: If set, the object is only updated if it was last modified at this time.
     ///
     /// # Returns
     /// The updated object.
     ///
     /// # Errors
     /// - [EntityNotFound](crate::Error::EntityNotFound): If the object does not exist.
+    /// - [Conflict](crate::Error::Conflict): If the object was modified at another time.
     pub async fn update_object(
         &self,
         id: &Uuid,
         new_label: impl Into<Option<&ObjectLabel>>,
         new_name: impl Into<Option<&[String]>>,
         properties: impl Into<Option<serde_json::Value>>,
+        revision: Option<DateTime<Utc>>,
     ) -> Result<Object> {
-        Ok(sqlx::query_as!(
+        let updated = sqlx::query_as!(
             Object,
             r#"
             UPDATE objects
@@ -199,6 +240,7 @@ impl Store {
                 name = COALESCE($3, name),
                 properties = COALESCE($4, properties)
             WHERE id = $1
+              AND ($5::timestamptz IS NULL OR COALESCE(updated_at, created_at) = $5)
             RETURNING
                 id,
                 label AS "label: ObjectLabel",
@@ -210,10 +252,21 @@ impl Store {
             id,
             new_label.into() as Option<&ObjectLabel>,
             new_name.into(),
-            properties.into()
+            properties.into(),
+            revision
         )
-        .fetch_one(&*self.pool)
-        .await?)
+        .fetch_optional(&*self.pool)
+        .await?;
+        match updated {
+            Some(object) => Ok(object),
+            None => {
+                // no row matched, either the object is missing or the revision is stale.
+                self.get_object(id).await?;
+                Err(crate::Error::Conflict(format!(
+                    "object '{id}' was modified"
+                )))
+            }
+        }
     }
 
     /// Delete an object from the store.
@@ -255,6 +308,7 @@ impl Store {
     /// # Parameters
     /// - `label`: The label of the objects.
     /// - `namespace`: The namespace of the objects.
+    /// - `filter`: Only list objects passing the visibility filter.
     /// - `page_token`: The page token.
     /// - `max_page_size`: The maximum page size.
     ///
@@ -264,6 +318,7 @@ impl Store {
         &self,
         label: &ObjectLabel,
         namespace: &[String],
+        filter: Option<&VisibilityFilter>,
         page_token: Option<&str>,
         max_page_size: Option<usize>,
     ) -> Result<(Vec<Object>, Option<String>)> {
@@ -276,9 +331,30 @@ impl Store {
             .map(|PaginateToken::V1(V1PaginateToken { created_at, id })| (created_at, id))
             .unzip();
 
+        let filter = FilterParams::from(filter);
+
+        // the `principal` and `granted` CTEs select the resources a principal holds a
+        // permission on, following the inheritance of grants to children in `crate::GraphPolicy`.
+        // They are repeated in `list_associations`, changes must be applied to both.
         let objects = sqlx::query_as!(
             Object,
             r#"
+            WITH RECURSIVE
+            principal AS (
+                SELECT id FROM objects
+                WHERE label = 'recipient_info' AND name = ARRAY[$7::text]
+            ),
+            granted AS (
+                SELECT g.to_id AS id
+                FROM associations g
+                JOIN principal p ON g.from_id = p.id
+                WHERE g.label = 'owner_of'
+                   OR ( g.label = 'has_grant' AND g.properties -> $9 ?| $8 )
+                UNION
+                SELECT c.to_id
+                FROM granted gr
+                JOIN associations c ON c.from_id = gr.id AND c.label = 'parent_of'
+            )
             SELECT
                 id,
                 label AS "label: ObjectLabel",
@@ -290,6 +366,9 @@ impl Store {
             WHERE label = $1
                 AND ( $2 = 0 OR name[1:$2] = $3)
                 AND ( id < $4 OR $4 IS NULL )
+                -- Visibility
+                AND ( id = ANY($6) OR $6 IS NULL )
+                AND ( id IN (SELECT id FROM granted) OR $7 IS NULL )
             ORDER BY id DESC
             LIMIT $5
             "#,
@@ -297,7 +376,11 @@ impl Store {
             namespace.len() as i32,
             namespace,
             token_id,
-            max_page_size as i64
+            max_page_size as i64,
+            filter.ids.as_deref(),
+            filter.principal,
+            &filter.permissions,
+            GRANT_PERMISSIONS_PROPERTY
         )
         .fetch_all(&*self.pool)
         .await?;
@@ -342,80 +425,18 @@ impl Store {
         properties: impl Into<Option<serde_json::Value>>,
     ) -> Result<Association> {
         let mut txn = self.pool.begin().await?;
-        let properties = properties.into();
-        let to_label = sqlx::query!(
-            r#"
-            SELECT id, label AS "label: ObjectLabel"
-            FROM objects
-            WHERE id = $1 OR id = $2
-            "#,
-            from_id,
-            to_id
-        )
-        .fetch_all(&mut *txn)
-        .await?;
-
-        let id_map = to_label
-            .into_iter()
-            .map(|o| (o.id, o.label))
-            .collect::<std::collections::HashMap<_, _>>();
-        let to_label = id_map
-            .get(to_id)
-            .ok_or(crate::Error::entity_not_found("to_id"))?;
-        let from_label = id_map
-            .get(from_id)
-            .ok_or(crate::Error::entity_not_found("from_id"))?;
-
-        // Add the association.
-        let association = sqlx::query_as!(
-            Association,
-            r#"
-            INSERT INTO associations ( from_id, label, to_id, to_label, properties )
-            VALUES ( $1, $2, $3, $4, $5 )
-            RETURNING
-                id,
-                from_id,
-                label AS "label: AssociationLabel",
-                to_id,
-                to_label as "to_label: ObjectLabel",
-                properties,
-                created_at,
-                updated_at
-            "#,
-            from_id,
-            label as &AssociationLabel,
-            to_id,
-            to_label as &ObjectLabel,
-            properties.clone()
-        )
-        .fetch_one(&mut *txn)
-        .await?;
-
-        // Add the inverse association.
-        if let Some(inverse_label) = label.inverse() {
-            sqlx::query!(
-                r#"
-                INSERT INTO associations ( from_id, label, to_id, to_label, properties )
-                VALUES ( $1, $2, $3, $4, $5 )
-                "#,
-                to_id,
-                inverse_label as AssociationLabel,
-                from_id,
-                from_label as &ObjectLabel,
-                properties
-            )
-            .execute(&mut *txn)
-            .await?;
-        }
-
+        let association =
+            add_association(from_id, label, to_id, properties.into(), &mut txn).await?;
         txn.commit().await?;
-
         Ok(association)
     }
 
     /// Delete an association from the store.
     ///
     /// If an inverse association exists, it is automatically deleted.
+    ///
+    /// # Errors
+    /// - [EntityNotFound](crate::Error::EntityNotFound): If the association does not exist.
     pub async fn delete_association(
         &self,
         from_id: &Uuid,
@@ -428,6 +449,51 @@ impl Store {
         Ok(())
     }
 
+    /// Replace the properties of an association.
+    ///
+    /// If an inverse association exists, it is updated in the same transaction.
+    ///
+    /// # Errors
+    /// - [EntityNotFound](crate::Error::EntityNotFound): If the association does not exist.
+    pub async fn update_association(
+        &self,
+        from_id: &Uuid,
+        label: &AssociationLabel,
+        to_id: &Uuid,
+        properties: impl Into<Option<serde_json::Value>>,
+    ) -> Result<()> {
+        let mut txn = self.pool.begin().await?;
+        update_association(from_id, label, to_id, properties.into(), &mut txn).await?;
+        txn.commit().await?;
+        Ok(())
+    }
+
+    /// Apply several changes to associations in a single transaction.
+    ///
+    /// Inverse associations are changed along with the associations.
+    ///
+    /// # Errors
+    /// The error of the first change that could not be applied, in which case
+    /// none of the changes are applied.
+    pub async fn apply_association_changes(&self, changes: &[AssociationWrite]) -> Result<()> {
+        let mut txn = self.pool.begin().await?;
+        for change in changes {
+            match change {
+                AssociationWrite::Add(from_id, label, to_id, properties) => {
+                    add_association(from_id, label, to_id, properties.clone(), &mut txn).await?;
+                }
+                AssociationWrite::Update(from_id, label, to_id, properties) => {
+                    update_association(from_id, label, to_id, properties.clone(), &mut txn).await?;
+                }
+                AssociationWrite::Remove(from_id, label, to_id) => {
+                    delete_association(from_id, label, to_id, &mut txn).await?;
+                }
+            }
+        }
+        txn.commit().await?;
+        Ok(())
+    }
+
     /// List associations of a specific type from an object to a set of objects.
     ///
     /// # Parameters
@@ -500,11 +566,15 @@ impl Store {
     }
 
     /// List associations of a specific type from an object to all objects.
+    ///
+    /// If a visibility filter is given, only associations to objects passing the filter
+    /// are listed.
     pub async fn list_associations(
         &self,
         from_id: &Uuid,
         label: &AssociationLabel,
         target_label: Option<&ObjectLabel>,
+        filter: Option<&VisibilityFilter>,
         page_token: Option<&str>,
         max_page_size: Option<usize>,
     ) -> Result<(Vec<Association>, Option<String>)> {
@@ -521,9 +591,28 @@ impl Store {
             )
             .unzip();
 
+        let filter = FilterParams::from(filter);
+
+        // same `principal` and `granted` CTEs as in `list_objects`, see there.
         let assocs = sqlx::query_as!(
             Association,
             r#"
+            WITH RECURSIVE
+            principal AS (
+                SELECT id FROM objects
+                WHERE label = 'recipient_info' AND name = ARRAY[$7::text]
+            ),
+            granted AS (
+                SELECT g.to_id AS id
+                FROM associations g
+                JOIN principal p ON g.from_id = p.id
+                WHERE g.label = 'owner_of'
+                   OR ( g.label = 'has_grant' AND g.properties -> $9 ?| $8 )
+                UNION
+                SELECT c.to_id
+                FROM granted gr
+                JOIN associations c ON c.from_id = gr.id AND c.label = 'parent_of'
+            )
             SELECT
                 id,
                 from_id,
@@ -539,6 +628,9 @@ impl Store {
               AND ( to_label = $3 OR $3 IS NULL )
               -- Pagination
               AND ( id < $4 OR $4 IS NULL )
+              -- Visibility
+              AND ( to_id = ANY($6) OR $6 IS NULL )
+              AND ( to_id IN (SELECT id FROM granted) OR $7 IS NULL )
             ORDER BY id DESC
             LIMIT $5
             "#,
@@ -546,7 +638,11 @@ impl Store {
             label as &AssociationLabel,
             target_label as Option<&ObjectLabel>,
             token_id,
-            max_page_size as i64
+            max_page_size as i64,
+            filter.ids.as_deref(),
+            filter.principal,
+            &filter.permissions,
+            GRANT_PERMISSIONS_PROPERTY
         )
         .fetch_all(&*self.pool)
         .await?;
@@ -573,7 +669,7 @@ async fn delete_association(
     to_id: &Uuid,
     txn: &mut sqlx::Transaction<'_, sqlx::Postgres>,
 ) -> Result<(), crate::error::Error> {
-    sqlx::query!(
+    let deleted = sqlx::query!(
         r#"
         DELETE FROM associations
         WHERE from_id = $1 AND label = $2 AND to_id = $3
@@ -583,7 +679,11 @@ async fn delete_association(
         to_id
     )
     .execute(&mut **txn)
-    .await?;
+    .await?
+    .rows_affected();
+    if deleted == 0 {
+        return Err(crate::Error::entity_not_found("association"));
+    }
     if let Some(inverse_label) = label.inverse() {
         sqlx::query!(
             r#"
@@ -599,3 +699,120 @@ async fn delete_association(
     };
     Ok(())
 }
+
+async fn add_association(
+    from_id: &Uuid,
+    label: &AssociationLabel,
+    to_id: &Uuid,
+    properties: Option<serde_json::Value>,
+    txn: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+) -> Result<Association> {
+    let to_label = sqlx::query!(
+        r#"
+        SELECT id, label AS "label: ObjectLabel"
+        FROM objects
+        WHERE id = $1 OR id = $2
+        "#,
+        from_id,
+        to_id
+    )
+    .fetch_all(&mut **txn)
+    .await?;
+
+    let id_map = to_label
+        .into_iter()
+        .map(|o| (o.id, o.label))
+        .collect::<std::collections::HashMap<_, _>>();
+    let to_label = id_map
+        .get(to_id)
+        .ok_or(crate::Error::entity_not_found("to_id"))?;
+    let from_label = id_map
+        .get(from_id)
+        .ok_or(crate::Error::entity_not_found("from_id"))?;
+
+    // Add the association.
+    let association = sqlx::query_as!(
+        Association,
+        r#"
+        INSERT INTO associations ( from_id, label, to_id, to_label, properties )
+        VALUES ( $1, $2, $3, $4, $5 )
+        RETURNING
+            id,
+            from_id,
+            label AS "label: AssociationLabel",
+            to_id,
+            to_label as "to_label: ObjectLabel",
+            properties,
+            created_at,
+            updated_at
+        "#,
+        from_id,
+        label as &AssociationLabel,
+        to_id,
+        to_label as &ObjectLabel,
+        properties.clone()
+    )
+    .fetch_one(&mut **txn)
+    .await?;
+
+    // Add the inverse association.
+    if let Some(inverse_label) = label.inverse() {
+        sqlx::query!(
+            r#"
+            INSERT INTO associations ( from_id, label, to_id, to_label, properties )
+            VALUES ( $1, $2, $3, $4, $5 )
+            "#,
+            to_id,
+            inverse_label as AssociationLabel,
+            from_id,
+            from_label as &ObjectLabel,
+            properties
+        )
+        .execute(&mut **txn)
+        .await?;
+    }
+
+    Ok(association)
+}
+
+async fn update_association(
+    from_id: &Uuid,
+    label: &AssociationLabel,
+    to_id: &Uuid,
+    properties: Option<serde_json::Value>,
+    txn: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+) -> Result<()> {
+    let updated = sqlx::query!(
+        r#"
+        UPDATE associations
+        SET properties = $4
+        WHERE from_id = $1 AND label = $2 AND to_id = $3
+        "#,
+        from_id,
+        label as &AssociationLabel,
+        to_id,
+        properties.clone()
+    )
+    .execute(&mut **txn)
+    .await?
+    .rows_affected();
+    if updated == 0 {
+        return Err(crate::Error::entity_not_found("association"));
+    }
+    if let Some(inverse_label) = label.inverse() {
+        sqlx::query!(
+            r#"
+            UPDATE associations
+            SET properties = $4
+            WHERE from_id = $1 AND label = $2 AND to_id = $3
+            "#,
+            to_id,
+            inverse_label as AssociationLabel,
+            from_id,
+            properties
+        )
+        .execute(&mut **txn)
+        .await?;
+    }
+    Ok(())
+}
@@ -1,11 +1,17 @@
 pub use crate::error::{Error, Result};
 pub use graph::*;
+pub use policy::*;
+pub use profiles::*;
+pub use secrets::*;
 
 mod constants;
 mod error;
 mod graph;
 mod pagination;
+mod policy;
+mod profiles;
 mod resources;
+mod secrets;
 
 #[cfg(all(test, feature = "integration-pg"))]
 mod tests {
@@ -0,0 +1,194 @@
+//! Authorization based on grants stored in the graph.
+//!
+//! Grants are [`HasGrant`](delta_sharing_common::AssociationLabel::HasGrant) associations from a recipient object
+//! to a resource. The granted permissions are stored in the `permissions` property of the
+//! association. Recipients that are owners of a resource ([`OwnerOf`](delta_sharing_common::AssociationLabel::OwnerOf))
+//! hold all permissions on it.
+//!
+//! Permissions are inherited by the children of a resource, following the
+//! [`ParentOf`](delta_sharing_common::AssociationLabel::ParentOf) associations between resources,
+//! e.g. `catalog → schema → table` or `share → schema → table`. The store writes these
+//! associations when resources are created or renamed, see [`link_parent`](delta_sharing_common::link_parent).
+use std::sync::Arc;
+
+use delta_sharing_common::{
+    Decision, ObjectLabel, Permission, Policy, Recipient, ResourceIdent, ResourceRef,
+    Result as CommonResult, VisibilityFilter,
+};
+use serde_json::{json, Value};
+use sqlx::PgPool;
+
+use crate::error::Result;
+use crate::resources::IdentRefs as _;
+
+pub use delta_sharing_common::api::permissions::GRANT_PERMISSIONS_PROPERTY;
+
+/// [`Policy`] deriving decisions from grants on resources and their ancestors.
+///
+/// Recipients are mapped to the [`RecipientInfo`](delta_sharing_common::ObjectLabel::RecipientInfo)
+/// object with the same name. Anonymous and custom recipients are denied access to all
+/// resources.
+///
+/// Checks against undefined resources (e.g. when creating or listing resources) are not
+/// covered by grants and are only allowed for the configured root permissions.
+#[derive(Clone)]
+pub struct GraphPolicy {
+    pool: Arc<PgPool>,
+    root_permissions: Vec<Permission>,
+}
+
+impl GraphPolicy {
+    /// Create a new [`GraphPolicy`].
+    ///
+    /// By default, [`Read`](Permission::Read) and [`Browse`](Permission::Browse) are allowed
+    /// on undefined resources, so recipients can list the resources they have access to.
+    pub fn new(pool: Arc<PgPool>) -> Self {
+        Self {
+            pool,
+            root_permissions: vec![Permission::Read, Permission::Browse],
+        }
+    }
+
+    /// Set the permissions allowed on undefined resources.
+    pub fn with_root_permissions(
+        mut self,
+        permissions: impl IntoIterator<Item = Permission>,
+    ) -> Self {
+        self.root_permissions = permissions.into_iter().collect();
+        self
+    }
+
+    /// Get the indices of all resources the principal holds the permission on.
+    async fn allowed(
+        &self,
+        resources: &[ResourceIdent],
+        permission: &Permission,
+        principal: &str,
+    ) -> Result<Vec<i32>> {
+        let targets = resources
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, resource)| match resource.ident() {
+                (_, ResourceRef::Uuid(id)) => Some(json!({ "idx": idx, "id": id })),
+                (label, ResourceRef::Name(name)) => Some(json!({
+                    "idx": idx,
+                    "label": label,
+                    "name": name.as_ref(),
+                })),
+                (_, ResourceRef::Undefined) => None,
+            })
+            .collect::<Vec<_>>();
+        if targets.is_empty() {
+            return Ok(Vec::new());
+        }
+        let permissions = [
+            permission.as_ref().to_string(),
+            Permission::Manage.as_ref().to_string(),
+        ];
+
+        // walks up from the targets to grants on their ancestors. Visibility filters in
+        // `GraphStore::list_objects` and `GraphStore::list_associations` walk down from the
+        // grants instead and must select the same resources.
+        Ok(sqlx::query_scalar!(
+            r#"
+            WITH RECURSIVE
+            targets AS (
+                SELECT t.idx, COALESCE(t.id, o.id) AS id
+                FROM jsonb_to_recordset($1::jsonb)
+                    AS t(idx int, id uuid, label object_label, name text[])
+                LEFT JOIN objects o
+                    ON t.id IS NULL AND o.label = t.label AND o.name = t.name
+            ),
+            ancestors AS (
+                SELECT idx, id FROM targets WHERE id IS NOT NULL
+                UNION
+                SELECT a.idx, assoc.to_id
+                FROM ancestors a
+                JOIN associations assoc
+                    ON assoc.from_id = a.id AND assoc.label = 'child_of'
+            ),
+            principal AS (
+                SELECT id FROM objects
+                WHERE label = 'recipient_info' AND name = ARRAY[$2]
+            )
+            SELECT DISTINCT a.idx AS "idx!"
+            FROM ancestors a
+            JOIN associations g ON g.to_id = a.id
+            JOIN principal p ON g.from_id = p.id
+            WHERE g.label = 'owner_of'
+               OR ( g.label = 'has_grant' AND g.properties -> $4 ?| $3 )
+            "#,
+            Value::Array(targets),
+            principal,
+            &permissions,
+            GRANT_PERMISSIONS_PROPERTY
+        )
+        .fetch_all(&*self.pool)
+        .await?)
+    }
+}
+
+fn principal(recipient: &Recipient) -> Option<&str> {
+    match recipient {
+        Recipient::User(name) => Some(name),
+        Recipient::Anonymous | Recipient::Custom(_) => None,
+    }
+}
+
+#[async_trait::async_trait]
+impl Policy for GraphPolicy {
+    async fn authorize(
+        &self,
+        resource: &ResourceIdent,
+        permission: &Permission,
+        recipient: &Recipient,
+    ) -> CommonResult<Decision> {
+        Ok(self
+            .authorize_many(std::slice::from_ref(resource), permission, recipient)
+            .await?
+            .pop()
+            .unwrap_or(Decision::Deny))
+    }
+
+    async fn authorize_many(
+        &self,
+        resources: &[ResourceIdent],
+        permission: &Permission,
+        recipient: &Recipient,
+    ) -> CommonResult<Vec<Decision>> {
+        let root_decision = if self.root_permissions.contains(permission) {
+            Decision::Allow
+        } else {
+            Decision::Deny
+        };
+        let mut decisions = resources
+            .iter()
+            .map(|resource| match resource.ident() {
+                (_, ResourceRef::Undefined) => root_decision,
+                _ => Decision::Deny,
+            })
+            .collect::<Vec<_>>();
+        let Some(principal) = principal(recipient) else {
+            return Ok(decisions);
+        };
+        for idx in self.allowed(resources, permission, principal).await? {
+            decisions[idx as usize] = Decision::Allow;
+        }
+        Ok(decisions)
+    }
+
+    async fn visibility_filter(
+        &self,
+        _label: &ObjectLabel,
+        permission: &Permission,
+        recipient: &Recipient,
+    ) -> CommonResult<Option<VisibilityFilter>> {
+        Ok(Some(match principal(recipient) {
+            Some(principal) => VisibilityFilter::Grants {
+                principal: principal.to_string(),
+                permission: permission.clone(),
+            },
+            None => VisibilityFilter::Ids(Default::default()),
+        }))
+    }
+}
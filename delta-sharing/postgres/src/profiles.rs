@@ -0,0 +1,230 @@
+//! Persistent profile management.
+//!
+//! Issued profiles are tracked by their fingerprint, so revocations survive
+//! restarts and are shared between all server replicas using the same database.
+use std::marker::PhantomData;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use dashmap::DashMap;
+use delta_sharing_common::{Error as CommonError, Profile, Result as CommonResult};
+use delta_sharing_profiles::{ProfileClaims, ProfileManager, TokenManager};
+use sqlx::PgPool;
+
+use crate::error::Result;
+
+/// Default time a revocation lookup is cached in process.
+const DEFAULT_CACHE_TTL: Duration = Duration::from_secs(30);
+
+/// Record of an issued profile.
+#[derive(Debug, Clone, PartialEq, sqlx::FromRow)]
+pub struct ProfileRecord {
+    /// Fingerprint of the claims encoded in the profile.
+    pub fingerprint: String,
+
+    /// Subject the profile was issued to.
+    pub subject: Option<String>,
+
+    /// Shares the profile grants access to.
+    pub shares: Vec<String>,
+
+    /// Time the profile expires.
+    pub expires_at: Option<DateTime<Utc>>,
+
+    /// Time the profile was revoked.
+    pub revoked_at: Option<DateTime<Utc>>,
+
+    /// Time the profile was issued.
+    pub created_at: DateTime<Utc>,
+}
+
+/// [`ProfileManager`] persisting issued profiles and their revocation state in PostgreSQL.
+///
+/// Revocation lookups are cached in process for a short time, so a revocation
+/// issued via another replica may take up to the configured TTL to take effect.
+pub struct PostgresProfileManager<T: ProfileClaims> {
+    pool: Arc<PgPool>,
+    token_manager: TokenManager,
+    server_endpoint: String,
+    share_credentials_version: i32,
+    cache: DashMap<String, (bool, Instant)>,
+    cache_ttl: Duration,
+    last_sweep: Mutex<Instant>,
+    _phantom: PhantomData<T>,
+}
+
+impl<T: ProfileClaims> PostgresProfileManager<T> {
+    pub fn new(
+        pool: Arc<PgPool>,
+        server_endpoint: String,
+        share_credentials_version: i32,
+        token_manager: TokenManager,
+    ) -> Self {
+        Self {
+            pool,
+            token_manager,
+            server_endpoint,
+            share_credentials_version,
+            cache: DashMap::new(),
+            cache_ttl: DEFAULT_CACHE_TTL,
+            last_sweep: Mutex::new(Instant::now()),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Set the time revocation lookups are cached in process.
+    pub fn with_cache_ttl(mut self, ttl: Duration) -> Self {
+        self.cache_ttl = ttl;
+        self
+    }
+
+    /// List all profiles issued to a subject that are neither expired nor revoked.
+    pub async fn list_active_profiles(&self, subject: &str) -> Result<Vec<ProfileRecord>> {
+        Ok(sqlx::query_as!(
+            ProfileRecord,
+            r#"
+            SELECT
+                fingerprint,
+                subject,
+                shares,
+                expires_at,
+                revoked_at,
+                created_at
+            FROM profiles
+            WHERE subject = $1
+              AND revoked_at IS NULL
+              AND (expires_at IS NULL OR expires_at > now())
+            ORDER BY created_at DESC
+            "#,
+            subject
+        )
+        .fetch_all(&*self.pool)
+        .await?)
+    }
+
+    /// Revoke all profiles granting access to a share.
+    ///
+    /// # Returns
+    /// The fingerprints of the profiles that were revoked.
+    pub async fn revoke_share(&self, share: &str) -> Result<Vec<String>> {
+        let fingerprints = sqlx::query_scalar!(
+            r#"
+            UPDATE profiles
+            SET revoked_at = now()
+            WHERE $1 = ANY(shares) AND revoked_at IS NULL
+            RETURNING fingerprint
+            "#,
+            share
+        )
+        .fetch_all(&*self.pool)
+        .await?;
+        for fingerprint in &fingerprints {
+            self.cache_revocation(fingerprint, true);
+        }
+        Ok(fingerprints)
+    }
+
+    /// Cache the revocation state of a profile.
+    ///
+    /// Expired entries are swept at most once per TTL, so the cache only holds
+    /// fingerprints that were looked up recently.
+    fn cache_revocation(&self, fingerprint: &str, revoked: bool) {
+        let now = Instant::now();
+        {
+            let mut last_sweep = self.last_sweep.lock().unwrap();
+            if now.duration_since(*last_sweep) >= self.cache_ttl {
+                *last_sweep = now;
+                self.cache
+                    .retain(|_, (_, cached_at)| now.duration_since(*cached_at) < self.cache_ttl);
+            }
+        }
+        self.cache.insert(fingerprint.to_string(), (revoked, now));
+    }
+
+    async fn is_revoked(&self, fingerprint: &str) -> Result<bool> {
+        if let Some(entry) = self.cache.get(fingerprint) {
+            let (revoked, cached_at) = *entry;
+            if cached_at.elapsed() < self.cache_ttl {
+                return Ok(revoked);
+            }
+        }
+        let revoked = sqlx::query_scalar!(
+            r#"
+            SELECT revoked_at IS NOT NULL AS "revoked!"
+            FROM profiles
+            WHERE fingerprint = $1
+            "#,
+            fingerprint
+        )
+        .fetch_optional(&*self.pool)
+        .await?
+        .unwrap_or(false);
+        self.cache_revocation(fingerprint, revoked);
+        Ok(revoked)
+    }
+}
+
+#[async_trait::async_trait]
+impl<T: ProfileClaims> ProfileManager for PostgresProfileManager<T> {
+    type Claims = T;
+
+    async fn issue_profile(
+        &self,
+        claims: &Self::Claims,
+        expiration_time: Option<DateTime<Utc>>,
+    ) -> CommonResult<Profile> {
+        let token = self.token_manager.encode(claims)?;
+        sqlx::query!(
+            r#"
+            INSERT INTO profiles ( fingerprint, subject, shares, expires_at )
+            VALUES ( $1, $2, $3, $4 )
+            ON CONFLICT ( fingerprint ) DO UPDATE
+            SET expires_at = EXCLUDED.expires_at
+            "#,
+            claims.fingerprint(),
+            claims.subject(),
+            &claims.shares(),
+            expiration_time
+        )
+        .execute(&*self.pool)
+        .await
+        .map_err(crate::Error::from)?;
+        Ok(Profile {
+            share_credentials_version: self.share_credentials_version,
+            endpoint: self.server_endpoint.clone(),
+            bearer_token: token,
+            expiration_time: expiration_time
+                .map(|dt| dt.to_rfc3339_opts(SecondsFormat::Secs, true)),
+        })
+    }
+
+    async fn revoke_profile(&self, fingerprint: &str) -> CommonResult<()> {
+        // profiles not issued through this manager are recorded as well,
+        // so they can be revoked before they are ever presented.
+        sqlx::query!(
+            r#"
+            INSERT INTO profiles ( fingerprint, revoked_at )
+            VALUES ( $1, now() )
+            ON CONFLICT ( fingerprint ) DO UPDATE
+            SET revoked_at = COALESCE(profiles.revoked_at, EXCLUDED.revoked_at)
+            "#,
+            fingerprint
+        )
+        .execute(&*self.pool)
+        .await
+        .map_err(crate::Error::from)?;
+        self.cache_revocation(fingerprint, true);
+        Ok(())
+    }
+
+    async fn validate_profile(&self, token: &str) -> CommonResult<Self::Claims> {
+        let claims = self.token_manager.decode::<Self::Claims>(token)?;
+        if self.is_revoked(&claims.fingerprint()).await? {
+            return Err(CommonError::Generic(
+                "Profile has previously been revoked".to_string(),
+            ));
+        }
+        Ok(claims)
+    }
+}
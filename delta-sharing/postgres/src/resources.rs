@@ -1,11 +1,12 @@
 use delta_sharing_common::models::PropertyMap;
 use delta_sharing_common::{
-    AssociationLabel, Error, Object, ObjectLabel, Resource, ResourceIdent, ResourceName,
-    ResourceRef, ResourceStore, Result, EMPTY_RESOURCE_NAME,
+    link_parent, AssociationChange, AssociationLabel, Error, Object, ObjectLabel, Resource,
+    ResourceIdent, ResourceName, ResourceRef, ResourceStore, Result, VisibilityFilter,
+    EMPTY_RESOURCE_NAME,
 };
 use itertools::Itertools;
 
-use crate::GraphStore;
+use crate::{AssociationWrite, GraphStore};
 
 pub trait IdentRefs {
     fn ident(&self) -> (&ObjectLabel, &ResourceRef);
@@ -52,12 +53,13 @@ impl ResourceStore for GraphStore {
         &self,
         label: &ObjectLabel,
         namespace: Option<&ResourceName>,
+        filter: Option<&VisibilityFilter>,
         max_results: Option<usize>,
         page_token: Option<String>,
     ) -> Result<(Vec<Resource>, Option<String>)> {
         let namespace = namespace.unwrap_or_else(|| &EMPTY_RESOURCE_NAME);
         let objects = self
-            .list_objects(label, namespace, page_token.as_deref(), max_results)
+            .list_objects(label, namespace, filter, page_token.as_deref(), max_results)
             .await?;
         Ok((
             objects
@@ -81,6 +83,14 @@ impl ResourceStore for GraphStore {
         let object = self
             .add_object(&object.label, &object.name, object.properties)
             .await?;
+        let ident = object.label.to_ident(object.id);
+        if let Err(err) = link_parent(self, &object.label, &object.name, &ident).await {
+            // resources without a link to their parent do not inherit grants.
+            if let Err(rollback) = self.delete_object(&object.id).await {
+                tracing::error!("failed to roll back {:?}: {}", ident, rollback);
+            }
+            return Err(err);
+        }
         let id = ResourceRef::Uuid(object.id);
         Ok((object.try_into()?, id))
     }
@@ -115,19 +125,27 @@ impl ResourceStore for GraphStore {
         id: &ResourceIdent,
         resource: Resource,
     ) -> Result<(Resource, ResourceRef)> {
-        let (uuid, _object) = self.ident_to_uuid(id).await?;
+        let (uuid, previous) = self.ident_to_uuid(id).await?;
+        let previous = match previous {
+            Some(previous) => previous,
+            None => self.get_object(&uuid).await?,
+        };
+        let revision = resource.etag().map(Object::parse_etag).transpose()?;
         let object: Object = resource.try_into()?;
-        Ok((
-            self.update_object(
+        let object = self
+            .update_object(
                 &uuid,
                 Some(&object.label),
                 Some(object.name.as_ref()),
                 object.properties,
+                revision,
             )
-            .await?
-            .try_into()?,
-            uuid.into(),
-        ))
+            .await?;
+        if object.name != previous.name {
+            let ident = object.label.to_ident(uuid);
+            link_parent(self, &object.label, &object.name, &ident).await?;
+        }
+        Ok((object.try_into()?, uuid.into()))
     }
 
     /// Add an association between two resources.
@@ -172,7 +190,122 @@ impl ResourceStore for GraphStore {
         to: &ResourceIdent,
         label: &AssociationLabel,
     ) -> Result<()> {
-        todo!("remove_association")
+        let (from_id, _) = self.ident_to_uuid(from).await?;
+        let (to_id, _) = self.ident_to_uuid(to).await?;
+        self.delete_association(&from_id, label, &to_id).await?;
+        Ok(())
+    }
+
+    async fn get_association(
+        &self,
+        from: &ResourceIdent,
+        to: &ResourceIdent,
+        label: &AssociationLabel,
+    ) -> Result<Option<PropertyMap>> {
+        let (from_id, _) = self.ident_to_uuid(from).await?;
+        let (to_id, _) = self.ident_to_uuid(to).await?;
+        let (associations, _) = self
+            .get_associations(&from_id, label, &[to_id], None, Some(1))
+            .await?;
+        let association = associations.into_iter().next().ok_or(Error::NotFound)?;
+        Ok(association.properties.and_then(|p| match p {
+            serde_json::Value::Object(map) => Some(map.into_iter().collect()),
+            _ => None,
+        }))
+    }
+
+    async fn apply_association_changes(&self, changes: &[AssociationChange]) -> Result<()> {
+        let properties = |p: &Option<PropertyMap>| {
+            p.clone()
+                .map(|p| serde_json::Value::Object(p.into_iter().collect()))
+        };
+        let mut writes = Vec::with_capacity(changes.len());
+        for change in changes {
+            writes.push(match change {
+                AssociationChange::Add {
+                    from,
+                    to,
+                    label,
+                    properties: props,
+                } => AssociationWrite::Add(
+                    self.ident_to_uuid(from).await?.0,
+                    label.clone(),
+                    self.ident_to_uuid(to).await?.0,
+                    properties(props),
+                ),
+                AssociationChange::Update {
+                    from,
+                    to,
+                    label,
+                    properties: props,
+                } => AssociationWrite::Update(
+                    self.ident_to_uuid(from).await?.0,
+                    label.clone(),
+                    self.ident_to_uuid(to).await?.0,
+                    properties(props),
+                ),
+                AssociationChange::Remove { from, to, label } => AssociationWrite::Remove(
+                    self.ident_to_uuid(from).await?.0,
+                    label.clone(),
+                    self.ident_to_uuid(to).await?.0,
+                ),
+            });
+        }
+        Ok(self.apply_association_changes(&writes).await?)
+    }
+
+    async fn update_association(
+        &self,
+        from: &ResourceIdent,
+        to: &ResourceIdent,
+        label: &AssociationLabel,
+        properties: Option<PropertyMap>,
+    ) -> Result<()> {
+        let (from_id, _) = self.ident_to_uuid(from).await?;
+        let (to_id, _) = self.ident_to_uuid(to).await?;
+        self.update_association(
+            &from_id,
+            label,
+            &to_id,
+            properties.map(|p| serde_json::Value::Object(p.into_iter().collect())),
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn get_associations(
+        &self,
+        from: &ResourceIdent,
+        to: &[ResourceIdent],
+        label: &AssociationLabel,
+    ) -> Result<Vec<Option<PropertyMap>>> {
+        let (from_id, _) = self.ident_to_uuid(from).await?;
+        let mut to_ids = Vec::with_capacity(to.len());
+        for ident in to {
+            to_ids.push(self.ident_to_uuid(ident).await?.0);
+        }
+        let mut properties = std::collections::HashMap::with_capacity(to_ids.len());
+        let mut page_token = None;
+        loop {
+            let (associations, next) = self
+                .get_associations(&from_id, label, &to_ids, page_token.as_deref(), None)
+                .await?;
+            properties.extend(associations.into_iter().map(|a| (a.to_id, a.properties)));
+            page_token = next;
+            if page_token.is_none() {
+                break;
+            }
+        }
+        to_ids
+            .iter()
+            .map(|id| {
+                let props = properties.remove(id).ok_or(Error::NotFound)?;
+                Ok(props.and_then(|p| match p {
+                    serde_json::Value::Object(map) => Some(map.into_iter().collect()),
+                    _ => None,
+                }))
+            })
+            .collect()
     }
 
     async fn list_associations(
@@ -180,6 +313,7 @@ impl ResourceStore for GraphStore {
         resource: &ResourceIdent,
         label: &AssociationLabel,
         target_label: Option<&ResourceIdent>,
+        filter: Option<&VisibilityFilter>,
         max_results: Option<usize>,
         page_token: Option<String>,
     ) -> Result<(Vec<ResourceIdent>, Option<String>)> {
@@ -190,6 +324,7 @@ impl ResourceStore for GraphStore {
                 &target_id,
                 label,
                 target_label,
+                filter,
                 page_token.as_deref(),
                 max_results,
             )
@@ -0,0 +1,270 @@
+//! Versioned secret storage.
+//!
+//! Every write adds a new version of a secret, so previous values remain available
+//! via [`SecretManager::get_secret_version`] until they are purged. Deleting a secret
+//! records a tombstone version, which hides all earlier versions.
+use std::sync::Arc;
+use std::time::Duration;
+
+use bytes::Bytes;
+use delta_sharing_common::{Error as CommonError, Result as CommonResult, SecretManager};
+use sqlx::{PgPool, Postgres, Transaction};
+use uuid::Uuid;
+
+use crate::error::{Error, Result};
+
+/// Default time superseded and deleted secret versions are kept.
+const DEFAULT_RETENTION: Duration = Duration::from_secs(30 * 24 * 60 * 60);
+
+/// [`SecretManager`] keeping the history of secret values in PostgreSQL.
+#[derive(Clone)]
+pub struct PostgresSecretManager {
+    pool: Arc<PgPool>,
+    retention: Duration,
+}
+
+impl PostgresSecretManager {
+    pub fn new(pool: Arc<PgPool>) -> Self {
+        Self {
+            pool,
+            retention: DEFAULT_RETENTION,
+        }
+    }
+
+    /// Set the time versions are kept after they were superseded or deleted.
+    pub fn with_retention(mut self, retention: Duration) -> Self {
+        self.retention = retention;
+        self
+    }
+
+    /// Remove versions that were superseded or deleted longer ago than the retention.
+    ///
+    /// The current version of a secret is never removed. Once its tombstone expires,
+    /// a deleted secret is removed entirely.
+    ///
+    /// # Returns
+    /// The number of removed versions.
+    pub async fn purge_expired(&self) -> Result<u64> {
+        let result = sqlx::query!(
+            r#"
+            DELETE FROM secrets s
+            WHERE EXISTS (
+                SELECT 1 FROM secrets n
+                WHERE n.name = s.name
+                  AND n.version > s.version
+                  AND n.created_at < now() - make_interval(secs => $1)
+            )
+            OR (s.value IS NULL AND s.created_at < now() - make_interval(secs => $1))
+            "#,
+            self.retention.as_secs_f64()
+        )
+        .execute(&*self.pool)
+        .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// Begin a transaction holding a lock on the name of a secret.
+    ///
+    /// Writes only add a version if the latest one permits it. Under READ COMMITTED,
+    /// concurrent writes could all pass that check, e.g. two creates both adding a first
+    /// version, so writes to the same secret are serialized.
+    async fn lock(&self, secret_name: &str) -> Result<Transaction<'static, Postgres>> {
+        let mut txn = self.pool.begin().await?;
+        sqlx::query!("SELECT pg_advisory_xact_lock(hashtext($1))", secret_name)
+            .execute(&mut *txn)
+            .await?;
+        Ok(txn)
+    }
+
+    /// Periodically purge expired versions in a background task.
+    pub fn purge_periodically(&self, interval: Duration) -> tokio::task::JoinHandle<()> {
+        let manager = self.clone();
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            loop {
+                ticker.tick().await;
+                match manager.purge_expired().await {
+                    Ok(0) => {}
+                    Ok(count) => tracing::info!("purged {count} expired secret versions"),
+                    Err(e) => tracing::warn!("failed to purge secret versions: {e}"),
+                }
+            }
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl SecretManager for PostgresSecretManager {
+    async fn get_secret(&self, secret_name: &str) -> CommonResult<(Uuid, Bytes)> {
+        let record = sqlx::query!(
+            r#"
+            SELECT version, value
+            FROM secrets
+            WHERE name = $1
+            ORDER BY version DESC
+            LIMIT 1
+            "#,
+            secret_name
+        )
+        .fetch_optional(&*self.pool)
+        .await
+        .map_err(Error::from)?;
+        match record {
+            Some(record) => match record.value {
+                Some(value) => Ok((record.version, value.into())),
+                None => Err(CommonError::NotFound),
+            },
+            None => Err(CommonError::NotFound),
+        }
+    }
+
+    async fn get_secret_version(&self, secret_name: &str, version: Uuid) -> CommonResult<Bytes> {
+        let value = sqlx::query_scalar!(
+            r#"
+            SELECT value AS "value!"
+            FROM secrets s
+            WHERE name = $1 AND version = $2 AND value IS NOT NULL
+              AND NOT EXISTS (
+                SELECT 1 FROM secrets t
+                WHERE t.name = s.name AND t.version > s.version AND t.value IS NULL
+              )
+            "#,
+            secret_name,
+            version
+        )
+        .fetch_optional(&*self.pool)
+        .await
+        .map_err(Error::from)?;
+        value.map(Bytes::from).ok_or(CommonError::NotFound)
+    }
+
+    async fn create_secret(&self, secret_name: &str, secret_value: Bytes) -> CommonResult<Uuid> {
+        let mut txn = self.lock(secret_name).await?;
+        // a secret may be re-created after it was deleted.
+        let version = sqlx::query_scalar!(
+            r#"
+            INSERT INTO secrets ( name, value )
+            SELECT $1, $2
+            WHERE NOT EXISTS (
+                SELECT 1 FROM (
+                    SELECT value FROM secrets
+                    WHERE name = $1
+                    ORDER BY version DESC
+                    LIMIT 1
+                ) latest
+                WHERE latest.value IS NOT NULL
+            )
+            RETURNING version
+            "#,
+            secret_name,
+            secret_value.as_ref()
+        )
+        .fetch_optional(&mut *txn)
+        .await
+        .map_err(Error::from)?;
+        txn.commit().await.map_err(Error::from)?;
+        version.ok_or(CommonError::AlreadyExists)
+    }
+
+    async fn update_secret(&self, secret_name: &str, secret_value: Bytes) -> CommonResult<Uuid> {
+        let mut txn = self.lock(secret_name).await?;
+        let version = sqlx::query_scalar!(
+            r#"
+            INSERT INTO secrets ( name, value )
+            SELECT $1, $2
+            WHERE EXISTS (
+                SELECT 1 FROM (
+                    SELECT value FROM secrets
+                    WHERE name = $1
+                    ORDER BY version DESC
+                    LIMIT 1
+                ) latest
+                WHERE latest.value IS NOT NULL
+            )
+            RETURNING version
+            "#,
+            secret_name,
+            secret_value.as_ref()
+        )
+        .fetch_optional(&mut *txn)
+        .await
+        .map_err(Error::from)?;
+        txn.commit().await.map_err(Error::from)?;
+        version.ok_or(CommonError::NotFound)
+    }
+
+    async fn delete_secret(&self, secret_name: &str) -> CommonResult<()> {
+        let mut txn = self.lock(secret_name).await?;
+        sqlx::query_scalar!(
+            r#"
+            INSERT INTO secrets ( name, value )
+            SELECT $1, NULL
+            WHERE EXISTS (
+                SELECT 1 FROM (
+                    SELECT value FROM secrets
+                    WHERE name = $1
+                    ORDER BY version DESC
+                    LIMIT 1
+                ) latest
+                WHERE latest.value IS NOT NULL
+            )
+            RETURNING version
+            "#,
+            secret_name
+        )
+        .fetch_optional(&mut *txn)
+        .await
+        .map_err(Error::from)?
+        .ok_or(CommonError::NotFound)?;
+        txn.commit().await.map_err(Error::from)?;
+        Ok(())
+    }
+
+    async fn put_secret_version(
+        &self,
+        secret_name: &str,
+        current: Option<Uuid>,
+        version: Uuid,
+        secret_value: Bytes,
+    ) -> CommonResult<()> {
+        let mut txn = self.lock(secret_name).await?;
+        // the latest version, including tombstones, must be `current` and precede `version`.
+        let inserted = sqlx::query_scalar!(
+            r#"
+            INSERT INTO secrets ( name, version, value )
+            SELECT $1, $2, $3
+            WHERE NOT EXISTS (
+                SELECT 1 FROM secrets
+                WHERE name = $1 AND version >= $2
+            )
+            AND (
+                SELECT CASE WHEN value IS NULL THEN NULL ELSE version END
+                FROM secrets
+                WHERE name = $1
+                ORDER BY version DESC
+                LIMIT 1
+            ) IS NOT DISTINCT FROM $4
+            RETURNING version
+            "#,
+            secret_name,
+            version,
+            secret_value.as_ref(),
+            current as Option<Uuid>
+        )
+        .fetch_optional(&mut *txn)
+        .await
+        .map_err(Error::from)?;
+        txn.commit().await.map_err(Error::from)?;
+        if inserted.is_some() {
+            return Ok(());
+        }
+        // tell apart why the version was not written.
+        match self.get_secret(secret_name).await {
+            Ok(_) if current.is_none() => Err(CommonError::AlreadyExists),
+            Err(CommonError::NotFound) if current.is_some() => Err(CommonError::NotFound),
+            Ok(_) | Err(CommonError::NotFound) => Err(CommonError::Conflict),
+            Err(err) => Err(err),
+        }
+    }
+}
@@ -3,7 +3,7 @@
 use std::sync::Arc;
 
 use delta_sharing_common::{AssociationLabel, ObjectLabel};
-use delta_sharing_postgres::{Error, GraphStore};
+use delta_sharing_postgres::{AssociationWrite, Error, GraphStore};
 use uuid::Uuid;
 
 #[sqlx::test]
@@ -63,6 +63,7 @@ async fn test_objects(pool: sqlx::PgPool) -> Result<(), Box<dyn std::error::Erro
             None,
             None,
             serde_json::json!({ "key": "value2" }),
+            None,
         )
         .await?;
     assert_eq!(
@@ -72,7 +73,7 @@ async fn test_objects(pool: sqlx::PgPool) -> Result<(), Box<dyn std::error::Erro
 
     // Updating an object with a non-existent ID should fail.
     let res = store
-        .update_object(&Uuid::new_v4(), None, None, serde_json::json!({}))
+        .update_object(&Uuid::new_v4(), None, None, serde_json::json!({}), None)
         .await;
     assert!(matches!(res, Err(Error::EntityNotFound(_))));
 
@@ -194,3 +195,72 @@ async fn test_associations(pool: sqlx::PgPool) -> Result<(), Box<dyn std::error:
 
     Ok(())
 }
+
+#[sqlx::test]
+async fn test_apply_association_changes(
+    pool: sqlx::PgPool,
+) -> Result<(), Box<dyn std::error::Error + 'static>> {
+    let store = GraphStore::new(Arc::new(pool));
+    let mut ids = Vec::new();
+    for name in ["alice", "bob"] {
+        ids.push(
+            store
+                .add_object(&ObjectLabel::RecipientInfo, &[name.to_string()], None)
+                .await?
+                .id,
+        );
+    }
+    let catalog = store
+        .add_object(&ObjectLabel::CatalogInfo, &["catalog".to_string()], None)
+        .await?
+        .id;
+    store
+        .add_association(&ids[1], &AssociationLabel::HasGrant, &catalog, None)
+        .await?;
+
+    // the second change fails, so the first one is rolled back.
+    let properties = Some(serde_json::json!({ "permissions": ["read"] }));
+    let res = store
+        .apply_association_changes(&[
+            AssociationWrite::Add(ids[0], AssociationLabel::HasGrant, catalog, None),
+            AssociationWrite::Add(ids[1], AssociationLabel::HasGrant, catalog, None),
+        ])
+        .await;
+    assert!(matches!(res, Err(Error::AlreadyExists(_))));
+    let (associations, _) = store
+        .get_associations(&ids[0], &AssociationLabel::HasGrant, &[catalog], None, None)
+        .await?;
+    assert!(associations.is_empty());
+
+    store
+        .apply_association_changes(&[
+            AssociationWrite::Add(ids[0], AssociationLabel::HasGrant, catalog, None),
+            AssociationWrite::Update(
+                ids[1],
+                AssociationLabel::HasGrant,
+                catalog,
+                properties.clone(),
+            ),
+        ])
+        .await?;
+    let (associations, _) = store
+        .get_associations(&catalog, &AssociationLabel::GrantedTo, &ids, None, None)
+        .await?;
+    assert_eq!(associations.len(), 2);
+    assert!(associations
+        .iter()
+        .any(|a| a.to_id == ids[1] && a.properties == properties));
+
+    store
+        .apply_association_changes(&[AssociationWrite::Remove(
+            ids[0],
+            AssociationLabel::HasGrant,
+            catalog,
+        )])
+        .await?;
+    let (associations, _) = store
+        .get_associations(&catalog, &AssociationLabel::GrantedTo, &ids, None, None)
+        .await?;
+    assert_eq!(associations.len(), 1);
+    Ok(())
+}
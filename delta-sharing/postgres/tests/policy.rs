@@ -0,0 +1,387 @@
+#![cfg(feature = "integration-pg")]
+
+use std::sync::Arc;
+
+use delta_sharing_common::models::tables::v1::TableInfo;
+use delta_sharing_common::models::{CatalogInfo, SchemaInfo};
+use delta_sharing_common::{
+    process_resources, AssociationLabel, Decision, ObjectLabel, Permission, Policy, Recipient,
+    Resource, ResourceExt, ResourceIdent, ResourceName, ResourceRef, ResourceStore, RuleSet,
+    RulesPolicy,
+};
+use delta_sharing_postgres::{GraphPolicy, GraphStore};
+use uuid::Uuid;
+
+struct Fixture {
+    store: GraphStore,
+    alice: Uuid,
+    catalog: Uuid,
+    schema: Uuid,
+    table: Uuid,
+}
+
+async fn add(store: &GraphStore, label: &ObjectLabel, name: &[&str]) -> Uuid {
+    let name = name.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+    store.add_object(label, &name, None).await.unwrap().id
+}
+
+async fn create(store: &GraphStore, resource: impl Into<Resource>) -> Uuid {
+    match store.create(resource.into()).await.unwrap().1 {
+        ResourceRef::Uuid(uuid) => uuid,
+        other => panic!("expected uuid reference, got {other:?}"),
+    }
+}
+
+fn table_info(schema: &str, name: &str) -> TableInfo {
+    TableInfo {
+        name: name.to_string(),
+        schema_name: schema.to_string(),
+        catalog_name: "catalog".to_string(),
+        ..Default::default()
+    }
+}
+
+async fn setup(pool: sqlx::PgPool) -> Fixture {
+    let store = GraphStore::new(Arc::new(pool));
+    let alice = add(&store, &ObjectLabel::RecipientInfo, &["alice"]).await;
+    add(&store, &ObjectLabel::RecipientInfo, &["bob"]).await;
+    let catalog = create(
+        &store,
+        CatalogInfo {
+            name: "catalog".to_string(),
+            ..Default::default()
+        },
+    )
+    .await;
+    let schema = create(
+        &store,
+        SchemaInfo {
+            name: "schema".to_string(),
+            catalog_name: "catalog".to_string(),
+            ..Default::default()
+        },
+    )
+    .await;
+    let table = create(&store, table_info("schema", "table")).await;
+    Fixture {
+        store,
+        alice,
+        catalog,
+        schema,
+        table,
+    }
+}
+
+fn grant(permissions: &[&str]) -> serde_json::Value {
+    serde_json::json!({ "permissions": permissions })
+}
+
+#[sqlx::test]
+async fn test_inherited_grants(pool: sqlx::PgPool) {
+    let fixture = setup(pool.clone()).await;
+    fixture
+        .store
+        .add_association(
+            &fixture.alice,
+            &AssociationLabel::HasGrant,
+            &fixture.schema,
+            grant(&["read"]),
+        )
+        .await
+        .unwrap();
+    let policy = GraphPolicy::new(Arc::new(pool));
+    let alice = Recipient::user("alice");
+
+    let table_by_name = ResourceIdent::table(ResourceName::new(["catalog", "schema", "table"]));
+    let decision = policy
+        .authorize(&table_by_name, &Permission::Read, &alice)
+        .await
+        .unwrap();
+    assert_eq!(decision, Decision::Allow);
+
+    let decision = policy
+        .authorize(&table_by_name, &Permission::Write, &alice)
+        .await
+        .unwrap();
+    assert_eq!(decision, Decision::Deny);
+
+    let decision = policy
+        .authorize(&table_by_name, &Permission::Read, &Recipient::user("bob"))
+        .await
+        .unwrap();
+    assert_eq!(decision, Decision::Deny);
+
+    let decision = policy
+        .authorize(&table_by_name, &Permission::Read, &Recipient::anonymous())
+        .await
+        .unwrap();
+    assert_eq!(decision, Decision::Deny);
+
+    let resources = [
+        ResourceIdent::catalog(fixture.catalog),
+        ResourceIdent::schema(fixture.schema),
+        ResourceIdent::table(fixture.table),
+        ResourceIdent::table(ResourceName::new(["catalog", "schema", "missing"])),
+        ResourceIdent::table(ResourceRef::Undefined),
+    ];
+    let decisions = policy
+        .authorize_many(&resources, &Permission::Read, &alice)
+        .await
+        .unwrap();
+    assert_eq!(
+        decisions,
+        vec![
+            Decision::Deny,
+            Decision::Allow,
+            Decision::Allow,
+            Decision::Deny,
+            Decision::Allow
+        ]
+    );
+
+    let decision = policy
+        .authorize(
+            &ResourceIdent::table(ResourceRef::Undefined),
+            &Permission::Create,
+            &alice,
+        )
+        .await
+        .unwrap();
+    assert_eq!(decision, Decision::Deny);
+}
+
+#[sqlx::test]
+async fn test_renamed_resources(pool: sqlx::PgPool) {
+    let fixture = setup(pool.clone()).await;
+    let other = create(
+        &fixture.store,
+        SchemaInfo {
+            name: "other".to_string(),
+            catalog_name: "catalog".to_string(),
+            ..Default::default()
+        },
+    )
+    .await;
+    fixture
+        .store
+        .add_association(
+            &fixture.alice,
+            &AssociationLabel::HasGrant,
+            &other,
+            grant(&["read"]),
+        )
+        .await
+        .unwrap();
+    let policy = GraphPolicy::new(Arc::new(pool));
+    let alice = Recipient::user("alice");
+    let table = ResourceIdent::table(fixture.table);
+
+    let decision = policy
+        .authorize(&table, &Permission::Read, &alice)
+        .await
+        .unwrap();
+    assert_eq!(decision, Decision::Deny);
+
+    // moving the table to another schema links it to the new parent.
+    fixture
+        .store
+        .update(&table, table_info("other", "table").into())
+        .await
+        .unwrap();
+    let decision = policy
+        .authorize(&table, &Permission::Read, &alice)
+        .await
+        .unwrap();
+    assert_eq!(decision, Decision::Allow);
+    let (parents, _) = ResourceStore::list_associations(
+        &fixture.store,
+        &table,
+        &AssociationLabel::ChildOf,
+        None,
+        None,
+        None,
+        None,
+    )
+    .await
+    .unwrap();
+    assert_eq!(parents, vec![ResourceIdent::schema(other)]);
+}
+
+#[sqlx::test]
+async fn test_owner_and_manage(pool: sqlx::PgPool) {
+    let fixture = setup(pool.clone()).await;
+    let bob = fixture
+        .store
+        .get_object_by_name(&ObjectLabel::RecipientInfo, &["bob".to_string()])
+        .await
+        .unwrap()
+        .id;
+    fixture
+        .store
+        .add_association(
+            &fixture.alice,
+            &AssociationLabel::OwnerOf,
+            &fixture.catalog,
+            None,
+        )
+        .await
+        .unwrap();
+    fixture
+        .store
+        .add_association(
+            &bob,
+            &AssociationLabel::HasGrant,
+            &fixture.table,
+            grant(&["manage"]),
+        )
+        .await
+        .unwrap();
+    let policy = GraphPolicy::new(Arc::new(pool));
+
+    let resources = [
+        ResourceIdent::catalog(fixture.catalog),
+        ResourceIdent::table(fixture.table),
+    ];
+    let decisions = policy
+        .authorize_many(&resources, &Permission::Write, &Recipient::user("alice"))
+        .await
+        .unwrap();
+    assert_eq!(decisions, vec![Decision::Allow, Decision::Allow]);
+
+    let decisions = policy
+        .authorize_many(&resources, &Permission::Select, &Recipient::user("bob"))
+        .await
+        .unwrap();
+    assert_eq!(decisions, vec![Decision::Deny, Decision::Allow]);
+}
+
+#[sqlx::test]
+async fn test_visibility_filter(pool: sqlx::PgPool) {
+    let fixture = setup(pool.clone()).await;
+    add(&fixture.store, &ObjectLabel::CatalogInfo, &["hidden"]).await;
+    add(
+        &fixture.store,
+        &ObjectLabel::SchemaInfo,
+        &["hidden", "schema"],
+    )
+    .await;
+    fixture
+        .store
+        .add_association(
+            &fixture.alice,
+            &AssociationLabel::HasGrant,
+            &fixture.schema,
+            grant(&["read"]),
+        )
+        .await
+        .unwrap();
+    let policy = GraphPolicy::new(Arc::new(pool));
+
+    let filter = policy
+        .visibility_filter(
+            &ObjectLabel::SchemaInfo,
+            &Permission::Read,
+            &Recipient::user("alice"),
+        )
+        .await
+        .unwrap();
+    let (schemas, _) = fixture
+        .store
+        .list_objects(&ObjectLabel::SchemaInfo, &[], filter.as_ref(), None, None)
+        .await
+        .unwrap();
+    assert_eq!(
+        schemas.iter().map(|o| o.id).collect::<Vec<_>>(),
+        vec![fixture.schema]
+    );
+
+    // grants on the schema are inherited by the table, but not by the parent catalog.
+    let filter = policy
+        .visibility_filter(
+            &ObjectLabel::TableInfo,
+            &Permission::Read,
+            &Recipient::user("alice"),
+        )
+        .await
+        .unwrap();
+    let (tables, _) = fixture
+        .store
+        .list_objects(&ObjectLabel::TableInfo, &[], filter.as_ref(), None, None)
+        .await
+        .unwrap();
+    assert_eq!(tables.len(), 1);
+    let (catalogs, _) = fixture
+        .store
+        .list_objects(&ObjectLabel::CatalogInfo, &[], filter.as_ref(), None, None)
+        .await
+        .unwrap();
+    assert!(catalogs.is_empty());
+
+    let filter = policy
+        .visibility_filter(
+            &ObjectLabel::CatalogInfo,
+            &Permission::Read,
+            &Recipient::anonymous(),
+        )
+        .await
+        .unwrap();
+    let (catalogs, _) = fixture
+        .store
+        .list_objects(&ObjectLabel::CatalogInfo, &[], filter.as_ref(), None, None)
+        .await
+        .unwrap();
+    assert!(catalogs.is_empty());
+}
+
+#[sqlx::test]
+async fn test_rules_policy_listing(pool: sqlx::PgPool) {
+    let store = Arc::new(GraphStore::new(Arc::new(pool)));
+    for (catalog, schema) in [
+        ("catalog", "schema"),
+        ("catalog", "other"),
+        ("hidden", "schema"),
+    ] {
+        let schema = SchemaInfo {
+            name: schema.to_string(),
+            catalog_name: catalog.to_string(),
+            ..Default::default()
+        };
+        store.create(schema.into()).await.unwrap();
+    }
+    let rules = RuleSet::parse(
+        r#"
+rules:
+  - principals: ["*"]
+    resources: [{ label: schema_info, name: "catalog.schema" }, { prefix: hidden.schema }]
+    permissions: [read]
+  - effect: deny
+    principals: ["*"]
+    resources: [{ prefix: hidden }]
+    permissions: ["*"]
+"#,
+    )
+    .unwrap();
+    let policy = RulesPolicy::new(rules).with_store(store.clone());
+
+    // listed resources are referenced by id and resolved by the policy.
+    let (mut schemas, _) = store
+        .list(&ObjectLabel::SchemaInfo, None, None, None, None)
+        .await
+        .unwrap();
+    assert_eq!(schemas.len(), 3);
+    process_resources(
+        &policy,
+        &Recipient::anonymous(),
+        &Permission::Read,
+        &mut schemas,
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        schemas
+            .iter()
+            .map(|s| s.resource_name().to_string())
+            .collect::<Vec<_>>(),
+        vec!["catalog.schema"]
+    );
+}
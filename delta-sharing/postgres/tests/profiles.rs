@@ -0,0 +1,108 @@
+#![cfg(feature = "integration-pg")]
+
+use std::sync::Arc;
+
+use delta_sharing_postgres::PostgresProfileManager;
+use delta_sharing_profiles::{DefaultClaims, ProfileClaims, ProfileManager, TokenManager};
+
+fn claims(sub: &str, issued_at: i64, shares: &[&str]) -> DefaultClaims {
+    DefaultClaims {
+        sub: sub.to_string(),
+        exp: Some(u64::MAX / 2),
+        issued_at,
+        shares: shares.iter().map(|s| s.to_string()).collect(),
+        admin: None,
+    }
+}
+
+fn profile_manager(pool: Arc<sqlx::PgPool>) -> PostgresProfileManager<DefaultClaims> {
+    PostgresProfileManager::new(
+        pool,
+        "http://localhost:8080".to_string(),
+        1,
+        TokenManager::new_from_secret(b"secret", None),
+    )
+}
+
+#[sqlx::test]
+async fn test_profiles(pool: sqlx::PgPool) -> Result<(), Box<dyn std::error::Error + 'static>> {
+    let pool = Arc::new(pool);
+    let manager = profile_manager(pool.clone());
+
+    let claims_1 = claims("user@example.com", 1, &["share1"]);
+    let claims_2 = claims("user@example.com", 2, &["share1", "share2"]);
+    let claims_3 = claims("other@example.com", 3, &["share2"]);
+
+    let profile_1 = manager.issue_profile(&claims_1, None).await?;
+    let profile_2 = manager.issue_profile(&claims_2, None).await?;
+    let profile_3 = manager.issue_profile(&claims_3, None).await?;
+
+    let active = manager.list_active_profiles("user@example.com").await?;
+    assert_eq!(active.len(), 2);
+
+    // revocation is persisted and visible to other managers on the same database.
+    manager.revoke_profile(&claims_1.fingerprint()).await?;
+    assert!(manager
+        .validate_profile(&profile_1.bearer_token)
+        .await
+        .is_err());
+    let other = profile_manager(pool.clone());
+    assert!(other
+        .validate_profile(&profile_1.bearer_token)
+        .await
+        .is_err());
+    assert!(other
+        .validate_profile(&profile_2.bearer_token)
+        .await
+        .is_ok());
+
+    let active = manager.list_active_profiles("user@example.com").await?;
+    assert_eq!(active.len(), 1);
+    assert_eq!(active[0].fingerprint, claims_2.fingerprint());
+
+    // bulk revocation by share.
+    let revoked = manager.revoke_share("share2").await?;
+    assert_eq!(revoked.len(), 2);
+    assert!(manager
+        .validate_profile(&profile_2.bearer_token)
+        .await
+        .is_err());
+    assert!(manager
+        .validate_profile(&profile_3.bearer_token)
+        .await
+        .is_err());
+    assert!(manager
+        .list_active_profiles("user@example.com")
+        .await?
+        .is_empty());
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn test_revoke_unknown_profile(
+    pool: sqlx::PgPool,
+) -> Result<(), Box<dyn std::error::Error + 'static>> {
+    let pool = Arc::new(pool);
+    let issuer = delta_sharing_profiles::DeltaProfileManager::new(
+        "http://localhost:8080".to_string(),
+        1,
+        TokenManager::new_from_secret(b"secret", None),
+    );
+    let claims = claims("user@example.com", 1, &["share1"]);
+    let profile = issuer.issue_profile(&claims, None).await?;
+
+    let manager = profile_manager(pool);
+    assert!(manager
+        .validate_profile(&profile.bearer_token)
+        .await
+        .is_ok());
+
+    manager.revoke_profile(&claims.fingerprint()).await?;
+    assert!(manager
+        .validate_profile(&profile.bearer_token)
+        .await
+        .is_err());
+
+    Ok(())
+}
@@ -0,0 +1,38 @@
+#![cfg(feature = "integration-pg")]
+
+use std::sync::Arc;
+
+use delta_sharing_common::memory::InMemoryResourceStore;
+use delta_sharing_common::testing::{resource_store_conformance, resource_store_equivalence};
+use delta_sharing_postgres::GraphStore;
+use sqlx::PgPool;
+
+/// Remove all objects, so every check starts from an empty store.
+async fn empty_store(pool: &Arc<PgPool>) -> GraphStore {
+    sqlx::query("DELETE FROM associations")
+        .execute(&**pool)
+        .await
+        .unwrap();
+    sqlx::query("DELETE FROM objects")
+        .execute(&**pool)
+        .await
+        .unwrap();
+    GraphStore::new(pool.clone())
+}
+
+#[sqlx::test]
+async fn test_conformance(pool: PgPool) {
+    let pool = Arc::new(pool);
+    resource_store_conformance(|| empty_store(&pool)).await;
+}
+
+#[sqlx::test]
+async fn test_equivalence(pool: PgPool) {
+    let pool = Arc::new(pool);
+    resource_store_equivalence(
+        || async { InMemoryResourceStore::new() },
+        || empty_store(&pool),
+        32,
+    )
+    .await;
+}
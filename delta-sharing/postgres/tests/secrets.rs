@@ -0,0 +1,192 @@
+#![cfg(feature = "integration-pg")]
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use bytes::Bytes;
+use delta_sharing_common::{Error, SecretManager};
+use delta_sharing_postgres::PostgresSecretManager;
+use uuid::Uuid;
+
+#[sqlx::test]
+async fn test_secrets(pool: sqlx::PgPool) -> Result<(), Box<dyn std::error::Error + 'static>> {
+    let manager = PostgresSecretManager::new(Arc::new(pool));
+
+    let v1 = manager
+        .create_secret("secret", Bytes::from_static(b"v1"))
+        .await?;
+    assert!(matches!(
+        manager
+            .create_secret("secret", Bytes::from_static(b"other"))
+            .await,
+        Err(Error::AlreadyExists)
+    ));
+    let v2 = manager
+        .update_secret("secret", Bytes::from_static(b"v2"))
+        .await?;
+    assert!(v2 > v1);
+
+    assert_eq!(
+        manager.get_secret("secret").await?,
+        (v2, Bytes::from_static(b"v2"))
+    );
+    assert_eq!(
+        manager.get_secret_version("secret", v1).await?,
+        Bytes::from_static(b"v1")
+    );
+
+    // deleting hides all versions, but the secret can be created again.
+    manager.delete_secret("secret").await?;
+    assert!(matches!(
+        manager.get_secret("secret").await,
+        Err(Error::NotFound)
+    ));
+    assert!(manager.get_secret_version("secret", v1).await.is_err());
+    assert!(manager.delete_secret("secret").await.is_err());
+    assert!(manager
+        .update_secret("secret", Bytes::from_static(b"v3"))
+        .await
+        .is_err());
+    let v3 = manager
+        .create_secret("secret", Bytes::from_static(b"v3"))
+        .await?;
+    assert_eq!(
+        manager.get_secret("secret").await?,
+        (v3, Bytes::from_static(b"v3"))
+    );
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn test_concurrent_create(
+    pool: sqlx::PgPool,
+) -> Result<(), Box<dyn std::error::Error + 'static>> {
+    let manager = PostgresSecretManager::new(Arc::new(pool));
+
+    let mut tasks = tokio::task::JoinSet::new();
+    for i in 0..8 {
+        let manager = manager.clone();
+        tasks.spawn(async move {
+            manager
+                .create_secret("secret", Bytes::from(format!("v{i}")))
+                .await
+        });
+    }
+    let results = tasks.join_all().await;
+    assert_eq!(results.iter().filter(|r| r.is_ok()).count(), 1);
+    assert!(results
+        .iter()
+        .filter_map(|r| r.as_ref().err())
+        .all(|e| matches!(e, Error::AlreadyExists)));
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn test_put_secret_version(
+    pool: sqlx::PgPool,
+) -> Result<(), Box<dyn std::error::Error + 'static>> {
+    let manager = PostgresSecretManager::new(Arc::new(pool));
+
+    let v1 = Uuid::now_v7();
+    manager
+        .put_secret_version("secret", None, v1, Bytes::from_static(b"v1"))
+        .await?;
+    assert!(matches!(
+        manager
+            .put_secret_version("secret", None, Uuid::now_v7(), Bytes::from_static(b"other"))
+            .await,
+        Err(Error::AlreadyExists)
+    ));
+    let v2 = Uuid::now_v7();
+    manager
+        .put_secret_version("secret", Some(v1), v2, Bytes::from_static(b"v2"))
+        .await?;
+    assert_eq!(
+        manager.get_secret("secret").await?,
+        (v2, Bytes::from_static(b"v2"))
+    );
+
+    // writes based on a superseded version conflict, as do versions sorting before the latest.
+    assert!(matches!(
+        manager
+            .put_secret_version(
+                "secret",
+                Some(v1),
+                Uuid::now_v7(),
+                Bytes::from_static(b"v3")
+            )
+            .await,
+        Err(Error::Conflict)
+    ));
+    assert!(matches!(
+        manager
+            .put_secret_version("secret", Some(v2), v1, Bytes::from_static(b"v3"))
+            .await,
+        Err(Error::Conflict)
+    ));
+    assert!(matches!(
+        manager
+            .put_secret_version(
+                "unknown",
+                Some(v2),
+                Uuid::now_v7(),
+                Bytes::from_static(b"v3")
+            )
+            .await,
+        Err(Error::NotFound)
+    ));
+
+    // deleted secrets can be created again.
+    manager.delete_secret("secret").await?;
+    let v3 = Uuid::now_v7();
+    manager
+        .put_secret_version("secret", None, v3, Bytes::from_static(b"v3"))
+        .await?;
+    assert_eq!(
+        manager.get_secret("secret").await?,
+        (v3, Bytes::from_static(b"v3"))
+    );
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn test_purge_secrets(
+    pool: sqlx::PgPool,
+) -> Result<(), Box<dyn std::error::Error + 'static>> {
+    let pool = Arc::new(pool);
+    let manager = PostgresSecretManager::new(pool.clone());
+
+    let v1 = manager
+        .create_secret("secret", Bytes::from_static(b"v1"))
+        .await?;
+    let v2 = manager
+        .update_secret("secret", Bytes::from_static(b"v2"))
+        .await?;
+    manager
+        .create_secret("deleted", Bytes::from_static(b"value"))
+        .await?;
+    manager.delete_secret("deleted").await?;
+
+    // nothing expired within the default retention.
+    assert_eq!(manager.purge_expired().await?, 0);
+    assert!(manager.get_secret_version("secret", v1).await.is_ok());
+
+    let manager = manager.with_retention(Duration::ZERO);
+    tokio::time::sleep(Duration::from_millis(10)).await;
+    assert_eq!(manager.purge_expired().await?, 3);
+    assert!(manager.get_secret_version("secret", v1).await.is_err());
+    assert_eq!(
+        manager.get_secret("secret").await?,
+        (v2, Bytes::from_static(b"v2"))
+    );
+
+    let remaining: i64 = sqlx::query_scalar("SELECT count(*) FROM secrets")
+        .fetch_one(&*pool)
+        .await?;
+    assert_eq!(remaining, 1);
+
+    Ok(())
+}
@@ -12,7 +12,8 @@ pub type DeltaRecipient = DefaultRecipient<DefaultClaims>;
 pub type DeltaProfileManager = InMemoryProfileManager<DefaultClaims>;
 
 /// Default recipient for delta sharing.
-#[derive(Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(bound(deserialize = ""))]
 pub enum DefaultRecipient<C: ProfileClaims> {
     Anonymous,
     Profile(C),
@@ -24,6 +25,20 @@ impl<C: ProfileClaims> From<DefaultRecipient<C>> for Recipient {
     }
 }
 
+impl<C: ProfileClaims> TryFrom<&Recipient> for DefaultRecipient<C> {
+    type Error = Error;
+
+    fn try_from(recipient: &Recipient) -> Result<Self> {
+        match recipient {
+            Recipient::Anonymous => Ok(DefaultRecipient::Anonymous),
+            Recipient::Custom(data) => Ok(serde_json::from_slice(data)?),
+            Recipient::User(_) => Err(Error::invalid_argument(
+                "user recipients cannot be converted to a profile recipient",
+            )),
+        }
+    }
+}
+
 impl<C: ProfileClaims> DefaultRecipient<C> {
     /// Get the profile fingerprint for the recipient.
     pub fn fingerprint(&self) -> Option<String> {
@@ -63,6 +78,18 @@ impl ProfileClaims for DefaultClaims {
         let digest = digest::digest(&digest::SHA256, seed.as_bytes());
         hex::encode(digest.as_ref())
     }
+
+    fn subject(&self) -> Option<String> {
+        Some(self.sub.clone())
+    }
+
+    fn shares(&self) -> Vec<String> {
+        self.shares.clone()
+    }
+
+    fn is_admin(&self) -> bool {
+        self.admin.unwrap_or(false)
+    }
 }
 
 pub struct InMemoryProfileManager<T: ProfileClaims + Send> {
@@ -0,0 +1,427 @@
+//! Keys used to sign and verify profile tokens.
+//!
+//! Tokens are signed with a single active [`SigningKey`], while a [`KeyRing`] may hold
+//! any number of additional [`VerificationKey`]s. Rotating the signing key keeps the
+//! previous key around for verification, so profiles issued before the rotation remain
+//! valid until the old key is explicitly retired.
+use std::path::Path;
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use delta_sharing_common::{Error, Result, SecretManager};
+use jsonwebtoken::jwk::{
+    AlgorithmParameters, CommonParameters, EllipticCurve, Jwk, JwkSet, KeyAlgorithm,
+    OctetKeyPairParameters, OctetKeyPairType, PublicKeyUse, RSAKeyParameters, RSAKeyType,
+};
+use jsonwebtoken::{Algorithm, DecodingKey, EncodingKey};
+use ring::rsa::PublicKeyComponents;
+use ring::signature::{Ed25519KeyPair, KeyPair, RsaKeyPair};
+use uuid::Uuid;
+
+/// Key used to verify the signature of tokens.
+#[derive(Clone)]
+pub struct VerificationKey {
+    kid: Option<String>,
+    algorithm: Algorithm,
+    decoding: DecodingKey,
+    jwk: Option<Jwk>,
+}
+
+impl VerificationKey {
+    /// Create a new [`VerificationKey`] from a public JSON Web Key.
+    ///
+    /// The key must specify its algorithm and should carry a key id.
+    pub fn from_jwk(jwk: Jwk) -> Result<Self> {
+        let algorithm = jwk
+            .common
+            .key_algorithm
+            .ok_or_else(|| Error::invalid_argument("JWK is missing the 'alg' parameter"))?;
+        let algorithm = algorithm.to_string().parse().map_err(jwt_err)?;
+        let decoding = DecodingKey::from_jwk(&jwk).map_err(jwt_err)?;
+        Ok(Self {
+            kid: jwk.common.key_id.clone(),
+            algorithm,
+            decoding,
+            jwk: Some(jwk),
+        })
+    }
+
+    /// Identifier of the key.
+    pub fn kid(&self) -> Option<&str> {
+        self.kid.as_deref()
+    }
+
+    /// Algorithm tokens verified with this key must be signed with.
+    pub fn algorithm(&self) -> Algorithm {
+        self.algorithm
+    }
+
+    /// Public JSON Web Key, if this is an asymmetric key.
+    pub fn jwk(&self) -> Option<&Jwk> {
+        self.jwk.as_ref()
+    }
+
+    pub(crate) fn decoding(&self) -> &DecodingKey {
+        &self.decoding
+    }
+}
+
+/// Key used to sign tokens.
+#[derive(Clone)]
+pub struct SigningKey {
+    encoding: EncodingKey,
+    verification: VerificationKey,
+}
+
+impl SigningKey {
+    /// Create a new HMAC (HS256) signing key from a shared secret.
+    pub fn from_secret(kid: Option<String>, secret: &[u8]) -> Self {
+        let mut key = Self::from_hmac_keys(
+            EncodingKey::from_secret(secret),
+            DecodingKey::from_secret(secret),
+        );
+        key.verification.kid = kid;
+        key
+    }
+
+    pub(crate) fn from_hmac_keys(encoding: EncodingKey, decoding: DecodingKey) -> Self {
+        Self {
+            encoding,
+            verification: VerificationKey {
+                kid: None,
+                algorithm: Algorithm::HS256,
+                decoding,
+                jwk: None,
+            },
+        }
+    }
+
+    /// Create a new signing key from a PEM encoded private key.
+    ///
+    /// RSA keys (PKCS#1 or PKCS#8) are used with RS256, Ed25519 keys (PKCS#8) with EdDSA.
+    pub fn from_pem(kid: impl Into<String>, pem: &[u8]) -> Result<Self> {
+        let kid = kid.into();
+        let parsed = pem::parse(pem).map_err(|e| Error::invalid_argument(e.to_string()))?;
+        let der = parsed.contents();
+
+        let rsa = match parsed.tag() {
+            "RSA PRIVATE KEY" => Some(RsaKeyPair::from_der(der)),
+            "PRIVATE KEY" => Some(RsaKeyPair::from_pkcs8(der)).filter(|key| key.is_ok()),
+            tag => {
+                return Err(Error::invalid_argument(format!(
+                    "unsupported PEM section: {tag}"
+                )))
+            }
+        };
+        if let Some(rsa) = rsa {
+            let pair = rsa.map_err(|e| Error::invalid_argument(e.to_string()))?;
+            let components = PublicKeyComponents::<Vec<u8>>::from(pair.public());
+            let jwk = public_jwk(
+                &kid,
+                KeyAlgorithm::RS256,
+                AlgorithmParameters::RSA(RSAKeyParameters {
+                    key_type: RSAKeyType::RSA,
+                    n: URL_SAFE_NO_PAD.encode(&components.n),
+                    e: URL_SAFE_NO_PAD.encode(&components.e),
+                }),
+            );
+            return Ok(Self {
+                encoding: EncodingKey::from_rsa_pem(pem).map_err(jwt_err)?,
+                verification: VerificationKey::from_jwk(jwk)?,
+            });
+        }
+
+        let pair = Ed25519KeyPair::from_pkcs8_maybe_unchecked(der)
+            .map_err(|e| Error::invalid_argument(e.to_string()))?;
+        let jwk = public_jwk(
+            &kid,
+            KeyAlgorithm::EdDSA,
+            AlgorithmParameters::OctetKeyPair(OctetKeyPairParameters {
+                key_type: OctetKeyPairType::OctetKeyPair,
+                curve: EllipticCurve::Ed25519,
+                x: URL_SAFE_NO_PAD.encode(pair.public_key().as_ref()),
+            }),
+        );
+        Ok(Self {
+            encoding: EncodingKey::from_ed_der(der),
+            verification: VerificationKey::from_jwk(jwk)?,
+        })
+    }
+
+    /// Create a new signing key from a PEM file.
+    pub fn from_pem_file(kid: impl Into<String>, path: impl AsRef<Path>) -> Result<Self> {
+        let pem = std::fs::read(path.as_ref()).map_err(|e| {
+            Error::generic(format!(
+                "failed to read key from {}: {}",
+                path.as_ref().display(),
+                e
+            ))
+        })?;
+        Self::from_pem(kid, &pem)
+    }
+
+    /// Load a signing key stored as a PEM encoded secret.
+    ///
+    /// The secret version is used as key id, so every version of the secret
+    /// yields a distinct key.
+    pub async fn from_secret_manager(
+        secrets: &dyn SecretManager,
+        secret_name: &str,
+        version: Option<Uuid>,
+    ) -> Result<Self> {
+        let (version, pem) = match version {
+            Some(version) => (
+                version,
+                secrets.get_secret_version(secret_name, version).await?,
+            ),
+            None => secrets.get_secret(secret_name).await?,
+        };
+        Self::from_pem(version.hyphenated().to_string(), &pem)
+    }
+
+    /// Identifier of the key.
+    pub fn kid(&self) -> Option<&str> {
+        self.verification.kid()
+    }
+
+    /// Algorithm used to sign tokens.
+    pub fn algorithm(&self) -> Algorithm {
+        self.verification.algorithm
+    }
+
+    /// Key used to verify tokens signed with this key.
+    pub fn verification_key(&self) -> &VerificationKey {
+        &self.verification
+    }
+
+    pub(crate) fn encoding(&self) -> &EncodingKey {
+        &self.encoding
+    }
+}
+
+/// Collection of keys used to sign and verify tokens.
+#[derive(Clone)]
+pub struct KeyRing {
+    signing: SigningKey,
+    verification: Vec<VerificationKey>,
+}
+
+impl KeyRing {
+    /// Create a new [`KeyRing`] with the given active signing key.
+    pub fn new(signing: SigningKey) -> Self {
+        Self {
+            verification: vec![signing.verification.clone()],
+            signing,
+        }
+    }
+
+    /// Add a key that is only used to verify tokens.
+    pub fn with_verification_key(mut self, key: VerificationKey) -> Self {
+        self.add_verification_key(key);
+        self
+    }
+
+    /// Add a key that is only used to verify tokens.
+    ///
+    /// A key with the same key id that is already in the ring is replaced.
+    pub fn add_verification_key(&mut self, key: VerificationKey) {
+        self.verification.retain(|k| k.kid != key.kid);
+        self.verification.push(key);
+    }
+
+    /// Replace the active signing key.
+    ///
+    /// The previous signing key is kept to verify tokens issued before the rotation.
+    pub fn rotate(&mut self, signing: SigningKey) {
+        self.add_verification_key(signing.verification.clone());
+        self.signing = signing;
+    }
+
+    /// Remove a verification key.
+    ///
+    /// Tokens signed with the retired key are no longer accepted. The active
+    /// signing key cannot be retired.
+    pub fn retire(&mut self, kid: &str) -> Result<()> {
+        if self.signing.kid() == Some(kid) {
+            return Err(Error::invalid_argument(
+                "the active signing key cannot be retired",
+            ));
+        }
+        self.verification.retain(|k| k.kid() != Some(kid));
+        Ok(())
+    }
+
+    /// The active signing key.
+    pub fn signing_key(&self) -> &SigningKey {
+        &self.signing
+    }
+
+    /// Find the key to verify a token with the given key id.
+    ///
+    /// Tokens without a key id are verified with the key that has no key id, if any.
+    pub fn verification_key(&self, kid: Option<&str>) -> Option<&VerificationKey> {
+        self.verification.iter().find(|k| k.kid() == kid)
+    }
+
+    /// Public keys of the ring as JSON Web Key Set.
+    ///
+    /// Symmetric keys are never included.
+    pub fn jwks(&self) -> JwkSet {
+        JwkSet {
+            keys: self
+                .verification
+                .iter()
+                .filter_map(|k| k.jwk.clone())
+                .collect(),
+        }
+    }
+}
+
+fn public_jwk(kid: &str, algorithm: KeyAlgorithm, parameters: AlgorithmParameters) -> Jwk {
+    Jwk {
+        common: CommonParameters {
+            public_key_use: Some(PublicKeyUse::Signature),
+            key_algorithm: Some(algorithm),
+            key_id: Some(kid.to_string()),
+            ..Default::default()
+        },
+        algorithm: parameters,
+    }
+}
+
+fn jwt_err(e: jsonwebtoken::errors::Error) -> Error {
+    Error::invalid_argument(e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::Bytes;
+    use delta_sharing_common::memory::InMemoryResourceStore;
+
+    use super::*;
+    use crate::{DefaultClaims, TokenManager};
+
+    const RSA_PEM: &[u8] = include_bytes!("../tests/data/rsa-private.pem");
+    const ED25519_PEM: &[u8] = include_bytes!("../tests/data/ed25519-private.pem");
+
+    fn claims() -> DefaultClaims {
+        DefaultClaims {
+            sub: "user@example.com".to_string(),
+            exp: Some(chrono::Utc::now().timestamp() as u64 + 3600),
+            issued_at: chrono::Utc::now().timestamp(),
+            shares: vec!["share".to_string()],
+            admin: None,
+        }
+    }
+
+    #[test]
+    fn test_asymmetric_keys() {
+        for (kid, pem, algorithm) in [
+            ("rsa", RSA_PEM, Algorithm::RS256),
+            ("ed", ED25519_PEM, Algorithm::EdDSA),
+        ] {
+            let key = SigningKey::from_pem(kid, pem).unwrap();
+            assert_eq!(key.algorithm(), algorithm);
+            assert_eq!(key.kid(), Some(kid));
+
+            let manager = TokenManager::new_from_key_ring(KeyRing::new(key), None);
+            let token = manager.encode(&claims()).unwrap();
+            let header = jsonwebtoken::decode_header(&token).unwrap();
+            assert_eq!(header.kid.as_deref(), Some(kid));
+            assert_eq!(header.alg, algorithm);
+            assert_eq!(
+                manager.decode::<DefaultClaims>(&token).unwrap().sub,
+                claims().sub
+            );
+
+            let jwks = manager.jwks().unwrap();
+            assert_eq!(jwks.keys.len(), 1);
+            assert!(jwks.find(kid).is_some());
+        }
+    }
+
+    #[test]
+    fn test_key_rotation() {
+        let manager = TokenManager::new_from_key_ring(
+            KeyRing::new(SigningKey::from_pem("rsa", RSA_PEM).unwrap()),
+            None,
+        );
+        let old_token = manager.encode(&claims()).unwrap();
+
+        manager
+            .rotate(SigningKey::from_pem("ed", ED25519_PEM).unwrap())
+            .unwrap();
+        let new_token = manager.encode(&claims()).unwrap();
+        assert_eq!(
+            jsonwebtoken::decode_header(&new_token)
+                .unwrap()
+                .kid
+                .as_deref(),
+            Some("ed")
+        );
+        assert!(manager.decode::<DefaultClaims>(&old_token).is_ok());
+        assert!(manager.decode::<DefaultClaims>(&new_token).is_ok());
+        assert_eq!(manager.jwks().unwrap().keys.len(), 2);
+
+        assert!(manager.retire("ed").is_err());
+        manager.retire("rsa").unwrap();
+        assert!(matches!(
+            manager.decode::<DefaultClaims>(&old_token),
+            Err(Error::Unauthenticated)
+        ));
+        assert!(manager.decode::<DefaultClaims>(&new_token).is_ok());
+    }
+
+    #[test]
+    fn test_verification_only_key() {
+        let signer = TokenManager::new_from_key_ring(
+            KeyRing::new(SigningKey::from_pem("rsa", RSA_PEM).unwrap()),
+            None,
+        );
+        let token = signer.encode(&claims()).unwrap();
+        let jwk = signer.jwks().unwrap().keys.pop().unwrap();
+
+        let verifier = TokenManager::new_from_key_ring(
+            KeyRing::new(SigningKey::from_secret(Some("hmac".to_string()), b"secret"))
+                .with_verification_key(VerificationKey::from_jwk(jwk).unwrap()),
+            None,
+        );
+        assert!(verifier.decode::<DefaultClaims>(&token).is_ok());
+        // symmetric keys are never published.
+        assert_eq!(verifier.jwks().unwrap().keys.len(), 1);
+    }
+
+    #[test]
+    fn test_hmac_keys() {
+        let manager = TokenManager::new_from_secret(b"secret", None);
+        let token = manager.encode(&claims()).unwrap();
+        assert!(jsonwebtoken::decode_header(&token).unwrap().kid.is_none());
+        assert!(manager.decode::<DefaultClaims>(&token).is_ok());
+        assert!(manager.jwks().unwrap().keys.is_empty());
+
+        let other = TokenManager::new_from_secret(b"other", None);
+        assert!(matches!(
+            other.decode::<DefaultClaims>(&token),
+            Err(Error::Unauthenticated)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_secret_manager_keys() {
+        let secrets = InMemoryResourceStore::new();
+        let version = secrets
+            .create_secret("signing-key", Bytes::from_static(RSA_PEM))
+            .await
+            .unwrap();
+
+        let key = SigningKey::from_secret_manager(&secrets, "signing-key", None)
+            .await
+            .unwrap();
+        assert_eq!(key.kid(), Some(version.hyphenated().to_string().as_str()));
+
+        let key = SigningKey::from_secret_manager(&secrets, "signing-key", Some(version))
+            .await
+            .unwrap();
+        assert_eq!(key.algorithm(), Algorithm::RS256);
+    }
+}
@@ -4,9 +4,15 @@ use jsonwebtoken::Validation;
 use serde::{de::DeserializeOwned, Serialize};
 
 pub use in_memory::*;
+pub use keys::*;
+pub use policy::*;
 pub use tokens::*;
 
 mod in_memory;
+mod keys;
+mod policy;
+#[cfg(feature = "axum")]
+pub mod rest;
 mod tokens;
 
 /// Claims that are encoded in a profile.
@@ -14,6 +20,21 @@ pub trait ProfileClaims: Serialize + DeserializeOwned + Send + Sync {
     /// Get the profile fingerprint from the claims.
     fn fingerprint(&self) -> String;
 
+    /// Get the subject the profile was issued to, if the claims carry one.
+    fn subject(&self) -> Option<String> {
+        None
+    }
+
+    /// Get the names of the shares the profile grants access to.
+    fn shares(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// Whether the profile grants administrative access to the server.
+    fn is_admin(&self) -> bool {
+        false
+    }
+
     fn validation() -> Validation {
         Validation::default()
     }
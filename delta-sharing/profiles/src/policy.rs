@@ -0,0 +1,281 @@
+//! Stateless authorization based on the claims encoded in a profile.
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use delta_sharing_common::{
+    Decision, ObjectLabel, Permission, Policy, Recipient, ResourceExt, ResourceIdent, ResourceRef,
+    ResourceStore, Result,
+};
+
+use crate::{DefaultClaims, DefaultRecipient, ProfileClaims};
+
+/// [`Policy`] deriving decisions from the claims of the profile presented by a recipient.
+///
+/// Recipients are expected to be encoded as [`DefaultRecipient`], e.g. by an authenticator
+/// validating profile tokens issued by a [`TokenManager`](crate::TokenManager). Users and
+/// other recipients are denied.
+///
+/// - Admins are allowed all permissions on all resources.
+/// - [`Use`](Permission::Use) and [`Read`](Permission::Read) are allowed on shares listed in
+///   the claims, as well as on their schemas and tables.
+/// - Everything else is denied.
+///
+/// Sharing resources are matched by name. Listed resources are referenced by id, which
+/// are resolved to names via the store configured with [`with_store`](Self::with_store).
+/// Without a store, references by id are denied unless the recipient is an admin.
+/// Checks against undefined sharing resources (e.g. when listing shares) are allowed,
+/// listed resources are checked individually.
+pub struct ClaimsPolicy<C: ProfileClaims = DefaultClaims> {
+    store: Option<Arc<dyn ResourceStore>>,
+    _phantom: PhantomData<C>,
+}
+
+impl<C: ProfileClaims> ClaimsPolicy<C> {
+    pub fn new() -> Self {
+        Self {
+            store: None,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Resolve sharing resources referenced by id in the given store.
+    pub fn with_store(mut self, store: Arc<dyn ResourceStore>) -> Self {
+        self.store = Some(store);
+        self
+    }
+
+    /// Replace references by id to sharing resources with references by name.
+    async fn resolve_names(&self, resources: &[ResourceIdent]) -> Result<Vec<ResourceIdent>> {
+        let mut resolved = resources.to_vec();
+        let Some(store) = &self.store else {
+            return Ok(resolved);
+        };
+        let (positions, ids): (Vec<_>, Vec<_>) = resources
+            .iter()
+            .enumerate()
+            .filter(|(_, r)| {
+                is_sharing_resource(r) && matches!(r.reference(), ResourceRef::Uuid(_))
+            })
+            .map(|(i, r)| (i, r.clone()))
+            .unzip();
+        if ids.is_empty() {
+            return Ok(resolved);
+        }
+        for (position, (resource, _)) in positions.into_iter().zip(store.get_many(&ids).await?) {
+            resolved[position] = resource.resource_label().to_ident(resource.resource_name());
+        }
+        Ok(resolved)
+    }
+
+    fn decide(&self, resource: &ResourceIdent, permission: &Permission, claims: &C) -> Decision {
+        if claims.is_admin() {
+            return Decision::Allow;
+        }
+        if !matches!(permission, Permission::Use | Permission::Read) {
+            return Decision::Deny;
+        }
+        if !is_sharing_resource(resource) {
+            return Decision::Deny;
+        }
+        let share = match resource.reference() {
+            ResourceRef::Undefined => return Decision::Allow,
+            ResourceRef::Uuid(_) => return Decision::Deny,
+            ResourceRef::Name(name) => match name.path().first() {
+                Some(share) => share,
+                None => return Decision::Deny,
+            },
+        };
+        if claims
+            .shares()
+            .iter()
+            .any(|s| s.eq_ignore_ascii_case(share))
+        {
+            Decision::Allow
+        } else {
+            Decision::Deny
+        }
+    }
+}
+
+fn is_sharing_resource(resource: &ResourceIdent) -> bool {
+    matches!(
+        resource.label(),
+        ObjectLabel::ShareInfo | ObjectLabel::SharingSchemaInfo | ObjectLabel::SharingTable
+    )
+}
+
+impl<C: ProfileClaims> Default for ClaimsPolicy<C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait::async_trait]
+impl<C: ProfileClaims + 'static> Policy for ClaimsPolicy<C> {
+    async fn authorize(
+        &self,
+        resource: &ResourceIdent,
+        permission: &Permission,
+        recipient: &Recipient,
+    ) -> Result<Decision> {
+        Ok(self
+            .authorize_many(std::slice::from_ref(resource), permission, recipient)
+            .await?
+            .pop()
+            .unwrap_or(Decision::Deny))
+    }
+
+    async fn authorize_many(
+        &self,
+        resources: &[ResourceIdent],
+        permission: &Permission,
+        recipient: &Recipient,
+    ) -> Result<Vec<Decision>> {
+        let claims = match DefaultRecipient::<C>::try_from(recipient) {
+            Ok(DefaultRecipient::Profile(claims)) => claims,
+            Ok(DefaultRecipient::Anonymous) | Err(_) => {
+                return Ok(vec![Decision::Deny; resources.len()])
+            }
+        };
+        if claims.is_admin() {
+            return Ok(vec![Decision::Allow; resources.len()]);
+        }
+        Ok(self
+            .resolve_names(resources)
+            .await?
+            .iter()
+            .map(|resource| self.decide(resource, permission, &claims))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use delta_sharing_common::api::sharing::SharingDiscoveryHandler;
+    use delta_sharing_common::api::RequestContext;
+    use delta_sharing_common::memory::InMemoryResourceStore;
+    use delta_sharing_common::models::sharing::v1::ListSharesRequest;
+    use delta_sharing_common::models::ShareInfo;
+    use delta_sharing_common::{ProvidesPolicy, ProvidesResourceStore, ResourceName};
+
+    use super::*;
+
+    struct Handler {
+        store: Arc<InMemoryResourceStore>,
+        policy: Arc<dyn Policy>,
+    }
+
+    impl ProvidesResourceStore for Handler {
+        fn store(&self) -> &dyn ResourceStore {
+            self.store.as_ref()
+        }
+    }
+
+    impl ProvidesPolicy for Handler {
+        fn policy(&self) -> &Arc<dyn Policy> {
+            &self.policy
+        }
+    }
+
+    fn recipient(shares: &[&str], admin: bool) -> Recipient {
+        DefaultRecipient::Profile(DefaultClaims {
+            sub: "alice".to_string(),
+            exp: None,
+            issued_at: 0,
+            shares: shares.iter().map(|s| s.to_string()).collect(),
+            admin: admin.then_some(true),
+        })
+        .into()
+    }
+
+    #[tokio::test]
+    async fn test_claims_policy() {
+        let policy = ClaimsPolicy::<DefaultClaims>::new();
+        let recipient = recipient(&["share"], false);
+
+        let resources = [
+            ResourceIdent::share(ResourceName::new(["share"])),
+            ResourceIdent::sharing_schema(ResourceName::new(["Share", "schema"])),
+            ResourceIdent::sharing_table(ResourceName::new(["share", "schema", "table"])),
+            ResourceIdent::sharing_table(ResourceName::new(["other", "schema", "table"])),
+            ResourceIdent::share(ResourceRef::Undefined),
+            ResourceIdent::catalog(ResourceName::new(["share"])),
+        ];
+        let decisions = policy
+            .authorize_many(&resources, &Permission::Read, &recipient)
+            .await
+            .unwrap();
+        assert_eq!(
+            decisions,
+            vec![
+                Decision::Allow,
+                Decision::Allow,
+                Decision::Allow,
+                Decision::Deny,
+                Decision::Allow,
+                Decision::Deny,
+            ]
+        );
+
+        let decision = policy
+            .authorize(&resources[0], &Permission::Manage, &recipient)
+            .await
+            .unwrap();
+        assert_eq!(decision, Decision::Deny);
+
+        for recipient in [Recipient::anonymous(), Recipient::user("alice")] {
+            let decision = policy
+                .authorize(&resources[0], &Permission::Read, &recipient)
+                .await
+                .unwrap();
+            assert_eq!(decision, Decision::Deny);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_claims_policy_admin() {
+        let policy = ClaimsPolicy::<DefaultClaims>::new();
+        let admin = recipient(&[], true);
+
+        let decision = policy
+            .authorize(
+                &ResourceIdent::catalog(ResourceRef::Undefined),
+                &Permission::Create,
+                &admin,
+            )
+            .await
+            .unwrap();
+        assert_eq!(decision, Decision::Allow);
+    }
+
+    #[tokio::test]
+    async fn test_claims_policy_list_shares() {
+        let store = Arc::new(InMemoryResourceStore::new());
+        for name in ["share", "other", "third"] {
+            let share = ShareInfo {
+                name: name.to_string(),
+                ..Default::default()
+            };
+            store.create(share.into()).await.unwrap();
+        }
+        let handler = Handler {
+            policy: Arc::new(ClaimsPolicy::<DefaultClaims>::new().with_store(store.clone())),
+            store,
+        };
+
+        let context = RequestContext {
+            recipient: recipient(&["share", "third"], false),
+        };
+        let response = handler
+            .list_shares(ListSharesRequest::default(), context)
+            .await
+            .unwrap();
+        let mut names = response
+            .items
+            .into_iter()
+            .map(|share| share.name)
+            .collect::<Vec<_>>();
+        names.sort();
+        assert_eq!(names, vec!["share", "third"]);
+    }
+}
@@ -0,0 +1,54 @@
+//! REST endpoints exposing the public keys used to sign recipient tokens.
+use std::sync::Arc;
+
+use axum::extract::State;
+use axum::routing::{get, Router};
+use axum::Json;
+use jsonwebtoken::jwk::JwkSet;
+
+use crate::TokenManager;
+
+/// Path at which the key set is served.
+pub const JWKS_PATH: &str = "/.well-known/jwks.json";
+
+/// Create a new [Router] serving the public keys of the token manager as JWKS.
+///
+/// Other services can use the key set to verify recipient tokens.
+pub fn get_jwks_router(token_manager: Arc<TokenManager>) -> Router {
+    Router::new()
+        .route(JWKS_PATH, get(get_jwks))
+        .with_state(token_manager)
+}
+
+async fn get_jwks(
+    State(token_manager): State<Arc<TokenManager>>,
+) -> delta_sharing_common::Result<Json<JwkSet>> {
+    Ok(Json(token_manager.jwks()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::Body;
+    use axum::http::{Request, StatusCode};
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    use super::*;
+    use crate::{KeyRing, SigningKey};
+
+    #[tokio::test]
+    async fn test_jwks_router() {
+        let key = SigningKey::from_pem("ed", include_bytes!("../tests/data/ed25519-private.pem"))
+            .unwrap();
+        let manager = Arc::new(TokenManager::new_from_key_ring(KeyRing::new(key), None));
+        let router = get_jwks_router(manager);
+
+        let request = Request::get(JWKS_PATH).body(Body::empty()).unwrap();
+        let response = router.oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let jwks: JwkSet = serde_json::from_slice(&body).unwrap();
+        assert!(jwks.find("ed").is_some());
+    }
+}
@@ -17,15 +17,19 @@
 //!
 //! [profiles]: https://github.com/delta-io/delta-sharing/blob/main/PROTOCOL.md#profile-file-format
 use std::collections::HashMap;
+use std::sync::RwLock;
 
 use chrono::{DateTime, SecondsFormat, Utc};
 use jsonwebtoken::errors::{Error as JwtError, ErrorKind as JwtErrorKind};
-use jsonwebtoken::{decode, encode, DecodingKey, EncodingKey, Header, Validation};
+use jsonwebtoken::jwk::JwkSet;
+use jsonwebtoken::{decode, decode_header, encode, DecodingKey, EncodingKey, Header, Validation};
 use serde::{de::DeserializeOwned, Serialize};
 use url::Url;
 
 use delta_sharing_common::{Error, Permission, ResourceIdent, Result};
 
+use crate::{KeyRing, SigningKey};
+
 /// A pair of encoding and decoding keys.
 #[derive(Clone)]
 pub struct Keys {
@@ -54,43 +58,87 @@ impl From<&[u8]> for Keys {
     }
 }
 
+impl From<Keys> for KeyRing {
+    fn from(keys: Keys) -> Self {
+        KeyRing::new(SigningKey::from_hmac_keys(keys.encoding, keys.decoding))
+    }
+}
+
 /// Token manager.
 ///
 /// This struct provides simple token management capabilities that can be used to
 /// build a profile management system for delta-sharing servers.
+///
+/// Tokens are signed with the active key of a [`KeyRing`] and carry its key id
+/// in the `kid` header, which is used to pick the verification key when decoding.
 pub struct TokenManager {
     validation: Validation,
-    keys: Keys,
+    keys: RwLock<KeyRing>,
 }
 
 impl TokenManager {
     /// Create a new instance of [`TokenManager`].
     pub fn new(keys: Keys, validation: Option<Validation>) -> Self {
-        Self {
-            validation: validation.unwrap_or_default(),
-            keys,
-        }
+        Self::new_from_key_ring(keys.into(), validation)
     }
 
     /// Create a new instance of [`TokenManager`] from a secret.
     pub fn new_from_secret(secret: &[u8], validation: Option<Validation>) -> Self {
+        Self::new(Keys::new_from_secret(secret), validation)
+    }
+
+    /// Create a new instance of [`TokenManager`] from a [`KeyRing`].
+    pub fn new_from_key_ring(keys: KeyRing, validation: Option<Validation>) -> Self {
         Self {
             validation: validation.unwrap_or_default(),
-            keys: Keys::new_from_secret(secret),
+            keys: RwLock::new(keys),
         }
     }
 
     /// Encode a set of claims into a token.
     pub fn encode<C: Serialize>(&self, claims: &C) -> Result<String> {
-        encode(&Header::default(), claims, &self.keys.encoding).map_err(to_err)
+        let keys = self.keys.read().map_err(|_| poisoned())?;
+        let signing = keys.signing_key();
+        let mut header = Header::new(signing.algorithm());
+        header.kid = signing.kid().map(ToString::to_string);
+        encode(&header, claims, signing.encoding()).map_err(to_err)
     }
 
     /// Decode a token into a set of claims.
     pub fn decode<C: DeserializeOwned>(&self, token: impl AsRef<str>) -> Result<C> {
-        decode::<C>(token.as_ref(), &self.keys.decoding, &self.validation)
+        let header = decode_header(token.as_ref()).map_err(to_err)?;
+        let keys = self.keys.read().map_err(|_| poisoned())?;
+        let key = keys
+            .verification_key(header.kid.as_deref())
+            .ok_or(Error::Unauthenticated)?;
+        let mut validation = self.validation.clone();
+        validation.algorithms = vec![key.algorithm()];
+        decode::<C>(token.as_ref(), key.decoding(), &validation)
             .map(|data| data.claims)
             .map_err(to_err)
     }
+
+    /// Replace the active signing key.
+    ///
+    /// Tokens signed with the previous key can still be decoded.
+    pub fn rotate(&self, signing: SigningKey) -> Result<()> {
+        self.keys.write().map_err(|_| poisoned())?.rotate(signing);
+        Ok(())
+    }
+
+    /// Stop accepting tokens signed with the given key.
+    pub fn retire(&self, kid: &str) -> Result<()> {
+        self.keys.write().map_err(|_| poisoned())?.retire(kid)
+    }
+
+    /// Public keys that can be used to verify tokens issued by this manager.
+    pub fn jwks(&self) -> Result<JwkSet> {
+        Ok(self.keys.read().map_err(|_| poisoned())?.jwks())
+    }
+}
+
+fn poisoned() -> Error {
+    Error::generic("key ring lock poisoned")
 }
 
 fn to_err(e: JwtError) -> Error {
@@ -100,7 +148,8 @@ fn to_err(e: JwtError) -> Error {
         | JwtErrorKind::InvalidSubject
         | JwtErrorKind::ExpiredSignature
         | JwtErrorKind::ImmatureSignature
-        | JwtErrorKind::InvalidSignature => Error::Unauthenticated,
+        | JwtErrorKind::InvalidSignature
+        | JwtErrorKind::InvalidAlgorithm => Error::Unauthenticated,
         _ => Error::Generic(e.to_string()),
     }
 }
@@ -4,6 +4,8 @@ pub use capabilities::*;
 // pub use grpc::run_server as run_grpc_server;
 #[cfg(feature = "rest")]
 pub use rest::run_server_full as run_rest_server_full;
+#[cfg(feature = "rest")]
+pub use rest::SHARING_PREFIX;
 
 mod capabilities;
 // #[cfg(feature = "grpc")]
@@ -1,17 +1,25 @@
 use delta_sharing_common::api::catalogs::CatalogHandler;
 use delta_sharing_common::api::credentials::CredentialsHandler;
 use delta_sharing_common::api::external_locations::ExternalLocationsHandler;
+use delta_sharing_common::api::permissions::PermissionsHandler;
 use delta_sharing_common::api::recipients::RecipientsHandler;
 use delta_sharing_common::api::schemas::SchemasHandler;
 use delta_sharing_common::api::shares::SharesHandler;
 use delta_sharing_common::api::sharing::{SharingDiscoveryHandler, SharingQueryHandler};
 use delta_sharing_common::api::tables::TablesHandler;
+use delta_sharing_common::api::temporary_credentials::TemporaryCredentialsHandler;
+use delta_sharing_common::audit::AuditLog;
 use delta_sharing_common::rest::{
     get_catalog_router, get_credentials_router, get_external_locations_router,
-    get_recipients_router, get_schemas_router, get_shares_router, get_tables_router,
-    AuthenticationLayer, Authenticator,
+    get_permissions_router, get_recipients_router, get_schemas_router, get_shares_router,
+    get_sharing_router, get_tables_router, get_temporary_credentials_router, AuditLayer,
+    AuthenticationLayer, Authenticator, IpAccessLayer,
 };
 use delta_sharing_common::{Error, Result};
+use delta_sharing_profiles::rest::get_jwks_router;
+use delta_sharing_profiles::TokenManager;
+use std::net::SocketAddr;
+use std::sync::Arc;
 use swagger_ui_dist::{ApiDefinition, OpenApiSource};
 use tokio::net::TcpListener;
 use tower_http::trace::{DefaultMakeSpan, DefaultOnRequest, DefaultOnResponse, TraceLayer};
@@ -20,11 +28,26 @@ use tracing::Level;
 
 use crate::shutdown::shutdown_signal;
 
+/// Path prefix of the Delta Sharing protocol API.
+///
+/// The protocol and the shares management API both define `/shares` routes, so the
+/// protocol is served under its own prefix.
+pub const SHARING_PREFIX: &str = "/delta-sharing";
+
+/// Run a REST server exposing all APIs of the handler.
+///
+/// The Delta Sharing protocol is served under [`SHARING_PREFIX`].
+///
+/// If a token manager is given, the public keys used to sign recipient tokens are served
+/// without authentication at [`JWKS_PATH`](delta_sharing_profiles::rest::JWKS_PATH).
 pub async fn run_server_full<T, A>(
     host: impl AsRef<str>,
     port: u16,
     handler: T,
     authenticator: A,
+    audit_log: Option<AuditLog>,
+    ip_access: Option<IpAccessLayer>,
+    token_manager: Option<Arc<TokenManager>>,
 ) -> Result<()>
 where
     T: CatalogHandler
@@ -36,6 +59,8 @@ where
         + TablesHandler
         + ExternalLocationsHandler
         + RecipientsHandler
+        + PermissionsHandler
+        + TemporaryCredentialsHandler
         + Clone,
     A: Authenticator + Clone,
 {
@@ -44,15 +69,58 @@ where
         api_definition: OpenApiSource::Inline(include_str!("../../openapi.yaml")),
         title: Some("Unity Catalog API"),
     };
+    let router = get_router(handler, authenticator, audit_log, ip_access, token_manager);
+    run(router, host, port, api_def).await
+}
+
+fn get_router<T, A>(
+    handler: T,
+    authenticator: A,
+    audit_log: Option<AuditLog>,
+    ip_access: Option<IpAccessLayer>,
+    token_manager: Option<Arc<TokenManager>>,
+) -> axum::Router
+where
+    T: CatalogHandler
+        + CredentialsHandler
+        + SharingDiscoveryHandler
+        + SharingQueryHandler
+        + SharesHandler
+        + SchemasHandler
+        + TablesHandler
+        + ExternalLocationsHandler
+        + RecipientsHandler
+        + PermissionsHandler
+        + TemporaryCredentialsHandler
+        + Clone,
+    A: Authenticator + Clone,
+{
     let router = get_catalog_router(handler.clone())
         .merge(get_schemas_router(handler.clone()))
         .merge(get_tables_router(handler.clone()))
         .merge(get_credentials_router(handler.clone()))
         .merge(get_external_locations_router(handler.clone()))
         .merge(get_recipients_router(handler.clone()))
-        .merge(get_shares_router(handler.clone()));
-    let server = router.layer(AuthenticationLayer::new(authenticator));
-    run(server, host, port, api_def).await
+        .merge(get_shares_router(handler.clone()))
+        .merge(get_permissions_router(handler.clone()))
+        .merge(get_temporary_credentials_router(handler.clone()))
+        .nest(SHARING_PREFIX, get_sharing_router(handler.clone()));
+    // blocked requests are audited, so access lists are checked inside the audit layer.
+    let router = match ip_access {
+        Some(layer) => router.layer(layer),
+        None => router,
+    };
+    // the audit layer reads the recipient, so it must run after authentication.
+    let router = match audit_log {
+        Some(log) => router.layer(AuditLayer::new(log)),
+        None => router,
+    };
+    let router = router.layer(AuthenticationLayer::new(authenticator));
+    // verifiers fetch the key set before they can authenticate anyone.
+    match token_manager {
+        Some(manager) => router.merge(get_jwks_router(manager)),
+        None => router,
+    }
 }
 
 async fn run<S: Into<String> + Clone>(
@@ -75,10 +143,98 @@ async fn run<S: Into<String> + Clone>(
         .await
         .map_err(|e| Error::Generic(e.to_string()))?;
     tracing::info!("Listning on: {}", listener.local_addr().unwrap());
-    axum::serve(listener, router)
+    // the peer address is needed to enforce IP access lists.
+    let service = router.into_make_service_with_connect_info::<SocketAddr>();
+    axum::serve(listener, service)
         .with_graceful_shutdown(shutdown_signal())
         .await
         .map_err(|e| Error::Generic(e.to_string()))?;
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use axum::body::Body;
+    use axum::extract::Request;
+    use axum::http::StatusCode;
+    use delta_sharing_common::memory::InMemoryResourceStore;
+    use delta_sharing_common::models::ShareInfo;
+    use delta_sharing_common::rest::AnonymousAuthenticator;
+    use delta_sharing_common::{
+        ConstantPolicy, KernelQueryHandler, Recipient, ResourceStore, ServerHandler,
+    };
+    use delta_sharing_profiles::rest::JWKS_PATH;
+    use delta_sharing_profiles::{KeyRing, SigningKey};
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    use super::*;
+
+    #[derive(Clone)]
+    struct RejectingAuthenticator;
+
+    impl Authenticator for RejectingAuthenticator {
+        fn authenticate(&self, _: &Request) -> Result<Recipient> {
+            Err(Error::Unauthenticated)
+        }
+    }
+
+    fn handler(store: Arc<InMemoryResourceStore>) -> ServerHandler {
+        let policy = Arc::new(ConstantPolicy::default());
+        ServerHandler {
+            query: Arc::new(
+                KernelQueryHandler::new_multi_thread(store.clone(), store.clone(), policy.clone())
+                    .with_restrictions(store.clone()),
+            ),
+            secrets: store.clone(),
+            store,
+            policy,
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_jwks_served_without_authentication() {
+        let handler = handler(Arc::new(InMemoryResourceStore::new()));
+        let key = SigningKey::from_pem(
+            "ed",
+            include_bytes!("../../../profiles/tests/data/ed25519-private.pem"),
+        )
+        .unwrap();
+        let manager = Arc::new(TokenManager::new_from_key_ring(KeyRing::new(key), None));
+        let router = get_router(handler, RejectingAuthenticator, None, None, Some(manager));
+
+        let request = Request::get(JWKS_PATH).body(Body::empty()).unwrap();
+        let response = router.clone().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let request = Request::get("/catalogs").body(Body::empty()).unwrap();
+        let response = router.oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_sharing_protocol_mounted() {
+        let store = Arc::new(InMemoryResourceStore::new());
+        let share = ShareInfo {
+            name: "share".to_string(),
+            ..Default::default()
+        };
+        store.create(share.into()).await.unwrap();
+        let router = get_router(handler(store), AnonymousAuthenticator, None, None, None);
+
+        let request = Request::get(format!("{SHARING_PREFIX}/shares"))
+            .body(Body::empty())
+            .unwrap();
+        let response = router.clone().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(body["items"][0]["name"], "share");
+
+        // the shares management API is still served at the root.
+        let request = Request::get("/shares").body(Body::empty()).unwrap();
+        let response = router.oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+}
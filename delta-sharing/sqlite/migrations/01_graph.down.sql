@@ -0,0 +1,6 @@
+drop index if exists associations_to_index;
+drop index if exists associations_tuple_index;
+drop index if exists associations_label_index;
+drop table if exists associations;
+drop index if exists objects_label_index;
+drop table if exists objects;
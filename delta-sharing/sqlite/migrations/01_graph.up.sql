@@ -0,0 +1,28 @@
+-- objects and associations mirror the graph model of the postgres store.
+-- identifiers are uuidv7 values in their hyphenated text form, so ordering by
+-- id follows creation order. Names are stored as JSON arrays of their parts.
+create table if not exists objects (
+    id text primary key not null,
+    label text not null,
+    name text collate nocase not null,
+    properties text,
+    created_at text not null,
+    updated_at text,
+    constraint unique_object_name unique (label, name)
+);
+create index if not exists objects_label_index on objects (label, name);
+
+create table if not exists associations (
+    id text primary key not null,
+    from_id text not null references objects (id),
+    label text not null,
+    to_id text not null references objects (id),
+    to_label text not null,
+    properties text,
+    created_at text not null,
+    updated_at text,
+    unique (from_id, label, to_id)
+);
+create index if not exists associations_label_index on associations (label);
+create index if not exists associations_tuple_index on associations (from_id, label, to_id);
+create index if not exists associations_to_index on associations (to_id, label);
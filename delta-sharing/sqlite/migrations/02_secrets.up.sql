@@ -0,0 +1,9 @@
+-- versioned secret values, rows are never updated in place.
+-- deleting a secret inserts a tombstone version without a value.
+create table if not exists secrets (
+    name text not null,
+    version text not null,
+    value blob,
+    created_at text not null,
+    primary key (name, version)
+);
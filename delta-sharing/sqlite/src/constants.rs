@@ -0,0 +1 @@
+pub(crate) const MAX_PAGE_SIZE: usize = 1000;
@@ -0,0 +1,74 @@
+/// A convenience type for declaring Results in the Delta Sharing libraries.
+use delta_sharing_common::Error as CommonError;
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error(transparent)]
+    Connection(sqlx::Error),
+
+    #[error(transparent)]
+    Migration(#[from] sqlx::migrate::MigrateError),
+
+    #[error("Failed to decode page token: '{0}'")]
+    DecodePageToken(#[from] base64::DecodeError),
+
+    #[error("Failed to (de)serialize column: {0}")]
+    SerDe(#[from] serde_json::Error),
+
+    #[error("Generic error: {0}")]
+    Generic(String),
+
+    #[error("Entity not found: '{0}'")]
+    EntityNotFound(String),
+
+    #[error("Already exists: '{0}'")]
+    AlreadyExists(String),
+
+    #[error("Conflict: '{0}'")]
+    Conflict(String),
+}
+
+impl Error {
+    pub fn entity_not_found(msg: impl Into<String>) -> Self {
+        Error::EntityNotFound(msg.into())
+    }
+
+    pub fn generic(msg: impl Into<String>) -> Self {
+        Error::Generic(msg.into())
+    }
+}
+
+impl From<sqlx::Error> for Error {
+    fn from(e: sqlx::Error) -> Self {
+        match &e {
+            sqlx::Error::RowNotFound => Error::EntityNotFound("Row not found".to_string()),
+            sqlx::Error::Database(db_err) => match db_err.kind() {
+                sqlx::error::ErrorKind::UniqueViolation => {
+                    Error::AlreadyExists("Unique violation".to_string())
+                }
+                sqlx::error::ErrorKind::ForeignKeyViolation => {
+                    Error::EntityNotFound("Foreign key violation".to_string())
+                }
+                _ => Error::Connection(e),
+            },
+            _ => Error::Connection(e),
+        }
+    }
+}
+
+impl From<Error> for CommonError {
+    fn from(e: Error) -> Self {
+        match e {
+            Error::Connection(e) => CommonError::generic(e.to_string()),
+            Error::Migration(e) => CommonError::generic(e.to_string()),
+            Error::DecodePageToken(e) => CommonError::InvalidArgument(e.to_string()),
+            Error::SerDe(e) => CommonError::SerDe(e),
+            Error::Generic(e) => CommonError::Generic(e),
+            Error::EntityNotFound(_) => CommonError::NotFound,
+            Error::AlreadyExists(_) => CommonError::AlreadyExists,
+            Error::Conflict(_) => CommonError::Conflict,
+        }
+    }
+}
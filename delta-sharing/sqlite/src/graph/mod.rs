@@ -0,0 +1,56 @@
+use delta_sharing_common::{AssociationLabel, ObjectLabel, ResourceIdent, ResourceRef};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+mod sharing;
+mod store;
+
+pub use store::Store as SqliteStore;
+
+/// Associations describe relationships between two objects.
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+pub struct Association {
+    id: Uuid,
+
+    /// Source object identifier.
+    pub from_id: Uuid,
+
+    /// The label / type of the association.
+    pub label: AssociationLabel,
+
+    /// Target object identifier.
+    pub to_id: Uuid,
+
+    /// The label / type of the target object.
+    pub to_label: ObjectLabel,
+
+    /// The properties of the association.
+    pub properties: Option<serde_json::Value>,
+
+    /// The time when the association was created.
+    pub created_at: chrono::DateTime<chrono::Utc>,
+
+    /// The time when the association was last updated.
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// Change to an association between two objects, see [`SqliteStore::apply_association_changes`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum AssociationWrite {
+    /// Add an association from the first to the second object with the given properties.
+    Add(Uuid, AssociationLabel, Uuid, Option<serde_json::Value>),
+    /// Replace the properties of the association from the first to the second object.
+    Update(Uuid, AssociationLabel, Uuid, Option<serde_json::Value>),
+    /// Remove the association from the first to the second object.
+    Remove(Uuid, AssociationLabel, Uuid),
+}
+
+impl Association {
+    pub fn target_ident(&self) -> ResourceIdent {
+        self.to_label.to_ident(ResourceRef::Uuid(self.to_id))
+    }
+
+    pub fn target_ref(&self) -> ResourceRef {
+        ResourceRef::Uuid(self.to_id)
+    }
+}
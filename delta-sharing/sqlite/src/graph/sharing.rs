@@ -0,0 +1,19 @@
+use delta_sharing_common::{
+    Error, ObjectLabel, Resource, ResourceRef, ResourceStore, Result, TableLocationResolver,
+};
+
+use super::SqliteStore;
+
+#[async_trait::async_trait]
+impl TableLocationResolver for SqliteStore {
+    async fn resolve(&self, table_ref: &ResourceRef) -> Result<url::Url> {
+        let ident = ObjectLabel::TableInfo.to_ident(table_ref.clone());
+        let (Resource::TableInfo(table), _) = self.get(&ident).await? else {
+            return Err(Error::NotFound);
+        };
+        let location = table
+            .storage_location
+            .ok_or_else(|| Error::generic("table has no storage location"))?;
+        Ok(url::Url::parse(&location)?)
+    }
+}
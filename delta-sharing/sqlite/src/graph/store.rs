@@ -0,0 +1,859 @@
+//! Storage layer for managing objects and associations.
+//!
+//! Implements the same graph model as the postgres store on top of a single SQLite database.
+//! Queries are checked at runtime, as the compile time checked macros are bound to a
+//! single database driver per workspace.
+
+use std::str::FromStr;
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use delta_sharing_common::api::permissions::GRANT_PERMISSIONS_PROPERTY;
+use delta_sharing_common::{
+    AssociationLabel, Object, ObjectLabel, Permission, ResourceIdent, ResourceRef, VisibilityFilter,
+};
+use sqlx::migrate::Migrator;
+use sqlx::sqlite::{SqliteConnectOptions, SqlitePool, SqlitePoolOptions};
+use sqlx::types::Json;
+use uuid::fmt::Hyphenated;
+use uuid::Uuid;
+
+use super::{Association, AssociationWrite};
+use crate::constants::MAX_PAGE_SIZE;
+use crate::pagination::{PaginateToken, V1PaginateToken};
+use crate::resources::IdentRefs as _;
+use crate::{Error, Result};
+
+static MIGRATOR: Migrator = sqlx::migrate!();
+
+/// Query parameters pushing a [`VisibilityFilter`] down into list queries.
+///
+/// Lists of values are passed as JSON arrays and expanded via `json_each`.
+#[derive(Default)]
+struct FilterParams {
+    ids: Option<String>,
+    principal: Option<String>,
+    permissions: String,
+}
+
+impl TryFrom<Option<&VisibilityFilter>> for FilterParams {
+    type Error = Error;
+
+    fn try_from(filter: Option<&VisibilityFilter>) -> Result<Self> {
+        Ok(match filter {
+            None => Self::default(),
+            Some(VisibilityFilter::Ids(ids)) => Self {
+                ids: Some(serde_json::to_string(
+                    &ids.iter().map(|id| id.hyphenated()).collect::<Vec<_>>(),
+                )?),
+                ..Default::default()
+            },
+            Some(VisibilityFilter::Grants {
+                principal,
+                permission,
+            }) => Self {
+                ids: None,
+                principal: Some(principal.clone()),
+                permissions: serde_json::to_string(&[
+                    permission.as_ref(),
+                    Permission::Manage.as_ref(),
+                ])?,
+            },
+        })
+    }
+}
+
+/// Objects as stored in the `objects` table.
+#[derive(sqlx::FromRow)]
+struct ObjectRow {
+    id: Hyphenated,
+    label: ObjectLabel,
+    name: String,
+    properties: Option<Json<serde_json::Value>>,
+    created_at: DateTime<Utc>,
+    updated_at: Option<DateTime<Utc>>,
+}
+
+impl TryFrom<ObjectRow> for Object {
+    type Error = Error;
+
+    fn try_from(row: ObjectRow) -> Result<Self> {
+        Ok(Object {
+            id: row.id.into_uuid(),
+            label: row.label,
+            name: serde_json::from_str::<Vec<String>>(&row.name)?.into(),
+            properties: row.properties.map(|p| p.0),
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        })
+    }
+}
+
+/// Associations as stored in the `associations` table.
+#[derive(sqlx::FromRow)]
+struct AssociationRow {
+    id: Hyphenated,
+    from_id: Hyphenated,
+    label: AssociationLabel,
+    to_id: Hyphenated,
+    to_label: ObjectLabel,
+    properties: Option<Json<serde_json::Value>>,
+    created_at: DateTime<Utc>,
+    updated_at: Option<DateTime<Utc>>,
+}
+
+impl From<AssociationRow> for Association {
+    fn from(row: AssociationRow) -> Self {
+        Association {
+            id: row.id.into_uuid(),
+            from_id: row.from_id.into_uuid(),
+            label: row.label,
+            to_id: row.to_id.into_uuid(),
+            to_label: row.to_label,
+            properties: row.properties.map(|p| p.0),
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }
+    }
+}
+
+/// Encode a (namespaced) name as stored in the `name` column.
+fn encode_name(name: &[String]) -> Result<String> {
+    Ok(serde_json::to_string(name)?)
+}
+
+/// Encode the prefix shared by the stored names of all objects in a namespace.
+fn encode_namespace(namespace: &[String]) -> Result<Option<String>> {
+    if namespace.is_empty() {
+        return Ok(None);
+    }
+    let mut prefix = encode_name(namespace)?;
+    prefix.pop();
+    prefix.push(',');
+    Ok(Some(prefix))
+}
+
+fn page_token(token: Option<&str>) -> Result<Option<Hyphenated>> {
+    Ok(token
+        .map(PaginateToken::<Uuid>::try_from)
+        .transpose()?
+        .map(|PaginateToken::V1(V1PaginateToken { id, .. })| id.hyphenated()))
+}
+
+fn next_page_token(
+    len: usize,
+    max_page_size: usize,
+    last: Option<(DateTime<Utc>, Uuid)>,
+) -> Option<String> {
+    (len == max_page_size)
+        .then(|| {
+            last.map(|(created_at, id)| {
+                PaginateToken::V1(V1PaginateToken { created_at, id }).to_string()
+            })
+        })
+        .flatten()
+}
+
+#[derive(Clone)]
+pub struct Store {
+    pool: Arc<SqlitePool>,
+}
+
+impl Store {
+    pub fn new(pool: Arc<SqlitePool>) -> Self {
+        Self { pool }
+    }
+
+    /// Connect to the database at the given url, creating it if it does not exist.
+    pub async fn connect(url: impl AsRef<str>) -> Result<Self> {
+        let options = SqliteConnectOptions::from_str(url.as_ref())?
+            .create_if_missing(true)
+            .foreign_keys(true);
+        let pool = SqlitePool::connect_with(options).await?;
+        Ok(Self::new(Arc::new(pool)))
+    }
+
+    /// Create a store backed by a private in-memory database.
+    ///
+    /// The database only lives as long as the store, which is mostly useful for tests.
+    pub async fn in_memory() -> Result<Self> {
+        let options = SqliteConnectOptions::from_str("sqlite::memory:")?.foreign_keys(true);
+        // every connection to an in-memory database opens a new database.
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .min_connections(1)
+            .idle_timeout(None)
+            .max_lifetime(None)
+            .connect_with(options)
+            .await?;
+        Ok(Self::new(Arc::new(pool)))
+    }
+
+    /// The connection pool used by the store.
+    pub fn pool(&self) -> Arc<SqlitePool> {
+        self.pool.clone()
+    }
+
+    pub async fn migrate(&self) -> Result<()> {
+        MIGRATOR.run(&*self.pool).await?;
+        Ok(())
+    }
+
+    /// Convert a resource reference to a UUID.
+    ///
+    /// If the reference is a name, the corresponding object is fetched from the store.
+    /// to get the UUID. The object is returned as well in case it is needed later
+    /// to avoid an additional fetch.
+    ///
+    /// # Parameters
+    /// - `reference`: The reference to convert.
+    ///
+    /// # Returns
+    /// The UUID of the reference and the object if the reference is a name.
+    ///
+    /// # Errors
+    /// In case of an undefined reference, an error is returned.
+    pub async fn ident_to_uuid(&self, reference: &ResourceIdent) -> Result<(Uuid, Option<Object>)> {
+        let (label, ident) = reference.ident();
+        match ident {
+            ResourceRef::Uuid(id) => Ok((*id, None)),
+            ResourceRef::Name(name) => {
+                let object = self.get_object_by_name(label, name).await?;
+                Ok((object.id, Some(object)))
+            }
+            ResourceRef::Undefined => Err(Error::entity_not_found("undefined")),
+        }
+    }
+
+    /// Add an object to the store.
+    ///
+    /// # Parameters
+    /// - `label`: The label of the object.
+    /// - `name`: The namespaced name of the object.
+    /// - `properties`: The properties of the object.
+    ///
+    /// # Returns
+    /// The object that was added to the store.
+    ///
+    /// # Errors
+    /// - [AlreadyExists](crate::Error::AlreadyExists): If an object with the
+    ///   same name already exists in the namespace
+    pub async fn add_object(
+        &self,
+        label: &ObjectLabel,
+        name: &[String],
+        properties: Option<serde_json::Value>,
+    ) -> Result<Object> {
+        sqlx::query_as::<_, ObjectRow>(
+            r#"
+            INSERT INTO objects ( id, label, name, properties, created_at )
+            VALUES ( ?1, ?2, ?3, ?4, ?5 )
+            RETURNING id, label, name, properties, created_at, updated_at
+            "#,
+        )
+        .bind(Uuid::now_v7().hyphenated())
+        .bind(label)
+        .bind(encode_name(name)?)
+        .bind(properties.map(Json))
+        .bind(Utc::now())
+        .fetch_one(&*self.pool)
+        .await?
+        .try_into()
+    }
+
+    /// Get an object from the store.
+    ///
+    /// # Parameters
+    /// - `id`: The globally unique identifier of the object.
+    ///
+    /// # Returns
+    /// The object with the given identifier.
+    ///
+    /// # Errors
+    /// - [EntityNotFound](crate::Error::EntityNotFound): If the object does not exist.
+    pub async fn get_object(&self, id: &Uuid) -> Result<Object> {
+        sqlx::query_as::<_, ObjectRow>(
+            r#"
+            SELECT id, label, name, properties, created_at, updated_at
+            FROM objects
+            WHERE id = ?1
+            "#,
+        )
+        .bind(id.hyphenated())
+        .fetch_one(&*self.pool)
+        .await?
+        .try_into()
+    }
+
+    /// Get an object from the store by name.
+    ///
+    /// The name of the object is unique within the namespace.
+    ///
+    /// # Parameters
+    /// - `label`: The label of the object.
+    /// - `name`: The namespaced name of the object.
+    ///
+    /// # Returns
+    /// The object with the given name.
+    ///
+    /// # Errors
+    /// - [EntityNotFound](crate::Error::EntityNotFound): If the object does not exist.
+    pub async fn get_object_by_name(&self, label: &ObjectLabel, name: &[String]) -> Result<Object> {
+        sqlx::query_as::<_, ObjectRow>(
+            r#"
+            SELECT id, label, name, properties, created_at, updated_at
+            FROM objects
+            WHERE label = ?1
+              AND name = ?2
+            "#,
+        )
+        .bind(label)
+        .bind(encode_name(name)?)
+        .fetch_one(&*self.pool)
+        .await?
+        .try_into()
+    }
+
+    /// Update an object in the store.
+    ///
+    /// # Parameters
+    /// - `id`: The globally unique identifier of the object.
+    /// - `properties`: The properties of the object.
+    /// - `revision`: If set, the object is only updated if it was last modified at this time.
+    ///
+    /// # Returns
+    /// The updated object.
+    ///
+    /// # Errors
+    /// - [EntityNotFound](crate::Error::EntityNotFound): If the object does not exist.
+    /// - [Conflict](crate::Error::Conflict): If the object was modified at another time.
+    pub async fn update_object(
+        &self,
+        id: &Uuid,
+        new_label: impl Into<Option<&ObjectLabel>>,
+        new_name: impl Into<Option<&[String]>>,
+        properties: impl Into<Option<serde_json::Value>>,
+        revision: Option<DateTime<Utc>>,
+    ) -> Result<Object> {
+        let new_name = new_name.into().map(encode_name).transpose()?;
+        let updated = sqlx::query_as::<_, ObjectRow>(
+            r#"
+            UPDATE objects
+            SET
+                label = COALESCE(?2, label),
+                name = COALESCE(?3, name),
+                properties = COALESCE(?4, properties),
+                updated_at = ?5
+            WHERE id = ?1
+              AND (?6 IS NULL OR COALESCE(updated_at, created_at) = ?6)
+            RETURNING id, label, name, properties, created_at, updated_at
+            "#,
+        )
+        .bind(id.hyphenated())
+        .bind(new_label.into())
+        .bind(new_name)
+        .bind(properties.into().map(Json))
+        .bind(Utc::now())
+        .bind(revision)
+        .fetch_optional(&*self.pool)
+        .await?;
+        match updated {
+            Some(row) => row.try_into(),
+            None => {
+                // no row matched, either the object is missing or the revision is stale.
+                self.get_object(id).await?;
+                Err(Error::Conflict(format!("object '{id}' was modified")))
+            }
+        }
+    }
+
+    /// Delete an object from the store.
+    ///
+    /// # Parameters
+    /// - `id`: The globally unique identifier of the object.
+    pub async fn delete_object(&self, id: &Uuid) -> Result<()> {
+        let mut txn = self.pool.begin().await?;
+
+        // Delete the associations.
+        sqlx::query(
+            r#"
+            DELETE FROM associations
+            WHERE from_id = ?1 OR to_id = ?1
+            "#,
+        )
+        .bind(id.hyphenated())
+        .execute(&mut *txn)
+        .await?;
+
+        // Delete the object.
+        sqlx::query(
+            r#"
+            DELETE FROM objects
+            WHERE id = ?1
+            "#,
+        )
+        .bind(id.hyphenated())
+        .execute(&mut *txn)
+        .await?;
+
+        Ok(txn.commit().await?)
+    }
+
+    /// List objects from the store.
+    ///
+    /// Returns a list of objects in the namespace. The list is paginated.
+    ///
+    /// # Parameters
+    /// - `label`: The label of the objects.
+    /// - `namespace`: The namespace of the objects.
+    /// - `filter`: Only list objects passing the visibility filter.
+    /// - `page_token`: The page token.
+    /// - `max_page_size`: The maximum page size.
+    ///
+    /// # Returns
+    /// A tuple containing the objects in the namespace and an optional next page token.
+    pub async fn list_objects(
+        &self,
+        label: &ObjectLabel,
+        namespace: &[String],
+        filter: Option<&VisibilityFilter>,
+        page_token: Option<&str>,
+        max_page_size: Option<usize>,
+    ) -> Result<(Vec<Object>, Option<String>)> {
+        let max_page_size = usize::min(max_page_size.unwrap_or(MAX_PAGE_SIZE), MAX_PAGE_SIZE);
+        let token_id = self::page_token(page_token)?;
+        let filter = FilterParams::try_from(filter)?;
+
+        // the `principal` and `granted` CTEs select the resources a principal holds a
+        // permission on, with grants inherited by children as in the postgres `GraphPolicy`.
+        // They are repeated in `list_associations`, changes must be applied to both.
+        let objects = sqlx::query_as::<_, ObjectRow>(
+            r#"
+            WITH RECURSIVE
+            principal AS (
+                SELECT id FROM objects
+                WHERE label = 'recipient_info' AND name = json_array(?7)
+            ),
+            granted AS (
+                SELECT g.to_id AS id
+                FROM associations g
+                JOIN principal p ON g.from_id = p.id
+                WHERE g.label = 'owner_of'
+                   OR ( g.label = 'has_grant' AND EXISTS (
+                        SELECT 1 FROM json_each(g.properties, ?9) gp
+                        WHERE gp.value IN (SELECT value FROM json_each(?8))
+                   ) )
+                UNION
+                SELECT c.to_id
+                FROM granted gr
+                JOIN associations c ON c.from_id = gr.id AND c.label = 'parent_of'
+            )
+            SELECT id, label, name, properties, created_at, updated_at
+            FROM objects
+            WHERE label = ?1
+                AND ( ?2 IS NULL OR substr(name, 1, length(?2)) = ?2 COLLATE NOCASE OR name = ?3 )
+                AND ( id < ?4 OR ?4 IS NULL )
+                -- Visibility
+                AND ( id IN (SELECT value FROM json_each(?6)) OR ?6 IS NULL )
+                AND ( id IN (SELECT id FROM granted) OR ?7 IS NULL )
+            ORDER BY id DESC
+            LIMIT ?5
+            "#,
+        )
+        .bind(label)
+        .bind(encode_namespace(namespace)?)
+        .bind(encode_name(namespace)?)
+        .bind(token_id)
+        .bind(max_page_size as i64)
+        .bind(filter.ids)
+        .bind(filter.principal)
+        .bind(filter.permissions)
+        .bind(format!("$.{GRANT_PERMISSIONS_PROPERTY}"))
+        .fetch_all(&*self.pool)
+        .await?
+        .into_iter()
+        .map(Object::try_from)
+        .collect::<Result<Vec<_>>>()?;
+
+        let next = next_page_token(
+            objects.len(),
+            max_page_size,
+            objects.last().map(|o| (o.created_at, o.id)),
+        );
+        Ok((objects, next))
+    }
+
+    /// Add an association to the store.
+    ///
+    /// Associations are directed edges between objects.
+    /// If an inverse association exists, it is automatically created.
+    ///
+    /// # Parameters
+    /// - `from_id`: The identifier of the source object.
+    /// - `label`: The label of the association.
+    /// - `to_id`: The identifier of the target object.
+    /// - `properties`: The properties of the association.
+    ///
+    /// # Returns
+    /// The association that was added to the store.
+    ///
+    /// # Errors
+    /// - [EntityNotFound](crate::Error::EntityNotFound): If the source or target object does not exist.
+    /// - [AlreadyExists](crate::Error::AlreadyExists): If the association already exists.
+    pub async fn add_association(
+        &self,
+        from_id: &Uuid,
+        label: &AssociationLabel,
+        to_id: &Uuid,
+        properties: impl Into<Option<serde_json::Value>>,
+    ) -> Result<Association> {
+        let mut txn = self.pool.begin().await?;
+        let association =
+            add_association(from_id, label, to_id, properties.into(), &mut txn).await?;
+        txn.commit().await?;
+        Ok(association)
+    }
+
+    /// Delete an association from the store.
+    ///
+    /// If an inverse association exists, it is automatically deleted.
+    ///
+    /// # Errors
+    /// - [EntityNotFound](crate::Error::EntityNotFound): If the association does not exist.
+    pub async fn delete_association(
+        &self,
+        from_id: &Uuid,
+        label: &AssociationLabel,
+        to_id: &Uuid,
+    ) -> Result<()> {
+        let mut txn = self.pool.begin().await?;
+        delete_association(from_id, label, to_id, &mut txn).await?;
+        txn.commit().await?;
+        Ok(())
+    }
+
+    /// Replace the properties of an association.
+    ///
+    /// If an inverse association exists, it is updated in the same transaction.
+    ///
+    /// # Errors
+    /// - [EntityNotFound](crate::Error::EntityNotFound): If the association does not exist.
+    pub async fn update_association(
+        &self,
+        from_id: &Uuid,
+        label: &AssociationLabel,
+        to_id: &Uuid,
+        properties: impl Into<Option<serde_json::Value>>,
+    ) -> Result<()> {
+        let mut txn = self.pool.begin().await?;
+        update_association(from_id, label, to_id, properties.into(), &mut txn).await?;
+        txn.commit().await?;
+        Ok(())
+    }
+
+    /// Apply several changes to associations in a single transaction.
+    ///
+    /// Inverse associations are changed along with the associations.
+    ///
+    /// # Errors
+    /// The error of the first change that could not be applied, in which case
+    /// none of the changes are applied.
+    pub async fn apply_association_changes(&self, changes: &[AssociationWrite]) -> Result<()> {
+        let mut txn = self.pool.begin().await?;
+        for change in changes {
+            match change {
+                AssociationWrite::Add(from_id, label, to_id, properties) => {
+                    add_association(from_id, label, to_id, properties.clone(), &mut txn).await?;
+                }
+                AssociationWrite::Update(from_id, label, to_id, properties) => {
+                    update_association(from_id, label, to_id, properties.clone(), &mut txn).await?;
+                }
+                AssociationWrite::Remove(from_id, label, to_id) => {
+                    delete_association(from_id, label, to_id, &mut txn).await?;
+                }
+            }
+        }
+        txn.commit().await?;
+        Ok(())
+    }
+
+    /// List associations of a specific type from an object to a set of objects.
+    ///
+    /// # Parameters
+    /// - `from_id`: The identifier of the source object.
+    /// - `label`: The label of the association.
+    /// - `to_ids`: The identifiers of the target objects.
+    ///
+    /// # Returns
+    /// The associations from the source object to the target objects.
+    pub async fn get_associations(
+        &self,
+        from_id: &Uuid,
+        label: &AssociationLabel,
+        to_ids: &[Uuid],
+        page_token: Option<&str>,
+        max_page_size: Option<usize>,
+    ) -> Result<(Vec<Association>, Option<String>)> {
+        let max_page_size = usize::min(max_page_size.unwrap_or(MAX_PAGE_SIZE), MAX_PAGE_SIZE);
+        let token_id = self::page_token(page_token)?;
+        let to_ids =
+            serde_json::to_string(&to_ids.iter().map(|id| id.hyphenated()).collect::<Vec<_>>())?;
+
+        let assocs: Vec<Association> = sqlx::query_as::<_, AssociationRow>(
+            r#"
+            SELECT id, from_id, label, to_id, to_label, properties, created_at, updated_at
+            FROM associations
+            WHERE from_id = ?1
+              AND label = ?2
+              AND to_id IN (SELECT value FROM json_each(?3))
+              -- Pagination
+              AND ( id < ?4 OR ?4 IS NULL )
+            ORDER BY id DESC
+            LIMIT ?5
+            "#,
+        )
+        .bind(from_id.hyphenated())
+        .bind(label)
+        .bind(to_ids)
+        .bind(token_id)
+        .bind(max_page_size as i64)
+        .fetch_all(&*self.pool)
+        .await?
+        .into_iter()
+        .map(Association::from)
+        .collect();
+
+        let next = next_page_token(
+            assocs.len(),
+            max_page_size,
+            assocs.last().map(|a| (a.created_at, a.id)),
+        );
+        Ok((assocs, next))
+    }
+
+    /// List associations of a specific type from an object to all objects.
+    ///
+    /// If a visibility filter is given, only associations to objects passing the filter
+    /// are listed.
+    pub async fn list_associations(
+        &self,
+        from_id: &Uuid,
+        label: &AssociationLabel,
+        target_label: Option<&ObjectLabel>,
+        filter: Option<&VisibilityFilter>,
+        page_token: Option<&str>,
+        max_page_size: Option<usize>,
+    ) -> Result<(Vec<Association>, Option<String>)> {
+        let max_page_size = usize::min(max_page_size.unwrap_or(MAX_PAGE_SIZE), MAX_PAGE_SIZE);
+        let token_id = self::page_token(page_token)?;
+        let filter = FilterParams::try_from(filter)?;
+
+        // same `principal` and `granted` CTEs as in `list_objects`, see there.
+        let assocs: Vec<Association> = sqlx::query_as::<_, AssociationRow>(
+            r#"
+            WITH RECURSIVE
+            principal AS (
+                SELECT id FROM objects
+                WHERE label = 'recipient_info' AND name = json_array(?7)
+            ),
+            granted AS (
+                SELECT g.to_id AS id
+                FROM associations g
+                JOIN principal p ON g.from_id = p.id
+                WHERE g.label = 'owner_of'
+                   OR ( g.label = 'has_grant' AND EXISTS (
+                        SELECT 1 FROM json_each(g.properties, ?9) gp
+                        WHERE gp.value IN (SELECT value FROM json_each(?8))
+                   ) )
+                UNION
+                SELECT c.to_id
+                FROM granted gr
+                JOIN associations c ON c.from_id = gr.id AND c.label = 'parent_of'
+            )
+            SELECT id, from_id, label, to_id, to_label, properties, created_at, updated_at
+            FROM associations
+            WHERE from_id = ?1
+              AND label = ?2
+              AND ( to_label = ?3 OR ?3 IS NULL )
+              -- Pagination
+              AND ( id < ?4 OR ?4 IS NULL )
+              -- Visibility
+              AND ( to_id IN (SELECT value FROM json_each(?6)) OR ?6 IS NULL )
+              AND ( to_id IN (SELECT id FROM granted) OR ?7 IS NULL )
+            ORDER BY id DESC
+            LIMIT ?5
+            "#,
+        )
+        .bind(from_id.hyphenated())
+        .bind(label)
+        .bind(target_label)
+        .bind(token_id)
+        .bind(max_page_size as i64)
+        .bind(filter.ids)
+        .bind(filter.principal)
+        .bind(filter.permissions)
+        .bind(format!("$.{GRANT_PERMISSIONS_PROPERTY}"))
+        .fetch_all(&*self.pool)
+        .await?
+        .into_iter()
+        .map(Association::from)
+        .collect();
+
+        let next = next_page_token(
+            assocs.len(),
+            max_page_size,
+            assocs.last().map(|a| (a.created_at, a.id)),
+        );
+        Ok((assocs, next))
+    }
+}
+
+async fn delete_association(
+    from_id: &Uuid,
+    label: &AssociationLabel,
+    to_id: &Uuid,
+    txn: &mut sqlx::Transaction<'_, sqlx::Sqlite>,
+) -> Result<()> {
+    let deleted = sqlx::query(
+        r#"
+        DELETE FROM associations
+        WHERE from_id = ?1 AND label = ?2 AND to_id = ?3
+        "#,
+    )
+    .bind(from_id.hyphenated())
+    .bind(label)
+    .bind(to_id.hyphenated())
+    .execute(&mut **txn)
+    .await?
+    .rows_affected();
+    if deleted == 0 {
+        return Err(crate::Error::entity_not_found("association"));
+    }
+    if let Some(inverse_label) = label.inverse() {
+        sqlx::query(
+            r#"
+            DELETE FROM associations
+            WHERE from_id = ?1 AND label = ?2 AND to_id = ?3
+            "#,
+        )
+        .bind(to_id.hyphenated())
+        .bind(inverse_label)
+        .bind(from_id.hyphenated())
+        .execute(&mut **txn)
+        .await?;
+    };
+    Ok(())
+}
+
+async fn add_association(
+    from_id: &Uuid,
+    label: &AssociationLabel,
+    to_id: &Uuid,
+    properties: Option<serde_json::Value>,
+    txn: &mut sqlx::Transaction<'_, sqlx::Sqlite>,
+) -> Result<Association> {
+    let labels: Vec<(Hyphenated, ObjectLabel)> = sqlx::query_as(
+        r#"
+        SELECT id, label
+        FROM objects
+        WHERE id = ?1 OR id = ?2
+        "#,
+    )
+    .bind(from_id.hyphenated())
+    .bind(to_id.hyphenated())
+    .fetch_all(&mut **txn)
+    .await?;
+
+    let id_map = labels
+        .into_iter()
+        .map(|(id, label)| (id.into_uuid(), label))
+        .collect::<std::collections::HashMap<_, _>>();
+    let to_label = id_map.get(to_id).ok_or(Error::entity_not_found("to_id"))?;
+    let from_label = id_map
+        .get(from_id)
+        .ok_or(Error::entity_not_found("from_id"))?;
+
+    // Add the association.
+    let association = sqlx::query_as::<_, AssociationRow>(
+        r#"
+        INSERT INTO associations ( id, from_id, label, to_id, to_label, properties, created_at )
+        VALUES ( ?1, ?2, ?3, ?4, ?5, ?6, ?7 )
+        RETURNING id, from_id, label, to_id, to_label, properties, created_at, updated_at
+        "#,
+    )
+    .bind(Uuid::now_v7().hyphenated())
+    .bind(from_id.hyphenated())
+    .bind(label)
+    .bind(to_id.hyphenated())
+    .bind(to_label)
+    .bind(properties.clone().map(Json))
+    .bind(Utc::now())
+    .fetch_one(&mut **txn)
+    .await?
+    .into();
+
+    // Add the inverse association.
+    if let Some(inverse_label) = label.inverse() {
+        sqlx::query(
+            r#"
+            INSERT INTO associations ( id, from_id, label, to_id, to_label, properties, created_at )
+            VALUES ( ?1, ?2, ?3, ?4, ?5, ?6, ?7 )
+            "#,
+        )
+        .bind(Uuid::now_v7().hyphenated())
+        .bind(to_id.hyphenated())
+        .bind(inverse_label)
+        .bind(from_id.hyphenated())
+        .bind(from_label)
+        .bind(properties.map(Json))
+        .bind(Utc::now())
+        .execute(&mut **txn)
+        .await?;
+    }
+
+    Ok(association)
+}
+
+async fn update_association(
+    from_id: &Uuid,
+    label: &AssociationLabel,
+    to_id: &Uuid,
+    properties: Option<serde_json::Value>,
+    txn: &mut sqlx::Transaction<'_, sqlx::Sqlite>,
+) -> Result<()> {
+    let updated = sqlx::query(
+        r#"
+        UPDATE associations
+        SET properties = ?4, updated_at = ?5
+        WHERE from_id = ?1 AND label = ?2 AND to_id = ?3
+        "#,
+    )
+    .bind(from_id.hyphenated())
+    .bind(label)
+    .bind(to_id.hyphenated())
+    .bind(properties.clone().map(Json))
+    .bind(Utc::now())
+    .execute(&mut **txn)
+    .await?
+    .rows_affected();
+    if updated == 0 {
+        return Err(crate::Error::entity_not_found("association"));
+    }
+    if let Some(inverse_label) = label.inverse() {
+        sqlx::query(
+            r#"
+            UPDATE associations
+            SET properties = ?4, updated_at = ?5
+            WHERE from_id = ?1 AND label = ?2 AND to_id = ?3
+            "#,
+        )
+        .bind(to_id.hyphenated())
+        .bind(inverse_label)
+        .bind(from_id.hyphenated())
+        .bind(properties.map(Json))
+        .bind(Utc::now())
+        .execute(&mut **txn)
+        .await?;
+    }
+    Ok(())
+}
@@ -0,0 +1,79 @@
+pub use crate::error::{Error, Result};
+pub use graph::*;
+
+mod constants;
+mod error;
+mod graph;
+mod pagination;
+mod resources;
+mod secrets;
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use delta_sharing_common::rest::integration::{test_catalog_router, test_credentials_router};
+    use delta_sharing_common::rest::{
+        get_catalog_router, get_credentials_router, get_external_locations_router,
+        get_schemas_router, AnonymousAuthenticator, AuthenticationLayer,
+    };
+    use delta_sharing_common::{
+        ConstantPolicy, Policy, ProvidesPolicy, ProvidesResourceStore, ProvidesSecretManager,
+        ResourceStore, SecretManager,
+    };
+
+    use super::*;
+
+    #[derive(Clone)]
+    struct Handler {
+        store: SqliteStore,
+        policy: Arc<dyn Policy>,
+    }
+
+    impl Handler {
+        async fn new() -> Self {
+            let store = SqliteStore::in_memory().await.unwrap();
+            store.migrate().await.unwrap();
+            Self {
+                store,
+                policy: Arc::new(ConstantPolicy::default()),
+            }
+        }
+    }
+
+    impl ProvidesResourceStore for Handler {
+        fn store(&self) -> &dyn ResourceStore {
+            &self.store
+        }
+    }
+
+    impl ProvidesPolicy for Handler {
+        fn policy(&self) -> &Arc<dyn Policy> {
+            &self.policy
+        }
+    }
+
+    impl ProvidesSecretManager for Handler {
+        fn secret_manager(&self) -> &dyn SecretManager {
+            &self.store
+        }
+    }
+
+    #[tokio::test]
+    async fn test_catalog() {
+        let handler = Handler::new().await;
+        let router = get_catalog_router(handler.clone())
+            .merge(get_schemas_router(handler))
+            .layer(AuthenticationLayer::new(AnonymousAuthenticator));
+        test_catalog_router(router.clone()).await;
+    }
+
+    #[tokio::test]
+    async fn test_credentials() {
+        let handler = Handler::new().await;
+        let router = get_credentials_router(handler.clone())
+            .merge(get_external_locations_router(handler))
+            .layer(AuthenticationLayer::new(AnonymousAuthenticator));
+        test_credentials_router(router).await;
+    }
+}
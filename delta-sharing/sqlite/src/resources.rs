@@ -0,0 +1,338 @@
+use delta_sharing_common::models::PropertyMap;
+use delta_sharing_common::{
+    link_parent, AssociationChange, AssociationLabel, Error, Object, ObjectLabel, Resource,
+    ResourceIdent, ResourceName, ResourceRef, ResourceStore, Result, VisibilityFilter,
+    EMPTY_RESOURCE_NAME,
+};
+use itertools::Itertools;
+
+use crate::{AssociationWrite, SqliteStore};
+
+pub trait IdentRefs {
+    fn ident(&self) -> (&ObjectLabel, &ResourceRef);
+}
+
+impl IdentRefs for ResourceIdent {
+    fn ident(&self) -> (&ObjectLabel, &ResourceRef) {
+        (self.as_ref(), self.as_ref())
+    }
+}
+
+#[async_trait::async_trait]
+impl ResourceStore for SqliteStore {
+    /// Get a resource by its identifier.
+    ///
+    /// # Arguments
+    /// - `id`: The identifier of the resource to get.
+    ///
+    /// # Returns
+    /// The resource with the given identifier.
+    async fn get(&self, id: &ResourceIdent) -> Result<(Resource, ResourceRef)> {
+        match id.as_ref() {
+            ResourceRef::Uuid(uuid) => Ok((self.get_object(uuid).await?.try_into()?, id.into())),
+            ResourceRef::Name(name) => {
+                let object = self.get_object_by_name(id.as_ref(), name).await?;
+                let id_new = ResourceRef::Uuid(object.id);
+                Ok((object.try_into()?, id_new))
+            }
+            ResourceRef::Undefined => Err(Error::generic("Cannot get undefined resource")),
+        }
+    }
+
+    /// List resources.
+    ///
+    /// List resources in the store that are children of the given resource.
+    /// If the Reference inside the ResourceIdent is [Undefined](crate::ResourceRef::Undefined),
+    /// the root of the store is used and resources of the specified type are listed.
+    ///
+    /// # Arguments
+    /// - `root`: The root resource to list children of.
+    /// - `max_results`: The maximum number of results to return.
+    /// - `page_token`: The token to use to get the next page of results.
+    async fn list(
+        &self,
+        label: &ObjectLabel,
+        namespace: Option<&ResourceName>,
+        filter: Option<&VisibilityFilter>,
+        max_results: Option<usize>,
+        page_token: Option<String>,
+    ) -> Result<(Vec<Resource>, Option<String>)> {
+        let namespace = namespace.unwrap_or_else(|| &EMPTY_RESOURCE_NAME);
+        let objects = self
+            .list_objects(label, namespace, filter, page_token.as_deref(), max_results)
+            .await?;
+        Ok((
+            objects
+                .0
+                .into_iter()
+                .map(|object| object.try_into())
+                .try_collect()?,
+            objects.1,
+        ))
+    }
+
+    /// Create a new resource.
+    ///
+    /// # Arguments
+    /// - `resource`: The resource to create.
+    ///
+    /// # Returns
+    /// The created resource.
+    async fn create(&self, resource: Resource) -> Result<(Resource, ResourceRef)> {
+        let object: Object = resource.try_into()?;
+        let object = self
+            .add_object(&object.label, &object.name, object.properties)
+            .await?;
+        let ident = object.label.to_ident(object.id);
+        if let Err(err) = link_parent(self, &object.label, &object.name, &ident).await {
+            // resources without a link to their parent do not inherit grants.
+            if let Err(rollback) = self.delete_object(&object.id).await {
+                tracing::error!("failed to roll back {:?}: {}", ident, rollback);
+            }
+            return Err(err);
+        }
+        let id = ResourceRef::Uuid(object.id);
+        Ok((object.try_into()?, id))
+    }
+
+    /// Delete a resource by its identifier.
+    ///
+    /// # Arguments
+    /// - `id`: The identifier of the resource to delete.
+    async fn delete(&self, id: &ResourceIdent) -> Result<()> {
+        match id.as_ref() {
+            ResourceRef::Uuid(uuid) => self.delete_object(uuid).await?,
+            ResourceRef::Name(name) => {
+                let obj = self.get_object_by_name(id.as_ref(), name).await?;
+                self.delete_object(&obj.id).await?;
+            }
+            ResourceRef::Undefined => {
+                return Err(Error::generic("Cannot delete undefined resource"))
+            }
+        };
+        Ok(())
+    }
+
+    /// Update a resource.
+    ///
+    /// # Arguments
+    /// - `resource`: The resource to update.
+    ///
+    /// # Returns
+    /// The updated resource.
+    async fn update(
+        &self,
+        id: &ResourceIdent,
+        resource: Resource,
+    ) -> Result<(Resource, ResourceRef)> {
+        let (uuid, previous) = self.ident_to_uuid(id).await?;
+        let previous = match previous {
+            Some(previous) => previous,
+            None => self.get_object(&uuid).await?,
+        };
+        let revision = resource.etag().map(Object::parse_etag).transpose()?;
+        let object: Object = resource.try_into()?;
+        let object = self
+            .update_object(
+                &uuid,
+                Some(&object.label),
+                Some(object.name.as_ref()),
+                object.properties,
+                revision,
+            )
+            .await?;
+        if object.name != previous.name {
+            let ident = object.label.to_ident(uuid);
+            link_parent(self, &object.label, &object.name, &ident).await?;
+        }
+        Ok((object.try_into()?, uuid.into()))
+    }
+
+    /// Add an association between two resources.
+    async fn add_association(
+        &self,
+        from: &ResourceIdent,
+        to: &ResourceIdent,
+        label: &AssociationLabel,
+        properties: Option<PropertyMap>,
+    ) -> Result<()> {
+        let from_id = match from.as_ref() {
+            ResourceRef::Uuid(uuid) => *uuid,
+            ResourceRef::Name(name) => self.get_object_by_name(from.as_ref(), name).await?.id,
+            ResourceRef::Undefined => {
+                return Err(Error::generic(
+                    "Cannot add association to undefined resource",
+                ))
+            }
+        };
+        let to_id = match to.as_ref() {
+            ResourceRef::Uuid(uuid) => *uuid,
+            ResourceRef::Name(name) => self.get_object_by_name(to.as_ref(), name).await?.id,
+            ResourceRef::Undefined => {
+                return Err(Error::generic(
+                    "Cannot add association to undefined resource",
+                ))
+            }
+        };
+        self.add_association(
+            &from_id,
+            label,
+            &to_id,
+            properties.map(|p| serde_json::Value::Object(p.into_iter().collect())),
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn remove_association(
+        &self,
+        from: &ResourceIdent,
+        to: &ResourceIdent,
+        label: &AssociationLabel,
+    ) -> Result<()> {
+        let (from_id, _) = self.ident_to_uuid(from).await?;
+        let (to_id, _) = self.ident_to_uuid(to).await?;
+        self.delete_association(&from_id, label, &to_id).await?;
+        Ok(())
+    }
+
+    async fn get_association(
+        &self,
+        from: &ResourceIdent,
+        to: &ResourceIdent,
+        label: &AssociationLabel,
+    ) -> Result<Option<PropertyMap>> {
+        let (from_id, _) = self.ident_to_uuid(from).await?;
+        let (to_id, _) = self.ident_to_uuid(to).await?;
+        let (associations, _) = self
+            .get_associations(&from_id, label, &[to_id], None, Some(1))
+            .await?;
+        let association = associations.into_iter().next().ok_or(Error::NotFound)?;
+        Ok(association.properties.and_then(|p| match p {
+            serde_json::Value::Object(map) => Some(map.into_iter().collect()),
+            _ => None,
+        }))
+    }
+
+    async fn apply_association_changes(&self, changes: &[AssociationChange]) -> Result<()> {
+        let properties = |p: &Option<PropertyMap>| {
+            p.clone()
+                .map(|p| serde_json::Value::Object(p.into_iter().collect()))
+        };
+        let mut writes = Vec::with_capacity(changes.len());
+        for change in changes {
+            writes.push(match change {
+                AssociationChange::Add {
+                    from,
+                    to,
+                    label,
+                    properties: props,
+                } => AssociationWrite::Add(
+                    self.ident_to_uuid(from).await?.0,
+                    label.clone(),
+                    self.ident_to_uuid(to).await?.0,
+                    properties(props),
+                ),
+                AssociationChange::Update {
+                    from,
+                    to,
+                    label,
+                    properties: props,
+                } => AssociationWrite::Update(
+                    self.ident_to_uuid(from).await?.0,
+                    label.clone(),
+                    self.ident_to_uuid(to).await?.0,
+                    properties(props),
+                ),
+                AssociationChange::Remove { from, to, label } => AssociationWrite::Remove(
+                    self.ident_to_uuid(from).await?.0,
+                    label.clone(),
+                    self.ident_to_uuid(to).await?.0,
+                ),
+            });
+        }
+        Ok(self.apply_association_changes(&writes).await?)
+    }
+
+    async fn update_association(
+        &self,
+        from: &ResourceIdent,
+        to: &ResourceIdent,
+        label: &AssociationLabel,
+        properties: Option<PropertyMap>,
+    ) -> Result<()> {
+        let (from_id, _) = self.ident_to_uuid(from).await?;
+        let (to_id, _) = self.ident_to_uuid(to).await?;
+        self.update_association(
+            &from_id,
+            label,
+            &to_id,
+            properties.map(|p| serde_json::Value::Object(p.into_iter().collect())),
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn get_associations(
+        &self,
+        from: &ResourceIdent,
+        to: &[ResourceIdent],
+        label: &AssociationLabel,
+    ) -> Result<Vec<Option<PropertyMap>>> {
+        let (from_id, _) = self.ident_to_uuid(from).await?;
+        let mut to_ids = Vec::with_capacity(to.len());
+        for ident in to {
+            to_ids.push(self.ident_to_uuid(ident).await?.0);
+        }
+        let mut properties = std::collections::HashMap::with_capacity(to_ids.len());
+        let mut page_token = None;
+        loop {
+            let (associations, next) = self
+                .get_associations(&from_id, label, &to_ids, page_token.as_deref(), None)
+                .await?;
+            properties.extend(associations.into_iter().map(|a| (a.to_id, a.properties)));
+            page_token = next;
+            if page_token.is_none() {
+                break;
+            }
+        }
+        to_ids
+            .iter()
+            .map(|id| {
+                let props = properties.remove(id).ok_or(Error::NotFound)?;
+                Ok(props.and_then(|p| match p {
+                    serde_json::Value::Object(map) => Some(map.into_iter().collect()),
+                    _ => None,
+                }))
+            })
+            .collect()
+    }
+
+    async fn list_associations(
+        &self,
+        resource: &ResourceIdent,
+        label: &AssociationLabel,
+        target_label: Option<&ResourceIdent>,
+        filter: Option<&VisibilityFilter>,
+        max_results: Option<usize>,
+        page_token: Option<String>,
+    ) -> Result<(Vec<ResourceIdent>, Option<String>)> {
+        let target_label = target_label.map(|r| r.ident().0);
+        let (target_id, _) = self.ident_to_uuid(resource).await?;
+        let (associations, token) = self
+            .list_associations(
+                &target_id,
+                label,
+                target_label,
+                filter,
+                page_token.as_deref(),
+                max_results,
+            )
+            .await?;
+        let idents = associations
+            .into_iter()
+            .map(|assoc| assoc.to_label.to_ident(assoc.to_id))
+            .collect();
+        Ok((idents, token))
+    }
+}
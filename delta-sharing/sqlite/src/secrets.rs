@@ -0,0 +1,158 @@
+//! Versioned secret storage.
+//!
+//! Secrets are kept in the same database as the resources. Every write adds a new
+//! version, deleting a secret records a tombstone version which hides all earlier ones.
+use bytes::Bytes;
+use chrono::Utc;
+use delta_sharing_common::{Error as CommonError, Result as CommonResult, SecretManager};
+use uuid::fmt::Hyphenated;
+use uuid::Uuid;
+
+use crate::{Error, SqliteStore};
+
+/// Condition that the latest version of secret `?1` exists and is not a tombstone.
+const IS_LIVE: &str = r#"
+    SELECT 1 FROM (
+        SELECT value FROM secrets
+        WHERE name = ?1
+        ORDER BY version DESC
+        LIMIT 1
+    ) latest
+    WHERE latest.value IS NOT NULL
+"#;
+
+impl SqliteStore {
+    async fn insert_secret_version(
+        &self,
+        secret_name: &str,
+        value: Option<&[u8]>,
+        condition: &str,
+    ) -> CommonResult<Option<Uuid>> {
+        let query = format!(
+            r#"
+            INSERT INTO secrets ( name, version, value, created_at )
+            SELECT ?1, ?2, ?3, ?4
+            WHERE {condition} ( {IS_LIVE} )
+            RETURNING version
+            "#
+        );
+        let version: Option<Hyphenated> = sqlx::query_scalar(&query)
+            .bind(secret_name)
+            .bind(Uuid::now_v7().hyphenated())
+            .bind(value)
+            .bind(Utc::now())
+            .fetch_optional(&*self.pool())
+            .await
+            .map_err(Error::from)?;
+        Ok(version.map(Hyphenated::into_uuid))
+    }
+}
+
+#[async_trait::async_trait]
+impl SecretManager for SqliteStore {
+    async fn get_secret(&self, secret_name: &str) -> CommonResult<(Uuid, Bytes)> {
+        let record: Option<(Hyphenated, Option<Vec<u8>>)> = sqlx::query_as(
+            r#"
+            SELECT version, value
+            FROM secrets
+            WHERE name = ?1
+            ORDER BY version DESC
+            LIMIT 1
+            "#,
+        )
+        .bind(secret_name)
+        .fetch_optional(&*self.pool())
+        .await
+        .map_err(Error::from)?;
+        match record {
+            Some((version, Some(value))) => Ok((version.into_uuid(), value.into())),
+            _ => Err(CommonError::NotFound),
+        }
+    }
+
+    async fn get_secret_version(&self, secret_name: &str, version: Uuid) -> CommonResult<Bytes> {
+        let value: Option<Vec<u8>> = sqlx::query_scalar(
+            r#"
+            SELECT value
+            FROM secrets s
+            WHERE name = ?1 AND version = ?2 AND value IS NOT NULL
+              AND NOT EXISTS (
+                SELECT 1 FROM secrets t
+                WHERE t.name = s.name AND t.version > s.version AND t.value IS NULL
+              )
+            "#,
+        )
+        .bind(secret_name)
+        .bind(version.hyphenated())
+        .fetch_optional(&*self.pool())
+        .await
+        .map_err(Error::from)?;
+        value.map(Bytes::from).ok_or(CommonError::NotFound)
+    }
+
+    async fn create_secret(&self, secret_name: &str, secret_value: Bytes) -> CommonResult<Uuid> {
+        // a secret may be re-created after it was deleted.
+        self.insert_secret_version(secret_name, Some(&secret_value), "NOT EXISTS")
+            .await?
+            .ok_or(CommonError::AlreadyExists)
+    }
+
+    async fn update_secret(&self, secret_name: &str, secret_value: Bytes) -> CommonResult<Uuid> {
+        self.insert_secret_version(secret_name, Some(&secret_value), "EXISTS")
+            .await?
+            .ok_or(CommonError::NotFound)
+    }
+
+    async fn delete_secret(&self, secret_name: &str) -> CommonResult<()> {
+        self.insert_secret_version(secret_name, None, "EXISTS")
+            .await?
+            .ok_or(CommonError::NotFound)?;
+        Ok(())
+    }
+
+    async fn put_secret_version(
+        &self,
+        secret_name: &str,
+        current: Option<Uuid>,
+        version: Uuid,
+        secret_value: Bytes,
+    ) -> CommonResult<()> {
+        // the latest version, including tombstones, must be `current` and precede `version`.
+        let inserted: Option<Hyphenated> = sqlx::query_scalar(
+            r#"
+            INSERT INTO secrets ( name, version, value, created_at )
+            SELECT ?1, ?2, ?3, ?4
+            WHERE NOT EXISTS (
+                SELECT 1 FROM secrets
+                WHERE name = ?1 AND version >= ?2
+            )
+            AND (
+                SELECT CASE WHEN value IS NULL THEN NULL ELSE version END
+                FROM secrets
+                WHERE name = ?1
+                ORDER BY version DESC
+                LIMIT 1
+            ) IS ?5
+            RETURNING version
+            "#,
+        )
+        .bind(secret_name)
+        .bind(version.hyphenated())
+        .bind(secret_value.as_ref())
+        .bind(Utc::now())
+        .bind(current.map(|v| v.hyphenated()))
+        .fetch_optional(&*self.pool())
+        .await
+        .map_err(Error::from)?;
+        if inserted.is_some() {
+            return Ok(());
+        }
+        // tell apart why the version was not written.
+        match self.get_secret(secret_name).await {
+            Ok(_) if current.is_none() => Err(CommonError::AlreadyExists),
+            Err(CommonError::NotFound) if current.is_some() => Err(CommonError::NotFound),
+            Ok(_) | Err(CommonError::NotFound) => Err(CommonError::Conflict),
+            Err(err) => Err(err),
+        }
+    }
+}
@@ -0,0 +1,314 @@
+use delta_sharing_common::api::permissions::GRANT_PERMISSIONS_PROPERTY;
+use delta_sharing_common::{AssociationLabel, ObjectLabel, Permission, VisibilityFilter};
+use delta_sharing_sqlite::{Error, SqliteStore};
+use uuid::Uuid;
+
+async fn store() -> SqliteStore {
+    let store = SqliteStore::in_memory().await.unwrap();
+    store.migrate().await.unwrap();
+    store
+}
+
+#[tokio::test]
+async fn test_objects() -> Result<(), Box<dyn std::error::Error + 'static>> {
+    let store = store().await;
+
+    let object = store
+        .add_object(
+            &ObjectLabel::SharingTable,
+            &["namespace".to_string(), "table_name".to_string()],
+            Some(serde_json::json!({ "key": "value" })),
+        )
+        .await?;
+    assert_eq!(object.label, ObjectLabel::SharingTable);
+    assert_eq!(
+        object.name,
+        vec!["namespace".to_string(), "table_name".to_string()].into()
+    );
+
+    // Adding the same object should fail.
+    let res = store
+        .add_object(
+            &ObjectLabel::SharingTable,
+            &["namespace".to_string(), "table_name".to_string()],
+            Some(serde_json::json!({ "key": "value" })),
+        )
+        .await;
+    assert!(matches!(res, Err(Error::AlreadyExists(_))));
+
+    let object = store.get_object(&object.id).await?;
+    assert_eq!(object.label, ObjectLabel::SharingTable);
+    assert_eq!(
+        object.name,
+        vec!["namespace".to_string(), "table_name".to_string()].into()
+    );
+    assert_eq!(
+        object.properties,
+        Some(serde_json::json!({ "key": "value" }))
+    );
+
+    // Test get_object_by_name
+    let object = store
+        .get_object_by_name(
+            &ObjectLabel::SharingTable,
+            &["namespace".to_string(), "table_name".to_string()],
+        )
+        .await?;
+    assert_eq!(object.label, ObjectLabel::SharingTable);
+    assert_eq!(
+        object.name,
+        vec!["namespace".to_string(), "table_name".to_string()].into()
+    );
+
+    let object = store
+        .update_object(
+            &object.id,
+            None,
+            None,
+            serde_json::json!({ "key": "value2" }),
+            None,
+        )
+        .await?;
+    assert_eq!(
+        object.properties,
+        Some(serde_json::json!({ "key": "value2" }))
+    );
+
+    // Updating an object with a non-existent ID should fail.
+    let res = store
+        .update_object(&Uuid::new_v4(), None, None, serde_json::json!({}), None)
+        .await;
+    assert!(matches!(res, Err(Error::EntityNotFound(_))));
+
+    store.delete_object(&object.id).await?;
+    let res = store.get_object(&object.id).await;
+    assert!(matches!(res, Err(Error::EntityNotFound(_))));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_associations() -> Result<(), Box<dyn std::error::Error + 'static>> {
+    let store = store().await;
+
+    let object1 = store
+        .add_object(
+            &ObjectLabel::SharingTable,
+            &["namespace".to_string(), "table_name1".to_string()],
+            Some(serde_json::json!({ "key": "value" })),
+        )
+        .await?;
+    let object2 = store
+        .add_object(
+            &ObjectLabel::SharingTable,
+            &["namespace".to_string(), "table_name2".to_string()],
+            Some(serde_json::json!({ "key": "value" })),
+        )
+        .await?;
+
+    let association = store
+        .add_association(
+            &object1.id,
+            &AssociationLabel::HasPart,
+            &object2.id,
+            serde_json::json!({ "key": "value" }),
+        )
+        .await?;
+    assert_eq!(association.label, AssociationLabel::HasPart);
+    assert_eq!(association.from_id, object1.id);
+    assert_eq!(association.to_id, object2.id);
+
+    // Adding the same association should fail.
+    let res = store
+        .add_association(
+            &object1.id,
+            &AssociationLabel::HasPart,
+            &object2.id,
+            serde_json::json!({ "key": "value" }),
+        )
+        .await;
+    assert!(matches!(res, Err(Error::AlreadyExists(_))));
+
+    // Adding an association with a non-existent source object should fail.
+    let res = store
+        .add_association(
+            &Uuid::new_v4(),
+            &AssociationLabel::HasPart,
+            &object2.id,
+            serde_json::json!({}),
+        )
+        .await;
+    assert!(matches!(res, Err(Error::EntityNotFound(_))));
+
+    // Adding an association with a non-existent target object should fail.
+    let res = store
+        .add_association(
+            &object1.id,
+            &AssociationLabel::HasPart,
+            &Uuid::new_v4(),
+            serde_json::json!({}),
+        )
+        .await;
+    assert!(matches!(res, Err(Error::EntityNotFound(_))));
+
+    let associations = store
+        .get_associations(
+            &object1.id,
+            &AssociationLabel::HasPart,
+            &[object2.id],
+            None,
+            None,
+        )
+        .await?;
+    assert_eq!(associations.0.len(), 1);
+    assert_eq!(associations.0[0].label, AssociationLabel::HasPart);
+    assert_eq!(associations.0[0].from_id, object1.id);
+    assert_eq!(associations.0[0].to_id, object2.id);
+
+    // assert inverse association
+    let associations = store
+        .get_associations(
+            &object2.id,
+            &AssociationLabel::PartOf,
+            &[object1.id],
+            None,
+            None,
+        )
+        .await?;
+    assert_eq!(associations.0.len(), 1);
+    assert_eq!(associations.0[0].label, AssociationLabel::PartOf);
+    assert_eq!(associations.0[0].from_id, object2.id);
+    assert_eq!(associations.0[0].to_id, object1.id);
+
+    store
+        .delete_association(&object1.id, &AssociationLabel::HasPart, &object2.id)
+        .await?;
+    let associations = store
+        .get_associations(
+            &object1.id,
+            &AssociationLabel::HasPart,
+            &[object2.id],
+            None,
+            None,
+        )
+        .await?;
+    assert!(associations.0.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_list_objects() -> Result<(), Box<dyn std::error::Error + 'static>> {
+    let store = store().await;
+
+    let name = |parts: &[&str]| parts.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+    let mut schemas = Vec::new();
+    for schema in ["a", "b", "c"] {
+        let object = store
+            .add_object(&ObjectLabel::SchemaInfo, &name(&["catalog", schema]), None)
+            .await?;
+        schemas.push(object.id);
+    }
+    store
+        .add_object(&ObjectLabel::SchemaInfo, &name(&["other", "a"]), None)
+        .await?;
+
+    // names of namespaces are matched case-insensitively.
+    let (objects, _) = store
+        .list_objects(
+            &ObjectLabel::SchemaInfo,
+            &name(&["Catalog"]),
+            None,
+            None,
+            None,
+        )
+        .await?;
+    assert_eq!(objects.len(), 3);
+    let (objects, _) = store
+        .list_objects(&ObjectLabel::SchemaInfo, &[], None, None, None)
+        .await?;
+    assert_eq!(objects.len(), 4);
+
+    // pages are returned newest first.
+    let (page, token) = store
+        .list_objects(
+            &ObjectLabel::SchemaInfo,
+            &name(&["catalog"]),
+            None,
+            None,
+            Some(2),
+        )
+        .await?;
+    assert_eq!(
+        page.iter().map(|o| o.id).collect::<Vec<_>>(),
+        vec![schemas[2], schemas[1]]
+    );
+    let (page, token) = store
+        .list_objects(
+            &ObjectLabel::SchemaInfo,
+            &name(&["catalog"]),
+            None,
+            token.as_deref(),
+            Some(2),
+        )
+        .await?;
+    assert_eq!(
+        page.iter().map(|o| o.id).collect::<Vec<_>>(),
+        vec![schemas[0]]
+    );
+    assert!(token.is_none());
+
+    // visibility via ids and grants on a parent.
+    let filter = VisibilityFilter::Ids([schemas[0]].into_iter().collect());
+    let (objects, _) = store
+        .list_objects(&ObjectLabel::SchemaInfo, &[], Some(&filter), None, None)
+        .await?;
+    assert_eq!(objects.len(), 1);
+
+    let alice = store
+        .add_object(&ObjectLabel::RecipientInfo, &name(&["alice"]), None)
+        .await?;
+    let catalog = store
+        .add_object(&ObjectLabel::CatalogInfo, &name(&["catalog"]), None)
+        .await?;
+    store
+        .add_association(&catalog.id, &AssociationLabel::ParentOf, &schemas[1], None)
+        .await?;
+    store
+        .add_association(
+            &alice.id,
+            &AssociationLabel::HasGrant,
+            &catalog.id,
+            serde_json::json!({ GRANT_PERMISSIONS_PROPERTY: ["read"] }),
+        )
+        .await?;
+    let filter = |permission| VisibilityFilter::Grants {
+        principal: "alice".to_string(),
+        permission,
+    };
+    let (objects, _) = store
+        .list_objects(
+            &ObjectLabel::SchemaInfo,
+            &[],
+            Some(&filter(Permission::Read)),
+            None,
+            None,
+        )
+        .await?;
+    assert_eq!(
+        objects.iter().map(|o| o.id).collect::<Vec<_>>(),
+        vec![schemas[1]]
+    );
+    let (objects, _) = store
+        .list_objects(
+            &ObjectLabel::SchemaInfo,
+            &[],
+            Some(&filter(Permission::Write)),
+            None,
+            None,
+        )
+        .await?;
+    assert!(objects.is_empty());
+
+    Ok(())
+}
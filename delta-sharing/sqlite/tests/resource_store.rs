@@ -0,0 +1,19 @@
+use delta_sharing_common::memory::InMemoryResourceStore;
+use delta_sharing_common::testing::{resource_store_conformance, resource_store_equivalence};
+use delta_sharing_sqlite::SqliteStore;
+
+async fn store() -> SqliteStore {
+    let store = SqliteStore::in_memory().await.unwrap();
+    store.migrate().await.unwrap();
+    store
+}
+
+#[tokio::test]
+async fn test_conformance() {
+    resource_store_conformance(store).await;
+}
+
+#[tokio::test]
+async fn test_equivalence() {
+    resource_store_equivalence(|| async { InMemoryResourceStore::new() }, store, 64).await;
+}
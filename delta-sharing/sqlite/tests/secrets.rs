@@ -0,0 +1,123 @@
+use bytes::Bytes;
+use delta_sharing_common::{Error, SecretManager};
+use delta_sharing_sqlite::SqliteStore;
+use uuid::Uuid;
+
+#[tokio::test]
+async fn test_secrets() -> Result<(), Box<dyn std::error::Error + 'static>> {
+    let manager = SqliteStore::in_memory().await?;
+    manager.migrate().await?;
+
+    let v1 = manager
+        .create_secret("secret", Bytes::from_static(b"v1"))
+        .await?;
+    assert!(matches!(
+        manager
+            .create_secret("secret", Bytes::from_static(b"other"))
+            .await,
+        Err(Error::AlreadyExists)
+    ));
+    let v2 = manager
+        .update_secret("secret", Bytes::from_static(b"v2"))
+        .await?;
+    assert!(v2 > v1);
+
+    assert_eq!(
+        manager.get_secret("secret").await?,
+        (v2, Bytes::from_static(b"v2"))
+    );
+    assert_eq!(
+        manager.get_secret_version("secret", v1).await?,
+        Bytes::from_static(b"v1")
+    );
+
+    // deleting hides all versions, but the secret can be created again.
+    manager.delete_secret("secret").await?;
+    assert!(matches!(
+        manager.get_secret("secret").await,
+        Err(Error::NotFound)
+    ));
+    assert!(manager.get_secret_version("secret", v1).await.is_err());
+    assert!(manager.delete_secret("secret").await.is_err());
+    assert!(manager
+        .update_secret("secret", Bytes::from_static(b"v3"))
+        .await
+        .is_err());
+    let v3 = manager
+        .create_secret("secret", Bytes::from_static(b"v3"))
+        .await?;
+    assert_eq!(
+        manager.get_secret("secret").await?,
+        (v3, Bytes::from_static(b"v3"))
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_put_secret_version() -> Result<(), Box<dyn std::error::Error + 'static>> {
+    let manager = SqliteStore::in_memory().await?;
+    manager.migrate().await?;
+
+    let v1 = Uuid::now_v7();
+    manager
+        .put_secret_version("secret", None, v1, Bytes::from_static(b"v1"))
+        .await?;
+    assert!(matches!(
+        manager
+            .put_secret_version("secret", None, Uuid::now_v7(), Bytes::from_static(b"other"))
+            .await,
+        Err(Error::AlreadyExists)
+    ));
+    let v2 = Uuid::now_v7();
+    manager
+        .put_secret_version("secret", Some(v1), v2, Bytes::from_static(b"v2"))
+        .await?;
+    assert_eq!(
+        manager.get_secret("secret").await?,
+        (v2, Bytes::from_static(b"v2"))
+    );
+
+    // writes based on a superseded version conflict, as do versions sorting before the latest.
+    assert!(matches!(
+        manager
+            .put_secret_version(
+                "secret",
+                Some(v1),
+                Uuid::now_v7(),
+                Bytes::from_static(b"v3")
+            )
+            .await,
+        Err(Error::Conflict)
+    ));
+    assert!(matches!(
+        manager
+            .put_secret_version("secret", Some(v2), v1, Bytes::from_static(b"v3"))
+            .await,
+        Err(Error::Conflict)
+    ));
+    assert!(matches!(
+        manager
+            .put_secret_version(
+                "unknown",
+                Some(v2),
+                Uuid::now_v7(),
+                Bytes::from_static(b"v3")
+            )
+            .await,
+        Err(Error::NotFound)
+    ));
+
+    // deleted secrets can be created again.
+    manager.delete_secret("secret").await?;
+    let v3 = Uuid::now_v7();
+    manager
+        .put_secret_version("secret", None, v3, Bytes::from_static(b"v3"))
+        .await?;
+    assert_eq!(
+        manager.get_secret("secret").await?,
+        (v3, Bytes::from_static(b"v3"))
+    );
+
+    Ok(())
+}